@@ -1,4 +1,39 @@
 library libsql_dart;
 
 export 'src/libsql_client.dart';
-export 'src/rust/api/transaction.dart' show LibsqlTransactionBehavior;
+export 'src/rows.dart';
+export 'src/rust/api/connection.dart' show LibsqlCollation, LibsqlConnection;
+export 'src/rust/api/libsql.dart'
+    show ConnectArgs, LibsqlCancelToken, LibsqlOpenFlags, connect, connectPool;
+export 'src/rust/api/pool.dart' show LibsqlPool;
+export 'src/rust/api/rows.dart' show LibsqlRows;
+export 'src/rust/api/savepoint.dart' show LibsqlSavepoint;
+export 'src/rust/api/statement.dart' show LibsqlStatement;
+export 'src/rust/api/transaction.dart'
+    show LibsqlTransaction, LibsqlTransactionBehavior;
+export 'src/rust/utils/changes.dart' show LibsqlUpdate, LibsqlUpdateOperation;
+export 'src/rust/utils/error.dart'
+    show LibsqlError, LibsqlErrorKind, LibsqlSyncConflict;
+export 'src/rust/utils/params.dart'
+    show LibsqlBatchStatement, LibsqlDateTimeFormat, LibsqlParams, LibsqlValue;
+export 'src/rust/utils/result.dart'
+    show
+        BatchResult,
+        ColumnarQueryResult,
+        ExecuteResult,
+        LibsqlColumn,
+        QueryResult,
+        SyncResult;
+export 'src/rust/utils/retry.dart' show LibsqlBusyRetry;
+export 'src/rust/utils/return_value.dart'
+    show LibsqlDateTimeUnit, LibsqlDecodeMode, LibsqlReturnValue;
+export 'src/rust/utils/sync.dart'
+    show
+        LibsqlSyncConflictPolicy,
+        LibsqlSyncEvent,
+        LibsqlSyncEvent_Failed,
+        LibsqlSyncEvent_Synced,
+        LibsqlSyncStatus,
+        LibsqlSyncTrigger;
+export 'src/statement.dart';
+export 'src/transaction.dart';
@@ -1,8 +1,19 @@
+import 'dart:convert';
 import 'dart:typed_data';
 
 import 'package:libsql_dart/src/rust/utils/params.dart';
+import 'package:libsql_dart/src/rust/utils/return_value.dart';
 
-LibsqlValue toLibsqlValue(dynamic value) {
+LibsqlValue toLibsqlValue(
+  dynamic value, {
+  LibsqlDateTimeFormat dateTimeFormat = LibsqlDateTimeFormat.unixMillis,
+}) {
+  if (value == null) {
+    return const LibsqlValue.null_();
+  }
+  if (value is bool) {
+    return LibsqlValue.bool(value);
+  }
   if (value is int) {
     return LibsqlValue.integer(value);
   }
@@ -15,5 +26,59 @@ LibsqlValue toLibsqlValue(dynamic value) {
   if (value is Uint8List) {
     return LibsqlValue.blob(value);
   }
-  return const LibsqlValue.null_();
+  if (value is DateTime) {
+    return LibsqlValue.dateTime(value: value.toUtc(), format: dateTimeFormat);
+  }
+  if (value is BigInt) {
+    return LibsqlValue.bigInt(value.toString());
+  }
+  if (value is Map || value is List) {
+    return LibsqlValue.json(jsonEncode(value));
+  }
+  throw ArgumentError.value(
+    value,
+    'value',
+    'Unsupported parameter type ${value.runtimeType}',
+  );
+}
+
+LibsqlParams toLibsqlParams({
+  Map<String, dynamic>? named,
+  List<dynamic>? positional,
+  LibsqlDateTimeFormat dateTimeFormat = LibsqlDateTimeFormat.unixMillis,
+}) {
+  return LibsqlParams(
+    named: named?.map(
+      (k, v) => MapEntry(k, toLibsqlValue(v, dateTimeFormat: dateTimeFormat)),
+    ),
+    positional: positional
+        ?.map((v) => toLibsqlValue(v, dateTimeFormat: dateTimeFormat))
+        .toList(),
+  );
+}
+
+/// Converts a per-call timeout into the milliseconds the calls take
+BigInt? toTimeoutMs(Duration? timeout) {
+  return timeout == null ? null : BigInt.from(timeout.inMilliseconds);
+}
+
+/// Converts a row returned by the database into plain Dart values, decoding
+/// `json` values and keeping `bool` and `dateTime` ones as they are
+Map<String, dynamic> toDartRow(Map<String, LibsqlReturnValue> row) {
+  return row.map((column, value) => MapEntry(column, toDartValue(value)));
+}
+
+/// Converts a value returned by the database into a plain Dart value, the
+/// same way as [toDartRow]
+dynamic toDartValue(LibsqlReturnValue value) {
+  return value.map(
+    integer: (integer) => integer.field0,
+    real: (real) => real.field0,
+    text: (text) => text.field0,
+    blob: (blob) => blob.field0,
+    null_: (_) => null,
+    bool: (bool) => bool.field0,
+    dateTime: (dateTime) => dateTime.field0,
+    json: (json) => jsonDecode(json.field0),
+  );
 }
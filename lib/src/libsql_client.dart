@@ -1,12 +1,30 @@
+import 'dart:async';
+
 import 'package:libsql_dart/src/helpers.dart';
 import 'package:libsql_dart/src/rust/api/api.dart' as libsql;
 import 'package:libsql_dart/src/rust/api/api.dart';
 import 'package:libsql_dart/src/rust/api/transaction.dart';
 import 'package:libsql_dart/src/rust/frb_generated.dart';
+import 'package:libsql_dart/src/rows.dart';
+import 'package:libsql_dart/src/rust/utils/changes.dart';
 import 'package:libsql_dart/src/rust/utils/params.dart';
+import 'package:libsql_dart/src/rust/utils/retry.dart';
+import 'package:libsql_dart/src/rust/utils/result.dart';
+import 'package:libsql_dart/src/rust/utils/return_value.dart';
+import 'package:libsql_dart/src/rust/utils/sync.dart';
 import 'package:libsql_dart/src/statement.dart';
 import 'package:libsql_dart/src/transaction.dart';
 
+/// A statement of [LibsqlClient.batchStatements] with either named or
+/// positional parameters
+class BatchStatement {
+  const BatchStatement(this.sql, {this.named, this.positional});
+
+  final String sql;
+  final Map<String, dynamic>? named;
+  final List<dynamic>? positional;
+}
+
 /// A client class to interact with LibSQL/Turso database instance.
 /// All variants of database types can be created using this class.
 /// Below is supported configuration for each variants
@@ -48,6 +66,12 @@ class LibsqlClient {
     this.readYourWrites,
     this.openFlags,
     this.offline,
+    this.dateTimeFormat = LibsqlDateTimeFormat.unixMillis,
+    this.decodeMode,
+    this.datetimeUnit,
+    this.busyTimeoutMs,
+    this.busyRetry,
+    this.lockTimeoutMs,
   });
 
   LibsqlClient.memory() : url = ':memory:';
@@ -83,6 +107,24 @@ class LibsqlClient {
   LibsqlOpenFlags? openFlags;
 
   bool? offline;
+  // How DateTime parameters are written, integer milliseconds since the
+  // epoch by default
+  LibsqlDateTimeFormat dateTimeFormat = LibsqlDateTimeFormat.unixMillis;
+  // Whether values are also converted according to their column's declared
+  // type, storage classes only by default
+  LibsqlDecodeMode? decodeMode;
+  // Unit of integer DATE, DATETIME and TIMESTAMP values when decoding by
+  // declared type, milliseconds by default
+  LibsqlDateTimeUnit? datetimeUnit;
+  // How long a local connection waits for a lock held by another connection
+  // before failing with a busy error
+  int? busyTimeoutMs;
+  // Retries execute and starting a transaction when the database is busy
+  LibsqlBusyRetry? busyRetry;
+  // How long a call waits for other calls on this client, or on the
+  // statement or transaction it uses, before failing with an interrupted
+  // error, indefinitely by default
+  int? lockTimeoutMs;
 
   LibsqlConnection? _connection;
 
@@ -103,14 +145,88 @@ class LibsqlClient {
         readYourWrites: readYourWrites,
         openFlags: openFlags,
         offline: offline,
+        decodeMode: decodeMode,
+        datetimeUnit: datetimeUnit,
+        busyTimeoutMs:
+            busyTimeoutMs == null ? null : BigInt.from(busyTimeoutMs!),
+        busyRetry: busyRetry,
+        lockTimeoutMs:
+            lockTimeoutMs == null ? null : BigInt.from(lockTimeoutMs!),
       ),
     );
   }
 
-  // Sync the embedded replica
-  Future<void> sync() async {
+  /// Sync the embedded replica, returning the frames it pulled and the
+  /// replication index it reached
+  Future<SyncResult> sync() async {
+    if (_connection == null) throw Exception('Database is not connected');
+    return _connection!.sync_();
+  }
+
+  /// Send the changes committed locally to the remote of an offline
+  /// database, see [LibsqlClient.offline]
+  ///
+  /// # Args
+  /// * `conflictPolicy` - What to do when the remote rejects the changes
+  ///   because it has moved on, fails with a `syncConflict` error by default
+  /// * `replay` - Writes the rejected changes again on top of the remote's
+  ///   state under [LibsqlSyncConflictPolicy.retryAfterPull], kept for later
+  ///   pushes
+  Future<SyncResult> push({
+    LibsqlSyncConflictPolicy? conflictPolicy,
+    FutureOr<void> Function()? replay,
+  }) async {
+    if (_connection == null) throw Exception('Database is not connected');
+    if (replay != null) {
+      await _connection!.setConflictReplay(replay: replay);
+    }
+    return _connection!.push(conflictPolicy: conflictPolicy);
+  }
+
+  /// Fetch the changes made on the remote of an offline database, failing
+  /// with a `misuse` error while there are local changes to push
+  Future<SyncResult> pull() async {
+    if (_connection == null) throw Exception('Database is not connected');
+    return _connection!.pull();
+  }
+
+  /// Throw away the local copy of an offline database, including changes
+  /// that were never pushed, and open it again from the remote. Functions
+  /// and collations have to be registered again afterwards
+  Future<void> discardLocalChanges() async {
+    if (_connection == null) throw Exception('Database is not connected');
+    await _connection!.discardLocalChanges();
+  }
+
+  /// Sync the embedded replica until it has caught up with
+  /// [replicationIndex], e.g. one returned by [maxWriteReplicationIndex] on
+  /// another device, failing with an `interrupted` error after [timeout]
+  Future<void> syncUntil(int replicationIndex, {Duration? timeout}) async {
+    if (_connection == null) throw Exception('Database is not connected');
+    await _connection!.syncUntil(
+      replicationIndex: BigInt.from(replicationIndex),
+      timeoutMs: toTimeoutMs(timeout),
+    );
+  }
+
+  // Replication index of the last write made through this client, if any
+  Future<int?> maxWriteReplicationIndex() async {
+    if (_connection == null) throw Exception('Database is not connected');
+    final index = await _connection!.maxWriteReplicationIndex();
+    return index?.toInt();
+  }
+
+  // Status of the embedded replica's syncs
+  Future<LibsqlSyncStatus> syncStatus() async {
     if (_connection == null) throw Exception('Database is not connected');
-    await _connection!.sync_();
+    return _connection!.syncStatus();
+  }
+
+  /// Streams an event after each sync of the embedded replica, manual or
+  /// periodic, that succeeded or failed
+  Stream<LibsqlSyncEvent> syncEvents() {
+    if (_connection == null) throw Exception('Database is not connected');
+    return _connection!.syncEvents();
   }
 
   /// Query the database, you can provide either named or positional parameters
@@ -119,6 +235,8 @@ class LibsqlClient {
   /// * `sql` - SQL query
   /// * `named` - Named parameters
   /// * `positional` - Positional parameters
+  /// * `timeout` - Fails the call with an `interrupted` error after this long
+  /// * `cancel` - Token that stops the call once cancelled
   ///
   /// # Returns
   /// Returns a list of object, eg: [{'id': 1, 'name': 'John'}, {'id': 2, 'name': 'Jane'}]
@@ -126,33 +244,89 @@ class LibsqlClient {
     String sql, {
     Map<String, dynamic>? named,
     List<dynamic>? positional,
+    Duration? timeout,
+    LibsqlCancelToken? cancel,
   }) async {
     if (_connection == null) throw Exception('Database is not connected');
     final res = await _connection!.query(
       sql: sql,
-      parameters: LibsqlParams(
-        named: named?.map((k, v) => MapEntry(k, toLibsqlValue(v))),
-        positional: positional?.map(toLibsqlValue).toList(),
+      parameters: toLibsqlParams(
+        named: named,
+        positional: positional,
+        dateTimeFormat: dateTimeFormat,
       ),
+      timeoutMs: toTimeoutMs(timeout),
+      cancel: cancel,
+    );
+    return res.rows.map(toDartRow).toList();
+  }
+
+  /// Query the database, streaming the rows instead of reading them all at
+  /// once, you can provide either named or positional parameters
+  ///
+  /// # Args
+  /// * `sql` - SQL query
+  /// * `named` - Named parameters
+  /// * `positional` - Positional parameters
+  /// * `pageSize` - Rows fetched at a time while the stream is listened to
+  /// * `timeout` - Fails the call with an `interrupted` error after this long
+  /// * `cancel` - Token that stops the call once cancelled
+  ///
+  /// # Returns
+  /// Returns a stream of object, eg: {'id': 1, 'name': 'John'}, {'id': 2, 'name': 'Jane'}
+  Stream<Map<String, dynamic>> queryStream(
+    String sql, {
+    Map<String, dynamic>? named,
+    List<dynamic>? positional,
+    int pageSize = 100,
+    Duration? timeout,
+    LibsqlCancelToken? cancel,
+  }) async* {
+    if (_connection == null) throw Exception('Database is not connected');
+    final rows = await _connection!.queryStream(
+      sql: sql,
+      parameters: toLibsqlParams(
+        named: named,
+        positional: positional,
+        dateTimeFormat: dateTimeFormat,
+      ),
+      timeoutMs: toTimeoutMs(timeout),
+      cancel: cancel,
+    );
+    yield* rowStream(rows, pageSize: pageSize);
+  }
+
+  /// Query the database, returning the values in columnar form instead of a
+  /// map per row, you can provide either named or positional parameters
+  ///
+  /// # Args
+  /// * `sql` - SQL query
+  /// * `named` - Named parameters
+  /// * `positional` - Positional parameters
+  /// * `timeout` - Fails the call with an `interrupted` error after this long
+  /// * `cancel` - Token that stops the call once cancelled
+  ///
+  /// # Returns
+  /// Column names once and the values of every row in a single flat list,
+  /// row `r` spans `values[r * columns.length..(r + 1) * columns.length]`
+  Future<ColumnarQueryResult> queryColumnar(
+    String sql, {
+    Map<String, dynamic>? named,
+    List<dynamic>? positional,
+    Duration? timeout,
+    LibsqlCancelToken? cancel,
+  }) async {
+    if (_connection == null) throw Exception('Database is not connected');
+    return _connection!.queryColumnar(
+      sql: sql,
+      parameters: toLibsqlParams(
+        named: named,
+        positional: positional,
+        dateTimeFormat: dateTimeFormat,
+      ),
+      timeoutMs: toTimeoutMs(timeout),
+      cancel: cancel,
     );
-    return res.rows
-        .map(
-          (row) => Map.fromEntries(
-            row.entries.map(
-              (entry) => MapEntry(
-                entry.key,
-                entry.value.mapOrNull(
-                  integer: (integer) => integer.field0,
-                  real: (real) => real.field0,
-                  text: (text) => text.field0,
-                  blob: (blob) => blob.field0,
-                  null_: (_) => null,
-                ),
-              ),
-            ),
-          ),
-        )
-        .toList();
   }
 
   /// Execute the statement, you can provide either named or positional parameters
@@ -161,6 +335,8 @@ class LibsqlClient {
   /// * `sql` - SQL query
   /// * `named` - Named parameters
   /// * `positional` - Positional parameters
+  /// * `timeout` - Fails the call with an `interrupted` error after this long
+  /// * `cancel` - Token that stops the call once cancelled
   ///
   /// # Returns
   /// Number of rows affected by the statement
@@ -168,18 +344,62 @@ class LibsqlClient {
     String sql, {
     Map<String, dynamic>? named,
     List<dynamic>? positional,
+    Duration? timeout,
+    LibsqlCancelToken? cancel,
   }) async {
     if (_connection == null) throw Exception('Database is not connected');
     final res = await _connection!.execute(
       sql: sql,
-      parameters: LibsqlParams(
-        named: named?.map((k, v) => MapEntry(k, toLibsqlValue(v))),
-        positional: positional?.map(toLibsqlValue).toList(),
+      parameters: toLibsqlParams(
+        named: named,
+        positional: positional,
+        dateTimeFormat: dateTimeFormat,
       ),
+      timeoutMs: toTimeoutMs(timeout),
+      cancel: cancel,
     );
     return res.rowsAffected.toInt();
   }
 
+  /// Query the database again each time the tables it reads change, you
+  /// can provide either named or positional parameters
+  ///
+  /// # Args
+  /// * `sql` - SQL query
+  /// * `named` - Named parameters
+  /// * `positional` - Positional parameters
+  /// * `debounce` - How long to wait for more changes before querying again
+  ///
+  /// # Returns
+  /// Returns a stream of the query's rows, starting with the current ones
+  Stream<List<Map<String, dynamic>>> watch(
+    String sql, {
+    Map<String, dynamic>? named,
+    List<dynamic>? positional,
+    Duration? debounce,
+  }) {
+    if (_connection == null) throw Exception('Database is not connected');
+    return _connection!
+        .watch(
+          sql: sql,
+          parameters: toLibsqlParams(
+            named: named,
+            positional: positional,
+            dateTimeFormat: dateTimeFormat,
+          ),
+          debounceMs:
+              debounce == null ? null : BigInt.from(debounce.inMilliseconds),
+        )
+        .map((res) => res.rows.map(toDartRow).toList());
+  }
+
+  /// Streams the rows inserted, updated or deleted through this client, one
+  /// list per committed transaction
+  Stream<List<LibsqlUpdate>> updates() {
+    if (_connection == null) throw Exception('Database is not connected');
+    return _connection!.updates();
+  }
+
   /// Create a prepared statement
   ///
   /// # Args
@@ -192,22 +412,214 @@ class LibsqlClient {
     final res = await _connection!.prepare(
       sql: sql,
     );
-    return Statement(res);
+    return Statement(res, dateTimeFormat: dateTimeFormat);
   }
 
   /// Run a batch transaction
   ///
   /// # Args
   /// * `sql` - batch SQL query, each statement is separated by a semicolon
-  Future<void> batch(String sql) async {
+  /// * `timeout` - Fails the call with an `interrupted` error after this long
+  /// * `cancel` - Token that stops the call once cancelled
+  Future<void> batch(
+    String sql, {
+    Duration? timeout,
+    LibsqlCancelToken? cancel,
+  }) async {
     if (_connection == null) throw Exception('Database is not connected');
-    await _connection!.batch(sql: sql);
+    await _connection!.batch(
+      sql: sql,
+      timeoutMs: toTimeoutMs(timeout),
+      cancel: cancel,
+    );
+  }
+
+  /// Run statements in order inside a single transaction, rolling all of
+  /// them back if one fails
+  ///
+  /// # Args
+  /// * `statements` - Statements to run, each with its own parameters
+  /// * `behavior` - How the transaction is started
+  /// * `timeout` - Fails the call with an `interrupted` error after this long
+  /// * `cancel` - Token that stops the call once cancelled
+  ///
+  /// # Returns
+  /// The result of each statement, in order
+  Future<List<QueryResult>> batchStatements(
+    List<BatchStatement> statements, {
+    LibsqlTransactionBehavior? behavior,
+    Duration? timeout,
+    LibsqlCancelToken? cancel,
+  }) async {
+    if (_connection == null) throw Exception('Database is not connected');
+    final res = await _connection!.batchStatements(
+      statements: statements
+          .map(
+            (statement) => LibsqlBatchStatement(
+              sql: statement.sql,
+              parameters: toLibsqlParams(
+                named: statement.named,
+                positional: statement.positional,
+                dateTimeFormat: dateTimeFormat,
+              ),
+            ),
+          )
+          .toList(),
+      behavior: behavior,
+      timeoutMs: toTimeoutMs(timeout),
+      cancel: cancel,
+    );
+    return res.results;
   }
 
   Future<Transaction> transaction({LibsqlTransactionBehavior? behavior}) async {
     if (_connection == null) throw Exception('Database is not connected');
     final res = await _connection!.transaction(behavior: behavior);
-    return Transaction(res);
+    return Transaction(res, dateTimeFormat: dateTimeFormat);
+  }
+
+  /// Register a SQL function implemented in Dart, local databases and
+  /// embedded replicas only
+  ///
+  /// # Args
+  /// * `name` - Name of the function in SQL
+  /// * `callback` - Called with the arguments of each call, returns its result
+  /// * `nArgs` - Number of arguments, any number when -1
+  /// * `deterministic` - Whether the same arguments always give the same
+  ///   result, which allows the function in indexes
+  ///
+  /// The callback must not use this client while the statement calling it
+  /// runs
+  Future<void> createFunction(
+    String name,
+    FutureOr<dynamic> Function(List<dynamic> args) callback, {
+    int nArgs = -1,
+    bool deterministic = false,
+  }) async {
+    if (_connection == null) throw Exception('Database is not connected');
+    await _connection!.createScalarFunction(
+      name: name,
+      nArgs: nArgs,
+      deterministic: deterministic,
+      callback: (args) async => toLibsqlValue(
+        await callback(args.map(toDartValue).toList()),
+        dateTimeFormat: dateTimeFormat,
+      ),
+    );
+  }
+
+  /// Register an aggregate SQL function implemented in Dart, local
+  /// databases and embedded replicas only
+  ///
+  /// # Args
+  /// * `name` - Name of the function in SQL
+  /// * `init` - Creates the accumulator of each group
+  /// * `step` - Folds the arguments of a row into the accumulator and
+  ///   returns the new one
+  /// * `finalize` - Turns the accumulator into the result of the group
+  /// * `nArgs` - Number of arguments, any number when -1
+  /// * `deterministic` - Whether the same rows always give the same result
+  ///
+  /// The callbacks must not use this client while the statement calling
+  /// them runs
+  Future<void> createAggregateFunction<A extends Object>(
+    String name, {
+    required FutureOr<A> Function() init,
+    required FutureOr<A> Function(A accumulator, List<dynamic> args) step,
+    required FutureOr<dynamic> Function(A accumulator) finalize,
+    int nArgs = -1,
+    bool deterministic = false,
+  }) async {
+    if (_connection == null) throw Exception('Database is not connected');
+    await _connection!.createAggregateFunction(
+      name: name,
+      nArgs: nArgs,
+      deterministic: deterministic,
+      init: () async => init(),
+      step: (accumulator, args) async =>
+          step(accumulator as A, args.map(toDartValue).toList()),
+      finalize: (accumulator) async => toLibsqlValue(
+        await finalize(accumulator as A),
+        dateTimeFormat: dateTimeFormat,
+      ),
+    );
+  }
+
+  /// Register an aggregate SQL function implemented in Dart that can also
+  /// be used as a window function with `OVER (...)`, local databases and
+  /// embedded replicas only
+  ///
+  /// # Args
+  /// * `name` - Name of the function in SQL
+  /// * `init` - Creates the accumulator of each group or window
+  /// * `step` - Folds the arguments of a row entering the window into the
+  ///   accumulator and returns the new one
+  /// * `inverse` - Removes the arguments of a row leaving the window from
+  ///   the accumulator and returns the new one
+  /// * `value` - Computes the result for the current window, without
+  ///   consuming the accumulator
+  /// * `finalize` - Turns the accumulator into the final result
+  /// * `nArgs` - Number of arguments, any number when -1
+  /// * `deterministic` - Whether the same rows always give the same result
+  ///
+  /// The callbacks must not use this client while the statement calling
+  /// them runs
+  Future<void> createWindowFunction<A extends Object>(
+    String name, {
+    required FutureOr<A> Function() init,
+    required FutureOr<A> Function(A accumulator, List<dynamic> args) step,
+    required FutureOr<A> Function(A accumulator, List<dynamic> args) inverse,
+    required FutureOr<dynamic> Function(A accumulator) value,
+    required FutureOr<dynamic> Function(A accumulator) finalize,
+    int nArgs = -1,
+    bool deterministic = false,
+  }) async {
+    if (_connection == null) throw Exception('Database is not connected');
+    await _connection!.createWindowFunction(
+      name: name,
+      nArgs: nArgs,
+      deterministic: deterministic,
+      init: () async => init(),
+      step: (accumulator, args) async =>
+          step(accumulator as A, args.map(toDartValue).toList()),
+      inverse: (accumulator, args) async =>
+          inverse(accumulator as A, args.map(toDartValue).toList()),
+      value: (accumulator) async => toLibsqlValue(
+        await value(accumulator as A),
+        dateTimeFormat: dateTimeFormat,
+      ),
+      finalize: (accumulator) async => toLibsqlValue(
+        await finalize(accumulator as A),
+        dateTimeFormat: dateTimeFormat,
+      ),
+    );
+  }
+
+  /// Register a collation implemented in Dart for `ORDER BY`, `COLLATE`
+  /// clauses and indexes, local databases and embedded replicas only
+  ///
+  /// # Args
+  /// * `name` - Name of the collation in SQL
+  /// * `compare` - Returns a negative number, zero or a positive number when
+  ///   the first string sorts before, equal to or after the second one
+  ///
+  /// Every comparison is a round trip to Dart, prefer
+  /// [createBuiltinCollation] when one fits
+  Future<void> createCollation(
+    String name,
+    FutureOr<int> Function(String a, String b) compare,
+  ) async {
+    if (_connection == null) throw Exception('Database is not connected');
+    await _connection!.createCollation(name: name, compare: compare);
+  }
+
+  /// Register one of the collations implemented in Rust as `name`
+  Future<void> createBuiltinCollation(
+    String name,
+    LibsqlCollation collation,
+  ) async {
+    if (_connection == null) throw Exception('Database is not connected');
+    await _connection!.createBuiltinCollation(name: name, collation: collation);
   }
 
   Future<void> loadExtension({required String path, String? entryPoint}) async {
@@ -225,8 +637,18 @@ class LibsqlClient {
     await _connection!.disableExtension();
   }
 
-  /// Close the database
-  Future<void> dispose() async {
-    _connection?.dispose();
+  // Interrupt the queries running on the database
+  Future<void> interrupt() async {
+    if (_connection == null) throw Exception('Database is not connected');
+    await _connection!.interrupt();
+  }
+
+  /// Close the database, waiting for the connection to be closed
+  Future<void> close() async {
+    await _connection?.close();
+    _connection = null;
   }
+
+  /// Close the database, same as [close]
+  Future<void> dispose() => close();
 }
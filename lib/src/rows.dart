@@ -0,0 +1,64 @@
+import 'dart:async';
+
+import 'package:libsql_dart/src/helpers.dart';
+import 'package:libsql_dart/src/rust/api/rows.dart';
+
+/// Streams the rows of a cursor returned by `queryStream`
+///
+/// Rows are fetched [pageSize] at a time, only while the stream is listened
+/// to and not paused. The cursor is closed once it is exhausted, on the
+/// first error, or when the subscription is cancelled.
+///
+/// # Returns
+/// Returns a stream of object, eg: {'id': 1, 'name': 'John'}, {'id': 2, 'name': 'Jane'}
+Stream<Map<String, dynamic>> rowStream(LibsqlRows rows, {int pageSize = 100}) {
+  late final StreamController<Map<String, dynamic>> controller;
+  var pulling = false;
+  var done = false;
+
+  Future<void> finish() async {
+    done = true;
+    try {
+      await rows.close();
+    } catch (error, stackTrace) {
+      controller.addError(error, stackTrace);
+    }
+    await controller.close();
+  }
+
+  Future<void> pull() async {
+    if (pulling || done) return;
+    pulling = true;
+    try {
+      while (!done && controller.hasListener && !controller.isPaused) {
+        final page = await rows.nextPage(size: pageSize);
+        // cancelled while the page was being fetched
+        if (done) return;
+        if (page.isEmpty) {
+          await finish();
+          return;
+        }
+        for (final row in page) {
+          controller.add(toDartRow(row));
+        }
+      }
+    } catch (error, stackTrace) {
+      if (done) return;
+      controller.addError(error, stackTrace);
+      await finish();
+    } finally {
+      pulling = false;
+    }
+  }
+
+  controller = StreamController<Map<String, dynamic>>(
+    onListen: pull,
+    onResume: pull,
+    onCancel: () {
+      if (done) return null;
+      done = true;
+      return rows.close();
+    },
+  );
+  return controller.stream;
+}
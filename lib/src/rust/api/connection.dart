@@ -4,44 +4,227 @@
 // ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
 
 import '../frb_generated.dart';
+import '../utils/changes.dart';
+import '../utils/error.dart';
 import '../utils/params.dart';
 import '../utils/result.dart';
 import '../utils/return_value.dart';
+import '../utils/sync.dart';
+import 'libsql.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'rows.dart';
 import 'statement.dart';
 import 'transaction.dart';
 
-// Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<InnerConnection>>
-abstract class InnerConnection implements RustOpaqueInterface {}
+            // These functions are ignored because they are not marked as `pub`: `add_rows`, `add_statement`, `add_transaction`, `begin`, `connection`, `detach_raw`, `interruptible`, `local_frames`, `new`, `on_raw`, `open_replica`, `push_frames`, `raw_frames`, `release_handles`, `run_batch`, `run_remote_batch`, `statement`, `synced_replica`, `unicode_case_fold`, `watch_on`, `with_busy_retry`, `with_decoding`, `with_lock_timeout`, `with_raw`, `with_remote`, `with_sync_interval`, `with_synced_replica`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `OpenHandles`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `drop`
+// These functions are ignored (category: IgnoreBecauseOwnerTyShouldIgnore): `default`
 
-// Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<InnerDatabase>>
-abstract class InnerDatabase implements RustOpaqueInterface {}
+                // Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<LibsqlConnection>>
+                abstract class LibsqlConnection implements RustOpaqueInterface {
+                     Future<void>  batch({required String sql , BigInt? timeoutMs , LibsqlCancelToken? cancel });
 
-// Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<LibsqlConnection>>
-abstract class LibsqlConnection implements RustOpaqueInterface {
-  Future<void> batch({required String sql});
+/// Runs `statements` in order inside a single transaction and returns a
+/// result for each of them. If any statement fails the transaction is
+/// rolled back and none of the changes are kept.
+///
+/// Remote connections send the whole batch in a single request, with the
+/// parameters written into the SQL, so each statement must be a single
+/// one. Columns are not decoded by their declared type there, and the
+/// results have empty `decl_types`.
+ Future<BatchResult>  batchStatements({required List<LibsqlBatchStatement> statements , LibsqlTransactionBehavior? behavior , BigInt? timeoutMs , LibsqlCancelToken? cancel });
 
-  Future<void> disableExtension();
+/// Rolls back open transactions, finalizes prepared statements and
+/// releases the connection and database. Any later call on this
+/// connection, or on statements prepared from it, fails with a
+/// `Closed` error. Closing twice is a no-op.
+ Future<void>  close();
 
-  Future<void> enableExtension();
+/// Registers an aggregate SQL function. `init` creates the accumulator of
+/// each group, any Dart object, `step` folds a row into it and returns
+/// the new accumulator, and `finalize` turns it into the result.
+///
+/// Only supported on local databases and embedded replicas. Every row
+/// waits for a round trip to Dart, and the callbacks must not use this
+/// connection, as with `create_scalar_function`.
+ Future<void>  createAggregateFunction({required String name , required int nArgs , required bool deterministic , required FutureOr<Object> Function() init , required FutureOr<Object> Function(Object, List<LibsqlReturnValue>) step , required FutureOr<LibsqlValue> Function(Object) finalize });
 
-  Future<ExecuteResult> execute(
-      {required String sql, LibsqlParams? parameters});
+/// Registers one of the collations implemented in Rust under `name`.
+ Future<void>  createBuiltinCollation({required String name , required LibsqlCollation collation });
 
-  Future<void> loadExtension({required String path, String? entryPoint});
+/// Registers `compare` as the collation `name`, for use in `ORDER BY`,
+/// `COLLATE` clauses and indexes. It returns a negative number, zero or a
+/// positive number when the first string sorts before, equal to or after
+/// the second one, and must be consistent across calls.
+///
+/// Only supported on local databases and embedded replicas. Every
+/// comparison blocks a Rust worker thread on a round trip to the Dart
+/// isolate, so sorting n rows costs about n log n of them, and `compare`
+/// must not use this connection, as with `create_scalar_function`.
+/// Prefer `create_builtin_collation` when one fits.
+ Future<void>  createCollation({required String name , required FutureOr<int> Function(String, String) compare });
 
-  // HINT: Make it `#[frb(sync)]` to let it become the default constructor of Dart class.
-  static Future<LibsqlConnection> newInstance(
-          {required InnerConnection connection,
-          required InnerDatabase database}) =>
-      RustLib.instance.api.crateApiConnectionLibsqlConnectionNew(
-          connection: connection, database: database);
+/// Registers `callback` as the SQL function `name` taking `n_args`
+/// arguments, or any number of them when `n_args` is -1. Deterministic
+/// functions can be used in indexes and generated columns.
+///
+/// Only supported on local databases and embedded replicas. Each call
+/// blocks a Rust worker thread until the Dart callback completes, with
+/// the connection locked by the calling statement, so it should return
+/// quickly. The callback must not use this connection, or its
+/// statements, cursors and transactions: that call waits for the
+/// statement, which waits for the callback, and both hang unless
+/// `lock_timeout_ms` is set.
+ Future<void>  createScalarFunction({required String name , required int nArgs , required bool deterministic , required FutureOr<LibsqlValue> Function(List<LibsqlReturnValue>) callback });
 
-  Future<LibsqlStatement> prepare({required String sql});
+/// Registers an aggregate that can also be used as a window function
+/// with `OVER (...)`. On top of the aggregate callbacks, `inverse` removes
+/// a row leaving the window and `value` computes the result for the
+/// current window without consuming the accumulator.
+///
+/// Only supported on local databases and embedded replicas. The same
+/// restrictions as for `create_aggregate_function` apply.
+ Future<void>  createWindowFunction({required String name , required int nArgs , required bool deterministic , required FutureOr<Object> Function() init , required FutureOr<Object> Function(Object, List<LibsqlReturnValue>) step , required FutureOr<Object> Function(Object, List<LibsqlReturnValue>) inverse , required FutureOr<LibsqlValue> Function(Object) value , required FutureOr<LibsqlValue> Function(Object) finalize });
 
-  Future<QueryResult> query({required String sql, LibsqlParams? parameters});
+ Future<void>  disableExtension();
 
-  Future<void> sync_();
+/// Throws away the local copy of a database opened with `offline`,
+/// including changes that were never pushed, and opens it again from
+/// the remote.
+///
+/// Like `close`, this rolls back open transactions and finalizes
+/// prepared statements. Functions and collations created on this
+/// connection and its busy timeout have to be set up again, `updates`
+/// and `watch` streams keep running. When the fresh copy can't be
+/// opened, the local one is kept and opened again, and if even that
+/// fails the connection is left closed.
+ Future<void>  discardLocalChanges();
 
-  Future<LibsqlTransaction> transaction({LibsqlTransactionBehavior? behavior});
-}
+ Future<void>  enableExtension();
+
+ Future<ExecuteResult>  execute({required String sql , LibsqlParams? parameters , BigInt? timeoutMs , LibsqlCancelToken? cancel });
+
+/// Stops the queries and statements running on this connection, which
+/// fail with an `Interrupted` error. Local statements are interrupted by
+/// SQLite, remote requests are aborted.
+///
+/// Dart futures can't be cancelled, so abandoning one leaves its query
+/// running. Use this, a `timeout_ms` or a `LibsqlCancelToken` to stop it.
+ Future<void>  interrupt();
+
+ Future<void>  loadExtension({required String path , String? entryPoint });
+
+/// Highest replication index returned by writes made through this
+/// connection's database, `None` before the first write. Only embedded
+/// replicas, whose writes are sent to the primary, report one.
+ Future<BigInt?>  maxWriteReplicationIndex();
+
+ Future<LibsqlStatement>  prepare({required String sql });
+
+/// Fetches the changes made on the remote of a database opened with
+/// `offline`. This runs the same sync as `push`, for when there is
+/// nothing local to send: it fails with a `Misuse` error while there are
+/// local changes that have not been pushed yet, which libsql would push
+/// instead, as it can only apply the remote's changes to a copy without
+/// changes of its own.
+///
+/// Local changes can only be told apart once this connection has pushed
+/// or pulled, before that libsql pushes any it finds first.
+ Future<SyncResult>  pull();
+
+/// Sends the changes committed locally to a database opened with
+/// `offline` to its remote. Returns right away when nothing was
+/// committed since the last push or pull, and fails with a `Network`
+/// error, keeping the changes for the next push, when the remote can't
+/// be reached.
+///
+/// When the remote has moved on since the changes were written it
+/// rejects them, and `conflict_policy` decides what happens: by default
+/// this fails with a `SyncConflict` error describing the rejected
+/// frames, while `RemoteWins` discards the local database, as with
+/// `discard_local_changes`, and returns the result of pulling it afresh.
+/// `RetryAfterPull` does the same, then runs the callback set with
+/// `set_conflict_replay` to write the changes again and pushes those,
+/// up to three times before failing with the last `SyncConflict` error.
+/// libsql does not say why it failed a push, so a pull into a scratch
+/// copy next to the database finds out whether the remote moved on.
+///
+/// libsql has a single sync for these databases, which pushes when there
+/// are local frames the remote has not seen and pulls otherwise. `push`
+/// and `pull` both run it, so before this connection has pushed or
+/// pulled once, `push` may pull instead when there is nothing to send.
+ Future<SyncResult>  push({LibsqlSyncConflictPolicy? conflictPolicy });
+
+ Future<QueryResult>  query({required String sql , LibsqlParams? parameters , BigInt? timeoutMs , LibsqlCancelToken? cancel });
+
+ Future<ColumnarQueryResult>  queryColumnar({required String sql , LibsqlParams? parameters , BigInt? timeoutMs , LibsqlCancelToken? cancel });
+
+ Future<LibsqlRows>  queryStream({required String sql , LibsqlParams? parameters , BigInt? timeoutMs , LibsqlCancelToken? cancel });
+
+/// Sets how long local statements wait for a lock held by another
+/// connection before failing with a `Busy` error. Fails with `Misuse` on
+/// remote connections, whose locks are up to the server.
+ Future<void>  setBusyTimeout({required BigInt timeoutMs });
+
+/// Sets the callback `push` runs under `RetryAfterPull` once the local
+/// database was replaced with the remote's, to write the rejected
+/// changes again. It runs with nothing locked and can use this
+/// connection, unlike the callbacks of SQL functions.
+ Future<void>  setConflictReplay({required FutureOr<void> Function() replay });
+
+/// Pulls the latest changes of an embedded replica or synced database
+/// from its remote.
+ Future<SyncResult>  sync_();
+
+/// Streams the outcome of every sync from now on, manual or periodic. The
+/// stream ends when the connection is closed.
+ Stream<LibsqlSyncEvent>  syncEvents();
+
+/// Outcome of the latest syncs, whether started by `sync` or by
+/// `sync_interval_seconds`.
+ Future<LibsqlSyncStatus>  syncStatus();
+
+/// Syncs an embedded replica until it has caught up with
+/// `replication_index`, such as one returned by
+/// `max_write_replication_index` on another device, so reads made
+/// afterwards see that write.
+///
+/// Waits for as long as it takes unless `timeout_ms` is given, after
+/// which it fails with an `Interrupted` error.
+ Future<SyncResult>  syncUntil({required BigInt replicationIndex , BigInt? timeoutMs });
+
+ Future<LibsqlTransaction>  transaction({LibsqlTransactionBehavior? behavior });
+
+/// Streams the rows inserted, updated or deleted through this connection,
+/// one list per committed transaction. Changes undone by a rollback, a
+/// `ROLLBACK TO` or a failed statement are left out, except those of a
+/// failed statement that fired triggers. Changes to `WITHOUT ROWID`
+/// tables are not reported.
+///
+/// Only supported on local databases and embedded replicas. The stream
+/// ends when the connection is closed.
+ Stream<List<LibsqlUpdate>>  updates();
+
+/// Streams the result of the read-only query `sql`, running it again
+/// whenever a table it reads from is changed through this connection,
+/// and after syncs that brought in changes from the remote. Changes less
+/// than `debounce_ms` (50 by default) apart trigger a single run, and
+/// results equal to the previous one are not sent again. Errors are sent
+/// on the stream, which keeps watching.
+///
+/// Queries reading from views run again on any change. Only supported on
+/// local databases and embedded replicas. The stream ends when the
+/// connection is closed.
+ Stream<QueryResult>  watch({required String sql , LibsqlParams? parameters , BigInt? debounceMs });
+
+                }
+
+enum LibsqlCollation {
+                    /// Case-insensitive ordering that lowercases every Unicode character
+/// before comparing, where `NOCASE` only folds ASCII letters. It is not
+/// locale-aware, use `create_collation` for that
+unicodeCaseFold,
+                    ;
+
+                }
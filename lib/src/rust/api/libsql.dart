@@ -4,62 +4,91 @@
 // ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
 
 import '../frb_generated.dart';
+import '../utils/error.dart';
+import '../utils/retry.dart';
+import '../utils/return_value.dart';
 import 'connection.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'pool.dart';
 
-Future<LibsqlConnection> connect({required ConnectArgs args}) =>
-    RustLib.instance.api.crateApiLibsqlConnect(args: args);
-
-class ConnectArgs {
-  final String url;
-  final String? authToken;
-  final String? syncUrl;
-  final BigInt? syncIntervalSeconds;
-  final String? encryptionKey;
-  final bool? readYourWrites;
-  final LibsqlOpenFlags? openFlags;
-  final bool? offline;
-
-  const ConnectArgs({
-    required this.url,
-    this.authToken,
-    this.syncUrl,
-    this.syncIntervalSeconds,
-    this.encryptionKey,
-    this.readYourWrites,
-    this.openFlags,
-    this.offline,
-  });
-
-  @override
-  int get hashCode =>
-      url.hashCode ^
-      authToken.hashCode ^
-      syncUrl.hashCode ^
-      syncIntervalSeconds.hashCode ^
-      encryptionKey.hashCode ^
-      readYourWrites.hashCode ^
-      openFlags.hashCode ^
-      offline.hashCode;
-
-  @override
-  bool operator ==(Object other) =>
-      identical(this, other) ||
-      other is ConnectArgs &&
-          runtimeType == other.runtimeType &&
-          url == other.url &&
-          authToken == other.authToken &&
-          syncUrl == other.syncUrl &&
-          syncIntervalSeconds == other.syncIntervalSeconds &&
-          encryptionKey == other.encryptionKey &&
-          readYourWrites == other.readYourWrites &&
-          openFlags == other.openFlags &&
-          offline == other.offline;
-}
+            // These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `clone`
+
+            Future<LibsqlConnection>  connect({required ConnectArgs args }) => RustLib.instance.api.crateApiLibsqlConnect(args: args);
+
+/// Opens the local database file at `args.url` as a pool of one writer and
+/// `readers` read-only connections, 4 by default.
+Future<LibsqlPool>  connectPool({required ConnectArgs args , int? readers }) => RustLib.instance.api.crateApiLibsqlConnectPool(args: args, readers: readers);
+
+            class ConnectArgs  {
+                final String url;
+final String? authToken;
+final String? syncUrl;
+final BigInt? syncIntervalSeconds;
+final String? encryptionKey;
+final bool? readYourWrites;
+final LibsqlOpenFlags? openFlags;
+final bool? offline;
+final LibsqlDecodeMode? decodeMode;
+/// Unit of integers read as `DateTime` under
+/// `LibsqlDecodeMode::DeclaredType`. Milliseconds by default
+final LibsqlDateTimeUnit? datetimeUnit;
+/// How long a local connection waits for a lock held by another
+/// connection before failing with a `Busy` error. Not supported on
+/// remote connections
+final BigInt? busyTimeoutMs;
+/// Retries `execute`, on the connection, its prepared statements and
+/// transactions, and starting a transaction when they are busy
+final LibsqlBusyRetry? busyRetry;
+/// How long a call waits for other calls on the same connection, or on
+/// the statement, cursor or transaction it uses, before failing with an
+/// `Interrupted` error. Calls wait indefinitely by default
+final BigInt? lockTimeoutMs;
+
+                const ConnectArgs({required this.url ,this.authToken ,this.syncUrl ,this.syncIntervalSeconds ,this.encryptionKey ,this.readYourWrites ,this.openFlags ,this.offline ,this.decodeMode ,this.datetimeUnit ,this.busyTimeoutMs ,this.busyRetry ,this.lockTimeoutMs ,});
+
+        @override
+        int get hashCode => url.hashCode^authToken.hashCode^syncUrl.hashCode^syncIntervalSeconds.hashCode^encryptionKey.hashCode^readYourWrites.hashCode^openFlags.hashCode^offline.hashCode^decodeMode.hashCode^datetimeUnit.hashCode^busyTimeoutMs.hashCode^busyRetry.hashCode^lockTimeoutMs.hashCode;
+
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is ConnectArgs &&
+                runtimeType == other.runtimeType
+                && url == other.url&& authToken == other.authToken&& syncUrl == other.syncUrl&& syncIntervalSeconds == other.syncIntervalSeconds&& encryptionKey == other.encryptionKey&& readYourWrites == other.readYourWrites&& openFlags == other.openFlags&& offline == other.offline&& decodeMode == other.decodeMode&& datetimeUnit == other.datetimeUnit&& busyTimeoutMs == other.busyTimeoutMs&& busyRetry == other.busyRetry&& lockTimeoutMs == other.lockTimeoutMs;
+
+            }
+
+/// Cancels the calls it is passed to, for example once the Dart future
+/// awaiting them is abandoned. Cancelled calls fail with an `Interrupted`
+/// error, and so do calls made with the token after it was cancelled.
+class LibsqlCancelToken  {
+                final BigInt id;
+
+                const LibsqlCancelToken({required this.id ,});
+
+                /// Stops the calls running with this token.
+Future<void>  cancel()=>RustLib.instance.api.crateApiLibsqlLibsqlCancelTokenCancel(that: this, );
+
+
+static Future<LibsqlCancelToken>  create()=>RustLib.instance.api.crateApiLibsqlLibsqlCancelTokenCreate();
+
+
+        @override
+        int get hashCode => id.hashCode;
+
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is LibsqlCancelToken &&
+                runtimeType == other.runtimeType
+                && id == other.id;
+
+            }
 
 enum LibsqlOpenFlags {
-  readOnly,
-  readWrite,
-  create,
-  ;
-}
+                    readOnly,
+readWrite,
+create,
+                    ;
+
+                }
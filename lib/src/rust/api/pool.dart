@@ -0,0 +1,53 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.10.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import '../utils/error.dart';
+import '../utils/params.dart';
+import '../utils/result.dart';
+import '../utils/return_value.dart';
+import 'libsql.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'transaction.dart';
+
+            // These functions are ignored because they are not marked as `pub`: `open`, `queue_writer`, `reader`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `Reader`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `drop`
+
+                // Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<LibsqlPool>>
+                abstract class LibsqlPool implements RustOpaqueInterface {
+                    /// Like `execute`, for several statements without parameters.
+ Future<void>  batch({required String sql , BigInt? timeoutMs , LibsqlCancelToken? cancel });
+
+ Future<BatchResult>  batchStatements({required List<LibsqlBatchStatement> statements , LibsqlTransactionBehavior? behavior , BigInt? timeoutMs , LibsqlCancelToken? cancel });
+
+/// Closes the writer and every reader.
+ Future<void>  close();
+
+/// Runs `sql` on the writer once the writes queued before it are done.
+/// `timeout_ms` covers the wait too, which fails with an `Interrupted`
+/// error.
+ Future<ExecuteResult>  execute({required String sql , LibsqlParams? parameters , BigInt? timeoutMs , LibsqlCancelToken? cancel });
+
+ Future<QueryResult>  query({required String sql , LibsqlParams? parameters , BigInt? timeoutMs , LibsqlCancelToken? cancel });
+
+ Future<ColumnarQueryResult>  queryColumnar({required String sql , LibsqlParams? parameters , BigInt? timeoutMs , LibsqlCancelToken? cancel });
+
+/// Starts a transaction on the writer once the writes queued before it
+/// are done. Other writes wait until it is committed or rolled back.
+///
+/// Always commit or roll back the transaction: one that is merely
+/// dropped in Dart holds up every later write on the pool until Dart's
+/// finalizer releases it. Fails with an `Interrupted` error if the writer
+/// is not free within `timeout_ms`, when given.
+ Future<LibsqlTransaction>  transaction({LibsqlTransactionBehavior? behavior , BigInt? timeoutMs });
+
+/// Streams the result of the read-only query `sql` like
+/// `LibsqlConnection::watch`, running it again whenever a table it reads
+/// from is changed through this pool. Each run waits for an idle reader
+/// like `query` does, so it only sees committed writes.
+ Stream<QueryResult>  watch({required String sql , LibsqlParams? parameters , BigInt? debounceMs });
+
+                }
@@ -0,0 +1,30 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.10.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import '../utils/error.dart';
+import '../utils/return_value.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+            // These functions are ignored because they are not marked as `pub`: `handle`, `new`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `drop`
+
+                // Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<LibsqlRows>>
+                abstract class LibsqlRows implements RustOpaqueInterface {
+                    /// Releases the underlying statement. Safe to call more than once.
+ Future<void>  close();
+
+ Future<List<String>>  columns();
+
+ Future<List<String?>>  declTypes();
+
+/// Returns the next row, or `None` once the cursor is exhausted or closed.
+ Future<Map<String, LibsqlReturnValue>?>  next();
+
+/// Returns up to `size` rows. An empty page means the cursor is exhausted
+/// or closed.
+ Future<List<Map<String, LibsqlReturnValue>>>  nextPage({required int size });
+
+                }
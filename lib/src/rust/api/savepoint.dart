@@ -0,0 +1,40 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.10.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import '../utils/error.dart';
+import '../utils/params.dart';
+import '../utils/result.dart';
+import '../utils/return_value.dart';
+import 'libsql.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+            // These functions are ignored because they are not marked as `pub`: `begin`, `ensure_active`, `interruptible`, `open`, `quote`, `run`, `unwind`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `OpenSavepoint`, `SavepointStack`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `drop`
+// These functions are ignored (category: IgnoreBecauseOwnerTyShouldIgnore): `clone`, `default`
+
+                // Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<LibsqlSavepoint>>
+                abstract class LibsqlSavepoint implements RustOpaqueInterface {
+                     Future<ExecuteResult>  execute({required String sql , LibsqlParams? parameters , BigInt? timeoutMs , LibsqlCancelToken? cancel });
+
+ Future<String>  name();
+
+ Future<QueryResult>  query({required String sql , LibsqlParams? parameters , BigInt? timeoutMs , LibsqlCancelToken? cancel });
+
+/// Keeps the changes made since the savepoint as part of the enclosing
+/// transaction or savepoint, ending this savepoint.
+ Future<void>  release();
+
+/// Discards the changes made since the savepoint. The savepoint stays
+/// open, so it can be rolled back to again or released, and so does the
+/// enclosing transaction.
+ Future<void>  rollbackTo();
+
+/// Starts a savepoint nested in this one, like
+/// `LibsqlTransaction::savepoint`.
+ Future<LibsqlSavepoint>  savepoint({required String name });
+
+                }
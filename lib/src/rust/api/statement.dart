@@ -4,27 +4,43 @@
 // ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
 
 import '../frb_generated.dart';
+import '../utils/error.dart';
 import '../utils/params.dart';
 import '../utils/result.dart';
 import '../utils/return_value.dart';
+import 'libsql.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'rows.dart';
 
-// Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<InnerStatement>>
-abstract class InnerStatement implements RustOpaqueInterface {}
+            // These functions are ignored because they are not marked as `pub`: `connection`, `finalized`, `handle`, `interruptible`, `new`, `with_busy_retry`, `with_transaction`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `drop`
 
-// Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<LibsqlStatement>>
-abstract class LibsqlStatement implements RustOpaqueInterface {
-  Future<ExecuteResult> execute({LibsqlParams? parameters});
+                // Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<LibsqlStatement>>
+                abstract class LibsqlStatement implements RustOpaqueInterface {
+                     Future<List<LibsqlColumn>>  columns();
 
-  Future<void> finalize();
+ Future<ExecuteResult>  execute({LibsqlParams? parameters , BigInt? timeoutMs , LibsqlCancelToken? cancel });
 
-  // HINT: Make it `#[frb(sync)]` to let it become the default constructor of Dart class.
-  static Future<LibsqlStatement> newInstance(
-          {required InnerStatement statement}) =>
-      RustLib.instance.api
-          .crateApiStatementLibsqlStatementNew(statement: statement);
+ Future<void>  finalize();
 
-  Future<QueryResult> query({LibsqlParams? parameters});
+/// Whether the statement leaves the database as it is, as SQLite tells.
+/// Only supported on local databases and embedded replicas.
+ Future<bool>  isReadonly();
 
-  Future<void> reset();
-}
+ Future<int>  parameterCount();
+
+/// Name of the parameter at `index`, counting from 1 like SQLite does.
+/// Positional `?` parameters have no name.
+ Future<String?>  parameterName({required int index });
+
+ Future<QueryResult>  query({LibsqlParams? parameters , BigInt? timeoutMs , LibsqlCancelToken? cancel });
+
+ Future<ColumnarQueryResult>  queryColumnar({LibsqlParams? parameters , BigInt? timeoutMs , LibsqlCancelToken? cancel });
+
+/// Starts the query, leaving its rows to be read page by page. The
+/// timeout only covers starting it.
+ Future<LibsqlRows>  queryStream({LibsqlParams? parameters , BigInt? timeoutMs , LibsqlCancelToken? cancel });
+
+ Future<void>  reset();
+
+                }
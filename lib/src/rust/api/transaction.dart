@@ -4,36 +4,56 @@
 // ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
 
 import '../frb_generated.dart';
+import '../utils/error.dart';
 import '../utils/params.dart';
 import '../utils/result.dart';
 import '../utils/return_value.dart';
+import 'libsql.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'savepoint.dart';
+import 'statement.dart';
 
-// Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<InnerTransaction>>
-abstract class InnerTransaction implements RustOpaqueInterface {}
+            // These functions are ignored because they are not marked as `pub`: `active`, `finalize_statements`, `finish`, `handle`, `interruptible`, `is_active`, `new`, `with_busy_retry`, `with_writer`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `TransactionState`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `drop`, `from`
 
-// Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<LibsqlTransaction>>
-abstract class LibsqlTransaction implements RustOpaqueInterface {
-  Future<void> commit();
+                // Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<LibsqlTransaction>>
+                abstract class LibsqlTransaction implements RustOpaqueInterface {
+/// Fails with a `Misuse` error, leaving the transaction active, while
+/// one of its savepoints is open.
+                     Future<void>  commit();
 
-  Future<ExecuteResult> execute(
-      {required String sql, LibsqlParams? parameters});
+ Future<ExecuteResult>  execute({required String sql , LibsqlParams? parameters , BigInt? timeoutMs , LibsqlCancelToken? cancel });
 
-  // HINT: Make it `#[frb(sync)]` to let it become the default constructor of Dart class.
-  static Future<LibsqlTransaction> newInstance(
-          {required InnerTransaction transaction}) =>
-      RustLib.instance.api
-          .crateApiTransactionLibsqlTransactionNew(transaction: transaction);
+ Future<bool>  isActive();
 
-  Future<QueryResult> query({required String sql, LibsqlParams? parameters});
+/// Whether the underlying connection is outside of any transaction, which
+/// is only the case once this transaction has been committed or rolled
+/// back and no other one has been started.
+ Future<bool>  isAutocommit();
 
-  Future<void> rollback();
-}
+/// Prepares a statement that runs inside this transaction. It can be used
+/// until the transaction is committed or rolled back.
+ Future<LibsqlStatement>  prepare({required String sql });
+
+ Future<QueryResult>  query({required String sql , LibsqlParams? parameters , BigInt? timeoutMs , LibsqlCancelToken? cancel });
+
+ Future<ColumnarQueryResult>  queryColumnar({required String sql , LibsqlParams? parameters , BigInt? timeoutMs , LibsqlCancelToken? cancel });
+
+ Future<void>  rollback();
+
+/// Starts a savepoint, which can be released or rolled back on its own
+/// without ending this transaction. Fails with a `Misuse` error while a
+/// savepoint of the same name is open.
+ Future<LibsqlSavepoint>  savepoint({required String name });
+
+                }
 
 enum LibsqlTransactionBehavior {
-  deferred_,
-  immediate,
-  exclusive,
-  readOnly,
-  ;
-}
+                    deferred_,
+immediate,
+exclusive,
+readOnly,
+                    ;
+
+                }
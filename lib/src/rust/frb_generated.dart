@@ -5,2739 +5,4400 @@
 
 import 'api/connection.dart';
 import 'api/libsql.dart';
+import 'api/pool.dart';
+import 'api/rows.dart';
+import 'api/savepoint.dart';
 import 'api/statement.dart';
 import 'api/transaction.dart';
 import 'dart:async';
 import 'dart:convert';
 import 'frb_generated.dart';
-import 'frb_generated.io.dart'
-    if (dart.library.js_interop) 'frb_generated.web.dart';
+import 'frb_generated.io.dart' if (dart.library.js_interop) 'frb_generated.web.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'utils/changes.dart';
+import 'utils/error.dart';
 import 'utils/params.dart';
 import 'utils/result.dart';
+import 'utils/retry.dart';
 import 'utils/return_value.dart';
+import 'utils/sync.dart';
 
-/// Main entrypoint of the Rust API
-class RustLib extends BaseEntrypoint<RustLibApi, RustLibApiImpl, RustLibWire> {
-  @internal
-  static final instance = RustLib._();
-
-  RustLib._();
-
-  /// Initialize flutter_rust_bridge
-  static Future<void> init({
-    RustLibApi? api,
-    BaseHandler? handler,
-    ExternalLibrary? externalLibrary,
-  }) async {
-    await instance.initImpl(
-      api: api,
-      handler: handler,
-      externalLibrary: externalLibrary,
-    );
-  }
-
-  /// Initialize flutter_rust_bridge in mock mode.
-  /// No libraries for FFI are loaded.
-  static void initMock({
-    required RustLibApi api,
-  }) {
-    instance.initMockImpl(
-      api: api,
-    );
-  }
-
-  /// Dispose flutter_rust_bridge
-  ///
-  /// The call to this function is optional, since flutter_rust_bridge (and everything else)
-  /// is automatically disposed when the app stops.
-  static void dispose() => instance.disposeImpl();
-
-  @override
-  ApiImplConstructor<RustLibApiImpl, RustLibWire> get apiImplConstructor =>
-      RustLibApiImpl.new;
-
-  @override
-  WireConstructor<RustLibWire> get wireConstructor =>
-      RustLibWire.fromExternalLibrary;
-
-  @override
-  Future<void> executeRustInitializers() async {
-    await api.crateApiLibsqlInitApp();
-  }
+                /// Main entrypoint of the Rust API
+                class RustLib extends BaseEntrypoint<RustLibApi, RustLibApiImpl, RustLibWire> {
+                  @internal
+                  static final instance = RustLib._();
 
-  @override
-  ExternalLibraryLoaderConfig get defaultExternalLibraryLoaderConfig =>
-      kDefaultExternalLibraryLoaderConfig;
+                  RustLib._();
 
-  @override
-  String get codegenVersion => '2.10.0';
+                  /// Initialize flutter_rust_bridge
+                  static Future<void> init({
+                    RustLibApi? api,
+                    BaseHandler? handler,
+                    ExternalLibrary? externalLibrary,
+                  }) async {
+                    await instance.initImpl(
+                      api: api,
+                      handler: handler,
+                      externalLibrary: externalLibrary,
+                    );
+                  }
 
-  @override
-  int get rustContentHash => -216189616;
+                  /// Initialize flutter_rust_bridge in mock mode.
+                  /// No libraries for FFI are loaded.
+                  static void initMock({
+                    required RustLibApi api,
+                  }) {
+                    instance.initMockImpl(
+                      api: api,
+                    );
+                  }
 
-  static const kDefaultExternalLibraryLoaderConfig =
-      ExternalLibraryLoaderConfig(
-    stem: 'libsql_dart',
-    ioDirectory: 'rust/target/release/',
-    webPrefix: 'pkg/',
-  );
-}
+                  /// Dispose flutter_rust_bridge
+                  ///
+                  /// The call to this function is optional, since flutter_rust_bridge (and everything else)
+                  /// is automatically disposed when the app stops.
+                  static void dispose() => instance.disposeImpl();
 
-abstract class RustLibApi extends BaseApi {
-  Future<void> crateApiConnectionLibsqlConnectionBatch(
-      {required LibsqlConnection that, required String sql});
+                  @override
+                  ApiImplConstructor<RustLibApiImpl, RustLibWire> get apiImplConstructor => RustLibApiImpl.new;
 
-  Future<void> crateApiConnectionLibsqlConnectionDisableExtension(
-      {required LibsqlConnection that});
+                  @override
+                  WireConstructor<RustLibWire> get wireConstructor => RustLibWire.fromExternalLibrary;
 
-  Future<void> crateApiConnectionLibsqlConnectionEnableExtension(
-      {required LibsqlConnection that});
+                  @override
+                  Future<void> executeRustInitializers() async {
+                    await api.crateApiLibsqlInitApp();
 
-  Future<ExecuteResult> crateApiConnectionLibsqlConnectionExecute(
-      {required LibsqlConnection that,
-      required String sql,
-      LibsqlParams? parameters});
+                  }
 
-  Future<void> crateApiConnectionLibsqlConnectionLoadExtension(
-      {required LibsqlConnection that,
-      required String path,
-      String? entryPoint});
+                  @override
+                  ExternalLibraryLoaderConfig get defaultExternalLibraryLoaderConfig => kDefaultExternalLibraryLoaderConfig;
 
-  Future<LibsqlConnection> crateApiConnectionLibsqlConnectionNew(
-      {required InnerConnection connection, required InnerDatabase database});
+                  @override
+                  String get codegenVersion => '2.10.0';
 
-  Future<LibsqlStatement> crateApiConnectionLibsqlConnectionPrepare(
-      {required LibsqlConnection that, required String sql});
+                  @override
+                  int get rustContentHash => 10616030;
 
-  Future<QueryResult> crateApiConnectionLibsqlConnectionQuery(
-      {required LibsqlConnection that,
-      required String sql,
-      LibsqlParams? parameters});
+                  static const kDefaultExternalLibraryLoaderConfig = ExternalLibraryLoaderConfig(
+                    stem: 'UNKNOWN',
+                    ioDirectory: 'rust/target/release/',
+                    webPrefix: 'pkg/',
+                  );
+                }
 
-  Future<void> crateApiConnectionLibsqlConnectionSync(
-      {required LibsqlConnection that});
+                abstract class RustLibApi extends BaseApi {
+                  Future<void> crateApiConnectionLibsqlConnectionBatch({required LibsqlConnection that , required String sql , BigInt? timeoutMs , LibsqlCancelToken? cancel });
 
-  Future<LibsqlTransaction> crateApiConnectionLibsqlConnectionTransaction(
-      {required LibsqlConnection that, LibsqlTransactionBehavior? behavior});
+Future<BatchResult> crateApiConnectionLibsqlConnectionBatchStatements({required LibsqlConnection that , required List<LibsqlBatchStatement> statements , LibsqlTransactionBehavior? behavior , BigInt? timeoutMs , LibsqlCancelToken? cancel });
 
-  Future<ExecuteResult> crateApiStatementLibsqlStatementExecute(
-      {required LibsqlStatement that, LibsqlParams? parameters});
+Future<void> crateApiConnectionLibsqlConnectionClose({required LibsqlConnection that });
 
-  Future<void> crateApiStatementLibsqlStatementFinalize(
-      {required LibsqlStatement that});
+Future<void> crateApiConnectionLibsqlConnectionCreateAggregateFunction({required LibsqlConnection that , required String name , required int nArgs , required bool deterministic , required FutureOr<Object> Function() init , required FutureOr<Object> Function(Object, List<LibsqlReturnValue>) step , required FutureOr<LibsqlValue> Function(Object) finalize });
 
-  Future<LibsqlStatement> crateApiStatementLibsqlStatementNew(
-      {required InnerStatement statement});
+Future<void> crateApiConnectionLibsqlConnectionCreateBuiltinCollation({required LibsqlConnection that , required String name , required LibsqlCollation collation });
 
-  Future<QueryResult> crateApiStatementLibsqlStatementQuery(
-      {required LibsqlStatement that, LibsqlParams? parameters});
+Future<void> crateApiConnectionLibsqlConnectionCreateCollation({required LibsqlConnection that , required String name , required FutureOr<int> Function(String, String) compare });
 
-  Future<void> crateApiStatementLibsqlStatementReset(
-      {required LibsqlStatement that});
+Future<void> crateApiConnectionLibsqlConnectionCreateScalarFunction({required LibsqlConnection that , required String name , required int nArgs , required bool deterministic , required FutureOr<LibsqlValue> Function(List<LibsqlReturnValue>) callback });
 
-  Future<void> crateApiTransactionLibsqlTransactionCommit(
-      {required LibsqlTransaction that});
+Future<void> crateApiConnectionLibsqlConnectionCreateWindowFunction({required LibsqlConnection that , required String name , required int nArgs , required bool deterministic , required FutureOr<Object> Function() init , required FutureOr<Object> Function(Object, List<LibsqlReturnValue>) step , required FutureOr<Object> Function(Object, List<LibsqlReturnValue>) inverse , required FutureOr<LibsqlValue> Function(Object) value , required FutureOr<LibsqlValue> Function(Object) finalize });
 
-  Future<ExecuteResult> crateApiTransactionLibsqlTransactionExecute(
-      {required LibsqlTransaction that,
-      required String sql,
-      LibsqlParams? parameters});
+Future<void> crateApiConnectionLibsqlConnectionDisableExtension({required LibsqlConnection that });
 
-  Future<LibsqlTransaction> crateApiTransactionLibsqlTransactionNew(
-      {required InnerTransaction transaction});
+Future<void> crateApiConnectionLibsqlConnectionDiscardLocalChanges({required LibsqlConnection that });
 
-  Future<QueryResult> crateApiTransactionLibsqlTransactionQuery(
-      {required LibsqlTransaction that,
-      required String sql,
-      LibsqlParams? parameters});
+Future<void> crateApiConnectionLibsqlConnectionEnableExtension({required LibsqlConnection that });
 
-  Future<void> crateApiTransactionLibsqlTransactionRollback(
-      {required LibsqlTransaction that});
+Future<ExecuteResult> crateApiConnectionLibsqlConnectionExecute({required LibsqlConnection that , required String sql , LibsqlParams? parameters , BigInt? timeoutMs , LibsqlCancelToken? cancel });
 
-  Future<LibsqlConnection> crateApiLibsqlConnect({required ConnectArgs args});
+Future<void> crateApiConnectionLibsqlConnectionInterrupt({required LibsqlConnection that });
 
-  Future<void> crateApiLibsqlInitApp();
+Future<void> crateApiConnectionLibsqlConnectionLoadExtension({required LibsqlConnection that , required String path , String? entryPoint });
 
-  RustArcIncrementStrongCountFnType
-      get rust_arc_increment_strong_count_InnerConnection;
+Future<BigInt?> crateApiConnectionLibsqlConnectionMaxWriteReplicationIndex({required LibsqlConnection that });
 
-  RustArcDecrementStrongCountFnType
-      get rust_arc_decrement_strong_count_InnerConnection;
+Future<LibsqlStatement> crateApiConnectionLibsqlConnectionPrepare({required LibsqlConnection that , required String sql });
 
-  CrossPlatformFinalizerArg
-      get rust_arc_decrement_strong_count_InnerConnectionPtr;
+Future<SyncResult> crateApiConnectionLibsqlConnectionPull({required LibsqlConnection that });
 
-  RustArcIncrementStrongCountFnType
-      get rust_arc_increment_strong_count_InnerDatabase;
-
-  RustArcDecrementStrongCountFnType
-      get rust_arc_decrement_strong_count_InnerDatabase;
-
-  CrossPlatformFinalizerArg
-      get rust_arc_decrement_strong_count_InnerDatabasePtr;
-
-  RustArcIncrementStrongCountFnType
-      get rust_arc_increment_strong_count_InnerStatement;
-
-  RustArcDecrementStrongCountFnType
-      get rust_arc_decrement_strong_count_InnerStatement;
-
-  CrossPlatformFinalizerArg
-      get rust_arc_decrement_strong_count_InnerStatementPtr;
-
-  RustArcIncrementStrongCountFnType
-      get rust_arc_increment_strong_count_InnerTransaction;
-
-  RustArcDecrementStrongCountFnType
-      get rust_arc_decrement_strong_count_InnerTransaction;
-
-  CrossPlatformFinalizerArg
-      get rust_arc_decrement_strong_count_InnerTransactionPtr;
-
-  RustArcIncrementStrongCountFnType
-      get rust_arc_increment_strong_count_LibsqlConnection;
-
-  RustArcDecrementStrongCountFnType
-      get rust_arc_decrement_strong_count_LibsqlConnection;
-
-  CrossPlatformFinalizerArg
-      get rust_arc_decrement_strong_count_LibsqlConnectionPtr;
-
-  RustArcIncrementStrongCountFnType
-      get rust_arc_increment_strong_count_LibsqlStatement;
-
-  RustArcDecrementStrongCountFnType
-      get rust_arc_decrement_strong_count_LibsqlStatement;
-
-  CrossPlatformFinalizerArg
-      get rust_arc_decrement_strong_count_LibsqlStatementPtr;
-
-  RustArcIncrementStrongCountFnType
-      get rust_arc_increment_strong_count_LibsqlTransaction;
-
-  RustArcDecrementStrongCountFnType
-      get rust_arc_decrement_strong_count_LibsqlTransaction;
-
-  CrossPlatformFinalizerArg
-      get rust_arc_decrement_strong_count_LibsqlTransactionPtr;
-}
-
-class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
-  RustLibApiImpl({
-    required super.handler,
-    required super.wire,
-    required super.generalizedFrbRustBinding,
-    required super.portManager,
-  });
-
-  @override
-  Future<void> crateApiConnectionLibsqlConnectionBatch(
-      {required LibsqlConnection that, required String sql}) {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlConnection(
-            that, serializer);
-        sse_encode_String(sql, serializer);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 1, port: port_);
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_unit,
-        decodeErrorData: null,
-      ),
-      constMeta: kCrateApiConnectionLibsqlConnectionBatchConstMeta,
-      argValues: [that, sql],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiConnectionLibsqlConnectionBatchConstMeta =>
-      const TaskConstMeta(
-        debugName: "LibsqlConnection_batch",
-        argNames: ["that", "sql"],
-      );
-
-  @override
-  Future<void> crateApiConnectionLibsqlConnectionDisableExtension(
-      {required LibsqlConnection that}) {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlConnection(
-            that, serializer);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 2, port: port_);
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_unit,
-        decodeErrorData: null,
-      ),
-      constMeta: kCrateApiConnectionLibsqlConnectionDisableExtensionConstMeta,
-      argValues: [that],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta
-      get kCrateApiConnectionLibsqlConnectionDisableExtensionConstMeta =>
-          const TaskConstMeta(
+Future<SyncResult> crateApiConnectionLibsqlConnectionPush({required LibsqlConnection that , LibsqlSyncConflictPolicy? conflictPolicy });
+
+Future<QueryResult> crateApiConnectionLibsqlConnectionQuery({required LibsqlConnection that , required String sql , LibsqlParams? parameters , BigInt? timeoutMs , LibsqlCancelToken? cancel });
+
+Future<ColumnarQueryResult> crateApiConnectionLibsqlConnectionQueryColumnar({required LibsqlConnection that , required String sql , LibsqlParams? parameters , BigInt? timeoutMs , LibsqlCancelToken? cancel });
+
+Future<LibsqlRows> crateApiConnectionLibsqlConnectionQueryStream({required LibsqlConnection that , required String sql , LibsqlParams? parameters , BigInt? timeoutMs , LibsqlCancelToken? cancel });
+
+Future<void> crateApiConnectionLibsqlConnectionSetBusyTimeout({required LibsqlConnection that , required BigInt timeoutMs });
+
+Future<void> crateApiConnectionLibsqlConnectionSetConflictReplay({required LibsqlConnection that , required FutureOr<void> Function() replay });
+
+Future<SyncResult> crateApiConnectionLibsqlConnectionSync({required LibsqlConnection that });
+
+Stream<LibsqlSyncEvent> crateApiConnectionLibsqlConnectionSyncEvents({required LibsqlConnection that });
+
+Future<LibsqlSyncStatus> crateApiConnectionLibsqlConnectionSyncStatus({required LibsqlConnection that });
+
+Future<SyncResult> crateApiConnectionLibsqlConnectionSyncUntil({required LibsqlConnection that , required BigInt replicationIndex , BigInt? timeoutMs });
+
+Future<LibsqlTransaction> crateApiConnectionLibsqlConnectionTransaction({required LibsqlConnection that , LibsqlTransactionBehavior? behavior });
+
+Stream<List<LibsqlUpdate>> crateApiConnectionLibsqlConnectionUpdates({required LibsqlConnection that });
+
+Stream<QueryResult> crateApiConnectionLibsqlConnectionWatch({required LibsqlConnection that , required String sql , LibsqlParams? parameters , BigInt? debounceMs });
+
+Future<void> crateApiLibsqlLibsqlCancelTokenCancel({required LibsqlCancelToken that });
+
+Future<LibsqlCancelToken> crateApiLibsqlLibsqlCancelTokenCreate();
+
+Future<void> crateApiPoolLibsqlPoolBatch({required LibsqlPool that , required String sql , BigInt? timeoutMs , LibsqlCancelToken? cancel });
+
+Future<BatchResult> crateApiPoolLibsqlPoolBatchStatements({required LibsqlPool that , required List<LibsqlBatchStatement> statements , LibsqlTransactionBehavior? behavior , BigInt? timeoutMs , LibsqlCancelToken? cancel });
+
+Future<void> crateApiPoolLibsqlPoolClose({required LibsqlPool that });
+
+Future<ExecuteResult> crateApiPoolLibsqlPoolExecute({required LibsqlPool that , required String sql , LibsqlParams? parameters , BigInt? timeoutMs , LibsqlCancelToken? cancel });
+
+Future<QueryResult> crateApiPoolLibsqlPoolQuery({required LibsqlPool that , required String sql , LibsqlParams? parameters , BigInt? timeoutMs , LibsqlCancelToken? cancel });
+
+Future<ColumnarQueryResult> crateApiPoolLibsqlPoolQueryColumnar({required LibsqlPool that , required String sql , LibsqlParams? parameters , BigInt? timeoutMs , LibsqlCancelToken? cancel });
+
+Future<LibsqlTransaction> crateApiPoolLibsqlPoolTransaction({required LibsqlPool that , LibsqlTransactionBehavior? behavior , BigInt? timeoutMs });
+
+Stream<QueryResult> crateApiPoolLibsqlPoolWatch({required LibsqlPool that , required String sql , LibsqlParams? parameters , BigInt? debounceMs });
+
+Future<void> crateApiRowsLibsqlRowsClose({required LibsqlRows that });
+
+Future<List<String>> crateApiRowsLibsqlRowsColumns({required LibsqlRows that });
+
+Future<List<String?>> crateApiRowsLibsqlRowsDeclTypes({required LibsqlRows that });
+
+Future<Map<String, LibsqlReturnValue>?> crateApiRowsLibsqlRowsNext({required LibsqlRows that });
+
+Future<List<Map<String, LibsqlReturnValue>>> crateApiRowsLibsqlRowsNextPage({required LibsqlRows that , required int size });
+
+Future<ExecuteResult> crateApiSavepointLibsqlSavepointExecute({required LibsqlSavepoint that , required String sql , LibsqlParams? parameters , BigInt? timeoutMs , LibsqlCancelToken? cancel });
+
+Future<String> crateApiSavepointLibsqlSavepointName({required LibsqlSavepoint that });
+
+Future<QueryResult> crateApiSavepointLibsqlSavepointQuery({required LibsqlSavepoint that , required String sql , LibsqlParams? parameters , BigInt? timeoutMs , LibsqlCancelToken? cancel });
+
+Future<void> crateApiSavepointLibsqlSavepointRelease({required LibsqlSavepoint that });
+
+Future<void> crateApiSavepointLibsqlSavepointRollbackTo({required LibsqlSavepoint that });
+
+Future<LibsqlSavepoint> crateApiSavepointLibsqlSavepointSavepoint({required LibsqlSavepoint that , required String name });
+
+Future<List<LibsqlColumn>> crateApiStatementLibsqlStatementColumns({required LibsqlStatement that });
+
+Future<ExecuteResult> crateApiStatementLibsqlStatementExecute({required LibsqlStatement that , LibsqlParams? parameters , BigInt? timeoutMs , LibsqlCancelToken? cancel });
+
+Future<void> crateApiStatementLibsqlStatementFinalize({required LibsqlStatement that });
+
+Future<bool> crateApiStatementLibsqlStatementIsReadonly({required LibsqlStatement that });
+
+Future<int> crateApiStatementLibsqlStatementParameterCount({required LibsqlStatement that });
+
+Future<String?> crateApiStatementLibsqlStatementParameterName({required LibsqlStatement that , required int index });
+
+Future<QueryResult> crateApiStatementLibsqlStatementQuery({required LibsqlStatement that , LibsqlParams? parameters , BigInt? timeoutMs , LibsqlCancelToken? cancel });
+
+Future<ColumnarQueryResult> crateApiStatementLibsqlStatementQueryColumnar({required LibsqlStatement that , LibsqlParams? parameters , BigInt? timeoutMs , LibsqlCancelToken? cancel });
+
+Future<LibsqlRows> crateApiStatementLibsqlStatementQueryStream({required LibsqlStatement that , LibsqlParams? parameters , BigInt? timeoutMs , LibsqlCancelToken? cancel });
+
+Future<void> crateApiStatementLibsqlStatementReset({required LibsqlStatement that });
+
+Future<void> crateApiTransactionLibsqlTransactionCommit({required LibsqlTransaction that });
+
+Future<ExecuteResult> crateApiTransactionLibsqlTransactionExecute({required LibsqlTransaction that , required String sql , LibsqlParams? parameters , BigInt? timeoutMs , LibsqlCancelToken? cancel });
+
+Future<bool> crateApiTransactionLibsqlTransactionIsActive({required LibsqlTransaction that });
+
+Future<bool> crateApiTransactionLibsqlTransactionIsAutocommit({required LibsqlTransaction that });
+
+Future<LibsqlStatement> crateApiTransactionLibsqlTransactionPrepare({required LibsqlTransaction that , required String sql });
+
+Future<QueryResult> crateApiTransactionLibsqlTransactionQuery({required LibsqlTransaction that , required String sql , LibsqlParams? parameters , BigInt? timeoutMs , LibsqlCancelToken? cancel });
+
+Future<ColumnarQueryResult> crateApiTransactionLibsqlTransactionQueryColumnar({required LibsqlTransaction that , required String sql , LibsqlParams? parameters , BigInt? timeoutMs , LibsqlCancelToken? cancel });
+
+Future<void> crateApiTransactionLibsqlTransactionRollback({required LibsqlTransaction that });
+
+Future<LibsqlSavepoint> crateApiTransactionLibsqlTransactionSavepoint({required LibsqlTransaction that , required String name });
+
+Future<LibsqlConnection> crateApiLibsqlConnect({required ConnectArgs args });
+
+Future<LibsqlPool> crateApiLibsqlConnectPool({required ConnectArgs args , int? readers });
+
+Future<void> crateApiLibsqlInitApp();
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_LibsqlConnection;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_LibsqlConnection;
+
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_LibsqlConnectionPtr;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_LibsqlPool;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_LibsqlPool;
+
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_LibsqlPoolPtr;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_LibsqlRows;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_LibsqlRows;
+
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_LibsqlRowsPtr;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_LibsqlSavepoint;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_LibsqlSavepoint;
+
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_LibsqlSavepointPtr;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_LibsqlStatement;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_LibsqlStatement;
+
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_LibsqlStatementPtr;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_LibsqlTransaction;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_LibsqlTransaction;
+
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_LibsqlTransactionPtr;
+
+                }
+
+                class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
+                  RustLibApiImpl({
+                    required super.handler,
+                    required super.wire,
+                    required super.generalizedFrbRustBinding,
+                    required super.portManager,
+                  });
+
+                  @override Future<void> crateApiConnectionLibsqlConnectionBatch({required LibsqlConnection that , required String sql , BigInt? timeoutMs , LibsqlCancelToken? cancel })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlConnection(that, serializer);
+sse_encode_String(sql, serializer);
+sse_encode_opt_box_autoadd_u_64(timeoutMs, serializer);
+sse_encode_opt_box_autoadd_libsql_cancel_token(cancel, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 1, port: port_);
+
+            },
+            codec:
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_libsql_error,
+        )
+        ,
+            constMeta: kCrateApiConnectionLibsqlConnectionBatchConstMeta,
+            argValues: [that, sql, timeoutMs, cancel],
+            apiImpl: this,
+        )); }
+
+        TaskConstMeta get kCrateApiConnectionLibsqlConnectionBatchConstMeta => const TaskConstMeta(
+            debugName: "LibsqlConnection_batch",
+            argNames: ["that", "sql", "timeoutMs", "cancel"],
+        );
+
+@override Future<BatchResult> crateApiConnectionLibsqlConnectionBatchStatements({required LibsqlConnection that , required List<LibsqlBatchStatement> statements , LibsqlTransactionBehavior? behavior , BigInt? timeoutMs , LibsqlCancelToken? cancel })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlConnection(that, serializer);
+sse_encode_list_libsql_batch_statement(statements, serializer);
+sse_encode_opt_box_autoadd_libsql_transaction_behavior(behavior, serializer);
+sse_encode_opt_box_autoadd_u_64(timeoutMs, serializer);
+sse_encode_opt_box_autoadd_libsql_cancel_token(cancel, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 2, port: port_);
+
+            },
+            codec:
+        SseCodec(
+          decodeSuccessData: sse_decode_batch_result,
+          decodeErrorData: sse_decode_libsql_error,
+        )
+        ,
+            constMeta: kCrateApiConnectionLibsqlConnectionBatchStatementsConstMeta,
+            argValues: [that, statements, behavior, timeoutMs, cancel],
+            apiImpl: this,
+        )); }
+
+        TaskConstMeta get kCrateApiConnectionLibsqlConnectionBatchStatementsConstMeta => const TaskConstMeta(
+            debugName: "LibsqlConnection_batch_statements",
+            argNames: ["that", "statements", "behavior", "timeoutMs", "cancel"],
+        );
+
+@override Future<void> crateApiConnectionLibsqlConnectionClose({required LibsqlConnection that })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlConnection(that, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 3, port: port_);
+
+            },
+            codec:
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_libsql_error,
+        )
+        ,
+            constMeta: kCrateApiConnectionLibsqlConnectionCloseConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+        TaskConstMeta get kCrateApiConnectionLibsqlConnectionCloseConstMeta => const TaskConstMeta(
+            debugName: "LibsqlConnection_close",
+            argNames: ["that"],
+        );
+
+@override Future<void> crateApiConnectionLibsqlConnectionCreateAggregateFunction({required LibsqlConnection that , required String name , required int nArgs , required bool deterministic , required FutureOr<Object> Function() init , required FutureOr<Object> Function(Object, List<LibsqlReturnValue>) step , required FutureOr<LibsqlValue> Function(Object) finalize })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlConnection(that, serializer);
+sse_encode_String(name, serializer);
+sse_encode_i_32(nArgs, serializer);
+sse_encode_bool(deterministic, serializer);
+sse_encode_DartFn_Inputs__Output_DartOpaque_AnyhowException(init, serializer);
+sse_encode_DartFn_Inputs_DartOpaque_list_libsql_return_value_Output_DartOpaque_AnyhowException(step, serializer);
+sse_encode_DartFn_Inputs_DartOpaque_Output_libsql_value_AnyhowException(finalize, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 4, port: port_);
+
+            },
+            codec:
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_libsql_error,
+        )
+        ,
+            constMeta: kCrateApiConnectionLibsqlConnectionCreateAggregateFunctionConstMeta,
+            argValues: [that, name, nArgs, deterministic, init, step, finalize],
+            apiImpl: this,
+        )); }
+
+        TaskConstMeta get kCrateApiConnectionLibsqlConnectionCreateAggregateFunctionConstMeta => const TaskConstMeta(
+            debugName: "LibsqlConnection_create_aggregate_function",
+            argNames: ["that", "name", "nArgs", "deterministic", "init", "step", "finalize"],
+        );
+
+@override Future<void> crateApiConnectionLibsqlConnectionCreateBuiltinCollation({required LibsqlConnection that , required String name , required LibsqlCollation collation })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlConnection(that, serializer);
+sse_encode_String(name, serializer);
+sse_encode_libsql_collation(collation, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 5, port: port_);
+
+            },
+            codec:
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_libsql_error,
+        )
+        ,
+            constMeta: kCrateApiConnectionLibsqlConnectionCreateBuiltinCollationConstMeta,
+            argValues: [that, name, collation],
+            apiImpl: this,
+        )); }
+
+        TaskConstMeta get kCrateApiConnectionLibsqlConnectionCreateBuiltinCollationConstMeta => const TaskConstMeta(
+            debugName: "LibsqlConnection_create_builtin_collation",
+            argNames: ["that", "name", "collation"],
+        );
+
+@override Future<void> crateApiConnectionLibsqlConnectionCreateCollation({required LibsqlConnection that , required String name , required FutureOr<int> Function(String, String) compare })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlConnection(that, serializer);
+sse_encode_String(name, serializer);
+sse_encode_DartFn_Inputs_String_String_Output_i_32_AnyhowException(compare, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 6, port: port_);
+
+            },
+            codec:
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_libsql_error,
+        )
+        ,
+            constMeta: kCrateApiConnectionLibsqlConnectionCreateCollationConstMeta,
+            argValues: [that, name, compare],
+            apiImpl: this,
+        )); }
+
+        TaskConstMeta get kCrateApiConnectionLibsqlConnectionCreateCollationConstMeta => const TaskConstMeta(
+            debugName: "LibsqlConnection_create_collation",
+            argNames: ["that", "name", "compare"],
+        );
+
+@override Future<void> crateApiConnectionLibsqlConnectionCreateScalarFunction({required LibsqlConnection that , required String name , required int nArgs , required bool deterministic , required FutureOr<LibsqlValue> Function(List<LibsqlReturnValue>) callback })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlConnection(that, serializer);
+sse_encode_String(name, serializer);
+sse_encode_i_32(nArgs, serializer);
+sse_encode_bool(deterministic, serializer);
+sse_encode_DartFn_Inputs_list_libsql_return_value_Output_libsql_value_AnyhowException(callback, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 7, port: port_);
+
+            },
+            codec:
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_libsql_error,
+        )
+        ,
+            constMeta: kCrateApiConnectionLibsqlConnectionCreateScalarFunctionConstMeta,
+            argValues: [that, name, nArgs, deterministic, callback],
+            apiImpl: this,
+        )); }
+
+        TaskConstMeta get kCrateApiConnectionLibsqlConnectionCreateScalarFunctionConstMeta => const TaskConstMeta(
+            debugName: "LibsqlConnection_create_scalar_function",
+            argNames: ["that", "name", "nArgs", "deterministic", "callback"],
+        );
+
+@override Future<void> crateApiConnectionLibsqlConnectionCreateWindowFunction({required LibsqlConnection that , required String name , required int nArgs , required bool deterministic , required FutureOr<Object> Function() init , required FutureOr<Object> Function(Object, List<LibsqlReturnValue>) step , required FutureOr<Object> Function(Object, List<LibsqlReturnValue>) inverse , required FutureOr<LibsqlValue> Function(Object) value , required FutureOr<LibsqlValue> Function(Object) finalize })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlConnection(that, serializer);
+sse_encode_String(name, serializer);
+sse_encode_i_32(nArgs, serializer);
+sse_encode_bool(deterministic, serializer);
+sse_encode_DartFn_Inputs__Output_DartOpaque_AnyhowException(init, serializer);
+sse_encode_DartFn_Inputs_DartOpaque_list_libsql_return_value_Output_DartOpaque_AnyhowException(step, serializer);
+sse_encode_DartFn_Inputs_DartOpaque_list_libsql_return_value_Output_DartOpaque_AnyhowException(inverse, serializer);
+sse_encode_DartFn_Inputs_DartOpaque_Output_libsql_value_AnyhowException(value, serializer);
+sse_encode_DartFn_Inputs_DartOpaque_Output_libsql_value_AnyhowException(finalize, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 8, port: port_);
+
+            },
+            codec:
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_libsql_error,
+        )
+        ,
+            constMeta: kCrateApiConnectionLibsqlConnectionCreateWindowFunctionConstMeta,
+            argValues: [that, name, nArgs, deterministic, init, step, inverse, value, finalize],
+            apiImpl: this,
+        )); }
+
+        TaskConstMeta get kCrateApiConnectionLibsqlConnectionCreateWindowFunctionConstMeta => const TaskConstMeta(
+            debugName: "LibsqlConnection_create_window_function",
+            argNames: ["that", "name", "nArgs", "deterministic", "init", "step", "inverse", "value", "finalize"],
+        );
+
+@override Future<void> crateApiConnectionLibsqlConnectionDisableExtension({required LibsqlConnection that })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlConnection(that, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 9, port: port_);
+
+            },
+            codec:
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_libsql_error,
+        )
+        ,
+            constMeta: kCrateApiConnectionLibsqlConnectionDisableExtensionConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+        TaskConstMeta get kCrateApiConnectionLibsqlConnectionDisableExtensionConstMeta => const TaskConstMeta(
             debugName: "LibsqlConnection_disable_extension",
             argNames: ["that"],
-          );
-
-  @override
-  Future<void> crateApiConnectionLibsqlConnectionEnableExtension(
-      {required LibsqlConnection that}) {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlConnection(
-            that, serializer);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 3, port: port_);
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_unit,
-        decodeErrorData: null,
-      ),
-      constMeta: kCrateApiConnectionLibsqlConnectionEnableExtensionConstMeta,
-      argValues: [that],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta
-      get kCrateApiConnectionLibsqlConnectionEnableExtensionConstMeta =>
-          const TaskConstMeta(
+        );
+
+@override Future<void> crateApiConnectionLibsqlConnectionDiscardLocalChanges({required LibsqlConnection that })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlConnection(that, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 10, port: port_);
+
+            },
+            codec:
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_libsql_error,
+        )
+        ,
+            constMeta: kCrateApiConnectionLibsqlConnectionDiscardLocalChangesConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+        TaskConstMeta get kCrateApiConnectionLibsqlConnectionDiscardLocalChangesConstMeta => const TaskConstMeta(
+            debugName: "LibsqlConnection_discard_local_changes",
+            argNames: ["that"],
+        );
+
+@override Future<void> crateApiConnectionLibsqlConnectionEnableExtension({required LibsqlConnection that })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlConnection(that, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 11, port: port_);
+
+            },
+            codec:
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_libsql_error,
+        )
+        ,
+            constMeta: kCrateApiConnectionLibsqlConnectionEnableExtensionConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+        TaskConstMeta get kCrateApiConnectionLibsqlConnectionEnableExtensionConstMeta => const TaskConstMeta(
             debugName: "LibsqlConnection_enable_extension",
             argNames: ["that"],
-          );
-
-  @override
-  Future<ExecuteResult> crateApiConnectionLibsqlConnectionExecute(
-      {required LibsqlConnection that,
-      required String sql,
-      LibsqlParams? parameters}) {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlConnection(
-            that, serializer);
-        sse_encode_String(sql, serializer);
-        sse_encode_opt_box_autoadd_libsql_params(parameters, serializer);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 4, port: port_);
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_execute_result,
-        decodeErrorData: null,
-      ),
-      constMeta: kCrateApiConnectionLibsqlConnectionExecuteConstMeta,
-      argValues: [that, sql, parameters],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiConnectionLibsqlConnectionExecuteConstMeta =>
-      const TaskConstMeta(
-        debugName: "LibsqlConnection_execute",
-        argNames: ["that", "sql", "parameters"],
-      );
-
-  @override
-  Future<void> crateApiConnectionLibsqlConnectionLoadExtension(
-      {required LibsqlConnection that,
-      required String path,
-      String? entryPoint}) {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlConnection(
-            that, serializer);
-        sse_encode_String(path, serializer);
-        sse_encode_opt_String(entryPoint, serializer);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 5, port: port_);
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_unit,
-        decodeErrorData: null,
-      ),
-      constMeta: kCrateApiConnectionLibsqlConnectionLoadExtensionConstMeta,
-      argValues: [that, path, entryPoint],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiConnectionLibsqlConnectionLoadExtensionConstMeta =>
-      const TaskConstMeta(
-        debugName: "LibsqlConnection_load_extension",
-        argNames: ["that", "path", "entryPoint"],
-      );
-
-  @override
-  Future<LibsqlConnection> crateApiConnectionLibsqlConnectionNew(
-      {required InnerConnection connection, required InnerDatabase database}) {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerInnerConnection(
-            connection, serializer);
-        sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerInnerDatabase(
-            database, serializer);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 6, port: port_);
-      },
-      codec: SseCodec(
-        decodeSuccessData:
-            sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlConnection,
-        decodeErrorData: null,
-      ),
-      constMeta: kCrateApiConnectionLibsqlConnectionNewConstMeta,
-      argValues: [connection, database],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiConnectionLibsqlConnectionNewConstMeta =>
-      const TaskConstMeta(
-        debugName: "LibsqlConnection_new",
-        argNames: ["connection", "database"],
-      );
-
-  @override
-  Future<LibsqlStatement> crateApiConnectionLibsqlConnectionPrepare(
-      {required LibsqlConnection that, required String sql}) {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlConnection(
-            that, serializer);
-        sse_encode_String(sql, serializer);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 7, port: port_);
-      },
-      codec: SseCodec(
-        decodeSuccessData:
-            sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlStatement,
-        decodeErrorData: null,
-      ),
-      constMeta: kCrateApiConnectionLibsqlConnectionPrepareConstMeta,
-      argValues: [that, sql],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiConnectionLibsqlConnectionPrepareConstMeta =>
-      const TaskConstMeta(
-        debugName: "LibsqlConnection_prepare",
-        argNames: ["that", "sql"],
-      );
-
-  @override
-  Future<QueryResult> crateApiConnectionLibsqlConnectionQuery(
-      {required LibsqlConnection that,
-      required String sql,
-      LibsqlParams? parameters}) {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlConnection(
-            that, serializer);
-        sse_encode_String(sql, serializer);
-        sse_encode_opt_box_autoadd_libsql_params(parameters, serializer);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 8, port: port_);
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_query_result,
-        decodeErrorData: null,
-      ),
-      constMeta: kCrateApiConnectionLibsqlConnectionQueryConstMeta,
-      argValues: [that, sql, parameters],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiConnectionLibsqlConnectionQueryConstMeta =>
-      const TaskConstMeta(
-        debugName: "LibsqlConnection_query",
-        argNames: ["that", "sql", "parameters"],
-      );
-
-  @override
-  Future<void> crateApiConnectionLibsqlConnectionSync(
-      {required LibsqlConnection that}) {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlConnection(
-            that, serializer);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 9, port: port_);
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_unit,
-        decodeErrorData: null,
-      ),
-      constMeta: kCrateApiConnectionLibsqlConnectionSyncConstMeta,
-      argValues: [that],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiConnectionLibsqlConnectionSyncConstMeta =>
-      const TaskConstMeta(
-        debugName: "LibsqlConnection_sync",
-        argNames: ["that"],
-      );
-
-  @override
-  Future<LibsqlTransaction> crateApiConnectionLibsqlConnectionTransaction(
-      {required LibsqlConnection that, LibsqlTransactionBehavior? behavior}) {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlConnection(
-            that, serializer);
-        sse_encode_opt_box_autoadd_libsql_transaction_behavior(
-            behavior, serializer);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 10, port: port_);
-      },
-      codec: SseCodec(
-        decodeSuccessData:
-            sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlTransaction,
-        decodeErrorData: null,
-      ),
-      constMeta: kCrateApiConnectionLibsqlConnectionTransactionConstMeta,
-      argValues: [that, behavior],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiConnectionLibsqlConnectionTransactionConstMeta =>
-      const TaskConstMeta(
-        debugName: "LibsqlConnection_transaction",
-        argNames: ["that", "behavior"],
-      );
-
-  @override
-  Future<ExecuteResult> crateApiStatementLibsqlStatementExecute(
-      {required LibsqlStatement that, LibsqlParams? parameters}) {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlStatement(
-            that, serializer);
-        sse_encode_opt_box_autoadd_libsql_params(parameters, serializer);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 11, port: port_);
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_execute_result,
-        decodeErrorData: null,
-      ),
-      constMeta: kCrateApiStatementLibsqlStatementExecuteConstMeta,
-      argValues: [that, parameters],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiStatementLibsqlStatementExecuteConstMeta =>
-      const TaskConstMeta(
-        debugName: "LibsqlStatement_execute",
-        argNames: ["that", "parameters"],
-      );
-
-  @override
-  Future<void> crateApiStatementLibsqlStatementFinalize(
-      {required LibsqlStatement that}) {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlStatement(
-            that, serializer);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 12, port: port_);
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_unit,
-        decodeErrorData: null,
-      ),
-      constMeta: kCrateApiStatementLibsqlStatementFinalizeConstMeta,
-      argValues: [that],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiStatementLibsqlStatementFinalizeConstMeta =>
-      const TaskConstMeta(
-        debugName: "LibsqlStatement_finalize",
-        argNames: ["that"],
-      );
-
-  @override
-  Future<LibsqlStatement> crateApiStatementLibsqlStatementNew(
-      {required InnerStatement statement}) {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerInnerStatement(
-            statement, serializer);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 13, port: port_);
-      },
-      codec: SseCodec(
-        decodeSuccessData:
-            sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlStatement,
-        decodeErrorData: null,
-      ),
-      constMeta: kCrateApiStatementLibsqlStatementNewConstMeta,
-      argValues: [statement],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiStatementLibsqlStatementNewConstMeta =>
-      const TaskConstMeta(
-        debugName: "LibsqlStatement_new",
-        argNames: ["statement"],
-      );
-
-  @override
-  Future<QueryResult> crateApiStatementLibsqlStatementQuery(
-      {required LibsqlStatement that, LibsqlParams? parameters}) {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlStatement(
-            that, serializer);
-        sse_encode_opt_box_autoadd_libsql_params(parameters, serializer);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 14, port: port_);
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_query_result,
-        decodeErrorData: null,
-      ),
-      constMeta: kCrateApiStatementLibsqlStatementQueryConstMeta,
-      argValues: [that, parameters],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiStatementLibsqlStatementQueryConstMeta =>
-      const TaskConstMeta(
-        debugName: "LibsqlStatement_query",
-        argNames: ["that", "parameters"],
-      );
-
-  @override
-  Future<void> crateApiStatementLibsqlStatementReset(
-      {required LibsqlStatement that}) {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlStatement(
-            that, serializer);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 15, port: port_);
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_unit,
-        decodeErrorData: null,
-      ),
-      constMeta: kCrateApiStatementLibsqlStatementResetConstMeta,
-      argValues: [that],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiStatementLibsqlStatementResetConstMeta =>
-      const TaskConstMeta(
-        debugName: "LibsqlStatement_reset",
-        argNames: ["that"],
-      );
-
-  @override
-  Future<void> crateApiTransactionLibsqlTransactionCommit(
-      {required LibsqlTransaction that}) {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlTransaction(
-            that, serializer);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 16, port: port_);
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_unit,
-        decodeErrorData: null,
-      ),
-      constMeta: kCrateApiTransactionLibsqlTransactionCommitConstMeta,
-      argValues: [that],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiTransactionLibsqlTransactionCommitConstMeta =>
-      const TaskConstMeta(
-        debugName: "LibsqlTransaction_commit",
-        argNames: ["that"],
-      );
-
-  @override
-  Future<ExecuteResult> crateApiTransactionLibsqlTransactionExecute(
-      {required LibsqlTransaction that,
-      required String sql,
-      LibsqlParams? parameters}) {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlTransaction(
-            that, serializer);
-        sse_encode_String(sql, serializer);
-        sse_encode_opt_box_autoadd_libsql_params(parameters, serializer);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 17, port: port_);
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_execute_result,
-        decodeErrorData: null,
-      ),
-      constMeta: kCrateApiTransactionLibsqlTransactionExecuteConstMeta,
-      argValues: [that, sql, parameters],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiTransactionLibsqlTransactionExecuteConstMeta =>
-      const TaskConstMeta(
-        debugName: "LibsqlTransaction_execute",
-        argNames: ["that", "sql", "parameters"],
-      );
-
-  @override
-  Future<LibsqlTransaction> crateApiTransactionLibsqlTransactionNew(
-      {required InnerTransaction transaction}) {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerInnerTransaction(
-            transaction, serializer);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 18, port: port_);
-      },
-      codec: SseCodec(
-        decodeSuccessData:
-            sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlTransaction,
-        decodeErrorData: null,
-      ),
-      constMeta: kCrateApiTransactionLibsqlTransactionNewConstMeta,
-      argValues: [transaction],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiTransactionLibsqlTransactionNewConstMeta =>
-      const TaskConstMeta(
-        debugName: "LibsqlTransaction_new",
-        argNames: ["transaction"],
-      );
-
-  @override
-  Future<QueryResult> crateApiTransactionLibsqlTransactionQuery(
-      {required LibsqlTransaction that,
-      required String sql,
-      LibsqlParams? parameters}) {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlTransaction(
-            that, serializer);
-        sse_encode_String(sql, serializer);
-        sse_encode_opt_box_autoadd_libsql_params(parameters, serializer);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 19, port: port_);
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_query_result,
-        decodeErrorData: null,
-      ),
-      constMeta: kCrateApiTransactionLibsqlTransactionQueryConstMeta,
-      argValues: [that, sql, parameters],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiTransactionLibsqlTransactionQueryConstMeta =>
-      const TaskConstMeta(
-        debugName: "LibsqlTransaction_query",
-        argNames: ["that", "sql", "parameters"],
-      );
-
-  @override
-  Future<void> crateApiTransactionLibsqlTransactionRollback(
-      {required LibsqlTransaction that}) {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlTransaction(
-            that, serializer);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 20, port: port_);
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_unit,
-        decodeErrorData: null,
-      ),
-      constMeta: kCrateApiTransactionLibsqlTransactionRollbackConstMeta,
-      argValues: [that],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiTransactionLibsqlTransactionRollbackConstMeta =>
-      const TaskConstMeta(
-        debugName: "LibsqlTransaction_rollback",
-        argNames: ["that"],
-      );
-
-  @override
-  Future<LibsqlConnection> crateApiLibsqlConnect({required ConnectArgs args}) {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_box_autoadd_connect_args(args, serializer);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 21, port: port_);
-      },
-      codec: SseCodec(
-        decodeSuccessData:
-            sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlConnection,
-        decodeErrorData: null,
-      ),
-      constMeta: kCrateApiLibsqlConnectConstMeta,
-      argValues: [args],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiLibsqlConnectConstMeta => const TaskConstMeta(
-        debugName: "connect",
-        argNames: ["args"],
-      );
-
-  @override
-  Future<void> crateApiLibsqlInitApp() {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 22, port: port_);
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_unit,
-        decodeErrorData: null,
-      ),
-      constMeta: kCrateApiLibsqlInitAppConstMeta,
-      argValues: [],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiLibsqlInitAppConstMeta => const TaskConstMeta(
-        debugName: "init_app",
-        argNames: [],
-      );
-
-  RustArcIncrementStrongCountFnType
-      get rust_arc_increment_strong_count_InnerConnection => wire
-          .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerInnerConnection;
-
-  RustArcDecrementStrongCountFnType
-      get rust_arc_decrement_strong_count_InnerConnection => wire
-          .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerInnerConnection;
-
-  RustArcIncrementStrongCountFnType
-      get rust_arc_increment_strong_count_InnerDatabase => wire
-          .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerInnerDatabase;
-
-  RustArcDecrementStrongCountFnType
-      get rust_arc_decrement_strong_count_InnerDatabase => wire
-          .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerInnerDatabase;
-
-  RustArcIncrementStrongCountFnType
-      get rust_arc_increment_strong_count_InnerStatement => wire
-          .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerInnerStatement;
-
-  RustArcDecrementStrongCountFnType
-      get rust_arc_decrement_strong_count_InnerStatement => wire
-          .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerInnerStatement;
-
-  RustArcIncrementStrongCountFnType
-      get rust_arc_increment_strong_count_InnerTransaction => wire
-          .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerInnerTransaction;
-
-  RustArcDecrementStrongCountFnType
-      get rust_arc_decrement_strong_count_InnerTransaction => wire
-          .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerInnerTransaction;
-
-  RustArcIncrementStrongCountFnType
-      get rust_arc_increment_strong_count_LibsqlConnection => wire
-          .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlConnection;
-
-  RustArcDecrementStrongCountFnType
-      get rust_arc_decrement_strong_count_LibsqlConnection => wire
-          .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlConnection;
-
-  RustArcIncrementStrongCountFnType
-      get rust_arc_increment_strong_count_LibsqlStatement => wire
-          .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlStatement;
-
-  RustArcDecrementStrongCountFnType
-      get rust_arc_decrement_strong_count_LibsqlStatement => wire
-          .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlStatement;
-
-  RustArcIncrementStrongCountFnType
-      get rust_arc_increment_strong_count_LibsqlTransaction => wire
-          .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlTransaction;
-
-  RustArcDecrementStrongCountFnType
-      get rust_arc_decrement_strong_count_LibsqlTransaction => wire
-          .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlTransaction;
-
-  @protected
-  InnerConnection
-      dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerInnerConnection(
-          dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return InnerConnectionImpl.frbInternalDcoDecode(raw as List<dynamic>);
-  }
-
-  @protected
-  InnerDatabase
-      dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerInnerDatabase(
-          dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return InnerDatabaseImpl.frbInternalDcoDecode(raw as List<dynamic>);
-  }
-
-  @protected
-  InnerStatement
-      dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerInnerStatement(
-          dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return InnerStatementImpl.frbInternalDcoDecode(raw as List<dynamic>);
-  }
-
-  @protected
-  InnerTransaction
-      dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerInnerTransaction(
-          dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return InnerTransactionImpl.frbInternalDcoDecode(raw as List<dynamic>);
-  }
-
-  @protected
-  LibsqlConnection
-      dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlConnection(
-          dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return LibsqlConnectionImpl.frbInternalDcoDecode(raw as List<dynamic>);
-  }
-
-  @protected
-  LibsqlStatement
-      dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlStatement(
-          dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return LibsqlStatementImpl.frbInternalDcoDecode(raw as List<dynamic>);
-  }
-
-  @protected
-  LibsqlTransaction
-      dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlTransaction(
-          dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return LibsqlTransactionImpl.frbInternalDcoDecode(raw as List<dynamic>);
-  }
-
-  @protected
-  LibsqlTransaction
-      dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlTransaction(
-          dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return LibsqlTransactionImpl.frbInternalDcoDecode(raw as List<dynamic>);
-  }
-
-  @protected
-  LibsqlConnection
-      dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlConnection(
-          dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return LibsqlConnectionImpl.frbInternalDcoDecode(raw as List<dynamic>);
-  }
-
-  @protected
-  LibsqlStatement
-      dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlStatement(
-          dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return LibsqlStatementImpl.frbInternalDcoDecode(raw as List<dynamic>);
-  }
-
-  @protected
-  LibsqlTransaction
-      dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlTransaction(
-          dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return LibsqlTransactionImpl.frbInternalDcoDecode(raw as List<dynamic>);
-  }
-
-  @protected
-  Map<String, LibsqlReturnValue> dco_decode_Map_String_libsql_return_value_None(
-      dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return Map.fromEntries(
-        dco_decode_list_record_string_libsql_return_value(raw)
-            .map((e) => MapEntry(e.$1, e.$2)));
-  }
-
-  @protected
-  Map<String, LibsqlValue> dco_decode_Map_String_libsql_value_None(
-      dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return Map.fromEntries(dco_decode_list_record_string_libsql_value(raw)
-        .map((e) => MapEntry(e.$1, e.$2)));
-  }
-
-  @protected
-  InnerConnection
-      dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerInnerConnection(
-          dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return InnerConnectionImpl.frbInternalDcoDecode(raw as List<dynamic>);
-  }
-
-  @protected
-  InnerDatabase
-      dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerInnerDatabase(
-          dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return InnerDatabaseImpl.frbInternalDcoDecode(raw as List<dynamic>);
-  }
-
-  @protected
-  InnerStatement
-      dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerInnerStatement(
-          dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return InnerStatementImpl.frbInternalDcoDecode(raw as List<dynamic>);
-  }
-
-  @protected
-  InnerTransaction
-      dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerInnerTransaction(
-          dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return InnerTransactionImpl.frbInternalDcoDecode(raw as List<dynamic>);
-  }
-
-  @protected
-  LibsqlConnection
-      dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlConnection(
-          dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return LibsqlConnectionImpl.frbInternalDcoDecode(raw as List<dynamic>);
-  }
-
-  @protected
-  LibsqlStatement
-      dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlStatement(
-          dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return LibsqlStatementImpl.frbInternalDcoDecode(raw as List<dynamic>);
-  }
-
-  @protected
-  LibsqlTransaction
-      dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlTransaction(
-          dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return LibsqlTransactionImpl.frbInternalDcoDecode(raw as List<dynamic>);
-  }
-
-  @protected
-  String dco_decode_String(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as String;
-  }
-
-  @protected
-  bool dco_decode_bool(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as bool;
-  }
-
-  @protected
-  bool dco_decode_box_autoadd_bool(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as bool;
-  }
-
-  @protected
-  ConnectArgs dco_decode_box_autoadd_connect_args(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_connect_args(raw);
-  }
-
-  @protected
-  LibsqlOpenFlags dco_decode_box_autoadd_libsql_open_flags(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_libsql_open_flags(raw);
-  }
-
-  @protected
-  LibsqlParams dco_decode_box_autoadd_libsql_params(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_libsql_params(raw);
-  }
-
-  @protected
-  LibsqlTransactionBehavior dco_decode_box_autoadd_libsql_transaction_behavior(
-      dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_libsql_transaction_behavior(raw);
-  }
-
-  @protected
-  BigInt dco_decode_box_autoadd_u_64(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_u_64(raw);
-  }
-
-  @protected
-  ConnectArgs dco_decode_connect_args(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 8)
-      throw Exception('unexpected arr length: expect 8 but see ${arr.length}');
-    return ConnectArgs(
-      url: dco_decode_String(arr[0]),
-      authToken: dco_decode_opt_String(arr[1]),
-      syncUrl: dco_decode_opt_String(arr[2]),
-      syncIntervalSeconds: dco_decode_opt_box_autoadd_u_64(arr[3]),
-      encryptionKey: dco_decode_opt_String(arr[4]),
-      readYourWrites: dco_decode_opt_box_autoadd_bool(arr[5]),
-      openFlags: dco_decode_opt_box_autoadd_libsql_open_flags(arr[6]),
-      offline: dco_decode_opt_box_autoadd_bool(arr[7]),
-    );
-  }
-
-  @protected
-  ExecuteResult dco_decode_execute_result(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 1)
-      throw Exception('unexpected arr length: expect 1 but see ${arr.length}');
-    return ExecuteResult(
-      rowsAffected: dco_decode_u_64(arr[0]),
-    );
-  }
-
-  @protected
-  double dco_decode_f_64(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as double;
-  }
-
-  @protected
-  int dco_decode_i_32(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as int;
-  }
-
-  @protected
-  PlatformInt64 dco_decode_i_64(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dcoDecodeI64(raw);
-  }
-
-  @protected
-  LibsqlOpenFlags dco_decode_libsql_open_flags(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return LibsqlOpenFlags.values[raw as int];
-  }
-
-  @protected
-  LibsqlParams dco_decode_libsql_params(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 2)
-      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
-    return LibsqlParams(
-      positional: dco_decode_opt_list_libsql_value(arr[0]),
-      named: dco_decode_opt_Map_String_libsql_value_None(arr[1]),
-    );
-  }
-
-  @protected
-  LibsqlReturnValue dco_decode_libsql_return_value(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    switch (raw[0]) {
-      case 0:
-        return LibsqlReturnValue_Integer(
-          dco_decode_i_64(raw[1]),
-        );
-      case 1:
-        return LibsqlReturnValue_Real(
-          dco_decode_f_64(raw[1]),
-        );
-      case 2:
-        return LibsqlReturnValue_Text(
-          dco_decode_String(raw[1]),
-        );
-      case 3:
-        return LibsqlReturnValue_Blob(
-          dco_decode_list_prim_u_8_strict(raw[1]),
-        );
-      case 4:
-        return LibsqlReturnValue_Null();
-      default:
-        throw Exception("unreachable");
-    }
-  }
-
-  @protected
-  LibsqlTransactionBehavior dco_decode_libsql_transaction_behavior(
-      dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return LibsqlTransactionBehavior.values[raw as int];
-  }
-
-  @protected
-  LibsqlValue dco_decode_libsql_value(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    switch (raw[0]) {
-      case 0:
-        return LibsqlValue_Integer(
-          dco_decode_i_64(raw[1]),
-        );
-      case 1:
-        return LibsqlValue_Real(
-          dco_decode_f_64(raw[1]),
-        );
-      case 2:
-        return LibsqlValue_Text(
-          dco_decode_String(raw[1]),
-        );
-      case 3:
-        return LibsqlValue_Blob(
-          dco_decode_list_prim_u_8_strict(raw[1]),
-        );
-      case 4:
-        return LibsqlValue_Null();
-      default:
-        throw Exception("unreachable");
-    }
-  }
-
-  @protected
-  List<Map<String, LibsqlReturnValue>>
-      dco_decode_list_Map_String_libsql_return_value_None(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>)
-        .map(dco_decode_Map_String_libsql_return_value_None)
-        .toList();
-  }
-
-  @protected
-  List<String> dco_decode_list_String(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_String).toList();
-  }
-
-  @protected
-  List<LibsqlValue> dco_decode_list_libsql_value(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_libsql_value).toList();
-  }
-
-  @protected
-  Uint8List dco_decode_list_prim_u_8_strict(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as Uint8List;
-  }
-
-  @protected
-  List<(String, LibsqlReturnValue)>
-      dco_decode_list_record_string_libsql_return_value(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>)
-        .map(dco_decode_record_string_libsql_return_value)
-        .toList();
-  }
-
-  @protected
-  List<(String, LibsqlValue)> dco_decode_list_record_string_libsql_value(
-      dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>)
-        .map(dco_decode_record_string_libsql_value)
-        .toList();
-  }
-
-  @protected
-  Map<String, LibsqlValue>? dco_decode_opt_Map_String_libsql_value_None(
-      dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null ? null : dco_decode_Map_String_libsql_value_None(raw);
-  }
-
-  @protected
-  String? dco_decode_opt_String(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null ? null : dco_decode_String(raw);
-  }
-
-  @protected
-  bool? dco_decode_opt_box_autoadd_bool(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null ? null : dco_decode_box_autoadd_bool(raw);
-  }
-
-  @protected
-  LibsqlOpenFlags? dco_decode_opt_box_autoadd_libsql_open_flags(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null ? null : dco_decode_box_autoadd_libsql_open_flags(raw);
-  }
-
-  @protected
-  LibsqlParams? dco_decode_opt_box_autoadd_libsql_params(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null ? null : dco_decode_box_autoadd_libsql_params(raw);
-  }
-
-  @protected
-  LibsqlTransactionBehavior?
-      dco_decode_opt_box_autoadd_libsql_transaction_behavior(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null
-        ? null
-        : dco_decode_box_autoadd_libsql_transaction_behavior(raw);
-  }
-
-  @protected
-  BigInt? dco_decode_opt_box_autoadd_u_64(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null ? null : dco_decode_box_autoadd_u_64(raw);
-  }
-
-  @protected
-  List<LibsqlValue>? dco_decode_opt_list_libsql_value(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null ? null : dco_decode_list_libsql_value(raw);
-  }
-
-  @protected
-  QueryResult dco_decode_query_result(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 4)
-      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
-    return QueryResult(
-      rows: dco_decode_list_Map_String_libsql_return_value_None(arr[0]),
-      columns: dco_decode_list_String(arr[1]),
-      rowsAffected: dco_decode_u_64(arr[2]),
-      lastInsertRowid: dco_decode_i_64(arr[3]),
-    );
-  }
-
-  @protected
-  (String, LibsqlReturnValue) dco_decode_record_string_libsql_return_value(
-      dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 2) {
-      throw Exception('Expected 2 elements, got ${arr.length}');
-    }
-    return (
-      dco_decode_String(arr[0]),
-      dco_decode_libsql_return_value(arr[1]),
-    );
-  }
-
-  @protected
-  (String, LibsqlValue) dco_decode_record_string_libsql_value(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 2) {
-      throw Exception('Expected 2 elements, got ${arr.length}');
-    }
-    return (
-      dco_decode_String(arr[0]),
-      dco_decode_libsql_value(arr[1]),
-    );
-  }
-
-  @protected
-  BigInt dco_decode_u_64(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dcoDecodeU64(raw);
-  }
-
-  @protected
-  int dco_decode_u_8(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as int;
-  }
-
-  @protected
-  void dco_decode_unit(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return;
-  }
-
-  @protected
-  BigInt dco_decode_usize(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dcoDecodeU64(raw);
-  }
-
-  @protected
-  InnerConnection
-      sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerInnerConnection(
-          SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return InnerConnectionImpl.frbInternalSseDecode(
-        sse_decode_usize(deserializer), sse_decode_i_32(deserializer));
-  }
-
-  @protected
-  InnerDatabase
-      sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerInnerDatabase(
-          SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return InnerDatabaseImpl.frbInternalSseDecode(
-        sse_decode_usize(deserializer), sse_decode_i_32(deserializer));
-  }
-
-  @protected
-  InnerStatement
-      sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerInnerStatement(
-          SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return InnerStatementImpl.frbInternalSseDecode(
-        sse_decode_usize(deserializer), sse_decode_i_32(deserializer));
-  }
-
-  @protected
-  InnerTransaction
-      sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerInnerTransaction(
-          SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return InnerTransactionImpl.frbInternalSseDecode(
-        sse_decode_usize(deserializer), sse_decode_i_32(deserializer));
-  }
-
-  @protected
-  LibsqlConnection
-      sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlConnection(
-          SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return LibsqlConnectionImpl.frbInternalSseDecode(
-        sse_decode_usize(deserializer), sse_decode_i_32(deserializer));
-  }
-
-  @protected
-  LibsqlStatement
-      sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlStatement(
-          SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return LibsqlStatementImpl.frbInternalSseDecode(
-        sse_decode_usize(deserializer), sse_decode_i_32(deserializer));
-  }
-
-  @protected
-  LibsqlTransaction
-      sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlTransaction(
-          SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return LibsqlTransactionImpl.frbInternalSseDecode(
-        sse_decode_usize(deserializer), sse_decode_i_32(deserializer));
-  }
-
-  @protected
-  LibsqlTransaction
-      sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlTransaction(
-          SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return LibsqlTransactionImpl.frbInternalSseDecode(
-        sse_decode_usize(deserializer), sse_decode_i_32(deserializer));
-  }
-
-  @protected
-  LibsqlConnection
-      sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlConnection(
-          SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return LibsqlConnectionImpl.frbInternalSseDecode(
-        sse_decode_usize(deserializer), sse_decode_i_32(deserializer));
-  }
-
-  @protected
-  LibsqlStatement
-      sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlStatement(
-          SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return LibsqlStatementImpl.frbInternalSseDecode(
-        sse_decode_usize(deserializer), sse_decode_i_32(deserializer));
-  }
-
-  @protected
-  LibsqlTransaction
-      sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlTransaction(
-          SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return LibsqlTransactionImpl.frbInternalSseDecode(
-        sse_decode_usize(deserializer), sse_decode_i_32(deserializer));
-  }
-
-  @protected
-  Map<String, LibsqlReturnValue> sse_decode_Map_String_libsql_return_value_None(
-      SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_list_record_string_libsql_return_value(deserializer);
-    return Map.fromEntries(inner.map((e) => MapEntry(e.$1, e.$2)));
-  }
-
-  @protected
-  Map<String, LibsqlValue> sse_decode_Map_String_libsql_value_None(
-      SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_list_record_string_libsql_value(deserializer);
-    return Map.fromEntries(inner.map((e) => MapEntry(e.$1, e.$2)));
-  }
-
-  @protected
-  InnerConnection
-      sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerInnerConnection(
-          SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return InnerConnectionImpl.frbInternalSseDecode(
-        sse_decode_usize(deserializer), sse_decode_i_32(deserializer));
-  }
-
-  @protected
-  InnerDatabase
-      sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerInnerDatabase(
-          SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return InnerDatabaseImpl.frbInternalSseDecode(
-        sse_decode_usize(deserializer), sse_decode_i_32(deserializer));
-  }
-
-  @protected
-  InnerStatement
-      sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerInnerStatement(
-          SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return InnerStatementImpl.frbInternalSseDecode(
-        sse_decode_usize(deserializer), sse_decode_i_32(deserializer));
-  }
-
-  @protected
-  InnerTransaction
-      sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerInnerTransaction(
-          SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return InnerTransactionImpl.frbInternalSseDecode(
-        sse_decode_usize(deserializer), sse_decode_i_32(deserializer));
-  }
-
-  @protected
-  LibsqlConnection
-      sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlConnection(
-          SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return LibsqlConnectionImpl.frbInternalSseDecode(
-        sse_decode_usize(deserializer), sse_decode_i_32(deserializer));
-  }
-
-  @protected
-  LibsqlStatement
-      sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlStatement(
-          SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return LibsqlStatementImpl.frbInternalSseDecode(
-        sse_decode_usize(deserializer), sse_decode_i_32(deserializer));
-  }
-
-  @protected
-  LibsqlTransaction
-      sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlTransaction(
-          SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return LibsqlTransactionImpl.frbInternalSseDecode(
-        sse_decode_usize(deserializer), sse_decode_i_32(deserializer));
-  }
-
-  @protected
-  String sse_decode_String(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_list_prim_u_8_strict(deserializer);
-    return utf8.decoder.convert(inner);
-  }
-
-  @protected
-  bool sse_decode_bool(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint8() != 0;
-  }
-
-  @protected
-  bool sse_decode_box_autoadd_bool(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_bool(deserializer));
-  }
-
-  @protected
-  ConnectArgs sse_decode_box_autoadd_connect_args(
-      SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_connect_args(deserializer));
-  }
-
-  @protected
-  LibsqlOpenFlags sse_decode_box_autoadd_libsql_open_flags(
-      SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_libsql_open_flags(deserializer));
-  }
-
-  @protected
-  LibsqlParams sse_decode_box_autoadd_libsql_params(
-      SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_libsql_params(deserializer));
-  }
-
-  @protected
-  LibsqlTransactionBehavior sse_decode_box_autoadd_libsql_transaction_behavior(
-      SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_libsql_transaction_behavior(deserializer));
-  }
-
-  @protected
-  BigInt sse_decode_box_autoadd_u_64(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_u_64(deserializer));
-  }
-
-  @protected
-  ConnectArgs sse_decode_connect_args(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_url = sse_decode_String(deserializer);
-    var var_authToken = sse_decode_opt_String(deserializer);
-    var var_syncUrl = sse_decode_opt_String(deserializer);
-    var var_syncIntervalSeconds = sse_decode_opt_box_autoadd_u_64(deserializer);
-    var var_encryptionKey = sse_decode_opt_String(deserializer);
-    var var_readYourWrites = sse_decode_opt_box_autoadd_bool(deserializer);
-    var var_openFlags =
-        sse_decode_opt_box_autoadd_libsql_open_flags(deserializer);
-    var var_offline = sse_decode_opt_box_autoadd_bool(deserializer);
-    return ConnectArgs(
-        url: var_url,
-        authToken: var_authToken,
-        syncUrl: var_syncUrl,
-        syncIntervalSeconds: var_syncIntervalSeconds,
-        encryptionKey: var_encryptionKey,
-        readYourWrites: var_readYourWrites,
-        openFlags: var_openFlags,
-        offline: var_offline);
-  }
-
-  @protected
-  ExecuteResult sse_decode_execute_result(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_rowsAffected = sse_decode_u_64(deserializer);
-    return ExecuteResult(rowsAffected: var_rowsAffected);
-  }
-
-  @protected
-  double sse_decode_f_64(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getFloat64();
-  }
-
-  @protected
-  int sse_decode_i_32(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getInt32();
-  }
-
-  @protected
-  PlatformInt64 sse_decode_i_64(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getPlatformInt64();
-  }
-
-  @protected
-  LibsqlOpenFlags sse_decode_libsql_open_flags(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_i_32(deserializer);
-    return LibsqlOpenFlags.values[inner];
-  }
-
-  @protected
-  LibsqlParams sse_decode_libsql_params(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_positional = sse_decode_opt_list_libsql_value(deserializer);
-    var var_named = sse_decode_opt_Map_String_libsql_value_None(deserializer);
-    return LibsqlParams(positional: var_positional, named: var_named);
-  }
-
-  @protected
-  LibsqlReturnValue sse_decode_libsql_return_value(
-      SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var tag_ = sse_decode_i_32(deserializer);
-    switch (tag_) {
-      case 0:
-        var var_field0 = sse_decode_i_64(deserializer);
-        return LibsqlReturnValue_Integer(var_field0);
-      case 1:
-        var var_field0 = sse_decode_f_64(deserializer);
-        return LibsqlReturnValue_Real(var_field0);
-      case 2:
-        var var_field0 = sse_decode_String(deserializer);
-        return LibsqlReturnValue_Text(var_field0);
-      case 3:
-        var var_field0 = sse_decode_list_prim_u_8_strict(deserializer);
-        return LibsqlReturnValue_Blob(var_field0);
-      case 4:
-        return LibsqlReturnValue_Null();
-      default:
-        throw UnimplementedError('');
-    }
-  }
-
-  @protected
-  LibsqlTransactionBehavior sse_decode_libsql_transaction_behavior(
-      SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_i_32(deserializer);
-    return LibsqlTransactionBehavior.values[inner];
-  }
-
-  @protected
-  LibsqlValue sse_decode_libsql_value(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var tag_ = sse_decode_i_32(deserializer);
-    switch (tag_) {
-      case 0:
-        var var_field0 = sse_decode_i_64(deserializer);
-        return LibsqlValue_Integer(var_field0);
-      case 1:
-        var var_field0 = sse_decode_f_64(deserializer);
-        return LibsqlValue_Real(var_field0);
-      case 2:
-        var var_field0 = sse_decode_String(deserializer);
-        return LibsqlValue_Text(var_field0);
-      case 3:
-        var var_field0 = sse_decode_list_prim_u_8_strict(deserializer);
-        return LibsqlValue_Blob(var_field0);
-      case 4:
-        return LibsqlValue_Null();
-      default:
-        throw UnimplementedError('');
-    }
-  }
-
-  @protected
-  List<Map<String, LibsqlReturnValue>>
-      sse_decode_list_Map_String_libsql_return_value_None(
-          SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <Map<String, LibsqlReturnValue>>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_Map_String_libsql_return_value_None(deserializer));
-    }
-    return ans_;
-  }
-
-  @protected
-  List<String> sse_decode_list_String(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <String>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_String(deserializer));
-    }
-    return ans_;
-  }
-
-  @protected
-  List<LibsqlValue> sse_decode_list_libsql_value(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <LibsqlValue>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_libsql_value(deserializer));
-    }
-    return ans_;
-  }
-
-  @protected
-  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var len_ = sse_decode_i_32(deserializer);
-    return deserializer.buffer.getUint8List(len_);
-  }
-
-  @protected
-  List<(String, LibsqlReturnValue)>
-      sse_decode_list_record_string_libsql_return_value(
-          SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <(String, LibsqlReturnValue)>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_record_string_libsql_return_value(deserializer));
-    }
-    return ans_;
-  }
-
-  @protected
-  List<(String, LibsqlValue)> sse_decode_list_record_string_libsql_value(
-      SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <(String, LibsqlValue)>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_record_string_libsql_value(deserializer));
-    }
-    return ans_;
-  }
-
-  @protected
-  Map<String, LibsqlValue>? sse_decode_opt_Map_String_libsql_value_None(
-      SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_Map_String_libsql_value_None(deserializer));
-    } else {
-      return null;
-    }
-  }
-
-  @protected
-  String? sse_decode_opt_String(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_String(deserializer));
-    } else {
-      return null;
-    }
-  }
-
-  @protected
-  bool? sse_decode_opt_box_autoadd_bool(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_bool(deserializer));
-    } else {
-      return null;
-    }
-  }
-
-  @protected
-  LibsqlOpenFlags? sse_decode_opt_box_autoadd_libsql_open_flags(
-      SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_libsql_open_flags(deserializer));
-    } else {
-      return null;
-    }
-  }
-
-  @protected
-  LibsqlParams? sse_decode_opt_box_autoadd_libsql_params(
-      SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_libsql_params(deserializer));
-    } else {
-      return null;
-    }
-  }
-
-  @protected
-  LibsqlTransactionBehavior?
-      sse_decode_opt_box_autoadd_libsql_transaction_behavior(
-          SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_libsql_transaction_behavior(deserializer));
-    } else {
-      return null;
-    }
-  }
-
-  @protected
-  BigInt? sse_decode_opt_box_autoadd_u_64(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_u_64(deserializer));
-    } else {
-      return null;
-    }
-  }
-
-  @protected
-  List<LibsqlValue>? sse_decode_opt_list_libsql_value(
-      SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_list_libsql_value(deserializer));
-    } else {
-      return null;
-    }
-  }
-
-  @protected
-  QueryResult sse_decode_query_result(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_rows =
-        sse_decode_list_Map_String_libsql_return_value_None(deserializer);
-    var var_columns = sse_decode_list_String(deserializer);
-    var var_rowsAffected = sse_decode_u_64(deserializer);
-    var var_lastInsertRowid = sse_decode_i_64(deserializer);
-    return QueryResult(
-        rows: var_rows,
-        columns: var_columns,
-        rowsAffected: var_rowsAffected,
-        lastInsertRowid: var_lastInsertRowid);
-  }
-
-  @protected
-  (String, LibsqlReturnValue) sse_decode_record_string_libsql_return_value(
-      SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_field0 = sse_decode_String(deserializer);
-    var var_field1 = sse_decode_libsql_return_value(deserializer);
-    return (var_field0, var_field1);
-  }
-
-  @protected
-  (String, LibsqlValue) sse_decode_record_string_libsql_value(
-      SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_field0 = sse_decode_String(deserializer);
-    var var_field1 = sse_decode_libsql_value(deserializer);
-    return (var_field0, var_field1);
-  }
-
-  @protected
-  BigInt sse_decode_u_64(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getBigUint64();
-  }
-
-  @protected
-  int sse_decode_u_8(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint8();
-  }
-
-  @protected
-  void sse_decode_unit(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-  }
-
-  @protected
-  BigInt sse_decode_usize(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getBigUint64();
-  }
-
-  @protected
-  void
-      sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerInnerConnection(
-          InnerConnection self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-        (self as InnerConnectionImpl).frbInternalSseEncode(move: true),
-        serializer);
-  }
-
-  @protected
-  void
-      sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerInnerDatabase(
-          InnerDatabase self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-        (self as InnerDatabaseImpl).frbInternalSseEncode(move: true),
-        serializer);
-  }
-
-  @protected
-  void
-      sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerInnerStatement(
-          InnerStatement self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-        (self as InnerStatementImpl).frbInternalSseEncode(move: true),
-        serializer);
-  }
-
-  @protected
-  void
-      sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerInnerTransaction(
-          InnerTransaction self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-        (self as InnerTransactionImpl).frbInternalSseEncode(move: true),
-        serializer);
-  }
-
-  @protected
-  void
-      sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlConnection(
-          LibsqlConnection self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-        (self as LibsqlConnectionImpl).frbInternalSseEncode(move: true),
-        serializer);
-  }
-
-  @protected
-  void
-      sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlStatement(
-          LibsqlStatement self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-        (self as LibsqlStatementImpl).frbInternalSseEncode(move: true),
-        serializer);
-  }
-
-  @protected
-  void
-      sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlTransaction(
-          LibsqlTransaction self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-        (self as LibsqlTransactionImpl).frbInternalSseEncode(move: true),
-        serializer);
-  }
-
-  @protected
-  void
-      sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlTransaction(
-          LibsqlTransaction self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-        (self as LibsqlTransactionImpl).frbInternalSseEncode(move: false),
-        serializer);
-  }
-
-  @protected
-  void
-      sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlConnection(
-          LibsqlConnection self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-        (self as LibsqlConnectionImpl).frbInternalSseEncode(move: false),
-        serializer);
-  }
-
-  @protected
-  void
-      sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlStatement(
-          LibsqlStatement self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-        (self as LibsqlStatementImpl).frbInternalSseEncode(move: false),
-        serializer);
-  }
-
-  @protected
-  void
-      sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlTransaction(
-          LibsqlTransaction self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-        (self as LibsqlTransactionImpl).frbInternalSseEncode(move: false),
-        serializer);
-  }
-
-  @protected
-  void sse_encode_Map_String_libsql_return_value_None(
-      Map<String, LibsqlReturnValue> self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_list_record_string_libsql_return_value(
-        self.entries.map((e) => (e.key, e.value)).toList(), serializer);
-  }
-
-  @protected
-  void sse_encode_Map_String_libsql_value_None(
-      Map<String, LibsqlValue> self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_list_record_string_libsql_value(
-        self.entries.map((e) => (e.key, e.value)).toList(), serializer);
-  }
-
-  @protected
-  void
-      sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerInnerConnection(
-          InnerConnection self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-        (self as InnerConnectionImpl).frbInternalSseEncode(move: null),
-        serializer);
-  }
-
-  @protected
-  void
-      sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerInnerDatabase(
-          InnerDatabase self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-        (self as InnerDatabaseImpl).frbInternalSseEncode(move: null),
-        serializer);
-  }
-
-  @protected
-  void
-      sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerInnerStatement(
-          InnerStatement self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-        (self as InnerStatementImpl).frbInternalSseEncode(move: null),
-        serializer);
-  }
-
-  @protected
-  void
-      sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerInnerTransaction(
-          InnerTransaction self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-        (self as InnerTransactionImpl).frbInternalSseEncode(move: null),
-        serializer);
-  }
-
-  @protected
-  void
-      sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlConnection(
-          LibsqlConnection self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-        (self as LibsqlConnectionImpl).frbInternalSseEncode(move: null),
-        serializer);
-  }
-
-  @protected
-  void
-      sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlStatement(
-          LibsqlStatement self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-        (self as LibsqlStatementImpl).frbInternalSseEncode(move: null),
-        serializer);
-  }
-
-  @protected
-  void
-      sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlTransaction(
-          LibsqlTransaction self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-        (self as LibsqlTransactionImpl).frbInternalSseEncode(move: null),
-        serializer);
-  }
-
-  @protected
-  void sse_encode_String(String self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_list_prim_u_8_strict(utf8.encoder.convert(self), serializer);
-  }
-
-  @protected
-  void sse_encode_bool(bool self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putUint8(self ? 1 : 0);
-  }
-
-  @protected
-  void sse_encode_box_autoadd_bool(bool self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_bool(self, serializer);
-  }
-
-  @protected
-  void sse_encode_box_autoadd_connect_args(
-      ConnectArgs self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_connect_args(self, serializer);
-  }
-
-  @protected
-  void sse_encode_box_autoadd_libsql_open_flags(
-      LibsqlOpenFlags self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_libsql_open_flags(self, serializer);
-  }
-
-  @protected
-  void sse_encode_box_autoadd_libsql_params(
-      LibsqlParams self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_libsql_params(self, serializer);
-  }
-
-  @protected
-  void sse_encode_box_autoadd_libsql_transaction_behavior(
-      LibsqlTransactionBehavior self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_libsql_transaction_behavior(self, serializer);
-  }
-
-  @protected
-  void sse_encode_box_autoadd_u_64(BigInt self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_u_64(self, serializer);
-  }
-
-  @protected
-  void sse_encode_connect_args(ConnectArgs self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(self.url, serializer);
-    sse_encode_opt_String(self.authToken, serializer);
-    sse_encode_opt_String(self.syncUrl, serializer);
-    sse_encode_opt_box_autoadd_u_64(self.syncIntervalSeconds, serializer);
-    sse_encode_opt_String(self.encryptionKey, serializer);
-    sse_encode_opt_box_autoadd_bool(self.readYourWrites, serializer);
-    sse_encode_opt_box_autoadd_libsql_open_flags(self.openFlags, serializer);
-    sse_encode_opt_box_autoadd_bool(self.offline, serializer);
-  }
-
-  @protected
-  void sse_encode_execute_result(ExecuteResult self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_u_64(self.rowsAffected, serializer);
-  }
-
-  @protected
-  void sse_encode_f_64(double self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putFloat64(self);
-  }
-
-  @protected
-  void sse_encode_i_32(int self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putInt32(self);
-  }
-
-  @protected
-  void sse_encode_i_64(PlatformInt64 self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putPlatformInt64(self);
-  }
-
-  @protected
-  void sse_encode_libsql_open_flags(
-      LibsqlOpenFlags self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.index, serializer);
-  }
-
-  @protected
-  void sse_encode_libsql_params(LibsqlParams self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_opt_list_libsql_value(self.positional, serializer);
-    sse_encode_opt_Map_String_libsql_value_None(self.named, serializer);
-  }
-
-  @protected
-  void sse_encode_libsql_return_value(
-      LibsqlReturnValue self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    switch (self) {
-      case LibsqlReturnValue_Integer(field0: final field0):
-        sse_encode_i_32(0, serializer);
-        sse_encode_i_64(field0, serializer);
-      case LibsqlReturnValue_Real(field0: final field0):
-        sse_encode_i_32(1, serializer);
-        sse_encode_f_64(field0, serializer);
-      case LibsqlReturnValue_Text(field0: final field0):
-        sse_encode_i_32(2, serializer);
-        sse_encode_String(field0, serializer);
-      case LibsqlReturnValue_Blob(field0: final field0):
-        sse_encode_i_32(3, serializer);
-        sse_encode_list_prim_u_8_strict(field0, serializer);
-      case LibsqlReturnValue_Null():
-        sse_encode_i_32(4, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_libsql_transaction_behavior(
-      LibsqlTransactionBehavior self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.index, serializer);
-  }
-
-  @protected
-  void sse_encode_libsql_value(LibsqlValue self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    switch (self) {
-      case LibsqlValue_Integer(field0: final field0):
-        sse_encode_i_32(0, serializer);
-        sse_encode_i_64(field0, serializer);
-      case LibsqlValue_Real(field0: final field0):
-        sse_encode_i_32(1, serializer);
-        sse_encode_f_64(field0, serializer);
-      case LibsqlValue_Text(field0: final field0):
-        sse_encode_i_32(2, serializer);
-        sse_encode_String(field0, serializer);
-      case LibsqlValue_Blob(field0: final field0):
-        sse_encode_i_32(3, serializer);
-        sse_encode_list_prim_u_8_strict(field0, serializer);
-      case LibsqlValue_Null():
-        sse_encode_i_32(4, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_list_Map_String_libsql_return_value_None(
-      List<Map<String, LibsqlReturnValue>> self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_Map_String_libsql_return_value_None(item, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_list_String(List<String> self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_String(item, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_list_libsql_value(
-      List<LibsqlValue> self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_libsql_value(item, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_list_prim_u_8_strict(
-      Uint8List self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    serializer.buffer.putUint8List(self);
-  }
-
-  @protected
-  void sse_encode_list_record_string_libsql_return_value(
-      List<(String, LibsqlReturnValue)> self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_record_string_libsql_return_value(item, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_list_record_string_libsql_value(
-      List<(String, LibsqlValue)> self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_record_string_libsql_value(item, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_opt_Map_String_libsql_value_None(
-      Map<String, LibsqlValue>? self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_Map_String_libsql_value_None(self, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_opt_String(String? self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_String(self, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_opt_box_autoadd_bool(bool? self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_bool(self, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_opt_box_autoadd_libsql_open_flags(
-      LibsqlOpenFlags? self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_libsql_open_flags(self, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_opt_box_autoadd_libsql_params(
-      LibsqlParams? self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_libsql_params(self, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_opt_box_autoadd_libsql_transaction_behavior(
-      LibsqlTransactionBehavior? self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_libsql_transaction_behavior(self, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_opt_box_autoadd_u_64(BigInt? self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_u_64(self, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_opt_list_libsql_value(
-      List<LibsqlValue>? self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_list_libsql_value(self, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_query_result(QueryResult self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_list_Map_String_libsql_return_value_None(self.rows, serializer);
-    sse_encode_list_String(self.columns, serializer);
-    sse_encode_u_64(self.rowsAffected, serializer);
-    sse_encode_i_64(self.lastInsertRowid, serializer);
-  }
-
-  @protected
-  void sse_encode_record_string_libsql_return_value(
-      (String, LibsqlReturnValue) self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(self.$1, serializer);
-    sse_encode_libsql_return_value(self.$2, serializer);
-  }
-
-  @protected
-  void sse_encode_record_string_libsql_value(
-      (String, LibsqlValue) self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(self.$1, serializer);
-    sse_encode_libsql_value(self.$2, serializer);
-  }
-
-  @protected
-  void sse_encode_u_64(BigInt self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putBigUint64(self);
-  }
-
-  @protected
-  void sse_encode_u_8(int self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putUint8(self);
-  }
-
-  @protected
-  void sse_encode_unit(void self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-  }
-
-  @protected
-  void sse_encode_usize(BigInt self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putBigUint64(self);
-  }
-}
-
-@sealed
-class InnerConnectionImpl extends RustOpaque implements InnerConnection {
-  // Not to be used by end users
-  InnerConnectionImpl.frbInternalDcoDecode(List<dynamic> wire)
-      : super.frbInternalDcoDecode(wire, _kStaticData);
-
-  // Not to be used by end users
-  InnerConnectionImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative)
-      : super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
-
-  static final _kStaticData = RustArcStaticData(
-    rustArcIncrementStrongCount:
-        RustLib.instance.api.rust_arc_increment_strong_count_InnerConnection,
-    rustArcDecrementStrongCount:
-        RustLib.instance.api.rust_arc_decrement_strong_count_InnerConnection,
-    rustArcDecrementStrongCountPtr:
-        RustLib.instance.api.rust_arc_decrement_strong_count_InnerConnectionPtr,
-  );
-}
-
-@sealed
-class InnerDatabaseImpl extends RustOpaque implements InnerDatabase {
-  // Not to be used by end users
-  InnerDatabaseImpl.frbInternalDcoDecode(List<dynamic> wire)
-      : super.frbInternalDcoDecode(wire, _kStaticData);
-
-  // Not to be used by end users
-  InnerDatabaseImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative)
-      : super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
-
-  static final _kStaticData = RustArcStaticData(
-    rustArcIncrementStrongCount:
-        RustLib.instance.api.rust_arc_increment_strong_count_InnerDatabase,
-    rustArcDecrementStrongCount:
-        RustLib.instance.api.rust_arc_decrement_strong_count_InnerDatabase,
-    rustArcDecrementStrongCountPtr:
-        RustLib.instance.api.rust_arc_decrement_strong_count_InnerDatabasePtr,
-  );
-}
-
-@sealed
-class InnerStatementImpl extends RustOpaque implements InnerStatement {
-  // Not to be used by end users
-  InnerStatementImpl.frbInternalDcoDecode(List<dynamic> wire)
-      : super.frbInternalDcoDecode(wire, _kStaticData);
-
-  // Not to be used by end users
-  InnerStatementImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative)
-      : super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
-
-  static final _kStaticData = RustArcStaticData(
-    rustArcIncrementStrongCount:
-        RustLib.instance.api.rust_arc_increment_strong_count_InnerStatement,
-    rustArcDecrementStrongCount:
-        RustLib.instance.api.rust_arc_decrement_strong_count_InnerStatement,
-    rustArcDecrementStrongCountPtr:
-        RustLib.instance.api.rust_arc_decrement_strong_count_InnerStatementPtr,
-  );
-}
-
-@sealed
-class InnerTransactionImpl extends RustOpaque implements InnerTransaction {
-  // Not to be used by end users
-  InnerTransactionImpl.frbInternalDcoDecode(List<dynamic> wire)
-      : super.frbInternalDcoDecode(wire, _kStaticData);
-
-  // Not to be used by end users
-  InnerTransactionImpl.frbInternalSseDecode(
-      BigInt ptr, int externalSizeOnNative)
-      : super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
-
-  static final _kStaticData = RustArcStaticData(
-    rustArcIncrementStrongCount:
-        RustLib.instance.api.rust_arc_increment_strong_count_InnerTransaction,
-    rustArcDecrementStrongCount:
-        RustLib.instance.api.rust_arc_decrement_strong_count_InnerTransaction,
-    rustArcDecrementStrongCountPtr: RustLib
-        .instance.api.rust_arc_decrement_strong_count_InnerTransactionPtr,
-  );
-}
-
-@sealed
-class LibsqlConnectionImpl extends RustOpaque implements LibsqlConnection {
-  // Not to be used by end users
-  LibsqlConnectionImpl.frbInternalDcoDecode(List<dynamic> wire)
-      : super.frbInternalDcoDecode(wire, _kStaticData);
-
-  // Not to be used by end users
-  LibsqlConnectionImpl.frbInternalSseDecode(
-      BigInt ptr, int externalSizeOnNative)
-      : super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
-
-  static final _kStaticData = RustArcStaticData(
-    rustArcIncrementStrongCount:
-        RustLib.instance.api.rust_arc_increment_strong_count_LibsqlConnection,
-    rustArcDecrementStrongCount:
-        RustLib.instance.api.rust_arc_decrement_strong_count_LibsqlConnection,
-    rustArcDecrementStrongCountPtr: RustLib
-        .instance.api.rust_arc_decrement_strong_count_LibsqlConnectionPtr,
-  );
-
-  Future<void> batch({required String sql}) => RustLib.instance.api
-      .crateApiConnectionLibsqlConnectionBatch(that: this, sql: sql);
-
-  Future<void> disableExtension() =>
-      RustLib.instance.api.crateApiConnectionLibsqlConnectionDisableExtension(
-        that: this,
-      );
-
-  Future<void> enableExtension() =>
-      RustLib.instance.api.crateApiConnectionLibsqlConnectionEnableExtension(
-        that: this,
-      );
-
-  Future<ExecuteResult> execute(
-          {required String sql, LibsqlParams? parameters}) =>
-      RustLib.instance.api.crateApiConnectionLibsqlConnectionExecute(
-          that: this, sql: sql, parameters: parameters);
-
-  Future<void> loadExtension({required String path, String? entryPoint}) =>
-      RustLib.instance.api.crateApiConnectionLibsqlConnectionLoadExtension(
-          that: this, path: path, entryPoint: entryPoint);
-
-  Future<LibsqlStatement> prepare({required String sql}) => RustLib.instance.api
-      .crateApiConnectionLibsqlConnectionPrepare(that: this, sql: sql);
-
-  Future<QueryResult> query({required String sql, LibsqlParams? parameters}) =>
-      RustLib.instance.api.crateApiConnectionLibsqlConnectionQuery(
-          that: this, sql: sql, parameters: parameters);
-
-  Future<void> sync_() =>
-      RustLib.instance.api.crateApiConnectionLibsqlConnectionSync(
-        that: this,
-      );
-
-  Future<LibsqlTransaction> transaction(
-          {LibsqlTransactionBehavior? behavior}) =>
-      RustLib.instance.api.crateApiConnectionLibsqlConnectionTransaction(
-          that: this, behavior: behavior);
-}
-
-@sealed
-class LibsqlStatementImpl extends RustOpaque implements LibsqlStatement {
-  // Not to be used by end users
-  LibsqlStatementImpl.frbInternalDcoDecode(List<dynamic> wire)
-      : super.frbInternalDcoDecode(wire, _kStaticData);
-
-  // Not to be used by end users
-  LibsqlStatementImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative)
-      : super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
-
-  static final _kStaticData = RustArcStaticData(
-    rustArcIncrementStrongCount:
-        RustLib.instance.api.rust_arc_increment_strong_count_LibsqlStatement,
-    rustArcDecrementStrongCount:
-        RustLib.instance.api.rust_arc_decrement_strong_count_LibsqlStatement,
-    rustArcDecrementStrongCountPtr:
-        RustLib.instance.api.rust_arc_decrement_strong_count_LibsqlStatementPtr,
-  );
-
-  Future<ExecuteResult> execute({LibsqlParams? parameters}) =>
-      RustLib.instance.api.crateApiStatementLibsqlStatementExecute(
-          that: this, parameters: parameters);
-
-  Future<void> finalize() =>
-      RustLib.instance.api.crateApiStatementLibsqlStatementFinalize(
-        that: this,
-      );
-
-  Future<QueryResult> query({LibsqlParams? parameters}) =>
-      RustLib.instance.api.crateApiStatementLibsqlStatementQuery(
-          that: this, parameters: parameters);
-
-  Future<void> reset() =>
-      RustLib.instance.api.crateApiStatementLibsqlStatementReset(
-        that: this,
-      );
-}
-
-@sealed
-class LibsqlTransactionImpl extends RustOpaque implements LibsqlTransaction {
-  // Not to be used by end users
-  LibsqlTransactionImpl.frbInternalDcoDecode(List<dynamic> wire)
-      : super.frbInternalDcoDecode(wire, _kStaticData);
-
-  // Not to be used by end users
-  LibsqlTransactionImpl.frbInternalSseDecode(
-      BigInt ptr, int externalSizeOnNative)
-      : super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
-
-  static final _kStaticData = RustArcStaticData(
-    rustArcIncrementStrongCount:
-        RustLib.instance.api.rust_arc_increment_strong_count_LibsqlTransaction,
-    rustArcDecrementStrongCount:
-        RustLib.instance.api.rust_arc_decrement_strong_count_LibsqlTransaction,
-    rustArcDecrementStrongCountPtr: RustLib
-        .instance.api.rust_arc_decrement_strong_count_LibsqlTransactionPtr,
-  );
-
-  Future<void> commit() =>
-      RustLib.instance.api.crateApiTransactionLibsqlTransactionCommit(
-        that: this,
-      );
-
-  Future<ExecuteResult> execute(
-          {required String sql, LibsqlParams? parameters}) =>
-      RustLib.instance.api.crateApiTransactionLibsqlTransactionExecute(
-          that: this, sql: sql, parameters: parameters);
-
-  Future<QueryResult> query({required String sql, LibsqlParams? parameters}) =>
-      RustLib.instance.api.crateApiTransactionLibsqlTransactionQuery(
-          that: this, sql: sql, parameters: parameters);
-
-  Future<void> rollback() =>
-      RustLib.instance.api.crateApiTransactionLibsqlTransactionRollback(
-        that: this,
-      );
-}
+        );
+
+@override Future<ExecuteResult> crateApiConnectionLibsqlConnectionExecute({required LibsqlConnection that , required String sql , LibsqlParams? parameters , BigInt? timeoutMs , LibsqlCancelToken? cancel })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlConnection(that, serializer);
+sse_encode_String(sql, serializer);
+sse_encode_opt_box_autoadd_libsql_params(parameters, serializer);
+sse_encode_opt_box_autoadd_u_64(timeoutMs, serializer);
+sse_encode_opt_box_autoadd_libsql_cancel_token(cancel, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 12, port: port_);
+
+            },
+            codec:
+        SseCodec(
+          decodeSuccessData: sse_decode_execute_result,
+          decodeErrorData: sse_decode_libsql_error,
+        )
+        ,
+            constMeta: kCrateApiConnectionLibsqlConnectionExecuteConstMeta,
+            argValues: [that, sql, parameters, timeoutMs, cancel],
+            apiImpl: this,
+        )); }
+
+        TaskConstMeta get kCrateApiConnectionLibsqlConnectionExecuteConstMeta => const TaskConstMeta(
+            debugName: "LibsqlConnection_execute",
+            argNames: ["that", "sql", "parameters", "timeoutMs", "cancel"],
+        );
+
+@override Future<void> crateApiConnectionLibsqlConnectionInterrupt({required LibsqlConnection that })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlConnection(that, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 13, port: port_);
+
+            },
+            codec:
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_libsql_error,
+        )
+        ,
+            constMeta: kCrateApiConnectionLibsqlConnectionInterruptConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+        TaskConstMeta get kCrateApiConnectionLibsqlConnectionInterruptConstMeta => const TaskConstMeta(
+            debugName: "LibsqlConnection_interrupt",
+            argNames: ["that"],
+        );
+
+@override Future<void> crateApiConnectionLibsqlConnectionLoadExtension({required LibsqlConnection that , required String path , String? entryPoint })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlConnection(that, serializer);
+sse_encode_String(path, serializer);
+sse_encode_opt_String(entryPoint, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 14, port: port_);
+
+            },
+            codec:
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_libsql_error,
+        )
+        ,
+            constMeta: kCrateApiConnectionLibsqlConnectionLoadExtensionConstMeta,
+            argValues: [that, path, entryPoint],
+            apiImpl: this,
+        )); }
+
+        TaskConstMeta get kCrateApiConnectionLibsqlConnectionLoadExtensionConstMeta => const TaskConstMeta(
+            debugName: "LibsqlConnection_load_extension",
+            argNames: ["that", "path", "entryPoint"],
+        );
+
+@override Future<BigInt?> crateApiConnectionLibsqlConnectionMaxWriteReplicationIndex({required LibsqlConnection that })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlConnection(that, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 15, port: port_);
+
+            },
+            codec:
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_u_64,
+          decodeErrorData: sse_decode_libsql_error,
+        )
+        ,
+            constMeta: kCrateApiConnectionLibsqlConnectionMaxWriteReplicationIndexConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+        TaskConstMeta get kCrateApiConnectionLibsqlConnectionMaxWriteReplicationIndexConstMeta => const TaskConstMeta(
+            debugName: "LibsqlConnection_max_write_replication_index",
+            argNames: ["that"],
+        );
+
+@override Future<LibsqlStatement> crateApiConnectionLibsqlConnectionPrepare({required LibsqlConnection that , required String sql })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlConnection(that, serializer);
+sse_encode_String(sql, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 16, port: port_);
+
+            },
+            codec:
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlStatement,
+          decodeErrorData: sse_decode_libsql_error,
+        )
+        ,
+            constMeta: kCrateApiConnectionLibsqlConnectionPrepareConstMeta,
+            argValues: [that, sql],
+            apiImpl: this,
+        )); }
+
+        TaskConstMeta get kCrateApiConnectionLibsqlConnectionPrepareConstMeta => const TaskConstMeta(
+            debugName: "LibsqlConnection_prepare",
+            argNames: ["that", "sql"],
+        );
+
+@override Future<SyncResult> crateApiConnectionLibsqlConnectionPull({required LibsqlConnection that })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlConnection(that, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 17, port: port_);
+
+            },
+            codec:
+        SseCodec(
+          decodeSuccessData: sse_decode_sync_result,
+          decodeErrorData: sse_decode_libsql_error,
+        )
+        ,
+            constMeta: kCrateApiConnectionLibsqlConnectionPullConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+        TaskConstMeta get kCrateApiConnectionLibsqlConnectionPullConstMeta => const TaskConstMeta(
+            debugName: "LibsqlConnection_pull",
+            argNames: ["that"],
+        );
+
+@override Future<SyncResult> crateApiConnectionLibsqlConnectionPush({required LibsqlConnection that , LibsqlSyncConflictPolicy? conflictPolicy })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlConnection(that, serializer);
+sse_encode_opt_box_autoadd_libsql_sync_conflict_policy(conflictPolicy, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 18, port: port_);
+
+            },
+            codec:
+        SseCodec(
+          decodeSuccessData: sse_decode_sync_result,
+          decodeErrorData: sse_decode_libsql_error,
+        )
+        ,
+            constMeta: kCrateApiConnectionLibsqlConnectionPushConstMeta,
+            argValues: [that, conflictPolicy],
+            apiImpl: this,
+        )); }
+
+        TaskConstMeta get kCrateApiConnectionLibsqlConnectionPushConstMeta => const TaskConstMeta(
+            debugName: "LibsqlConnection_push",
+            argNames: ["that", "conflictPolicy"],
+        );
+
+@override Future<QueryResult> crateApiConnectionLibsqlConnectionQuery({required LibsqlConnection that , required String sql , LibsqlParams? parameters , BigInt? timeoutMs , LibsqlCancelToken? cancel })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlConnection(that, serializer);
+sse_encode_String(sql, serializer);
+sse_encode_opt_box_autoadd_libsql_params(parameters, serializer);
+sse_encode_opt_box_autoadd_u_64(timeoutMs, serializer);
+sse_encode_opt_box_autoadd_libsql_cancel_token(cancel, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 19, port: port_);
+
+            },
+            codec:
+        SseCodec(
+          decodeSuccessData: sse_decode_query_result,
+          decodeErrorData: sse_decode_libsql_error,
+        )
+        ,
+            constMeta: kCrateApiConnectionLibsqlConnectionQueryConstMeta,
+            argValues: [that, sql, parameters, timeoutMs, cancel],
+            apiImpl: this,
+        )); }
+
+        TaskConstMeta get kCrateApiConnectionLibsqlConnectionQueryConstMeta => const TaskConstMeta(
+            debugName: "LibsqlConnection_query",
+            argNames: ["that", "sql", "parameters", "timeoutMs", "cancel"],
+        );
+
+@override Future<ColumnarQueryResult> crateApiConnectionLibsqlConnectionQueryColumnar({required LibsqlConnection that , required String sql , LibsqlParams? parameters , BigInt? timeoutMs , LibsqlCancelToken? cancel })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlConnection(that, serializer);
+sse_encode_String(sql, serializer);
+sse_encode_opt_box_autoadd_libsql_params(parameters, serializer);
+sse_encode_opt_box_autoadd_u_64(timeoutMs, serializer);
+sse_encode_opt_box_autoadd_libsql_cancel_token(cancel, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 20, port: port_);
+
+            },
+            codec:
+        SseCodec(
+          decodeSuccessData: sse_decode_columnar_query_result,
+          decodeErrorData: sse_decode_libsql_error,
+        )
+        ,
+            constMeta: kCrateApiConnectionLibsqlConnectionQueryColumnarConstMeta,
+            argValues: [that, sql, parameters, timeoutMs, cancel],
+            apiImpl: this,
+        )); }
+
+        TaskConstMeta get kCrateApiConnectionLibsqlConnectionQueryColumnarConstMeta => const TaskConstMeta(
+            debugName: "LibsqlConnection_query_columnar",
+            argNames: ["that", "sql", "parameters", "timeoutMs", "cancel"],
+        );
+
+@override Future<LibsqlRows> crateApiConnectionLibsqlConnectionQueryStream({required LibsqlConnection that , required String sql , LibsqlParams? parameters , BigInt? timeoutMs , LibsqlCancelToken? cancel })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlConnection(that, serializer);
+sse_encode_String(sql, serializer);
+sse_encode_opt_box_autoadd_libsql_params(parameters, serializer);
+sse_encode_opt_box_autoadd_u_64(timeoutMs, serializer);
+sse_encode_opt_box_autoadd_libsql_cancel_token(cancel, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 21, port: port_);
+
+            },
+            codec:
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlRows,
+          decodeErrorData: sse_decode_libsql_error,
+        )
+        ,
+            constMeta: kCrateApiConnectionLibsqlConnectionQueryStreamConstMeta,
+            argValues: [that, sql, parameters, timeoutMs, cancel],
+            apiImpl: this,
+        )); }
+
+        TaskConstMeta get kCrateApiConnectionLibsqlConnectionQueryStreamConstMeta => const TaskConstMeta(
+            debugName: "LibsqlConnection_query_stream",
+            argNames: ["that", "sql", "parameters", "timeoutMs", "cancel"],
+        );
+
+@override Future<void> crateApiConnectionLibsqlConnectionSetBusyTimeout({required LibsqlConnection that , required BigInt timeoutMs })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlConnection(that, serializer);
+sse_encode_u_64(timeoutMs, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 22, port: port_);
+
+            },
+            codec:
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_libsql_error,
+        )
+        ,
+            constMeta: kCrateApiConnectionLibsqlConnectionSetBusyTimeoutConstMeta,
+            argValues: [that, timeoutMs],
+            apiImpl: this,
+        )); }
+
+        TaskConstMeta get kCrateApiConnectionLibsqlConnectionSetBusyTimeoutConstMeta => const TaskConstMeta(
+            debugName: "LibsqlConnection_set_busy_timeout",
+            argNames: ["that", "timeoutMs"],
+        );
+
+@override Future<void> crateApiConnectionLibsqlConnectionSetConflictReplay({required LibsqlConnection that , required FutureOr<void> Function() replay })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlConnection(that, serializer);
+sse_encode_DartFn_Inputs__Output_unit_AnyhowException(replay, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 23, port: port_);
+
+            },
+            codec:
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiConnectionLibsqlConnectionSetConflictReplayConstMeta,
+            argValues: [that, replay],
+            apiImpl: this,
+        )); }
+
+        TaskConstMeta get kCrateApiConnectionLibsqlConnectionSetConflictReplayConstMeta => const TaskConstMeta(
+            debugName: "LibsqlConnection_set_conflict_replay",
+            argNames: ["that", "replay"],
+        );
+
+@override Future<SyncResult> crateApiConnectionLibsqlConnectionSync({required LibsqlConnection that })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlConnection(that, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 24, port: port_);
+
+            },
+            codec:
+        SseCodec(
+          decodeSuccessData: sse_decode_sync_result,
+          decodeErrorData: sse_decode_libsql_error,
+        )
+        ,
+            constMeta: kCrateApiConnectionLibsqlConnectionSyncConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+        TaskConstMeta get kCrateApiConnectionLibsqlConnectionSyncConstMeta => const TaskConstMeta(
+            debugName: "LibsqlConnection_sync",
+            argNames: ["that"],
+        );
+
+@override Stream<LibsqlSyncEvent> crateApiConnectionLibsqlConnectionSyncEvents({required LibsqlConnection that })  {
+            final sink = RustStreamSink<LibsqlSyncEvent>();
+            unawaited(handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlConnection(that, serializer);
+sse_encode_StreamSink_libsql_sync_event_Sse(sink, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 25, port: port_);
+
+            },
+            codec:
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiConnectionLibsqlConnectionSyncEventsConstMeta,
+            argValues: [that, sink],
+            apiImpl: this,
+        )));
+            return sink.stream;
+             }
+
+        TaskConstMeta get kCrateApiConnectionLibsqlConnectionSyncEventsConstMeta => const TaskConstMeta(
+            debugName: "LibsqlConnection_sync_events",
+            argNames: ["that", "sink"],
+        );
+
+@override Future<LibsqlSyncStatus> crateApiConnectionLibsqlConnectionSyncStatus({required LibsqlConnection that })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlConnection(that, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 26, port: port_);
+
+            },
+            codec:
+        SseCodec(
+          decodeSuccessData: sse_decode_libsql_sync_status,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiConnectionLibsqlConnectionSyncStatusConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+        TaskConstMeta get kCrateApiConnectionLibsqlConnectionSyncStatusConstMeta => const TaskConstMeta(
+            debugName: "LibsqlConnection_sync_status",
+            argNames: ["that"],
+        );
+
+@override Future<SyncResult> crateApiConnectionLibsqlConnectionSyncUntil({required LibsqlConnection that , required BigInt replicationIndex , BigInt? timeoutMs })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlConnection(that, serializer);
+sse_encode_u_64(replicationIndex, serializer);
+sse_encode_opt_box_autoadd_u_64(timeoutMs, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 27, port: port_);
+
+            },
+            codec:
+        SseCodec(
+          decodeSuccessData: sse_decode_sync_result,
+          decodeErrorData: sse_decode_libsql_error,
+        )
+        ,
+            constMeta: kCrateApiConnectionLibsqlConnectionSyncUntilConstMeta,
+            argValues: [that, replicationIndex, timeoutMs],
+            apiImpl: this,
+        )); }
+
+        TaskConstMeta get kCrateApiConnectionLibsqlConnectionSyncUntilConstMeta => const TaskConstMeta(
+            debugName: "LibsqlConnection_sync_until",
+            argNames: ["that", "replicationIndex", "timeoutMs"],
+        );
+
+@override Future<LibsqlTransaction> crateApiConnectionLibsqlConnectionTransaction({required LibsqlConnection that , LibsqlTransactionBehavior? behavior })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlConnection(that, serializer);
+sse_encode_opt_box_autoadd_libsql_transaction_behavior(behavior, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 28, port: port_);
+
+            },
+            codec:
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlTransaction,
+          decodeErrorData: sse_decode_libsql_error,
+        )
+        ,
+            constMeta: kCrateApiConnectionLibsqlConnectionTransactionConstMeta,
+            argValues: [that, behavior],
+            apiImpl: this,
+        )); }
+
+        TaskConstMeta get kCrateApiConnectionLibsqlConnectionTransactionConstMeta => const TaskConstMeta(
+            debugName: "LibsqlConnection_transaction",
+            argNames: ["that", "behavior"],
+        );
+
+@override Stream<List<LibsqlUpdate>> crateApiConnectionLibsqlConnectionUpdates({required LibsqlConnection that })  {
+            final sink = RustStreamSink<List<LibsqlUpdate>>();
+            unawaited(handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlConnection(that, serializer);
+sse_encode_StreamSink_list_libsql_update_Sse(sink, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 29, port: port_);
+
+            },
+            codec:
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_libsql_error,
+        )
+        ,
+            constMeta: kCrateApiConnectionLibsqlConnectionUpdatesConstMeta,
+            argValues: [that, sink],
+            apiImpl: this,
+        )));
+            return sink.stream;
+             }
+
+        TaskConstMeta get kCrateApiConnectionLibsqlConnectionUpdatesConstMeta => const TaskConstMeta(
+            debugName: "LibsqlConnection_updates",
+            argNames: ["that", "sink"],
+        );
+
+@override Stream<QueryResult> crateApiConnectionLibsqlConnectionWatch({required LibsqlConnection that , required String sql , LibsqlParams? parameters , BigInt? debounceMs })  {
+            final sink = RustStreamSink<QueryResult>();
+            unawaited(handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlConnection(that, serializer);
+sse_encode_String(sql, serializer);
+sse_encode_opt_box_autoadd_libsql_params(parameters, serializer);
+sse_encode_opt_box_autoadd_u_64(debounceMs, serializer);
+sse_encode_StreamSink_query_result_Sse(sink, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 30, port: port_);
+
+            },
+            codec:
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_libsql_error,
+        )
+        ,
+            constMeta: kCrateApiConnectionLibsqlConnectionWatchConstMeta,
+            argValues: [that, sql, parameters, debounceMs, sink],
+            apiImpl: this,
+        )));
+            return sink.stream;
+             }
+
+        TaskConstMeta get kCrateApiConnectionLibsqlConnectionWatchConstMeta => const TaskConstMeta(
+            debugName: "LibsqlConnection_watch",
+            argNames: ["that", "sql", "parameters", "debounceMs", "sink"],
+        );
+
+@override Future<void> crateApiLibsqlLibsqlCancelTokenCancel({required LibsqlCancelToken that })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_libsql_cancel_token(that, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 31, port: port_);
+
+            },
+            codec:
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiLibsqlLibsqlCancelTokenCancelConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+        TaskConstMeta get kCrateApiLibsqlLibsqlCancelTokenCancelConstMeta => const TaskConstMeta(
+            debugName: "LibsqlCancelToken_cancel",
+            argNames: ["that"],
+        );
+
+@override Future<LibsqlCancelToken> crateApiLibsqlLibsqlCancelTokenCreate()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 32, port: port_);
+
+            },
+            codec:
+        SseCodec(
+          decodeSuccessData: sse_decode_libsql_cancel_token,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiLibsqlLibsqlCancelTokenCreateConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+        TaskConstMeta get kCrateApiLibsqlLibsqlCancelTokenCreateConstMeta => const TaskConstMeta(
+            debugName: "LibsqlCancelToken_create",
+            argNames: [],
+        );
+
+@override Future<void> crateApiPoolLibsqlPoolBatch({required LibsqlPool that , required String sql , BigInt? timeoutMs , LibsqlCancelToken? cancel })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlPool(that, serializer);
+sse_encode_String(sql, serializer);
+sse_encode_opt_box_autoadd_u_64(timeoutMs, serializer);
+sse_encode_opt_box_autoadd_libsql_cancel_token(cancel, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 33, port: port_);
+
+            },
+            codec:
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_libsql_error,
+        )
+        ,
+            constMeta: kCrateApiPoolLibsqlPoolBatchConstMeta,
+            argValues: [that, sql, timeoutMs, cancel],
+            apiImpl: this,
+        )); }
+
+        TaskConstMeta get kCrateApiPoolLibsqlPoolBatchConstMeta => const TaskConstMeta(
+            debugName: "LibsqlPool_batch",
+            argNames: ["that", "sql", "timeoutMs", "cancel"],
+        );
+
+@override Future<BatchResult> crateApiPoolLibsqlPoolBatchStatements({required LibsqlPool that , required List<LibsqlBatchStatement> statements , LibsqlTransactionBehavior? behavior , BigInt? timeoutMs , LibsqlCancelToken? cancel })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlPool(that, serializer);
+sse_encode_list_libsql_batch_statement(statements, serializer);
+sse_encode_opt_box_autoadd_libsql_transaction_behavior(behavior, serializer);
+sse_encode_opt_box_autoadd_u_64(timeoutMs, serializer);
+sse_encode_opt_box_autoadd_libsql_cancel_token(cancel, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 34, port: port_);
+
+            },
+            codec:
+        SseCodec(
+          decodeSuccessData: sse_decode_batch_result,
+          decodeErrorData: sse_decode_libsql_error,
+        )
+        ,
+            constMeta: kCrateApiPoolLibsqlPoolBatchStatementsConstMeta,
+            argValues: [that, statements, behavior, timeoutMs, cancel],
+            apiImpl: this,
+        )); }
+
+        TaskConstMeta get kCrateApiPoolLibsqlPoolBatchStatementsConstMeta => const TaskConstMeta(
+            debugName: "LibsqlPool_batch_statements",
+            argNames: ["that", "statements", "behavior", "timeoutMs", "cancel"],
+        );
+
+@override Future<void> crateApiPoolLibsqlPoolClose({required LibsqlPool that })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlPool(that, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 35, port: port_);
+
+            },
+            codec:
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_libsql_error,
+        )
+        ,
+            constMeta: kCrateApiPoolLibsqlPoolCloseConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+        TaskConstMeta get kCrateApiPoolLibsqlPoolCloseConstMeta => const TaskConstMeta(
+            debugName: "LibsqlPool_close",
+            argNames: ["that"],
+        );
+
+@override Future<ExecuteResult> crateApiPoolLibsqlPoolExecute({required LibsqlPool that , required String sql , LibsqlParams? parameters , BigInt? timeoutMs , LibsqlCancelToken? cancel })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlPool(that, serializer);
+sse_encode_String(sql, serializer);
+sse_encode_opt_box_autoadd_libsql_params(parameters, serializer);
+sse_encode_opt_box_autoadd_u_64(timeoutMs, serializer);
+sse_encode_opt_box_autoadd_libsql_cancel_token(cancel, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 36, port: port_);
+
+            },
+            codec:
+        SseCodec(
+          decodeSuccessData: sse_decode_execute_result,
+          decodeErrorData: sse_decode_libsql_error,
+        )
+        ,
+            constMeta: kCrateApiPoolLibsqlPoolExecuteConstMeta,
+            argValues: [that, sql, parameters, timeoutMs, cancel],
+            apiImpl: this,
+        )); }
+
+        TaskConstMeta get kCrateApiPoolLibsqlPoolExecuteConstMeta => const TaskConstMeta(
+            debugName: "LibsqlPool_execute",
+            argNames: ["that", "sql", "parameters", "timeoutMs", "cancel"],
+        );
+
+@override Future<QueryResult> crateApiPoolLibsqlPoolQuery({required LibsqlPool that , required String sql , LibsqlParams? parameters , BigInt? timeoutMs , LibsqlCancelToken? cancel })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlPool(that, serializer);
+sse_encode_String(sql, serializer);
+sse_encode_opt_box_autoadd_libsql_params(parameters, serializer);
+sse_encode_opt_box_autoadd_u_64(timeoutMs, serializer);
+sse_encode_opt_box_autoadd_libsql_cancel_token(cancel, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 37, port: port_);
+
+            },
+            codec:
+        SseCodec(
+          decodeSuccessData: sse_decode_query_result,
+          decodeErrorData: sse_decode_libsql_error,
+        )
+        ,
+            constMeta: kCrateApiPoolLibsqlPoolQueryConstMeta,
+            argValues: [that, sql, parameters, timeoutMs, cancel],
+            apiImpl: this,
+        )); }
+
+        TaskConstMeta get kCrateApiPoolLibsqlPoolQueryConstMeta => const TaskConstMeta(
+            debugName: "LibsqlPool_query",
+            argNames: ["that", "sql", "parameters", "timeoutMs", "cancel"],
+        );
+
+@override Future<ColumnarQueryResult> crateApiPoolLibsqlPoolQueryColumnar({required LibsqlPool that , required String sql , LibsqlParams? parameters , BigInt? timeoutMs , LibsqlCancelToken? cancel })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlPool(that, serializer);
+sse_encode_String(sql, serializer);
+sse_encode_opt_box_autoadd_libsql_params(parameters, serializer);
+sse_encode_opt_box_autoadd_u_64(timeoutMs, serializer);
+sse_encode_opt_box_autoadd_libsql_cancel_token(cancel, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 38, port: port_);
+
+            },
+            codec:
+        SseCodec(
+          decodeSuccessData: sse_decode_columnar_query_result,
+          decodeErrorData: sse_decode_libsql_error,
+        )
+        ,
+            constMeta: kCrateApiPoolLibsqlPoolQueryColumnarConstMeta,
+            argValues: [that, sql, parameters, timeoutMs, cancel],
+            apiImpl: this,
+        )); }
+
+        TaskConstMeta get kCrateApiPoolLibsqlPoolQueryColumnarConstMeta => const TaskConstMeta(
+            debugName: "LibsqlPool_query_columnar",
+            argNames: ["that", "sql", "parameters", "timeoutMs", "cancel"],
+        );
+
+@override Future<LibsqlTransaction> crateApiPoolLibsqlPoolTransaction({required LibsqlPool that , LibsqlTransactionBehavior? behavior , BigInt? timeoutMs })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlPool(that, serializer);
+sse_encode_opt_box_autoadd_libsql_transaction_behavior(behavior, serializer);
+sse_encode_opt_box_autoadd_u_64(timeoutMs, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 39, port: port_);
+
+            },
+            codec:
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlTransaction,
+          decodeErrorData: sse_decode_libsql_error,
+        )
+        ,
+            constMeta: kCrateApiPoolLibsqlPoolTransactionConstMeta,
+            argValues: [that, behavior, timeoutMs],
+            apiImpl: this,
+        )); }
+
+        TaskConstMeta get kCrateApiPoolLibsqlPoolTransactionConstMeta => const TaskConstMeta(
+            debugName: "LibsqlPool_transaction",
+            argNames: ["that", "behavior", "timeoutMs"],
+        );
+
+@override Stream<QueryResult> crateApiPoolLibsqlPoolWatch({required LibsqlPool that , required String sql , LibsqlParams? parameters , BigInt? debounceMs })  {
+            final sink = RustStreamSink<QueryResult>();
+            unawaited(handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlPool(that, serializer);
+sse_encode_String(sql, serializer);
+sse_encode_opt_box_autoadd_libsql_params(parameters, serializer);
+sse_encode_opt_box_autoadd_u_64(debounceMs, serializer);
+sse_encode_StreamSink_query_result_Sse(sink, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 40, port: port_);
+
+            },
+            codec:
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_libsql_error,
+        )
+        ,
+            constMeta: kCrateApiPoolLibsqlPoolWatchConstMeta,
+            argValues: [that, sql, parameters, debounceMs, sink],
+            apiImpl: this,
+        )));
+            return sink.stream;
+             }
+
+        TaskConstMeta get kCrateApiPoolLibsqlPoolWatchConstMeta => const TaskConstMeta(
+            debugName: "LibsqlPool_watch",
+            argNames: ["that", "sql", "parameters", "debounceMs", "sink"],
+        );
+
+@override Future<void> crateApiRowsLibsqlRowsClose({required LibsqlRows that })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlRows(that, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 41, port: port_);
+
+            },
+            codec:
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_libsql_error,
+        )
+        ,
+            constMeta: kCrateApiRowsLibsqlRowsCloseConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+        TaskConstMeta get kCrateApiRowsLibsqlRowsCloseConstMeta => const TaskConstMeta(
+            debugName: "LibsqlRows_close",
+            argNames: ["that"],
+        );
+
+@override Future<List<String>> crateApiRowsLibsqlRowsColumns({required LibsqlRows that })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlRows(that, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 42, port: port_);
+
+            },
+            codec:
+        SseCodec(
+          decodeSuccessData: sse_decode_list_String,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiRowsLibsqlRowsColumnsConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+        TaskConstMeta get kCrateApiRowsLibsqlRowsColumnsConstMeta => const TaskConstMeta(
+            debugName: "LibsqlRows_columns",
+            argNames: ["that"],
+        );
+
+@override Future<List<String?>> crateApiRowsLibsqlRowsDeclTypes({required LibsqlRows that })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlRows(that, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 43, port: port_);
+
+            },
+            codec:
+        SseCodec(
+          decodeSuccessData: sse_decode_list_opt_String,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiRowsLibsqlRowsDeclTypesConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+        TaskConstMeta get kCrateApiRowsLibsqlRowsDeclTypesConstMeta => const TaskConstMeta(
+            debugName: "LibsqlRows_decl_types",
+            argNames: ["that"],
+        );
+
+@override Future<Map<String, LibsqlReturnValue>?> crateApiRowsLibsqlRowsNext({required LibsqlRows that })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlRows(that, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 44, port: port_);
+
+            },
+            codec:
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_Map_String_libsql_return_value_None,
+          decodeErrorData: sse_decode_libsql_error,
+        )
+        ,
+            constMeta: kCrateApiRowsLibsqlRowsNextConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+        TaskConstMeta get kCrateApiRowsLibsqlRowsNextConstMeta => const TaskConstMeta(
+            debugName: "LibsqlRows_next",
+            argNames: ["that"],
+        );
+
+@override Future<List<Map<String, LibsqlReturnValue>>> crateApiRowsLibsqlRowsNextPage({required LibsqlRows that , required int size })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlRows(that, serializer);
+sse_encode_u_32(size, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 45, port: port_);
+
+            },
+            codec:
+        SseCodec(
+          decodeSuccessData: sse_decode_list_Map_String_libsql_return_value_None,
+          decodeErrorData: sse_decode_libsql_error,
+        )
+        ,
+            constMeta: kCrateApiRowsLibsqlRowsNextPageConstMeta,
+            argValues: [that, size],
+            apiImpl: this,
+        )); }
+
+        TaskConstMeta get kCrateApiRowsLibsqlRowsNextPageConstMeta => const TaskConstMeta(
+            debugName: "LibsqlRows_next_page",
+            argNames: ["that", "size"],
+        );
+
+@override Future<ExecuteResult> crateApiSavepointLibsqlSavepointExecute({required LibsqlSavepoint that , required String sql , LibsqlParams? parameters , BigInt? timeoutMs , LibsqlCancelToken? cancel })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlSavepoint(that, serializer);
+sse_encode_String(sql, serializer);
+sse_encode_opt_box_autoadd_libsql_params(parameters, serializer);
+sse_encode_opt_box_autoadd_u_64(timeoutMs, serializer);
+sse_encode_opt_box_autoadd_libsql_cancel_token(cancel, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 46, port: port_);
+
+            },
+            codec:
+        SseCodec(
+          decodeSuccessData: sse_decode_execute_result,
+          decodeErrorData: sse_decode_libsql_error,
+        )
+        ,
+            constMeta: kCrateApiSavepointLibsqlSavepointExecuteConstMeta,
+            argValues: [that, sql, parameters, timeoutMs, cancel],
+            apiImpl: this,
+        )); }
+
+        TaskConstMeta get kCrateApiSavepointLibsqlSavepointExecuteConstMeta => const TaskConstMeta(
+            debugName: "LibsqlSavepoint_execute",
+            argNames: ["that", "sql", "parameters", "timeoutMs", "cancel"],
+        );
+
+@override Future<String> crateApiSavepointLibsqlSavepointName({required LibsqlSavepoint that })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlSavepoint(that, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 47, port: port_);
+
+            },
+            codec:
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiSavepointLibsqlSavepointNameConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+        TaskConstMeta get kCrateApiSavepointLibsqlSavepointNameConstMeta => const TaskConstMeta(
+            debugName: "LibsqlSavepoint_name",
+            argNames: ["that"],
+        );
+
+@override Future<QueryResult> crateApiSavepointLibsqlSavepointQuery({required LibsqlSavepoint that , required String sql , LibsqlParams? parameters , BigInt? timeoutMs , LibsqlCancelToken? cancel })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlSavepoint(that, serializer);
+sse_encode_String(sql, serializer);
+sse_encode_opt_box_autoadd_libsql_params(parameters, serializer);
+sse_encode_opt_box_autoadd_u_64(timeoutMs, serializer);
+sse_encode_opt_box_autoadd_libsql_cancel_token(cancel, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 48, port: port_);
+
+            },
+            codec:
+        SseCodec(
+          decodeSuccessData: sse_decode_query_result,
+          decodeErrorData: sse_decode_libsql_error,
+        )
+        ,
+            constMeta: kCrateApiSavepointLibsqlSavepointQueryConstMeta,
+            argValues: [that, sql, parameters, timeoutMs, cancel],
+            apiImpl: this,
+        )); }
+
+        TaskConstMeta get kCrateApiSavepointLibsqlSavepointQueryConstMeta => const TaskConstMeta(
+            debugName: "LibsqlSavepoint_query",
+            argNames: ["that", "sql", "parameters", "timeoutMs", "cancel"],
+        );
+
+@override Future<void> crateApiSavepointLibsqlSavepointRelease({required LibsqlSavepoint that })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlSavepoint(that, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 49, port: port_);
+
+            },
+            codec:
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_libsql_error,
+        )
+        ,
+            constMeta: kCrateApiSavepointLibsqlSavepointReleaseConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+        TaskConstMeta get kCrateApiSavepointLibsqlSavepointReleaseConstMeta => const TaskConstMeta(
+            debugName: "LibsqlSavepoint_release",
+            argNames: ["that"],
+        );
+
+@override Future<void> crateApiSavepointLibsqlSavepointRollbackTo({required LibsqlSavepoint that })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlSavepoint(that, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 50, port: port_);
+
+            },
+            codec:
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_libsql_error,
+        )
+        ,
+            constMeta: kCrateApiSavepointLibsqlSavepointRollbackToConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+        TaskConstMeta get kCrateApiSavepointLibsqlSavepointRollbackToConstMeta => const TaskConstMeta(
+            debugName: "LibsqlSavepoint_rollback_to",
+            argNames: ["that"],
+        );
+
+@override Future<LibsqlSavepoint> crateApiSavepointLibsqlSavepointSavepoint({required LibsqlSavepoint that , required String name })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlSavepoint(that, serializer);
+sse_encode_String(name, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 51, port: port_);
+
+            },
+            codec:
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlSavepoint,
+          decodeErrorData: sse_decode_libsql_error,
+        )
+        ,
+            constMeta: kCrateApiSavepointLibsqlSavepointSavepointConstMeta,
+            argValues: [that, name],
+            apiImpl: this,
+        )); }
+
+        TaskConstMeta get kCrateApiSavepointLibsqlSavepointSavepointConstMeta => const TaskConstMeta(
+            debugName: "LibsqlSavepoint_savepoint",
+            argNames: ["that", "name"],
+        );
+
+@override Future<List<LibsqlColumn>> crateApiStatementLibsqlStatementColumns({required LibsqlStatement that })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlStatement(that, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 52, port: port_);
+
+            },
+            codec:
+        SseCodec(
+          decodeSuccessData: sse_decode_list_libsql_column,
+          decodeErrorData: sse_decode_libsql_error,
+        )
+        ,
+            constMeta: kCrateApiStatementLibsqlStatementColumnsConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+        TaskConstMeta get kCrateApiStatementLibsqlStatementColumnsConstMeta => const TaskConstMeta(
+            debugName: "LibsqlStatement_columns",
+            argNames: ["that"],
+        );
+
+@override Future<ExecuteResult> crateApiStatementLibsqlStatementExecute({required LibsqlStatement that , LibsqlParams? parameters , BigInt? timeoutMs , LibsqlCancelToken? cancel })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlStatement(that, serializer);
+sse_encode_opt_box_autoadd_libsql_params(parameters, serializer);
+sse_encode_opt_box_autoadd_u_64(timeoutMs, serializer);
+sse_encode_opt_box_autoadd_libsql_cancel_token(cancel, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 53, port: port_);
+
+            },
+            codec:
+        SseCodec(
+          decodeSuccessData: sse_decode_execute_result,
+          decodeErrorData: sse_decode_libsql_error,
+        )
+        ,
+            constMeta: kCrateApiStatementLibsqlStatementExecuteConstMeta,
+            argValues: [that, parameters, timeoutMs, cancel],
+            apiImpl: this,
+        )); }
+
+        TaskConstMeta get kCrateApiStatementLibsqlStatementExecuteConstMeta => const TaskConstMeta(
+            debugName: "LibsqlStatement_execute",
+            argNames: ["that", "parameters", "timeoutMs", "cancel"],
+        );
+
+@override Future<void> crateApiStatementLibsqlStatementFinalize({required LibsqlStatement that })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlStatement(that, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 54, port: port_);
+
+            },
+            codec:
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_libsql_error,
+        )
+        ,
+            constMeta: kCrateApiStatementLibsqlStatementFinalizeConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+        TaskConstMeta get kCrateApiStatementLibsqlStatementFinalizeConstMeta => const TaskConstMeta(
+            debugName: "LibsqlStatement_finalize",
+            argNames: ["that"],
+        );
+
+@override Future<bool> crateApiStatementLibsqlStatementIsReadonly({required LibsqlStatement that })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlStatement(that, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 55, port: port_);
+
+            },
+            codec:
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: sse_decode_libsql_error,
+        )
+        ,
+            constMeta: kCrateApiStatementLibsqlStatementIsReadonlyConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+        TaskConstMeta get kCrateApiStatementLibsqlStatementIsReadonlyConstMeta => const TaskConstMeta(
+            debugName: "LibsqlStatement_is_readonly",
+            argNames: ["that"],
+        );
+
+@override Future<int> crateApiStatementLibsqlStatementParameterCount({required LibsqlStatement that })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlStatement(that, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 56, port: port_);
+
+            },
+            codec:
+        SseCodec(
+          decodeSuccessData: sse_decode_u_32,
+          decodeErrorData: sse_decode_libsql_error,
+        )
+        ,
+            constMeta: kCrateApiStatementLibsqlStatementParameterCountConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+        TaskConstMeta get kCrateApiStatementLibsqlStatementParameterCountConstMeta => const TaskConstMeta(
+            debugName: "LibsqlStatement_parameter_count",
+            argNames: ["that"],
+        );
+
+@override Future<String?> crateApiStatementLibsqlStatementParameterName({required LibsqlStatement that , required int index })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlStatement(that, serializer);
+sse_encode_u_32(index, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 57, port: port_);
+
+            },
+            codec:
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_String,
+          decodeErrorData: sse_decode_libsql_error,
+        )
+        ,
+            constMeta: kCrateApiStatementLibsqlStatementParameterNameConstMeta,
+            argValues: [that, index],
+            apiImpl: this,
+        )); }
+
+        TaskConstMeta get kCrateApiStatementLibsqlStatementParameterNameConstMeta => const TaskConstMeta(
+            debugName: "LibsqlStatement_parameter_name",
+            argNames: ["that", "index"],
+        );
+
+@override Future<QueryResult> crateApiStatementLibsqlStatementQuery({required LibsqlStatement that , LibsqlParams? parameters , BigInt? timeoutMs , LibsqlCancelToken? cancel })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlStatement(that, serializer);
+sse_encode_opt_box_autoadd_libsql_params(parameters, serializer);
+sse_encode_opt_box_autoadd_u_64(timeoutMs, serializer);
+sse_encode_opt_box_autoadd_libsql_cancel_token(cancel, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 58, port: port_);
+
+            },
+            codec:
+        SseCodec(
+          decodeSuccessData: sse_decode_query_result,
+          decodeErrorData: sse_decode_libsql_error,
+        )
+        ,
+            constMeta: kCrateApiStatementLibsqlStatementQueryConstMeta,
+            argValues: [that, parameters, timeoutMs, cancel],
+            apiImpl: this,
+        )); }
+
+        TaskConstMeta get kCrateApiStatementLibsqlStatementQueryConstMeta => const TaskConstMeta(
+            debugName: "LibsqlStatement_query",
+            argNames: ["that", "parameters", "timeoutMs", "cancel"],
+        );
+
+@override Future<ColumnarQueryResult> crateApiStatementLibsqlStatementQueryColumnar({required LibsqlStatement that , LibsqlParams? parameters , BigInt? timeoutMs , LibsqlCancelToken? cancel })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlStatement(that, serializer);
+sse_encode_opt_box_autoadd_libsql_params(parameters, serializer);
+sse_encode_opt_box_autoadd_u_64(timeoutMs, serializer);
+sse_encode_opt_box_autoadd_libsql_cancel_token(cancel, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 59, port: port_);
+
+            },
+            codec:
+        SseCodec(
+          decodeSuccessData: sse_decode_columnar_query_result,
+          decodeErrorData: sse_decode_libsql_error,
+        )
+        ,
+            constMeta: kCrateApiStatementLibsqlStatementQueryColumnarConstMeta,
+            argValues: [that, parameters, timeoutMs, cancel],
+            apiImpl: this,
+        )); }
+
+        TaskConstMeta get kCrateApiStatementLibsqlStatementQueryColumnarConstMeta => const TaskConstMeta(
+            debugName: "LibsqlStatement_query_columnar",
+            argNames: ["that", "parameters", "timeoutMs", "cancel"],
+        );
+
+@override Future<LibsqlRows> crateApiStatementLibsqlStatementQueryStream({required LibsqlStatement that , LibsqlParams? parameters , BigInt? timeoutMs , LibsqlCancelToken? cancel })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlStatement(that, serializer);
+sse_encode_opt_box_autoadd_libsql_params(parameters, serializer);
+sse_encode_opt_box_autoadd_u_64(timeoutMs, serializer);
+sse_encode_opt_box_autoadd_libsql_cancel_token(cancel, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 60, port: port_);
+
+            },
+            codec:
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlRows,
+          decodeErrorData: sse_decode_libsql_error,
+        )
+        ,
+            constMeta: kCrateApiStatementLibsqlStatementQueryStreamConstMeta,
+            argValues: [that, parameters, timeoutMs, cancel],
+            apiImpl: this,
+        )); }
+
+        TaskConstMeta get kCrateApiStatementLibsqlStatementQueryStreamConstMeta => const TaskConstMeta(
+            debugName: "LibsqlStatement_query_stream",
+            argNames: ["that", "parameters", "timeoutMs", "cancel"],
+        );
+
+@override Future<void> crateApiStatementLibsqlStatementReset({required LibsqlStatement that })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlStatement(that, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 61, port: port_);
+
+            },
+            codec:
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_libsql_error,
+        )
+        ,
+            constMeta: kCrateApiStatementLibsqlStatementResetConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+        TaskConstMeta get kCrateApiStatementLibsqlStatementResetConstMeta => const TaskConstMeta(
+            debugName: "LibsqlStatement_reset",
+            argNames: ["that"],
+        );
+
+@override Future<void> crateApiTransactionLibsqlTransactionCommit({required LibsqlTransaction that })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlTransaction(that, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 62, port: port_);
+
+            },
+            codec:
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_libsql_error,
+        )
+        ,
+            constMeta: kCrateApiTransactionLibsqlTransactionCommitConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+        TaskConstMeta get kCrateApiTransactionLibsqlTransactionCommitConstMeta => const TaskConstMeta(
+            debugName: "LibsqlTransaction_commit",
+            argNames: ["that"],
+        );
+
+@override Future<ExecuteResult> crateApiTransactionLibsqlTransactionExecute({required LibsqlTransaction that , required String sql , LibsqlParams? parameters , BigInt? timeoutMs , LibsqlCancelToken? cancel })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlTransaction(that, serializer);
+sse_encode_String(sql, serializer);
+sse_encode_opt_box_autoadd_libsql_params(parameters, serializer);
+sse_encode_opt_box_autoadd_u_64(timeoutMs, serializer);
+sse_encode_opt_box_autoadd_libsql_cancel_token(cancel, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 63, port: port_);
+
+            },
+            codec:
+        SseCodec(
+          decodeSuccessData: sse_decode_execute_result,
+          decodeErrorData: sse_decode_libsql_error,
+        )
+        ,
+            constMeta: kCrateApiTransactionLibsqlTransactionExecuteConstMeta,
+            argValues: [that, sql, parameters, timeoutMs, cancel],
+            apiImpl: this,
+        )); }
+
+        TaskConstMeta get kCrateApiTransactionLibsqlTransactionExecuteConstMeta => const TaskConstMeta(
+            debugName: "LibsqlTransaction_execute",
+            argNames: ["that", "sql", "parameters", "timeoutMs", "cancel"],
+        );
+
+@override Future<bool> crateApiTransactionLibsqlTransactionIsActive({required LibsqlTransaction that })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlTransaction(that, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 64, port: port_);
+
+            },
+            codec:
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: sse_decode_libsql_error,
+        )
+        ,
+            constMeta: kCrateApiTransactionLibsqlTransactionIsActiveConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+        TaskConstMeta get kCrateApiTransactionLibsqlTransactionIsActiveConstMeta => const TaskConstMeta(
+            debugName: "LibsqlTransaction_is_active",
+            argNames: ["that"],
+        );
+
+@override Future<bool> crateApiTransactionLibsqlTransactionIsAutocommit({required LibsqlTransaction that })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlTransaction(that, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 65, port: port_);
+
+            },
+            codec:
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: sse_decode_libsql_error,
+        )
+        ,
+            constMeta: kCrateApiTransactionLibsqlTransactionIsAutocommitConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+        TaskConstMeta get kCrateApiTransactionLibsqlTransactionIsAutocommitConstMeta => const TaskConstMeta(
+            debugName: "LibsqlTransaction_is_autocommit",
+            argNames: ["that"],
+        );
+
+@override Future<LibsqlStatement> crateApiTransactionLibsqlTransactionPrepare({required LibsqlTransaction that , required String sql })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlTransaction(that, serializer);
+sse_encode_String(sql, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 66, port: port_);
+
+            },
+            codec:
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlStatement,
+          decodeErrorData: sse_decode_libsql_error,
+        )
+        ,
+            constMeta: kCrateApiTransactionLibsqlTransactionPrepareConstMeta,
+            argValues: [that, sql],
+            apiImpl: this,
+        )); }
+
+        TaskConstMeta get kCrateApiTransactionLibsqlTransactionPrepareConstMeta => const TaskConstMeta(
+            debugName: "LibsqlTransaction_prepare",
+            argNames: ["that", "sql"],
+        );
+
+@override Future<QueryResult> crateApiTransactionLibsqlTransactionQuery({required LibsqlTransaction that , required String sql , LibsqlParams? parameters , BigInt? timeoutMs , LibsqlCancelToken? cancel })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlTransaction(that, serializer);
+sse_encode_String(sql, serializer);
+sse_encode_opt_box_autoadd_libsql_params(parameters, serializer);
+sse_encode_opt_box_autoadd_u_64(timeoutMs, serializer);
+sse_encode_opt_box_autoadd_libsql_cancel_token(cancel, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 67, port: port_);
+
+            },
+            codec:
+        SseCodec(
+          decodeSuccessData: sse_decode_query_result,
+          decodeErrorData: sse_decode_libsql_error,
+        )
+        ,
+            constMeta: kCrateApiTransactionLibsqlTransactionQueryConstMeta,
+            argValues: [that, sql, parameters, timeoutMs, cancel],
+            apiImpl: this,
+        )); }
+
+        TaskConstMeta get kCrateApiTransactionLibsqlTransactionQueryConstMeta => const TaskConstMeta(
+            debugName: "LibsqlTransaction_query",
+            argNames: ["that", "sql", "parameters", "timeoutMs", "cancel"],
+        );
+
+@override Future<ColumnarQueryResult> crateApiTransactionLibsqlTransactionQueryColumnar({required LibsqlTransaction that , required String sql , LibsqlParams? parameters , BigInt? timeoutMs , LibsqlCancelToken? cancel })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlTransaction(that, serializer);
+sse_encode_String(sql, serializer);
+sse_encode_opt_box_autoadd_libsql_params(parameters, serializer);
+sse_encode_opt_box_autoadd_u_64(timeoutMs, serializer);
+sse_encode_opt_box_autoadd_libsql_cancel_token(cancel, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 68, port: port_);
+
+            },
+            codec:
+        SseCodec(
+          decodeSuccessData: sse_decode_columnar_query_result,
+          decodeErrorData: sse_decode_libsql_error,
+        )
+        ,
+            constMeta: kCrateApiTransactionLibsqlTransactionQueryColumnarConstMeta,
+            argValues: [that, sql, parameters, timeoutMs, cancel],
+            apiImpl: this,
+        )); }
+
+        TaskConstMeta get kCrateApiTransactionLibsqlTransactionQueryColumnarConstMeta => const TaskConstMeta(
+            debugName: "LibsqlTransaction_query_columnar",
+            argNames: ["that", "sql", "parameters", "timeoutMs", "cancel"],
+        );
+
+@override Future<void> crateApiTransactionLibsqlTransactionRollback({required LibsqlTransaction that })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlTransaction(that, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 69, port: port_);
+
+            },
+            codec:
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_libsql_error,
+        )
+        ,
+            constMeta: kCrateApiTransactionLibsqlTransactionRollbackConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+        TaskConstMeta get kCrateApiTransactionLibsqlTransactionRollbackConstMeta => const TaskConstMeta(
+            debugName: "LibsqlTransaction_rollback",
+            argNames: ["that"],
+        );
+
+@override Future<LibsqlSavepoint> crateApiTransactionLibsqlTransactionSavepoint({required LibsqlTransaction that , required String name })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlTransaction(that, serializer);
+sse_encode_String(name, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 70, port: port_);
+
+            },
+            codec:
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlSavepoint,
+          decodeErrorData: sse_decode_libsql_error,
+        )
+        ,
+            constMeta: kCrateApiTransactionLibsqlTransactionSavepointConstMeta,
+            argValues: [that, name],
+            apiImpl: this,
+        )); }
+
+        TaskConstMeta get kCrateApiTransactionLibsqlTransactionSavepointConstMeta => const TaskConstMeta(
+            debugName: "LibsqlTransaction_savepoint",
+            argNames: ["that", "name"],
+        );
+
+@override Future<LibsqlConnection> crateApiLibsqlConnect({required ConnectArgs args })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_connect_args(args, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 71, port: port_);
+
+            },
+            codec:
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlConnection,
+          decodeErrorData: sse_decode_libsql_error,
+        )
+        ,
+            constMeta: kCrateApiLibsqlConnectConstMeta,
+            argValues: [args],
+            apiImpl: this,
+        )); }
+
+        TaskConstMeta get kCrateApiLibsqlConnectConstMeta => const TaskConstMeta(
+            debugName: "connect",
+            argNames: ["args"],
+        );
+
+@override Future<LibsqlPool> crateApiLibsqlConnectPool({required ConnectArgs args , int? readers })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_connect_args(args, serializer);
+sse_encode_opt_box_autoadd_u_32(readers, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 72, port: port_);
+
+            },
+            codec:
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlPool,
+          decodeErrorData: sse_decode_libsql_error,
+        )
+        ,
+            constMeta: kCrateApiLibsqlConnectPoolConstMeta,
+            argValues: [args, readers],
+            apiImpl: this,
+        )); }
+
+        TaskConstMeta get kCrateApiLibsqlConnectPoolConstMeta => const TaskConstMeta(
+            debugName: "connect_pool",
+            argNames: ["args", "readers"],
+        );
+
+@override Future<void> crateApiLibsqlInitApp()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 73, port: port_);
+
+            },
+            codec:
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiLibsqlInitAppConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+        TaskConstMeta get kCrateApiLibsqlInitAppConstMeta => const TaskConstMeta(
+            debugName: "init_app",
+            argNames: [],
+        );
+
+            Future<void> Function(int, dynamic)
+                encode_DartFn_Inputs_DartOpaque_Output_libsql_value_AnyhowException(FutureOr<LibsqlValue> Function(Object) raw) {
+              return (callId, rawArg0) async {
+                final arg0 = dco_decode_DartOpaque(rawArg0);
+
+                Box<LibsqlValue>? rawOutput;
+                Box<AnyhowException>? rawError;
+                try {
+                    rawOutput = Box(await raw(arg0));
+                } catch (e, s) {
+                    rawError = Box(AnyhowException("$e\n\n$s"));
+                }
+
+                final serializer = SseSerializer(generalizedFrbRustBinding);
+                assert((rawOutput != null) ^ (rawError != null));
+                if (rawOutput != null) {
+                    serializer.buffer.putUint8(0);
+                    sse_encode_libsql_value(rawOutput.value, serializer);
+                } else {
+                    serializer.buffer.putUint8(1);
+                    sse_encode_AnyhowException(rawError!.value, serializer);
+                }
+                final output = serializer.intoRaw();
+
+                generalizedFrbRustBinding.dartFnDeliverOutput(
+                  callId: callId, ptr: output.ptr, rustVecLen: output.rustVecLen, dataLen: output.dataLen);
+              };
+            }
+
+            Future<void> Function(int, dynamic, dynamic)
+                encode_DartFn_Inputs_DartOpaque_list_libsql_return_value_Output_DartOpaque_AnyhowException(FutureOr<Object> Function(Object, List<LibsqlReturnValue>) raw) {
+              return (callId, rawArg0, rawArg1) async {
+                final arg0 = dco_decode_DartOpaque(rawArg0);
+final arg1 = dco_decode_list_libsql_return_value(rawArg1);
+
+                Box<Object>? rawOutput;
+                Box<AnyhowException>? rawError;
+                try {
+                    rawOutput = Box(await raw(arg0, arg1));
+                } catch (e, s) {
+                    rawError = Box(AnyhowException("$e\n\n$s"));
+                }
+
+                final serializer = SseSerializer(generalizedFrbRustBinding);
+                assert((rawOutput != null) ^ (rawError != null));
+                if (rawOutput != null) {
+                    serializer.buffer.putUint8(0);
+                    sse_encode_DartOpaque(rawOutput.value, serializer);
+                } else {
+                    serializer.buffer.putUint8(1);
+                    sse_encode_AnyhowException(rawError!.value, serializer);
+                }
+                final output = serializer.intoRaw();
+
+                generalizedFrbRustBinding.dartFnDeliverOutput(
+                  callId: callId, ptr: output.ptr, rustVecLen: output.rustVecLen, dataLen: output.dataLen);
+              };
+            }
+
+            Future<void> Function(int, dynamic, dynamic)
+                encode_DartFn_Inputs_String_String_Output_i_32_AnyhowException(FutureOr<int> Function(String, String) raw) {
+              return (callId, rawArg0, rawArg1) async {
+                final arg0 = dco_decode_String(rawArg0);
+final arg1 = dco_decode_String(rawArg1);
+
+                Box<int>? rawOutput;
+                Box<AnyhowException>? rawError;
+                try {
+                    rawOutput = Box(await raw(arg0, arg1));
+                } catch (e, s) {
+                    rawError = Box(AnyhowException("$e\n\n$s"));
+                }
+
+                final serializer = SseSerializer(generalizedFrbRustBinding);
+                assert((rawOutput != null) ^ (rawError != null));
+                if (rawOutput != null) {
+                    serializer.buffer.putUint8(0);
+                    sse_encode_i_32(rawOutput.value, serializer);
+                } else {
+                    serializer.buffer.putUint8(1);
+                    sse_encode_AnyhowException(rawError!.value, serializer);
+                }
+                final output = serializer.intoRaw();
+
+                generalizedFrbRustBinding.dartFnDeliverOutput(
+                  callId: callId, ptr: output.ptr, rustVecLen: output.rustVecLen, dataLen: output.dataLen);
+              };
+            }
+
+            Future<void> Function(int, )
+                encode_DartFn_Inputs__Output_DartOpaque_AnyhowException(FutureOr<Object> Function() raw) {
+              return (callId, ) async {
+
+                Box<Object>? rawOutput;
+                Box<AnyhowException>? rawError;
+                try {
+                    rawOutput = Box(await raw());
+                } catch (e, s) {
+                    rawError = Box(AnyhowException("$e\n\n$s"));
+                }
+
+                final serializer = SseSerializer(generalizedFrbRustBinding);
+                assert((rawOutput != null) ^ (rawError != null));
+                if (rawOutput != null) {
+                    serializer.buffer.putUint8(0);
+                    sse_encode_DartOpaque(rawOutput.value, serializer);
+                } else {
+                    serializer.buffer.putUint8(1);
+                    sse_encode_AnyhowException(rawError!.value, serializer);
+                }
+                final output = serializer.intoRaw();
+
+                generalizedFrbRustBinding.dartFnDeliverOutput(
+                  callId: callId, ptr: output.ptr, rustVecLen: output.rustVecLen, dataLen: output.dataLen);
+              };
+            }
+
+            Future<void> Function(int, )
+                encode_DartFn_Inputs__Output_unit_AnyhowException(FutureOr<void> Function() raw) {
+              return (callId, ) async {
+
+                Box<void>? rawOutput;
+                Box<AnyhowException>? rawError;
+                try {
+                    rawOutput = Box(await raw());
+                } catch (e, s) {
+                    rawError = Box(AnyhowException("$e\n\n$s"));
+                }
+
+                final serializer = SseSerializer(generalizedFrbRustBinding);
+                assert((rawOutput != null) ^ (rawError != null));
+                if (rawOutput != null) {
+                    serializer.buffer.putUint8(0);
+                    sse_encode_unit(rawOutput.value, serializer);
+                } else {
+                    serializer.buffer.putUint8(1);
+                    sse_encode_AnyhowException(rawError!.value, serializer);
+                }
+                final output = serializer.intoRaw();
+
+                generalizedFrbRustBinding.dartFnDeliverOutput(
+                  callId: callId, ptr: output.ptr, rustVecLen: output.rustVecLen, dataLen: output.dataLen);
+              };
+            }
+
+            Future<void> Function(int, dynamic)
+                encode_DartFn_Inputs_list_libsql_return_value_Output_libsql_value_AnyhowException(FutureOr<LibsqlValue> Function(List<LibsqlReturnValue>) raw) {
+              return (callId, rawArg0) async {
+                final arg0 = dco_decode_list_libsql_return_value(rawArg0);
+
+                Box<LibsqlValue>? rawOutput;
+                Box<AnyhowException>? rawError;
+                try {
+                    rawOutput = Box(await raw(arg0));
+                } catch (e, s) {
+                    rawError = Box(AnyhowException("$e\n\n$s"));
+                }
+
+                final serializer = SseSerializer(generalizedFrbRustBinding);
+                assert((rawOutput != null) ^ (rawError != null));
+                if (rawOutput != null) {
+                    serializer.buffer.putUint8(0);
+                    sse_encode_libsql_value(rawOutput.value, serializer);
+                } else {
+                    serializer.buffer.putUint8(1);
+                    sse_encode_AnyhowException(rawError!.value, serializer);
+                }
+                final output = serializer.intoRaw();
+
+                generalizedFrbRustBinding.dartFnDeliverOutput(
+                  callId: callId, ptr: output.ptr, rustVecLen: output.rustVecLen, dataLen: output.dataLen);
+              };
+            }
+            RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_LibsqlConnection => wire.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlConnection;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_LibsqlConnection => wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlConnection;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_LibsqlPool => wire.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlPool;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_LibsqlPool => wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlPool;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_LibsqlRows => wire.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlRows;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_LibsqlRows => wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlRows;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_LibsqlSavepoint => wire.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlSavepoint;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_LibsqlSavepoint => wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlSavepoint;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_LibsqlStatement => wire.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlStatement;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_LibsqlStatement => wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlStatement;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_LibsqlTransaction => wire.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlTransaction;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_LibsqlTransaction => wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlTransaction;
+
+                  @protected AnyhowException dco_decode_AnyhowException(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return AnyhowException(raw as String); }
+
+@protected LibsqlConnection dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlConnection(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return LibsqlConnectionImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected LibsqlPool dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlPool(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return LibsqlPoolImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected LibsqlRows dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlRows(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return LibsqlRowsImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected LibsqlSavepoint dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlSavepoint(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return LibsqlSavepointImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected LibsqlStatement dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlStatement(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return LibsqlStatementImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected LibsqlTransaction dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlTransaction(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return LibsqlTransactionImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected LibsqlTransaction dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlTransaction(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return LibsqlTransactionImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected LibsqlConnection dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlConnection(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return LibsqlConnectionImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected LibsqlPool dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlPool(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return LibsqlPoolImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected LibsqlRows dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlRows(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return LibsqlRowsImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected LibsqlSavepoint dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlSavepoint(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return LibsqlSavepointImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected LibsqlStatement dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlStatement(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return LibsqlStatementImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected LibsqlTransaction dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlTransaction(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return LibsqlTransactionImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected DateTime dco_decode_Chrono_Utc(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dcoDecodeTimestamp(ts: dco_decode_i_64(raw).toInt(), isUtc: true); }
+
+@protected FutureOr<LibsqlValue> Function(Object) dco_decode_DartFn_Inputs_DartOpaque_Output_libsql_value_AnyhowException(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+throw UnimplementedError(''); }
+
+@protected FutureOr<Object> Function(Object, List<LibsqlReturnValue>) dco_decode_DartFn_Inputs_DartOpaque_list_libsql_return_value_Output_DartOpaque_AnyhowException(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+throw UnimplementedError(''); }
+
+@protected FutureOr<int> Function(String, String) dco_decode_DartFn_Inputs_String_String_Output_i_32_AnyhowException(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+throw UnimplementedError(''); }
+
+@protected FutureOr<Object> Function() dco_decode_DartFn_Inputs__Output_DartOpaque_AnyhowException(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+throw UnimplementedError(''); }
+
+@protected FutureOr<void> Function() dco_decode_DartFn_Inputs__Output_unit_AnyhowException(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+throw UnimplementedError(''); }
+
+@protected FutureOr<LibsqlValue> Function(List<LibsqlReturnValue>) dco_decode_DartFn_Inputs_list_libsql_return_value_Output_libsql_value_AnyhowException(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+throw UnimplementedError(''); }
+
+@protected Object dco_decode_DartOpaque(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return decodeDartOpaque(raw, generalizedFrbRustBinding); }
+
+@protected Map<String, LibsqlReturnValue> dco_decode_Map_String_libsql_return_value_None(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return Map.fromEntries(dco_decode_list_record_string_libsql_return_value(raw).map((e) => MapEntry(e.$1, e.$2))); }
+
+@protected Map<String, LibsqlValue> dco_decode_Map_String_libsql_value_None(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return Map.fromEntries(dco_decode_list_record_string_libsql_value(raw).map((e) => MapEntry(e.$1, e.$2))); }
+
+@protected LibsqlConnection dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlConnection(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return LibsqlConnectionImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected LibsqlPool dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlPool(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return LibsqlPoolImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected LibsqlRows dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlRows(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return LibsqlRowsImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected LibsqlSavepoint dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlSavepoint(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return LibsqlSavepointImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected LibsqlStatement dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlStatement(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return LibsqlStatementImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected LibsqlTransaction dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlTransaction(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return LibsqlTransactionImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected RustStreamSink<LibsqlSyncEvent> dco_decode_StreamSink_libsql_sync_event_Sse(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+throw UnimplementedError(); }
+
+@protected RustStreamSink<List<LibsqlUpdate>> dco_decode_StreamSink_list_libsql_update_Sse(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+throw UnimplementedError(); }
+
+@protected RustStreamSink<QueryResult> dco_decode_StreamSink_query_result_Sse(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+throw UnimplementedError(); }
+
+@protected String dco_decode_String(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as String; }
+
+@protected BatchResult dco_decode_batch_result(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 1) throw Exception('unexpected arr length: expect 1 but see ${arr.length}');
+                return BatchResult(results: dco_decode_list_query_result(arr[0]),); }
+
+@protected bool dco_decode_bool(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as bool; }
+
+@protected DateTime dco_decode_box_autoadd_Chrono_Utc(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_Chrono_Utc(raw); }
+
+@protected bool dco_decode_box_autoadd_bool(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as bool; }
+
+@protected ConnectArgs dco_decode_box_autoadd_connect_args(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_connect_args(raw); }
+
+@protected int dco_decode_box_autoadd_i_32(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as int; }
+
+@protected LibsqlBusyRetry dco_decode_box_autoadd_libsql_busy_retry(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_libsql_busy_retry(raw); }
+
+@protected LibsqlCancelToken dco_decode_box_autoadd_libsql_cancel_token(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_libsql_cancel_token(raw); }
+
+@protected LibsqlDateTimeUnit dco_decode_box_autoadd_libsql_date_time_unit(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_libsql_date_time_unit(raw); }
+
+@protected LibsqlDecodeMode dco_decode_box_autoadd_libsql_decode_mode(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_libsql_decode_mode(raw); }
+
+@protected LibsqlError dco_decode_box_autoadd_libsql_error(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_libsql_error(raw); }
+
+@protected LibsqlOpenFlags dco_decode_box_autoadd_libsql_open_flags(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_libsql_open_flags(raw); }
+
+@protected LibsqlParams dco_decode_box_autoadd_libsql_params(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_libsql_params(raw); }
+
+@protected LibsqlSyncConflict dco_decode_box_autoadd_libsql_sync_conflict(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_libsql_sync_conflict(raw); }
+
+@protected LibsqlSyncConflictPolicy dco_decode_box_autoadd_libsql_sync_conflict_policy(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_libsql_sync_conflict_policy(raw); }
+
+@protected LibsqlTransactionBehavior dco_decode_box_autoadd_libsql_transaction_behavior(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_libsql_transaction_behavior(raw); }
+
+@protected SyncResult dco_decode_box_autoadd_sync_result(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_sync_result(raw); }
+
+@protected int dco_decode_box_autoadd_u_32(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as int; }
+
+@protected BigInt dco_decode_box_autoadd_u_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_u_64(raw); }
+
+@protected ColumnarQueryResult dco_decode_columnar_query_result(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 7) throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+                return ColumnarQueryResult(columns: dco_decode_list_String(arr[0]),
+declTypes: dco_decode_list_opt_String(arr[1]),
+values: dco_decode_list_libsql_return_value(arr[2]),
+rowCount: dco_decode_u_64(arr[3]),
+rowsAffected: dco_decode_u_64(arr[4]),
+lastInsertRowid: dco_decode_i_64(arr[5]),
+totalChanges: dco_decode_u_64(arr[6]),); }
+
+@protected ConnectArgs dco_decode_connect_args(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 13) throw Exception('unexpected arr length: expect 13 but see ${arr.length}');
+                return ConnectArgs(url: dco_decode_String(arr[0]),
+authToken: dco_decode_opt_String(arr[1]),
+syncUrl: dco_decode_opt_String(arr[2]),
+syncIntervalSeconds: dco_decode_opt_box_autoadd_u_64(arr[3]),
+encryptionKey: dco_decode_opt_String(arr[4]),
+readYourWrites: dco_decode_opt_box_autoadd_bool(arr[5]),
+openFlags: dco_decode_opt_box_autoadd_libsql_open_flags(arr[6]),
+offline: dco_decode_opt_box_autoadd_bool(arr[7]),
+decodeMode: dco_decode_opt_box_autoadd_libsql_decode_mode(arr[8]),
+datetimeUnit: dco_decode_opt_box_autoadd_libsql_date_time_unit(arr[9]),
+busyTimeoutMs: dco_decode_opt_box_autoadd_u_64(arr[10]),
+busyRetry: dco_decode_opt_box_autoadd_libsql_busy_retry(arr[11]),
+lockTimeoutMs: dco_decode_opt_box_autoadd_u_64(arr[12]),); }
+
+@protected ExecuteResult dco_decode_execute_result(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return ExecuteResult(rowsAffected: dco_decode_u_64(arr[0]),
+lastInsertRowid: dco_decode_i_64(arr[1]),
+totalChanges: dco_decode_u_64(arr[2]),); }
+
+@protected double dco_decode_f_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as double; }
+
+@protected int dco_decode_i_32(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as int; }
+
+@protected PlatformInt64 dco_decode_i_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dcoDecodeI64(raw); }
+
+@protected PlatformInt64 dco_decode_isize(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dcoDecodeI64(raw); }
+
+@protected LibsqlBatchStatement dco_decode_libsql_batch_statement(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return LibsqlBatchStatement(sql: dco_decode_String(arr[0]),
+parameters: dco_decode_opt_box_autoadd_libsql_params(arr[1]),); }
+
+@protected LibsqlBusyRetry dco_decode_libsql_busy_retry(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return LibsqlBusyRetry(maxAttempts: dco_decode_u_32(arr[0]),
+initialDelayMs: dco_decode_u_64(arr[1]),
+maxDelayMs: dco_decode_u_64(arr[2]),); }
+
+@protected LibsqlCancelToken dco_decode_libsql_cancel_token(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 1) throw Exception('unexpected arr length: expect 1 but see ${arr.length}');
+                return LibsqlCancelToken(id: dco_decode_u_64(arr[0]),); }
+
+@protected LibsqlCollation dco_decode_libsql_collation(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return LibsqlCollation.values[raw as int]; }
+
+@protected LibsqlColumn dco_decode_libsql_column(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+                return LibsqlColumn(name: dco_decode_String(arr[0]),
+originName: dco_decode_opt_String(arr[1]),
+tableName: dco_decode_opt_String(arr[2]),
+databaseName: dco_decode_opt_String(arr[3]),
+declType: dco_decode_opt_String(arr[4]),); }
+
+@protected LibsqlDateTimeFormat dco_decode_libsql_date_time_format(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return LibsqlDateTimeFormat.values[raw as int]; }
+
+@protected LibsqlDateTimeUnit dco_decode_libsql_date_time_unit(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return LibsqlDateTimeUnit.values[raw as int]; }
+
+@protected LibsqlDecodeMode dco_decode_libsql_decode_mode(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return LibsqlDecodeMode.values[raw as int]; }
+
+@protected LibsqlError dco_decode_libsql_error(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+                return LibsqlError(kind: dco_decode_libsql_error_kind(arr[0]),
+code: dco_decode_opt_box_autoadd_i_32(arr[1]),
+message: dco_decode_String(arr[2]),
+offset: dco_decode_opt_box_autoadd_i_32(arr[3]),
+conflict: dco_decode_opt_box_autoadd_libsql_sync_conflict(arr[4]),); }
+
+@protected LibsqlErrorKind dco_decode_libsql_error_kind(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return LibsqlErrorKind.values[raw as int]; }
+
+@protected LibsqlOpenFlags dco_decode_libsql_open_flags(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return LibsqlOpenFlags.values[raw as int]; }
+
+@protected LibsqlParams dco_decode_libsql_params(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return LibsqlParams(positional: dco_decode_opt_list_libsql_value(arr[0]),
+named: dco_decode_opt_Map_String_libsql_value_None(arr[1]),); }
+
+@protected LibsqlReturnValue dco_decode_libsql_return_value(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+switch (raw[0]) {
+                case 0: return LibsqlReturnValue_Integer(dco_decode_i_64(raw[1]),);
+case 1: return LibsqlReturnValue_Real(dco_decode_f_64(raw[1]),);
+case 2: return LibsqlReturnValue_Text(dco_decode_String(raw[1]),);
+case 3: return LibsqlReturnValue_Blob(dco_decode_list_prim_u_8_strict(raw[1]),);
+case 4: return LibsqlReturnValue_Null();
+case 5: return LibsqlReturnValue_Bool(dco_decode_bool(raw[1]),);
+case 6: return LibsqlReturnValue_DateTime(dco_decode_Chrono_Utc(raw[1]),);
+case 7: return LibsqlReturnValue_Json(dco_decode_String(raw[1]),);
+                default: throw Exception("unreachable");
+            } }
+
+@protected LibsqlSyncConflict dco_decode_libsql_sync_conflict(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return LibsqlSyncConflict(firstRejectedFrame: dco_decode_u_64(arr[0]),
+lastLocalFrame: dco_decode_u_64(arr[1]),
+remoteFrameNo: dco_decode_u_64(arr[2]),); }
+
+@protected LibsqlSyncConflictPolicy dco_decode_libsql_sync_conflict_policy(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return LibsqlSyncConflictPolicy.values[raw as int]; }
+
+@protected LibsqlSyncEvent dco_decode_libsql_sync_event(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+switch (raw[0]) {
+                case 0: return LibsqlSyncEvent_Synced(trigger: dco_decode_libsql_sync_trigger(raw[1]),result: dco_decode_box_autoadd_sync_result(raw[2]),);
+case 1: return LibsqlSyncEvent_Failed(trigger: dco_decode_libsql_sync_trigger(raw[1]),error: dco_decode_box_autoadd_libsql_error(raw[2]),);
+                default: throw Exception("unreachable");
+            } }
+
+@protected LibsqlSyncStatus dco_decode_libsql_sync_status(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return LibsqlSyncStatus(syncing: dco_decode_bool(arr[0]),
+lastSyncedAt: dco_decode_opt_box_autoadd_Chrono_Utc(arr[1]),
+lastResult: dco_decode_opt_box_autoadd_sync_result(arr[2]),
+lastError: dco_decode_opt_box_autoadd_libsql_error(arr[3]),); }
+
+@protected LibsqlSyncTrigger dco_decode_libsql_sync_trigger(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return LibsqlSyncTrigger.values[raw as int]; }
+
+@protected LibsqlTransactionBehavior dco_decode_libsql_transaction_behavior(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return LibsqlTransactionBehavior.values[raw as int]; }
+
+@protected LibsqlUpdate dco_decode_libsql_update(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return LibsqlUpdate(operation: dco_decode_libsql_update_operation(arr[0]),
+database: dco_decode_String(arr[1]),
+table: dco_decode_String(arr[2]),
+rowid: dco_decode_i_64(arr[3]),); }
+
+@protected LibsqlUpdateOperation dco_decode_libsql_update_operation(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return LibsqlUpdateOperation.values[raw as int]; }
+
+@protected LibsqlValue dco_decode_libsql_value(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+switch (raw[0]) {
+                case 0: return LibsqlValue_Integer(dco_decode_i_64(raw[1]),);
+case 1: return LibsqlValue_Real(dco_decode_f_64(raw[1]),);
+case 2: return LibsqlValue_Text(dco_decode_String(raw[1]),);
+case 3: return LibsqlValue_Blob(dco_decode_list_prim_u_8_strict(raw[1]),);
+case 4: return LibsqlValue_Null();
+case 5: return LibsqlValue_Bool(dco_decode_bool(raw[1]),);
+case 6: return LibsqlValue_DateTime(value: dco_decode_Chrono_Utc(raw[1]),format: dco_decode_libsql_date_time_format(raw[2]),);
+case 7: return LibsqlValue_BigInt(dco_decode_String(raw[1]),);
+case 8: return LibsqlValue_Json(dco_decode_String(raw[1]),);
+                default: throw Exception("unreachable");
+            } }
+
+@protected List<Map<String, LibsqlReturnValue>> dco_decode_list_Map_String_libsql_return_value_None(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_Map_String_libsql_return_value_None).toList(); }
+
+@protected List<String> dco_decode_list_String(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_String).toList(); }
+
+@protected List<LibsqlBatchStatement> dco_decode_list_libsql_batch_statement(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_libsql_batch_statement).toList(); }
+
+@protected List<LibsqlColumn> dco_decode_list_libsql_column(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_libsql_column).toList(); }
+
+@protected List<LibsqlReturnValue> dco_decode_list_libsql_return_value(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_libsql_return_value).toList(); }
+
+@protected List<LibsqlUpdate> dco_decode_list_libsql_update(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_libsql_update).toList(); }
+
+@protected List<LibsqlValue> dco_decode_list_libsql_value(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_libsql_value).toList(); }
+
+@protected List<String?> dco_decode_list_opt_String(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_opt_String).toList(); }
+
+@protected Uint8List dco_decode_list_prim_u_8_strict(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as Uint8List; }
+
+@protected List<QueryResult> dco_decode_list_query_result(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_query_result).toList(); }
+
+@protected List<(String,LibsqlReturnValue)> dco_decode_list_record_string_libsql_return_value(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_record_string_libsql_return_value).toList(); }
+
+@protected List<(String,LibsqlValue)> dco_decode_list_record_string_libsql_value(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_record_string_libsql_value).toList(); }
+
+@protected Map<String, LibsqlReturnValue>? dco_decode_opt_Map_String_libsql_return_value_None(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_Map_String_libsql_return_value_None(raw); }
+
+@protected Map<String, LibsqlValue>? dco_decode_opt_Map_String_libsql_value_None(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_Map_String_libsql_value_None(raw); }
+
+@protected String? dco_decode_opt_String(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_String(raw); }
+
+@protected DateTime? dco_decode_opt_box_autoadd_Chrono_Utc(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_Chrono_Utc(raw); }
+
+@protected bool? dco_decode_opt_box_autoadd_bool(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_bool(raw); }
+
+@protected int? dco_decode_opt_box_autoadd_i_32(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_i_32(raw); }
+
+@protected LibsqlBusyRetry? dco_decode_opt_box_autoadd_libsql_busy_retry(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_libsql_busy_retry(raw); }
+
+@protected LibsqlCancelToken? dco_decode_opt_box_autoadd_libsql_cancel_token(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_libsql_cancel_token(raw); }
+
+@protected LibsqlDateTimeUnit? dco_decode_opt_box_autoadd_libsql_date_time_unit(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_libsql_date_time_unit(raw); }
+
+@protected LibsqlDecodeMode? dco_decode_opt_box_autoadd_libsql_decode_mode(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_libsql_decode_mode(raw); }
+
+@protected LibsqlError? dco_decode_opt_box_autoadd_libsql_error(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_libsql_error(raw); }
+
+@protected LibsqlOpenFlags? dco_decode_opt_box_autoadd_libsql_open_flags(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_libsql_open_flags(raw); }
+
+@protected LibsqlParams? dco_decode_opt_box_autoadd_libsql_params(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_libsql_params(raw); }
+
+@protected LibsqlSyncConflict? dco_decode_opt_box_autoadd_libsql_sync_conflict(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_libsql_sync_conflict(raw); }
+
+@protected LibsqlSyncConflictPolicy? dco_decode_opt_box_autoadd_libsql_sync_conflict_policy(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_libsql_sync_conflict_policy(raw); }
+
+@protected LibsqlTransactionBehavior? dco_decode_opt_box_autoadd_libsql_transaction_behavior(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_libsql_transaction_behavior(raw); }
+
+@protected SyncResult? dco_decode_opt_box_autoadd_sync_result(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_sync_result(raw); }
+
+@protected int? dco_decode_opt_box_autoadd_u_32(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_u_32(raw); }
+
+@protected BigInt? dco_decode_opt_box_autoadd_u_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_u_64(raw); }
+
+@protected List<LibsqlValue>? dco_decode_opt_list_libsql_value(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_list_libsql_value(raw); }
+
+@protected QueryResult dco_decode_query_result(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 6) throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+                return QueryResult(rows: dco_decode_list_Map_String_libsql_return_value_None(arr[0]),
+columns: dco_decode_list_String(arr[1]),
+declTypes: dco_decode_list_opt_String(arr[2]),
+rowsAffected: dco_decode_u_64(arr[3]),
+lastInsertRowid: dco_decode_i_64(arr[4]),
+totalChanges: dco_decode_u_64(arr[5]),); }
+
+@protected (String,LibsqlReturnValue) dco_decode_record_string_libsql_return_value(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+            if (arr.length != 2) {
+                throw Exception('Expected 2 elements, got ${arr.length}');
+            }
+            return (dco_decode_String(arr[0]),dco_decode_libsql_return_value(arr[1]),); }
+
+@protected (String,LibsqlValue) dco_decode_record_string_libsql_value(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+            if (arr.length != 2) {
+                throw Exception('Expected 2 elements, got ${arr.length}');
+            }
+            return (dco_decode_String(arr[0]),dco_decode_libsql_value(arr[1]),); }
+
+@protected SyncResult dco_decode_sync_result(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return SyncResult(framesSynced: dco_decode_u_64(arr[0]),
+frameNo: dco_decode_opt_box_autoadd_u_64(arr[1]),
+replicationIndex: dco_decode_opt_box_autoadd_u_64(arr[2]),
+durationMs: dco_decode_u_64(arr[3]),); }
+
+@protected int dco_decode_u_32(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as int; }
+
+@protected BigInt dco_decode_u_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dcoDecodeU64(raw); }
+
+@protected int dco_decode_u_8(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as int; }
+
+@protected void dco_decode_unit(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return; }
+
+@protected BigInt dco_decode_usize(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dcoDecodeU64(raw); }
+
+@protected AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_String(deserializer);
+        return AnyhowException(inner); }
+
+@protected LibsqlConnection sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlConnection(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return LibsqlConnectionImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected LibsqlPool sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlPool(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return LibsqlPoolImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected LibsqlRows sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlRows(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return LibsqlRowsImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected LibsqlSavepoint sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlSavepoint(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return LibsqlSavepointImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected LibsqlStatement sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlStatement(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return LibsqlStatementImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected LibsqlTransaction sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlTransaction(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return LibsqlTransactionImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected LibsqlTransaction sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlTransaction(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return LibsqlTransactionImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected LibsqlConnection sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlConnection(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return LibsqlConnectionImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected LibsqlPool sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlPool(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return LibsqlPoolImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected LibsqlRows sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlRows(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return LibsqlRowsImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected LibsqlSavepoint sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlSavepoint(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return LibsqlSavepointImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected LibsqlStatement sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlStatement(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return LibsqlStatementImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected LibsqlTransaction sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlTransaction(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return LibsqlTransactionImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected DateTime sse_decode_Chrono_Utc(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_64(deserializer);
+        return DateTime.fromMicrosecondsSinceEpoch(inner.toInt(), isUtc: true); }
+
+@protected Object sse_decode_DartOpaque(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_isize(deserializer);
+        return decodeDartOpaque(inner, generalizedFrbRustBinding); }
+
+@protected Map<String, LibsqlReturnValue> sse_decode_Map_String_libsql_return_value_None(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_list_record_string_libsql_return_value(deserializer);
+        return Map.fromEntries(inner.map((e) => MapEntry(e.$1, e.$2))); }
+
+@protected Map<String, LibsqlValue> sse_decode_Map_String_libsql_value_None(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_list_record_string_libsql_value(deserializer);
+        return Map.fromEntries(inner.map((e) => MapEntry(e.$1, e.$2))); }
+
+@protected LibsqlConnection sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlConnection(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return LibsqlConnectionImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected LibsqlPool sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlPool(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return LibsqlPoolImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected LibsqlRows sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlRows(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return LibsqlRowsImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected LibsqlSavepoint sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlSavepoint(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return LibsqlSavepointImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected LibsqlStatement sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlStatement(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return LibsqlStatementImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected LibsqlTransaction sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlTransaction(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return LibsqlTransactionImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected RustStreamSink<LibsqlSyncEvent> sse_decode_StreamSink_libsql_sync_event_Sse(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+throw UnimplementedError('Unreachable ()'); }
+
+@protected RustStreamSink<List<LibsqlUpdate>> sse_decode_StreamSink_list_libsql_update_Sse(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+throw UnimplementedError('Unreachable ()'); }
+
+@protected RustStreamSink<QueryResult> sse_decode_StreamSink_query_result_Sse(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+throw UnimplementedError('Unreachable ()'); }
+
+@protected String sse_decode_String(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_list_prim_u_8_strict(deserializer);
+        return utf8.decoder.convert(inner); }
+
+@protected BatchResult sse_decode_batch_result(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_results = sse_decode_list_query_result(deserializer);
+return BatchResult(results: var_results); }
+
+@protected bool sse_decode_bool(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getUint8() != 0; }
+
+@protected DateTime sse_decode_box_autoadd_Chrono_Utc(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_Chrono_Utc(deserializer)); }
+
+@protected bool sse_decode_box_autoadd_bool(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_bool(deserializer)); }
+
+@protected ConnectArgs sse_decode_box_autoadd_connect_args(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_connect_args(deserializer)); }
+
+@protected int sse_decode_box_autoadd_i_32(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_i_32(deserializer)); }
+
+@protected LibsqlBusyRetry sse_decode_box_autoadd_libsql_busy_retry(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_libsql_busy_retry(deserializer)); }
+
+@protected LibsqlCancelToken sse_decode_box_autoadd_libsql_cancel_token(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_libsql_cancel_token(deserializer)); }
+
+@protected LibsqlDateTimeUnit sse_decode_box_autoadd_libsql_date_time_unit(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_libsql_date_time_unit(deserializer)); }
+
+@protected LibsqlDecodeMode sse_decode_box_autoadd_libsql_decode_mode(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_libsql_decode_mode(deserializer)); }
+
+@protected LibsqlError sse_decode_box_autoadd_libsql_error(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_libsql_error(deserializer)); }
+
+@protected LibsqlOpenFlags sse_decode_box_autoadd_libsql_open_flags(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_libsql_open_flags(deserializer)); }
+
+@protected LibsqlParams sse_decode_box_autoadd_libsql_params(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_libsql_params(deserializer)); }
+
+@protected LibsqlSyncConflict sse_decode_box_autoadd_libsql_sync_conflict(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_libsql_sync_conflict(deserializer)); }
+
+@protected LibsqlSyncConflictPolicy sse_decode_box_autoadd_libsql_sync_conflict_policy(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_libsql_sync_conflict_policy(deserializer)); }
+
+@protected LibsqlTransactionBehavior sse_decode_box_autoadd_libsql_transaction_behavior(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_libsql_transaction_behavior(deserializer)); }
+
+@protected SyncResult sse_decode_box_autoadd_sync_result(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_sync_result(deserializer)); }
+
+@protected int sse_decode_box_autoadd_u_32(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_u_32(deserializer)); }
+
+@protected BigInt sse_decode_box_autoadd_u_64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_u_64(deserializer)); }
+
+@protected ColumnarQueryResult sse_decode_columnar_query_result(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_columns = sse_decode_list_String(deserializer);
+var var_declTypes = sse_decode_list_opt_String(deserializer);
+var var_values = sse_decode_list_libsql_return_value(deserializer);
+var var_rowCount = sse_decode_u_64(deserializer);
+var var_rowsAffected = sse_decode_u_64(deserializer);
+var var_lastInsertRowid = sse_decode_i_64(deserializer);
+var var_totalChanges = sse_decode_u_64(deserializer);
+return ColumnarQueryResult(columns: var_columns, declTypes: var_declTypes, values: var_values, rowCount: var_rowCount, rowsAffected: var_rowsAffected, lastInsertRowid: var_lastInsertRowid, totalChanges: var_totalChanges); }
+
+@protected ConnectArgs sse_decode_connect_args(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_url = sse_decode_String(deserializer);
+var var_authToken = sse_decode_opt_String(deserializer);
+var var_syncUrl = sse_decode_opt_String(deserializer);
+var var_syncIntervalSeconds = sse_decode_opt_box_autoadd_u_64(deserializer);
+var var_encryptionKey = sse_decode_opt_String(deserializer);
+var var_readYourWrites = sse_decode_opt_box_autoadd_bool(deserializer);
+var var_openFlags = sse_decode_opt_box_autoadd_libsql_open_flags(deserializer);
+var var_offline = sse_decode_opt_box_autoadd_bool(deserializer);
+var var_decodeMode = sse_decode_opt_box_autoadd_libsql_decode_mode(deserializer);
+var var_datetimeUnit = sse_decode_opt_box_autoadd_libsql_date_time_unit(deserializer);
+var var_busyTimeoutMs = sse_decode_opt_box_autoadd_u_64(deserializer);
+var var_busyRetry = sse_decode_opt_box_autoadd_libsql_busy_retry(deserializer);
+var var_lockTimeoutMs = sse_decode_opt_box_autoadd_u_64(deserializer);
+return ConnectArgs(url: var_url, authToken: var_authToken, syncUrl: var_syncUrl, syncIntervalSeconds: var_syncIntervalSeconds, encryptionKey: var_encryptionKey, readYourWrites: var_readYourWrites, openFlags: var_openFlags, offline: var_offline, decodeMode: var_decodeMode, datetimeUnit: var_datetimeUnit, busyTimeoutMs: var_busyTimeoutMs, busyRetry: var_busyRetry, lockTimeoutMs: var_lockTimeoutMs); }
+
+@protected ExecuteResult sse_decode_execute_result(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_rowsAffected = sse_decode_u_64(deserializer);
+var var_lastInsertRowid = sse_decode_i_64(deserializer);
+var var_totalChanges = sse_decode_u_64(deserializer);
+return ExecuteResult(rowsAffected: var_rowsAffected, lastInsertRowid: var_lastInsertRowid, totalChanges: var_totalChanges); }
+
+@protected double sse_decode_f_64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getFloat64(); }
+
+@protected int sse_decode_i_32(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getInt32(); }
+
+@protected PlatformInt64 sse_decode_i_64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getPlatformInt64(); }
+
+@protected PlatformInt64 sse_decode_isize(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getPlatformInt64(); }
+
+@protected LibsqlBatchStatement sse_decode_libsql_batch_statement(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_sql = sse_decode_String(deserializer);
+var var_parameters = sse_decode_opt_box_autoadd_libsql_params(deserializer);
+return LibsqlBatchStatement(sql: var_sql, parameters: var_parameters); }
+
+@protected LibsqlBusyRetry sse_decode_libsql_busy_retry(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_maxAttempts = sse_decode_u_32(deserializer);
+var var_initialDelayMs = sse_decode_u_64(deserializer);
+var var_maxDelayMs = sse_decode_u_64(deserializer);
+return LibsqlBusyRetry(maxAttempts: var_maxAttempts, initialDelayMs: var_initialDelayMs, maxDelayMs: var_maxDelayMs); }
+
+@protected LibsqlCancelToken sse_decode_libsql_cancel_token(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_id = sse_decode_u_64(deserializer);
+return LibsqlCancelToken(id: var_id); }
+
+@protected LibsqlCollation sse_decode_libsql_collation(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return LibsqlCollation.values[inner]; }
+
+@protected LibsqlColumn sse_decode_libsql_column(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_name = sse_decode_String(deserializer);
+var var_originName = sse_decode_opt_String(deserializer);
+var var_tableName = sse_decode_opt_String(deserializer);
+var var_databaseName = sse_decode_opt_String(deserializer);
+var var_declType = sse_decode_opt_String(deserializer);
+return LibsqlColumn(name: var_name, originName: var_originName, tableName: var_tableName, databaseName: var_databaseName, declType: var_declType); }
+
+@protected LibsqlDateTimeFormat sse_decode_libsql_date_time_format(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return LibsqlDateTimeFormat.values[inner]; }
+
+@protected LibsqlDateTimeUnit sse_decode_libsql_date_time_unit(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return LibsqlDateTimeUnit.values[inner]; }
+
+@protected LibsqlDecodeMode sse_decode_libsql_decode_mode(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return LibsqlDecodeMode.values[inner]; }
+
+@protected LibsqlError sse_decode_libsql_error(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_kind = sse_decode_libsql_error_kind(deserializer);
+var var_code = sse_decode_opt_box_autoadd_i_32(deserializer);
+var var_message = sse_decode_String(deserializer);
+var var_offset = sse_decode_opt_box_autoadd_i_32(deserializer);
+var var_conflict = sse_decode_opt_box_autoadd_libsql_sync_conflict(deserializer);
+return LibsqlError(kind: var_kind, code: var_code, message: var_message, offset: var_offset, conflict: var_conflict); }
+
+@protected LibsqlErrorKind sse_decode_libsql_error_kind(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return LibsqlErrorKind.values[inner]; }
+
+@protected LibsqlOpenFlags sse_decode_libsql_open_flags(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return LibsqlOpenFlags.values[inner]; }
+
+@protected LibsqlParams sse_decode_libsql_params(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_positional = sse_decode_opt_list_libsql_value(deserializer);
+var var_named = sse_decode_opt_Map_String_libsql_value_None(deserializer);
+return LibsqlParams(positional: var_positional, named: var_named); }
+
+@protected LibsqlReturnValue sse_decode_libsql_return_value(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            var tag_ = sse_decode_i_32(deserializer);
+            switch (tag_) { case 0: var var_field0 = sse_decode_i_64(deserializer);
+return LibsqlReturnValue_Integer(var_field0);case 1: var var_field0 = sse_decode_f_64(deserializer);
+return LibsqlReturnValue_Real(var_field0);case 2: var var_field0 = sse_decode_String(deserializer);
+return LibsqlReturnValue_Text(var_field0);case 3: var var_field0 = sse_decode_list_prim_u_8_strict(deserializer);
+return LibsqlReturnValue_Blob(var_field0);case 4: return LibsqlReturnValue_Null();case 5: var var_field0 = sse_decode_bool(deserializer);
+return LibsqlReturnValue_Bool(var_field0);case 6: var var_field0 = sse_decode_Chrono_Utc(deserializer);
+return LibsqlReturnValue_DateTime(var_field0);case 7: var var_field0 = sse_decode_String(deserializer);
+return LibsqlReturnValue_Json(var_field0); default: throw UnimplementedError(''); }
+             }
+
+@protected LibsqlSyncConflict sse_decode_libsql_sync_conflict(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_firstRejectedFrame = sse_decode_u_64(deserializer);
+var var_lastLocalFrame = sse_decode_u_64(deserializer);
+var var_remoteFrameNo = sse_decode_u_64(deserializer);
+return LibsqlSyncConflict(firstRejectedFrame: var_firstRejectedFrame, lastLocalFrame: var_lastLocalFrame, remoteFrameNo: var_remoteFrameNo); }
+
+@protected LibsqlSyncConflictPolicy sse_decode_libsql_sync_conflict_policy(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return LibsqlSyncConflictPolicy.values[inner]; }
+
+@protected LibsqlSyncEvent sse_decode_libsql_sync_event(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            var tag_ = sse_decode_i_32(deserializer);
+            switch (tag_) { case 0: var var_trigger = sse_decode_libsql_sync_trigger(deserializer);
+var var_result = sse_decode_box_autoadd_sync_result(deserializer);
+return LibsqlSyncEvent_Synced(trigger: var_trigger, result: var_result);case 1: var var_trigger = sse_decode_libsql_sync_trigger(deserializer);
+var var_error = sse_decode_box_autoadd_libsql_error(deserializer);
+return LibsqlSyncEvent_Failed(trigger: var_trigger, error: var_error); default: throw UnimplementedError(''); }
+             }
+
+@protected LibsqlSyncStatus sse_decode_libsql_sync_status(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_syncing = sse_decode_bool(deserializer);
+var var_lastSyncedAt = sse_decode_opt_box_autoadd_Chrono_Utc(deserializer);
+var var_lastResult = sse_decode_opt_box_autoadd_sync_result(deserializer);
+var var_lastError = sse_decode_opt_box_autoadd_libsql_error(deserializer);
+return LibsqlSyncStatus(syncing: var_syncing, lastSyncedAt: var_lastSyncedAt, lastResult: var_lastResult, lastError: var_lastError); }
+
+@protected LibsqlSyncTrigger sse_decode_libsql_sync_trigger(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return LibsqlSyncTrigger.values[inner]; }
+
+@protected LibsqlTransactionBehavior sse_decode_libsql_transaction_behavior(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return LibsqlTransactionBehavior.values[inner]; }
+
+@protected LibsqlUpdate sse_decode_libsql_update(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_operation = sse_decode_libsql_update_operation(deserializer);
+var var_database = sse_decode_String(deserializer);
+var var_table = sse_decode_String(deserializer);
+var var_rowid = sse_decode_i_64(deserializer);
+return LibsqlUpdate(operation: var_operation, database: var_database, table: var_table, rowid: var_rowid); }
+
+@protected LibsqlUpdateOperation sse_decode_libsql_update_operation(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return LibsqlUpdateOperation.values[inner]; }
+
+@protected LibsqlValue sse_decode_libsql_value(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            var tag_ = sse_decode_i_32(deserializer);
+            switch (tag_) { case 0: var var_field0 = sse_decode_i_64(deserializer);
+return LibsqlValue_Integer(var_field0);case 1: var var_field0 = sse_decode_f_64(deserializer);
+return LibsqlValue_Real(var_field0);case 2: var var_field0 = sse_decode_String(deserializer);
+return LibsqlValue_Text(var_field0);case 3: var var_field0 = sse_decode_list_prim_u_8_strict(deserializer);
+return LibsqlValue_Blob(var_field0);case 4: return LibsqlValue_Null();case 5: var var_field0 = sse_decode_bool(deserializer);
+return LibsqlValue_Bool(var_field0);case 6: var var_value = sse_decode_Chrono_Utc(deserializer);
+var var_format = sse_decode_libsql_date_time_format(deserializer);
+return LibsqlValue_DateTime(value: var_value, format: var_format);case 7: var var_field0 = sse_decode_String(deserializer);
+return LibsqlValue_BigInt(var_field0);case 8: var var_field0 = sse_decode_String(deserializer);
+return LibsqlValue_Json(var_field0); default: throw UnimplementedError(''); }
+             }
+
+@protected List<Map<String, LibsqlReturnValue>> sse_decode_list_Map_String_libsql_return_value_None(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <Map<String, LibsqlReturnValue>>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_Map_String_libsql_return_value_None(deserializer)); }
+        return ans_;
+         }
+
+@protected List<String> sse_decode_list_String(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <String>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_String(deserializer)); }
+        return ans_;
+         }
+
+@protected List<LibsqlBatchStatement> sse_decode_list_libsql_batch_statement(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <LibsqlBatchStatement>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_libsql_batch_statement(deserializer)); }
+        return ans_;
+         }
+
+@protected List<LibsqlColumn> sse_decode_list_libsql_column(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <LibsqlColumn>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_libsql_column(deserializer)); }
+        return ans_;
+         }
+
+@protected List<LibsqlReturnValue> sse_decode_list_libsql_return_value(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <LibsqlReturnValue>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_libsql_return_value(deserializer)); }
+        return ans_;
+         }
+
+@protected List<LibsqlUpdate> sse_decode_list_libsql_update(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <LibsqlUpdate>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_libsql_update(deserializer)); }
+        return ans_;
+         }
+
+@protected List<LibsqlValue> sse_decode_list_libsql_value(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <LibsqlValue>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_libsql_value(deserializer)); }
+        return ans_;
+         }
+
+@protected List<String?> sse_decode_list_opt_String(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <String?>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_opt_String(deserializer)); }
+        return ans_;
+         }
+
+@protected Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var len_ = sse_decode_i_32(deserializer);
+                return deserializer.buffer.getUint8List(len_); }
+
+@protected List<QueryResult> sse_decode_list_query_result(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <QueryResult>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_query_result(deserializer)); }
+        return ans_;
+         }
+
+@protected List<(String,LibsqlReturnValue)> sse_decode_list_record_string_libsql_return_value(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <(String,LibsqlReturnValue)>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_record_string_libsql_return_value(deserializer)); }
+        return ans_;
+         }
+
+@protected List<(String,LibsqlValue)> sse_decode_list_record_string_libsql_value(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <(String,LibsqlValue)>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_record_string_libsql_value(deserializer)); }
+        return ans_;
+         }
+
+@protected Map<String, LibsqlReturnValue>? sse_decode_opt_Map_String_libsql_return_value_None(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_Map_String_libsql_return_value_None(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected Map<String, LibsqlValue>? sse_decode_opt_Map_String_libsql_value_None(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_Map_String_libsql_value_None(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected String? sse_decode_opt_String(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_String(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected DateTime? sse_decode_opt_box_autoadd_Chrono_Utc(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_Chrono_Utc(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected bool? sse_decode_opt_box_autoadd_bool(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_bool(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected int? sse_decode_opt_box_autoadd_i_32(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_i_32(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected LibsqlBusyRetry? sse_decode_opt_box_autoadd_libsql_busy_retry(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_libsql_busy_retry(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected LibsqlCancelToken? sse_decode_opt_box_autoadd_libsql_cancel_token(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_libsql_cancel_token(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected LibsqlDateTimeUnit? sse_decode_opt_box_autoadd_libsql_date_time_unit(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_libsql_date_time_unit(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected LibsqlDecodeMode? sse_decode_opt_box_autoadd_libsql_decode_mode(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_libsql_decode_mode(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected LibsqlError? sse_decode_opt_box_autoadd_libsql_error(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_libsql_error(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected LibsqlOpenFlags? sse_decode_opt_box_autoadd_libsql_open_flags(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_libsql_open_flags(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected LibsqlParams? sse_decode_opt_box_autoadd_libsql_params(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_libsql_params(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected LibsqlSyncConflict? sse_decode_opt_box_autoadd_libsql_sync_conflict(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_libsql_sync_conflict(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected LibsqlSyncConflictPolicy? sse_decode_opt_box_autoadd_libsql_sync_conflict_policy(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_libsql_sync_conflict_policy(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected LibsqlTransactionBehavior? sse_decode_opt_box_autoadd_libsql_transaction_behavior(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_libsql_transaction_behavior(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected SyncResult? sse_decode_opt_box_autoadd_sync_result(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_sync_result(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected int? sse_decode_opt_box_autoadd_u_32(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_u_32(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected BigInt? sse_decode_opt_box_autoadd_u_64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_u_64(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected List<LibsqlValue>? sse_decode_opt_list_libsql_value(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_list_libsql_value(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected QueryResult sse_decode_query_result(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_rows = sse_decode_list_Map_String_libsql_return_value_None(deserializer);
+var var_columns = sse_decode_list_String(deserializer);
+var var_declTypes = sse_decode_list_opt_String(deserializer);
+var var_rowsAffected = sse_decode_u_64(deserializer);
+var var_lastInsertRowid = sse_decode_i_64(deserializer);
+var var_totalChanges = sse_decode_u_64(deserializer);
+return QueryResult(rows: var_rows, columns: var_columns, declTypes: var_declTypes, rowsAffected: var_rowsAffected, lastInsertRowid: var_lastInsertRowid, totalChanges: var_totalChanges); }
+
+@protected (String,LibsqlReturnValue) sse_decode_record_string_libsql_return_value(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_field0 = sse_decode_String(deserializer);
+var var_field1 = sse_decode_libsql_return_value(deserializer);
+return (var_field0, var_field1); }
+
+@protected (String,LibsqlValue) sse_decode_record_string_libsql_value(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_field0 = sse_decode_String(deserializer);
+var var_field1 = sse_decode_libsql_value(deserializer);
+return (var_field0, var_field1); }
+
+@protected SyncResult sse_decode_sync_result(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_framesSynced = sse_decode_u_64(deserializer);
+var var_frameNo = sse_decode_opt_box_autoadd_u_64(deserializer);
+var var_replicationIndex = sse_decode_opt_box_autoadd_u_64(deserializer);
+var var_durationMs = sse_decode_u_64(deserializer);
+return SyncResult(framesSynced: var_framesSynced, frameNo: var_frameNo, replicationIndex: var_replicationIndex, durationMs: var_durationMs); }
+
+@protected int sse_decode_u_32(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getUint32(); }
+
+@protected BigInt sse_decode_u_64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getBigUint64(); }
+
+@protected int sse_decode_u_8(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getUint8(); }
+
+@protected void sse_decode_unit(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+ }
+
+@protected BigInt sse_decode_usize(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getBigUint64(); }
+
+@protected void sse_encode_AnyhowException(AnyhowException self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.message, serializer); }
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlConnection(LibsqlConnection self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as LibsqlConnectionImpl).frbInternalSseEncode(move: true), serializer); }
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlPool(LibsqlPool self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as LibsqlPoolImpl).frbInternalSseEncode(move: true), serializer); }
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlRows(LibsqlRows self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as LibsqlRowsImpl).frbInternalSseEncode(move: true), serializer); }
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlSavepoint(LibsqlSavepoint self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as LibsqlSavepointImpl).frbInternalSseEncode(move: true), serializer); }
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlStatement(LibsqlStatement self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as LibsqlStatementImpl).frbInternalSseEncode(move: true), serializer); }
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlTransaction(LibsqlTransaction self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as LibsqlTransactionImpl).frbInternalSseEncode(move: true), serializer); }
+
+@protected void sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlTransaction(LibsqlTransaction self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as LibsqlTransactionImpl).frbInternalSseEncode(move: false), serializer); }
+
+@protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlConnection(LibsqlConnection self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as LibsqlConnectionImpl).frbInternalSseEncode(move: false), serializer); }
+
+@protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlPool(LibsqlPool self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as LibsqlPoolImpl).frbInternalSseEncode(move: false), serializer); }
+
+@protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlRows(LibsqlRows self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as LibsqlRowsImpl).frbInternalSseEncode(move: false), serializer); }
+
+@protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlSavepoint(LibsqlSavepoint self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as LibsqlSavepointImpl).frbInternalSseEncode(move: false), serializer); }
+
+@protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlStatement(LibsqlStatement self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as LibsqlStatementImpl).frbInternalSseEncode(move: false), serializer); }
+
+@protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlTransaction(LibsqlTransaction self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as LibsqlTransactionImpl).frbInternalSseEncode(move: false), serializer); }
+
+@protected void sse_encode_Chrono_Utc(DateTime self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_64(PlatformInt64Util.from(self.microsecondsSinceEpoch), serializer); }
+
+@protected void sse_encode_DartFn_Inputs_DartOpaque_Output_libsql_value_AnyhowException(FutureOr<LibsqlValue> Function(Object) self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_DartOpaque(encode_DartFn_Inputs_DartOpaque_Output_libsql_value_AnyhowException(self), serializer); }
+
+@protected void sse_encode_DartFn_Inputs_DartOpaque_list_libsql_return_value_Output_DartOpaque_AnyhowException(FutureOr<Object> Function(Object, List<LibsqlReturnValue>) self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_DartOpaque(encode_DartFn_Inputs_DartOpaque_list_libsql_return_value_Output_DartOpaque_AnyhowException(self), serializer); }
+
+@protected void sse_encode_DartFn_Inputs_String_String_Output_i_32_AnyhowException(FutureOr<int> Function(String, String) self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_DartOpaque(encode_DartFn_Inputs_String_String_Output_i_32_AnyhowException(self), serializer); }
+
+@protected void sse_encode_DartFn_Inputs__Output_DartOpaque_AnyhowException(FutureOr<Object> Function() self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_DartOpaque(encode_DartFn_Inputs__Output_DartOpaque_AnyhowException(self), serializer); }
+
+@protected void sse_encode_DartFn_Inputs__Output_unit_AnyhowException(FutureOr<void> Function() self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_DartOpaque(encode_DartFn_Inputs__Output_unit_AnyhowException(self), serializer); }
+
+@protected void sse_encode_DartFn_Inputs_list_libsql_return_value_Output_libsql_value_AnyhowException(FutureOr<LibsqlValue> Function(List<LibsqlReturnValue>) self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_DartOpaque(encode_DartFn_Inputs_list_libsql_return_value_Output_libsql_value_AnyhowException(self), serializer); }
+
+@protected void sse_encode_DartOpaque(Object self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_isize(PlatformPointerUtil.ptrToPlatformInt64(encodeDartOpaque(self, portManager.dartHandlerPort, generalizedFrbRustBinding)), serializer); }
+
+@protected void sse_encode_Map_String_libsql_return_value_None(Map<String, LibsqlReturnValue> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_list_record_string_libsql_return_value(self.entries.map((e) => (e.key, e.value)).toList(), serializer); }
+
+@protected void sse_encode_Map_String_libsql_value_None(Map<String, LibsqlValue> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_list_record_string_libsql_value(self.entries.map((e) => (e.key, e.value)).toList(), serializer); }
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlConnection(LibsqlConnection self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as LibsqlConnectionImpl).frbInternalSseEncode(move: null), serializer); }
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlPool(LibsqlPool self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as LibsqlPoolImpl).frbInternalSseEncode(move: null), serializer); }
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlRows(LibsqlRows self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as LibsqlRowsImpl).frbInternalSseEncode(move: null), serializer); }
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlSavepoint(LibsqlSavepoint self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as LibsqlSavepointImpl).frbInternalSseEncode(move: null), serializer); }
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlStatement(LibsqlStatement self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as LibsqlStatementImpl).frbInternalSseEncode(move: null), serializer); }
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlTransaction(LibsqlTransaction self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as LibsqlTransactionImpl).frbInternalSseEncode(move: null), serializer); }
+
+@protected void sse_encode_StreamSink_libsql_sync_event_Sse(RustStreamSink<LibsqlSyncEvent> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.setupAndSerialize(codec: SseCodec(
+            decodeSuccessData: sse_decode_libsql_sync_event,
+            decodeErrorData: sse_decode_AnyhowException,
+        )), serializer); }
+
+@protected void sse_encode_StreamSink_list_libsql_update_Sse(RustStreamSink<List<LibsqlUpdate>> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.setupAndSerialize(codec: SseCodec(
+            decodeSuccessData: sse_decode_list_libsql_update,
+            decodeErrorData: sse_decode_AnyhowException,
+        )), serializer); }
+
+@protected void sse_encode_StreamSink_query_result_Sse(RustStreamSink<QueryResult> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.setupAndSerialize(codec: SseCodec(
+            decodeSuccessData: sse_decode_query_result,
+            decodeErrorData: sse_decode_AnyhowException,
+        )), serializer); }
+
+@protected void sse_encode_String(String self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_list_prim_u_8_strict(utf8.encoder.convert(self), serializer); }
+
+@protected void sse_encode_batch_result(BatchResult self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_list_query_result(self.results, serializer);
+ }
+
+@protected void sse_encode_bool(bool self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putUint8(self ? 1 : 0); }
+
+@protected void sse_encode_box_autoadd_Chrono_Utc(DateTime self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_Chrono_Utc(self, serializer); }
+
+@protected void sse_encode_box_autoadd_bool(bool self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_bool(self, serializer); }
+
+@protected void sse_encode_box_autoadd_connect_args(ConnectArgs self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_connect_args(self, serializer); }
+
+@protected void sse_encode_box_autoadd_i_32(int self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self, serializer); }
+
+@protected void sse_encode_box_autoadd_libsql_busy_retry(LibsqlBusyRetry self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_libsql_busy_retry(self, serializer); }
+
+@protected void sse_encode_box_autoadd_libsql_cancel_token(LibsqlCancelToken self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_libsql_cancel_token(self, serializer); }
+
+@protected void sse_encode_box_autoadd_libsql_date_time_unit(LibsqlDateTimeUnit self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_libsql_date_time_unit(self, serializer); }
+
+@protected void sse_encode_box_autoadd_libsql_decode_mode(LibsqlDecodeMode self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_libsql_decode_mode(self, serializer); }
+
+@protected void sse_encode_box_autoadd_libsql_error(LibsqlError self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_libsql_error(self, serializer); }
+
+@protected void sse_encode_box_autoadd_libsql_open_flags(LibsqlOpenFlags self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_libsql_open_flags(self, serializer); }
+
+@protected void sse_encode_box_autoadd_libsql_params(LibsqlParams self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_libsql_params(self, serializer); }
+
+@protected void sse_encode_box_autoadd_libsql_sync_conflict(LibsqlSyncConflict self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_libsql_sync_conflict(self, serializer); }
+
+@protected void sse_encode_box_autoadd_libsql_sync_conflict_policy(LibsqlSyncConflictPolicy self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_libsql_sync_conflict_policy(self, serializer); }
+
+@protected void sse_encode_box_autoadd_libsql_transaction_behavior(LibsqlTransactionBehavior self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_libsql_transaction_behavior(self, serializer); }
+
+@protected void sse_encode_box_autoadd_sync_result(SyncResult self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_sync_result(self, serializer); }
+
+@protected void sse_encode_box_autoadd_u_32(int self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_32(self, serializer); }
+
+@protected void sse_encode_box_autoadd_u_64(BigInt self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_64(self, serializer); }
+
+@protected void sse_encode_columnar_query_result(ColumnarQueryResult self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_list_String(self.columns, serializer);
+sse_encode_list_opt_String(self.declTypes, serializer);
+sse_encode_list_libsql_return_value(self.values, serializer);
+sse_encode_u_64(self.rowCount, serializer);
+sse_encode_u_64(self.rowsAffected, serializer);
+sse_encode_i_64(self.lastInsertRowid, serializer);
+sse_encode_u_64(self.totalChanges, serializer);
+ }
+
+@protected void sse_encode_connect_args(ConnectArgs self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.url, serializer);
+sse_encode_opt_String(self.authToken, serializer);
+sse_encode_opt_String(self.syncUrl, serializer);
+sse_encode_opt_box_autoadd_u_64(self.syncIntervalSeconds, serializer);
+sse_encode_opt_String(self.encryptionKey, serializer);
+sse_encode_opt_box_autoadd_bool(self.readYourWrites, serializer);
+sse_encode_opt_box_autoadd_libsql_open_flags(self.openFlags, serializer);
+sse_encode_opt_box_autoadd_bool(self.offline, serializer);
+sse_encode_opt_box_autoadd_libsql_decode_mode(self.decodeMode, serializer);
+sse_encode_opt_box_autoadd_libsql_date_time_unit(self.datetimeUnit, serializer);
+sse_encode_opt_box_autoadd_u_64(self.busyTimeoutMs, serializer);
+sse_encode_opt_box_autoadd_libsql_busy_retry(self.busyRetry, serializer);
+sse_encode_opt_box_autoadd_u_64(self.lockTimeoutMs, serializer);
+ }
+
+@protected void sse_encode_execute_result(ExecuteResult self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_64(self.rowsAffected, serializer);
+sse_encode_i_64(self.lastInsertRowid, serializer);
+sse_encode_u_64(self.totalChanges, serializer);
+ }
+
+@protected void sse_encode_f_64(double self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putFloat64(self); }
+
+@protected void sse_encode_i_32(int self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putInt32(self); }
+
+@protected void sse_encode_i_64(PlatformInt64 self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putPlatformInt64(self); }
+
+@protected void sse_encode_isize(PlatformInt64 self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putPlatformInt64(self); }
+
+@protected void sse_encode_libsql_batch_statement(LibsqlBatchStatement self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.sql, serializer);
+sse_encode_opt_box_autoadd_libsql_params(self.parameters, serializer);
+ }
+
+@protected void sse_encode_libsql_busy_retry(LibsqlBusyRetry self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_32(self.maxAttempts, serializer);
+sse_encode_u_64(self.initialDelayMs, serializer);
+sse_encode_u_64(self.maxDelayMs, serializer);
+ }
+
+@protected void sse_encode_libsql_cancel_token(LibsqlCancelToken self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_64(self.id, serializer);
+ }
+
+@protected void sse_encode_libsql_collation(LibsqlCollation self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_libsql_column(LibsqlColumn self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.name, serializer);
+sse_encode_opt_String(self.originName, serializer);
+sse_encode_opt_String(self.tableName, serializer);
+sse_encode_opt_String(self.databaseName, serializer);
+sse_encode_opt_String(self.declType, serializer);
+ }
+
+@protected void sse_encode_libsql_date_time_format(LibsqlDateTimeFormat self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_libsql_date_time_unit(LibsqlDateTimeUnit self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_libsql_decode_mode(LibsqlDecodeMode self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_libsql_error(LibsqlError self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_libsql_error_kind(self.kind, serializer);
+sse_encode_opt_box_autoadd_i_32(self.code, serializer);
+sse_encode_String(self.message, serializer);
+sse_encode_opt_box_autoadd_i_32(self.offset, serializer);
+sse_encode_opt_box_autoadd_libsql_sync_conflict(self.conflict, serializer);
+ }
+
+@protected void sse_encode_libsql_error_kind(LibsqlErrorKind self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_libsql_open_flags(LibsqlOpenFlags self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_libsql_params(LibsqlParams self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_opt_list_libsql_value(self.positional, serializer);
+sse_encode_opt_Map_String_libsql_value_None(self.named, serializer);
+ }
+
+@protected void sse_encode_libsql_return_value(LibsqlReturnValue self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+switch (self) { case LibsqlReturnValue_Integer(field0: final field0): sse_encode_i_32(0, serializer); sse_encode_i_64(field0, serializer);
+case LibsqlReturnValue_Real(field0: final field0): sse_encode_i_32(1, serializer); sse_encode_f_64(field0, serializer);
+case LibsqlReturnValue_Text(field0: final field0): sse_encode_i_32(2, serializer); sse_encode_String(field0, serializer);
+case LibsqlReturnValue_Blob(field0: final field0): sse_encode_i_32(3, serializer); sse_encode_list_prim_u_8_strict(field0, serializer);
+case LibsqlReturnValue_Null(): sse_encode_i_32(4, serializer); case LibsqlReturnValue_Bool(field0: final field0): sse_encode_i_32(5, serializer); sse_encode_bool(field0, serializer);
+case LibsqlReturnValue_DateTime(field0: final field0): sse_encode_i_32(6, serializer); sse_encode_Chrono_Utc(field0, serializer);
+case LibsqlReturnValue_Json(field0: final field0): sse_encode_i_32(7, serializer); sse_encode_String(field0, serializer);
+  } }
+
+@protected void sse_encode_libsql_sync_conflict(LibsqlSyncConflict self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_64(self.firstRejectedFrame, serializer);
+sse_encode_u_64(self.lastLocalFrame, serializer);
+sse_encode_u_64(self.remoteFrameNo, serializer);
+ }
+
+@protected void sse_encode_libsql_sync_conflict_policy(LibsqlSyncConflictPolicy self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_libsql_sync_event(LibsqlSyncEvent self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+switch (self) { case LibsqlSyncEvent_Synced(trigger: final trigger,result: final result): sse_encode_i_32(0, serializer); sse_encode_libsql_sync_trigger(trigger, serializer);
+sse_encode_box_autoadd_sync_result(result, serializer);
+case LibsqlSyncEvent_Failed(trigger: final trigger,error: final error): sse_encode_i_32(1, serializer); sse_encode_libsql_sync_trigger(trigger, serializer);
+sse_encode_box_autoadd_libsql_error(error, serializer);
+  } }
+
+@protected void sse_encode_libsql_sync_status(LibsqlSyncStatus self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_bool(self.syncing, serializer);
+sse_encode_opt_box_autoadd_Chrono_Utc(self.lastSyncedAt, serializer);
+sse_encode_opt_box_autoadd_sync_result(self.lastResult, serializer);
+sse_encode_opt_box_autoadd_libsql_error(self.lastError, serializer);
+ }
+
+@protected void sse_encode_libsql_sync_trigger(LibsqlSyncTrigger self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_libsql_transaction_behavior(LibsqlTransactionBehavior self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_libsql_update(LibsqlUpdate self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_libsql_update_operation(self.operation, serializer);
+sse_encode_String(self.database, serializer);
+sse_encode_String(self.table, serializer);
+sse_encode_i_64(self.rowid, serializer);
+ }
+
+@protected void sse_encode_libsql_update_operation(LibsqlUpdateOperation self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_libsql_value(LibsqlValue self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+switch (self) { case LibsqlValue_Integer(field0: final field0): sse_encode_i_32(0, serializer); sse_encode_i_64(field0, serializer);
+case LibsqlValue_Real(field0: final field0): sse_encode_i_32(1, serializer); sse_encode_f_64(field0, serializer);
+case LibsqlValue_Text(field0: final field0): sse_encode_i_32(2, serializer); sse_encode_String(field0, serializer);
+case LibsqlValue_Blob(field0: final field0): sse_encode_i_32(3, serializer); sse_encode_list_prim_u_8_strict(field0, serializer);
+case LibsqlValue_Null(): sse_encode_i_32(4, serializer); case LibsqlValue_Bool(field0: final field0): sse_encode_i_32(5, serializer); sse_encode_bool(field0, serializer);
+case LibsqlValue_DateTime(value: final value,format: final format): sse_encode_i_32(6, serializer); sse_encode_Chrono_Utc(value, serializer);
+sse_encode_libsql_date_time_format(format, serializer);
+case LibsqlValue_BigInt(field0: final field0): sse_encode_i_32(7, serializer); sse_encode_String(field0, serializer);
+case LibsqlValue_Json(field0: final field0): sse_encode_i_32(8, serializer); sse_encode_String(field0, serializer);
+  } }
+
+@protected void sse_encode_list_Map_String_libsql_return_value_None(List<Map<String, LibsqlReturnValue>> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_Map_String_libsql_return_value_None(item, serializer); } }
+
+@protected void sse_encode_list_String(List<String> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_String(item, serializer); } }
+
+@protected void sse_encode_list_libsql_batch_statement(List<LibsqlBatchStatement> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_libsql_batch_statement(item, serializer); } }
+
+@protected void sse_encode_list_libsql_column(List<LibsqlColumn> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_libsql_column(item, serializer); } }
+
+@protected void sse_encode_list_libsql_return_value(List<LibsqlReturnValue> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_libsql_return_value(item, serializer); } }
+
+@protected void sse_encode_list_libsql_update(List<LibsqlUpdate> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_libsql_update(item, serializer); } }
+
+@protected void sse_encode_list_libsql_value(List<LibsqlValue> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_libsql_value(item, serializer); } }
+
+@protected void sse_encode_list_opt_String(List<String?> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_opt_String(item, serializer); } }
+
+@protected void sse_encode_list_prim_u_8_strict(Uint8List self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+                    serializer.buffer.putUint8List(self); }
+
+@protected void sse_encode_list_query_result(List<QueryResult> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_query_result(item, serializer); } }
+
+@protected void sse_encode_list_record_string_libsql_return_value(List<(String,LibsqlReturnValue)> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_record_string_libsql_return_value(item, serializer); } }
+
+@protected void sse_encode_list_record_string_libsql_value(List<(String,LibsqlValue)> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_record_string_libsql_value(item, serializer); } }
+
+@protected void sse_encode_opt_Map_String_libsql_return_value_None(Map<String, LibsqlReturnValue>? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_Map_String_libsql_return_value_None(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_Map_String_libsql_value_None(Map<String, LibsqlValue>? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_Map_String_libsql_value_None(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_String(String? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_String(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_Chrono_Utc(DateTime? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_Chrono_Utc(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_bool(bool? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_bool(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_i_32(int? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_i_32(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_libsql_busy_retry(LibsqlBusyRetry? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_libsql_busy_retry(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_libsql_cancel_token(LibsqlCancelToken? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_libsql_cancel_token(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_libsql_date_time_unit(LibsqlDateTimeUnit? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_libsql_date_time_unit(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_libsql_decode_mode(LibsqlDecodeMode? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_libsql_decode_mode(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_libsql_error(LibsqlError? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_libsql_error(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_libsql_open_flags(LibsqlOpenFlags? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_libsql_open_flags(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_libsql_params(LibsqlParams? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_libsql_params(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_libsql_sync_conflict(LibsqlSyncConflict? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_libsql_sync_conflict(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_libsql_sync_conflict_policy(LibsqlSyncConflictPolicy? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_libsql_sync_conflict_policy(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_libsql_transaction_behavior(LibsqlTransactionBehavior? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_libsql_transaction_behavior(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_sync_result(SyncResult? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_sync_result(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_u_32(int? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_u_32(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_u_64(BigInt? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_u_64(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_list_libsql_value(List<LibsqlValue>? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_list_libsql_value(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_query_result(QueryResult self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_list_Map_String_libsql_return_value_None(self.rows, serializer);
+sse_encode_list_String(self.columns, serializer);
+sse_encode_list_opt_String(self.declTypes, serializer);
+sse_encode_u_64(self.rowsAffected, serializer);
+sse_encode_i_64(self.lastInsertRowid, serializer);
+sse_encode_u_64(self.totalChanges, serializer);
+ }
+
+@protected void sse_encode_record_string_libsql_return_value((String,LibsqlReturnValue) self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.$1, serializer);
+sse_encode_libsql_return_value(self.$2, serializer);
+ }
+
+@protected void sse_encode_record_string_libsql_value((String,LibsqlValue) self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.$1, serializer);
+sse_encode_libsql_value(self.$2, serializer);
+ }
+
+@protected void sse_encode_sync_result(SyncResult self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_64(self.framesSynced, serializer);
+sse_encode_opt_box_autoadd_u_64(self.frameNo, serializer);
+sse_encode_opt_box_autoadd_u_64(self.replicationIndex, serializer);
+sse_encode_u_64(self.durationMs, serializer);
+ }
+
+@protected void sse_encode_u_32(int self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putUint32(self); }
+
+@protected void sse_encode_u_64(BigInt self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putBigUint64(self); }
+
+@protected void sse_encode_u_8(int self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putUint8(self); }
+
+@protected void sse_encode_unit(void self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+ }
+
+@protected void sse_encode_usize(BigInt self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putBigUint64(self); }
+                }
+
+            @sealed class LibsqlConnectionImpl extends RustOpaque implements LibsqlConnection {
+                // Not to be used by end users
+                LibsqlConnectionImpl.frbInternalDcoDecode(List<dynamic> wire):
+                    super.frbInternalDcoDecode(wire, _kStaticData);
+
+                // Not to be used by end users
+                LibsqlConnectionImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative):
+                    super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
+
+                static final _kStaticData = RustArcStaticData(
+                    rustArcIncrementStrongCount: RustLib.instance.api.rust_arc_increment_strong_count_LibsqlConnection,
+                    rustArcDecrementStrongCount: RustLib.instance.api.rust_arc_decrement_strong_count_LibsqlConnection,
+                    rustArcDecrementStrongCountPtr: RustLib.instance.api.rust_arc_decrement_strong_count_LibsqlConnectionPtr,
+                );
+
+                 Future<void>  batch({required String sql , BigInt? timeoutMs , LibsqlCancelToken? cancel })=>RustLib.instance.api.crateApiConnectionLibsqlConnectionBatch(that: this, sql: sql, timeoutMs: timeoutMs, cancel: cancel);
+
+/// Runs `statements` in order inside a single transaction and returns a
+/// result for each of them. If any statement fails the transaction is
+/// rolled back and none of the changes are kept.
+///
+/// Remote connections send the whole batch in a single request, with the
+/// parameters written into the SQL, so each statement must be a single
+/// one. Columns are not decoded by their declared type there, and the
+/// results have empty `decl_types`.
+ Future<BatchResult>  batchStatements({required List<LibsqlBatchStatement> statements , LibsqlTransactionBehavior? behavior , BigInt? timeoutMs , LibsqlCancelToken? cancel })=>RustLib.instance.api.crateApiConnectionLibsqlConnectionBatchStatements(that: this, statements: statements, behavior: behavior, timeoutMs: timeoutMs, cancel: cancel);
+
+/// Rolls back open transactions, finalizes prepared statements and
+/// releases the connection and database. Any later call on this
+/// connection, or on statements prepared from it, fails with a
+/// `Closed` error. Closing twice is a no-op.
+ Future<void>  close()=>RustLib.instance.api.crateApiConnectionLibsqlConnectionClose(that: this, );
+
+/// Registers an aggregate SQL function. `init` creates the accumulator of
+/// each group, any Dart object, `step` folds a row into it and returns
+/// the new accumulator, and `finalize` turns it into the result.
+///
+/// Only supported on local databases and embedded replicas. Every row
+/// waits for a round trip to Dart, and the callbacks must not use this
+/// connection, as with `create_scalar_function`.
+ Future<void>  createAggregateFunction({required String name , required int nArgs , required bool deterministic , required FutureOr<Object> Function() init , required FutureOr<Object> Function(Object, List<LibsqlReturnValue>) step , required FutureOr<LibsqlValue> Function(Object) finalize })=>RustLib.instance.api.crateApiConnectionLibsqlConnectionCreateAggregateFunction(that: this, name: name, nArgs: nArgs, deterministic: deterministic, init: init, step: step, finalize: finalize);
+
+/// Registers one of the collations implemented in Rust under `name`.
+ Future<void>  createBuiltinCollation({required String name , required LibsqlCollation collation })=>RustLib.instance.api.crateApiConnectionLibsqlConnectionCreateBuiltinCollation(that: this, name: name, collation: collation);
+
+/// Registers `compare` as the collation `name`, for use in `ORDER BY`,
+/// `COLLATE` clauses and indexes. It returns a negative number, zero or a
+/// positive number when the first string sorts before, equal to or after
+/// the second one, and must be consistent across calls.
+///
+/// Only supported on local databases and embedded replicas. Every
+/// comparison blocks a Rust worker thread on a round trip to the Dart
+/// isolate, so sorting n rows costs about n log n of them, and `compare`
+/// must not use this connection, as with `create_scalar_function`.
+/// Prefer `create_builtin_collation` when one fits.
+ Future<void>  createCollation({required String name , required FutureOr<int> Function(String, String) compare })=>RustLib.instance.api.crateApiConnectionLibsqlConnectionCreateCollation(that: this, name: name, compare: compare);
+
+/// Registers `callback` as the SQL function `name` taking `n_args`
+/// arguments, or any number of them when `n_args` is -1. Deterministic
+/// functions can be used in indexes and generated columns.
+///
+/// Only supported on local databases and embedded replicas. Each call
+/// blocks a Rust worker thread until the Dart callback completes, with
+/// the connection locked by the calling statement, so it should return
+/// quickly. The callback must not use this connection, or its
+/// statements, cursors and transactions: that call waits for the
+/// statement, which waits for the callback, and both hang unless
+/// `lock_timeout_ms` is set.
+ Future<void>  createScalarFunction({required String name , required int nArgs , required bool deterministic , required FutureOr<LibsqlValue> Function(List<LibsqlReturnValue>) callback })=>RustLib.instance.api.crateApiConnectionLibsqlConnectionCreateScalarFunction(that: this, name: name, nArgs: nArgs, deterministic: deterministic, callback: callback);
+
+/// Registers an aggregate that can also be used as a window function
+/// with `OVER (...)`. On top of the aggregate callbacks, `inverse` removes
+/// a row leaving the window and `value` computes the result for the
+/// current window without consuming the accumulator.
+///
+/// Only supported on local databases and embedded replicas. The same
+/// restrictions as for `create_aggregate_function` apply.
+ Future<void>  createWindowFunction({required String name , required int nArgs , required bool deterministic , required FutureOr<Object> Function() init , required FutureOr<Object> Function(Object, List<LibsqlReturnValue>) step , required FutureOr<Object> Function(Object, List<LibsqlReturnValue>) inverse , required FutureOr<LibsqlValue> Function(Object) value , required FutureOr<LibsqlValue> Function(Object) finalize })=>RustLib.instance.api.crateApiConnectionLibsqlConnectionCreateWindowFunction(that: this, name: name, nArgs: nArgs, deterministic: deterministic, init: init, step: step, inverse: inverse, value: value, finalize: finalize);
+
+ Future<void>  disableExtension()=>RustLib.instance.api.crateApiConnectionLibsqlConnectionDisableExtension(that: this, );
+
+/// Throws away the local copy of a database opened with `offline`,
+/// including changes that were never pushed, and opens it again from
+/// the remote.
+///
+/// Like `close`, this rolls back open transactions and finalizes
+/// prepared statements. Functions and collations created on this
+/// connection and its busy timeout have to be set up again, `updates`
+/// and `watch` streams keep running. When the fresh copy can't be
+/// opened, the local one is kept and opened again, and if even that
+/// fails the connection is left closed.
+ Future<void>  discardLocalChanges()=>RustLib.instance.api.crateApiConnectionLibsqlConnectionDiscardLocalChanges(that: this, );
+
+ Future<void>  enableExtension()=>RustLib.instance.api.crateApiConnectionLibsqlConnectionEnableExtension(that: this, );
+
+ Future<ExecuteResult>  execute({required String sql , LibsqlParams? parameters , BigInt? timeoutMs , LibsqlCancelToken? cancel })=>RustLib.instance.api.crateApiConnectionLibsqlConnectionExecute(that: this, sql: sql, parameters: parameters, timeoutMs: timeoutMs, cancel: cancel);
+
+/// Stops the queries and statements running on this connection, which
+/// fail with an `Interrupted` error. Local statements are interrupted by
+/// SQLite, remote requests are aborted.
+///
+/// Dart futures can't be cancelled, so abandoning one leaves its query
+/// running. Use this, a `timeout_ms` or a `LibsqlCancelToken` to stop it.
+ Future<void>  interrupt()=>RustLib.instance.api.crateApiConnectionLibsqlConnectionInterrupt(that: this, );
+
+ Future<void>  loadExtension({required String path , String? entryPoint })=>RustLib.instance.api.crateApiConnectionLibsqlConnectionLoadExtension(that: this, path: path, entryPoint: entryPoint);
+
+/// Highest replication index returned by writes made through this
+/// connection's database, `None` before the first write. Only embedded
+/// replicas, whose writes are sent to the primary, report one.
+ Future<BigInt?>  maxWriteReplicationIndex()=>RustLib.instance.api.crateApiConnectionLibsqlConnectionMaxWriteReplicationIndex(that: this, );
+
+ Future<LibsqlStatement>  prepare({required String sql })=>RustLib.instance.api.crateApiConnectionLibsqlConnectionPrepare(that: this, sql: sql);
+
+/// Fetches the changes made on the remote of a database opened with
+/// `offline`. This runs the same sync as `push`, for when there is
+/// nothing local to send: it fails with a `Misuse` error while there are
+/// local changes that have not been pushed yet, which libsql would push
+/// instead, as it can only apply the remote's changes to a copy without
+/// changes of its own.
+///
+/// Local changes can only be told apart once this connection has pushed
+/// or pulled, before that libsql pushes any it finds first.
+ Future<SyncResult>  pull()=>RustLib.instance.api.crateApiConnectionLibsqlConnectionPull(that: this, );
+
+/// Sends the changes committed locally to a database opened with
+/// `offline` to its remote. Returns right away when nothing was
+/// committed since the last push or pull, and fails with a `Network`
+/// error, keeping the changes for the next push, when the remote can't
+/// be reached.
+///
+/// When the remote has moved on since the changes were written it
+/// rejects them, and `conflict_policy` decides what happens: by default
+/// this fails with a `SyncConflict` error describing the rejected
+/// frames, while `RemoteWins` discards the local database, as with
+/// `discard_local_changes`, and returns the result of pulling it afresh.
+/// `RetryAfterPull` does the same, then runs the callback set with
+/// `set_conflict_replay` to write the changes again and pushes those,
+/// up to three times before failing with the last `SyncConflict` error.
+/// libsql does not say why it failed a push, so a pull into a scratch
+/// copy next to the database finds out whether the remote moved on.
+///
+/// libsql has a single sync for these databases, which pushes when there
+/// are local frames the remote has not seen and pulls otherwise. `push`
+/// and `pull` both run it, so before this connection has pushed or
+/// pulled once, `push` may pull instead when there is nothing to send.
+ Future<SyncResult>  push({LibsqlSyncConflictPolicy? conflictPolicy })=>RustLib.instance.api.crateApiConnectionLibsqlConnectionPush(that: this, conflictPolicy: conflictPolicy);
+
+ Future<QueryResult>  query({required String sql , LibsqlParams? parameters , BigInt? timeoutMs , LibsqlCancelToken? cancel })=>RustLib.instance.api.crateApiConnectionLibsqlConnectionQuery(that: this, sql: sql, parameters: parameters, timeoutMs: timeoutMs, cancel: cancel);
+
+ Future<ColumnarQueryResult>  queryColumnar({required String sql , LibsqlParams? parameters , BigInt? timeoutMs , LibsqlCancelToken? cancel })=>RustLib.instance.api.crateApiConnectionLibsqlConnectionQueryColumnar(that: this, sql: sql, parameters: parameters, timeoutMs: timeoutMs, cancel: cancel);
+
+ Future<LibsqlRows>  queryStream({required String sql , LibsqlParams? parameters , BigInt? timeoutMs , LibsqlCancelToken? cancel })=>RustLib.instance.api.crateApiConnectionLibsqlConnectionQueryStream(that: this, sql: sql, parameters: parameters, timeoutMs: timeoutMs, cancel: cancel);
+
+/// Sets how long local statements wait for a lock held by another
+/// connection before failing with a `Busy` error. Fails with `Misuse` on
+/// remote connections, whose locks are up to the server.
+ Future<void>  setBusyTimeout({required BigInt timeoutMs })=>RustLib.instance.api.crateApiConnectionLibsqlConnectionSetBusyTimeout(that: this, timeoutMs: timeoutMs);
+
+/// Sets the callback `push` runs under `RetryAfterPull` once the local
+/// database was replaced with the remote's, to write the rejected
+/// changes again. It runs with nothing locked and can use this
+/// connection, unlike the callbacks of SQL functions.
+ Future<void>  setConflictReplay({required FutureOr<void> Function() replay })=>RustLib.instance.api.crateApiConnectionLibsqlConnectionSetConflictReplay(that: this, replay: replay);
+
+/// Pulls the latest changes of an embedded replica or synced database
+/// from its remote.
+ Future<SyncResult>  sync_()=>RustLib.instance.api.crateApiConnectionLibsqlConnectionSync(that: this, );
+
+/// Streams the outcome of every sync from now on, manual or periodic. The
+/// stream ends when the connection is closed.
+ Stream<LibsqlSyncEvent>  syncEvents()=>RustLib.instance.api.crateApiConnectionLibsqlConnectionSyncEvents(that: this, );
+
+/// Outcome of the latest syncs, whether started by `sync` or by
+/// `sync_interval_seconds`.
+ Future<LibsqlSyncStatus>  syncStatus()=>RustLib.instance.api.crateApiConnectionLibsqlConnectionSyncStatus(that: this, );
+
+/// Syncs an embedded replica until it has caught up with
+/// `replication_index`, such as one returned by
+/// `max_write_replication_index` on another device, so reads made
+/// afterwards see that write.
+///
+/// Waits for as long as it takes unless `timeout_ms` is given, after
+/// which it fails with an `Interrupted` error.
+ Future<SyncResult>  syncUntil({required BigInt replicationIndex , BigInt? timeoutMs })=>RustLib.instance.api.crateApiConnectionLibsqlConnectionSyncUntil(that: this, replicationIndex: replicationIndex, timeoutMs: timeoutMs);
+
+ Future<LibsqlTransaction>  transaction({LibsqlTransactionBehavior? behavior })=>RustLib.instance.api.crateApiConnectionLibsqlConnectionTransaction(that: this, behavior: behavior);
+
+/// Streams the rows inserted, updated or deleted through this connection,
+/// one list per committed transaction. Changes undone by a rollback, a
+/// `ROLLBACK TO` or a failed statement are left out, except those of a
+/// failed statement that fired triggers. Changes to `WITHOUT ROWID`
+/// tables are not reported.
+///
+/// Only supported on local databases and embedded replicas. The stream
+/// ends when the connection is closed.
+ Stream<List<LibsqlUpdate>>  updates()=>RustLib.instance.api.crateApiConnectionLibsqlConnectionUpdates(that: this, );
+
+/// Streams the result of the read-only query `sql`, running it again
+/// whenever a table it reads from is changed through this connection,
+/// and after syncs that brought in changes from the remote. Changes less
+/// than `debounce_ms` (50 by default) apart trigger a single run, and
+/// results equal to the previous one are not sent again. Errors are sent
+/// on the stream, which keeps watching.
+///
+/// Queries reading from views run again on any change. Only supported on
+/// local databases and embedded replicas. The stream ends when the
+/// connection is closed.
+ Stream<QueryResult>  watch({required String sql , LibsqlParams? parameters , BigInt? debounceMs })=>RustLib.instance.api.crateApiConnectionLibsqlConnectionWatch(that: this, sql: sql, parameters: parameters, debounceMs: debounceMs);
+
+            }
+            @sealed class LibsqlPoolImpl extends RustOpaque implements LibsqlPool {
+                // Not to be used by end users
+                LibsqlPoolImpl.frbInternalDcoDecode(List<dynamic> wire):
+                    super.frbInternalDcoDecode(wire, _kStaticData);
+
+                // Not to be used by end users
+                LibsqlPoolImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative):
+                    super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
+
+                static final _kStaticData = RustArcStaticData(
+                    rustArcIncrementStrongCount: RustLib.instance.api.rust_arc_increment_strong_count_LibsqlPool,
+                    rustArcDecrementStrongCount: RustLib.instance.api.rust_arc_decrement_strong_count_LibsqlPool,
+                    rustArcDecrementStrongCountPtr: RustLib.instance.api.rust_arc_decrement_strong_count_LibsqlPoolPtr,
+                );
+
+                    /// Like `execute`, for several statements without parameters.
+ Future<void>  batch({required String sql , BigInt? timeoutMs , LibsqlCancelToken? cancel })=>RustLib.instance.api.crateApiPoolLibsqlPoolBatch(that: this, sql: sql, timeoutMs: timeoutMs, cancel: cancel);
+
+ Future<BatchResult>  batchStatements({required List<LibsqlBatchStatement> statements , LibsqlTransactionBehavior? behavior , BigInt? timeoutMs , LibsqlCancelToken? cancel })=>RustLib.instance.api.crateApiPoolLibsqlPoolBatchStatements(that: this, statements: statements, behavior: behavior, timeoutMs: timeoutMs, cancel: cancel);
+
+/// Closes the writer and every reader.
+ Future<void>  close()=>RustLib.instance.api.crateApiPoolLibsqlPoolClose(that: this, );
+
+/// Runs `sql` on the writer once the writes queued before it are done.
+/// `timeout_ms` covers the wait too, which fails with an `Interrupted`
+/// error.
+ Future<ExecuteResult>  execute({required String sql , LibsqlParams? parameters , BigInt? timeoutMs , LibsqlCancelToken? cancel })=>RustLib.instance.api.crateApiPoolLibsqlPoolExecute(that: this, sql: sql, parameters: parameters, timeoutMs: timeoutMs, cancel: cancel);
+
+ Future<QueryResult>  query({required String sql , LibsqlParams? parameters , BigInt? timeoutMs , LibsqlCancelToken? cancel })=>RustLib.instance.api.crateApiPoolLibsqlPoolQuery(that: this, sql: sql, parameters: parameters, timeoutMs: timeoutMs, cancel: cancel);
+
+ Future<ColumnarQueryResult>  queryColumnar({required String sql , LibsqlParams? parameters , BigInt? timeoutMs , LibsqlCancelToken? cancel })=>RustLib.instance.api.crateApiPoolLibsqlPoolQueryColumnar(that: this, sql: sql, parameters: parameters, timeoutMs: timeoutMs, cancel: cancel);
+
+/// Starts a transaction on the writer once the writes queued before it
+/// are done. Other writes wait until it is committed or rolled back.
+///
+/// Always commit or roll back the transaction: one that is merely
+/// dropped in Dart holds up every later write on the pool until Dart's
+/// finalizer releases it. Fails with an `Interrupted` error if the writer
+/// is not free within `timeout_ms`, when given.
+ Future<LibsqlTransaction>  transaction({LibsqlTransactionBehavior? behavior , BigInt? timeoutMs })=>RustLib.instance.api.crateApiPoolLibsqlPoolTransaction(that: this, behavior: behavior, timeoutMs: timeoutMs);
+
+/// Streams the result of the read-only query `sql` like
+/// `LibsqlConnection::watch`, running it again whenever a table it reads
+/// from is changed through this pool. Each run waits for an idle reader
+/// like `query` does, so it only sees committed writes.
+ Stream<QueryResult>  watch({required String sql , LibsqlParams? parameters , BigInt? debounceMs })=>RustLib.instance.api.crateApiPoolLibsqlPoolWatch(that: this, sql: sql, parameters: parameters, debounceMs: debounceMs);
+
+            }
+            @sealed class LibsqlRowsImpl extends RustOpaque implements LibsqlRows {
+                // Not to be used by end users
+                LibsqlRowsImpl.frbInternalDcoDecode(List<dynamic> wire):
+                    super.frbInternalDcoDecode(wire, _kStaticData);
+
+                // Not to be used by end users
+                LibsqlRowsImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative):
+                    super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
+
+                static final _kStaticData = RustArcStaticData(
+                    rustArcIncrementStrongCount: RustLib.instance.api.rust_arc_increment_strong_count_LibsqlRows,
+                    rustArcDecrementStrongCount: RustLib.instance.api.rust_arc_decrement_strong_count_LibsqlRows,
+                    rustArcDecrementStrongCountPtr: RustLib.instance.api.rust_arc_decrement_strong_count_LibsqlRowsPtr,
+                );
+
+                /// Releases the underlying statement. Safe to call more than once.
+ Future<void>  close()=>RustLib.instance.api.crateApiRowsLibsqlRowsClose(that: this, );
+
+ Future<List<String>>  columns()=>RustLib.instance.api.crateApiRowsLibsqlRowsColumns(that: this, );
+
+ Future<List<String?>>  declTypes()=>RustLib.instance.api.crateApiRowsLibsqlRowsDeclTypes(that: this, );
+
+/// Returns the next row, or `None` once the cursor is exhausted or closed.
+ Future<Map<String, LibsqlReturnValue>?>  next()=>RustLib.instance.api.crateApiRowsLibsqlRowsNext(that: this, );
+
+/// Returns up to `size` rows. An empty page means the cursor is exhausted
+/// or closed.
+ Future<List<Map<String, LibsqlReturnValue>>>  nextPage({required int size })=>RustLib.instance.api.crateApiRowsLibsqlRowsNextPage(that: this, size: size);
+
+            }
+            @sealed class LibsqlSavepointImpl extends RustOpaque implements LibsqlSavepoint {
+                // Not to be used by end users
+                LibsqlSavepointImpl.frbInternalDcoDecode(List<dynamic> wire):
+                    super.frbInternalDcoDecode(wire, _kStaticData);
+
+                // Not to be used by end users
+                LibsqlSavepointImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative):
+                    super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
+
+                static final _kStaticData = RustArcStaticData(
+                    rustArcIncrementStrongCount: RustLib.instance.api.rust_arc_increment_strong_count_LibsqlSavepoint,
+                    rustArcDecrementStrongCount: RustLib.instance.api.rust_arc_decrement_strong_count_LibsqlSavepoint,
+                    rustArcDecrementStrongCountPtr: RustLib.instance.api.rust_arc_decrement_strong_count_LibsqlSavepointPtr,
+                );
+
+                 Future<ExecuteResult>  execute({required String sql , LibsqlParams? parameters , BigInt? timeoutMs , LibsqlCancelToken? cancel })=>RustLib.instance.api.crateApiSavepointLibsqlSavepointExecute(that: this, sql: sql, parameters: parameters, timeoutMs: timeoutMs, cancel: cancel);
+
+ Future<String>  name()=>RustLib.instance.api.crateApiSavepointLibsqlSavepointName(that: this, );
+
+ Future<QueryResult>  query({required String sql , LibsqlParams? parameters , BigInt? timeoutMs , LibsqlCancelToken? cancel })=>RustLib.instance.api.crateApiSavepointLibsqlSavepointQuery(that: this, sql: sql, parameters: parameters, timeoutMs: timeoutMs, cancel: cancel);
+
+/// Keeps the changes made since the savepoint as part of the enclosing
+/// transaction or savepoint, ending this savepoint.
+ Future<void>  release()=>RustLib.instance.api.crateApiSavepointLibsqlSavepointRelease(that: this, );
+
+/// Discards the changes made since the savepoint. The savepoint stays
+/// open, so it can be rolled back to again or released, and so does the
+/// enclosing transaction.
+ Future<void>  rollbackTo()=>RustLib.instance.api.crateApiSavepointLibsqlSavepointRollbackTo(that: this, );
+
+/// Starts a savepoint nested in this one, like
+/// `LibsqlTransaction::savepoint`.
+ Future<LibsqlSavepoint>  savepoint({required String name })=>RustLib.instance.api.crateApiSavepointLibsqlSavepointSavepoint(that: this, name: name);
+
+            }
+            @sealed class LibsqlStatementImpl extends RustOpaque implements LibsqlStatement {
+                // Not to be used by end users
+                LibsqlStatementImpl.frbInternalDcoDecode(List<dynamic> wire):
+                    super.frbInternalDcoDecode(wire, _kStaticData);
+
+                // Not to be used by end users
+                LibsqlStatementImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative):
+                    super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
+
+                static final _kStaticData = RustArcStaticData(
+                    rustArcIncrementStrongCount: RustLib.instance.api.rust_arc_increment_strong_count_LibsqlStatement,
+                    rustArcDecrementStrongCount: RustLib.instance.api.rust_arc_decrement_strong_count_LibsqlStatement,
+                    rustArcDecrementStrongCountPtr: RustLib.instance.api.rust_arc_decrement_strong_count_LibsqlStatementPtr,
+                );
+
+                 Future<List<LibsqlColumn>>  columns()=>RustLib.instance.api.crateApiStatementLibsqlStatementColumns(that: this, );
+
+ Future<ExecuteResult>  execute({LibsqlParams? parameters , BigInt? timeoutMs , LibsqlCancelToken? cancel })=>RustLib.instance.api.crateApiStatementLibsqlStatementExecute(that: this, parameters: parameters, timeoutMs: timeoutMs, cancel: cancel);
+
+ Future<void>  finalize()=>RustLib.instance.api.crateApiStatementLibsqlStatementFinalize(that: this, );
+
+/// Whether the statement leaves the database as it is, as SQLite tells.
+/// Only supported on local databases and embedded replicas.
+ Future<bool>  isReadonly()=>RustLib.instance.api.crateApiStatementLibsqlStatementIsReadonly(that: this, );
+
+ Future<int>  parameterCount()=>RustLib.instance.api.crateApiStatementLibsqlStatementParameterCount(that: this, );
+
+/// Name of the parameter at `index`, counting from 1 like SQLite does.
+/// Positional `?` parameters have no name.
+ Future<String?>  parameterName({required int index })=>RustLib.instance.api.crateApiStatementLibsqlStatementParameterName(that: this, index: index);
+
+ Future<QueryResult>  query({LibsqlParams? parameters , BigInt? timeoutMs , LibsqlCancelToken? cancel })=>RustLib.instance.api.crateApiStatementLibsqlStatementQuery(that: this, parameters: parameters, timeoutMs: timeoutMs, cancel: cancel);
+
+ Future<ColumnarQueryResult>  queryColumnar({LibsqlParams? parameters , BigInt? timeoutMs , LibsqlCancelToken? cancel })=>RustLib.instance.api.crateApiStatementLibsqlStatementQueryColumnar(that: this, parameters: parameters, timeoutMs: timeoutMs, cancel: cancel);
+
+/// Starts the query, leaving its rows to be read page by page. The
+/// timeout only covers starting it.
+ Future<LibsqlRows>  queryStream({LibsqlParams? parameters , BigInt? timeoutMs , LibsqlCancelToken? cancel })=>RustLib.instance.api.crateApiStatementLibsqlStatementQueryStream(that: this, parameters: parameters, timeoutMs: timeoutMs, cancel: cancel);
+
+ Future<void>  reset()=>RustLib.instance.api.crateApiStatementLibsqlStatementReset(that: this, );
+
+            }
+            @sealed class LibsqlTransactionImpl extends RustOpaque implements LibsqlTransaction {
+                // Not to be used by end users
+                LibsqlTransactionImpl.frbInternalDcoDecode(List<dynamic> wire):
+                    super.frbInternalDcoDecode(wire, _kStaticData);
+
+                // Not to be used by end users
+                LibsqlTransactionImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative):
+                    super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
+
+                static final _kStaticData = RustArcStaticData(
+                    rustArcIncrementStrongCount: RustLib.instance.api.rust_arc_increment_strong_count_LibsqlTransaction,
+                    rustArcDecrementStrongCount: RustLib.instance.api.rust_arc_decrement_strong_count_LibsqlTransaction,
+                    rustArcDecrementStrongCountPtr: RustLib.instance.api.rust_arc_decrement_strong_count_LibsqlTransactionPtr,
+                );
+
+/// Fails with a `Misuse` error, leaving the transaction active, while
+/// one of its savepoints is open.
+                 Future<void>  commit()=>RustLib.instance.api.crateApiTransactionLibsqlTransactionCommit(that: this, );
+
+ Future<ExecuteResult>  execute({required String sql , LibsqlParams? parameters , BigInt? timeoutMs , LibsqlCancelToken? cancel })=>RustLib.instance.api.crateApiTransactionLibsqlTransactionExecute(that: this, sql: sql, parameters: parameters, timeoutMs: timeoutMs, cancel: cancel);
+
+ Future<bool>  isActive()=>RustLib.instance.api.crateApiTransactionLibsqlTransactionIsActive(that: this, );
+
+/// Whether the underlying connection is outside of any transaction, which
+/// is only the case once this transaction has been committed or rolled
+/// back and no other one has been started.
+ Future<bool>  isAutocommit()=>RustLib.instance.api.crateApiTransactionLibsqlTransactionIsAutocommit(that: this, );
+
+/// Prepares a statement that runs inside this transaction. It can be used
+/// until the transaction is committed or rolled back.
+ Future<LibsqlStatement>  prepare({required String sql })=>RustLib.instance.api.crateApiTransactionLibsqlTransactionPrepare(that: this, sql: sql);
+
+ Future<QueryResult>  query({required String sql , LibsqlParams? parameters , BigInt? timeoutMs , LibsqlCancelToken? cancel })=>RustLib.instance.api.crateApiTransactionLibsqlTransactionQuery(that: this, sql: sql, parameters: parameters, timeoutMs: timeoutMs, cancel: cancel);
+
+ Future<ColumnarQueryResult>  queryColumnar({required String sql , LibsqlParams? parameters , BigInt? timeoutMs , LibsqlCancelToken? cancel })=>RustLib.instance.api.crateApiTransactionLibsqlTransactionQueryColumnar(that: this, sql: sql, parameters: parameters, timeoutMs: timeoutMs, cancel: cancel);
+
+ Future<void>  rollback()=>RustLib.instance.api.crateApiTransactionLibsqlTransactionRollback(that: this, );
+
+/// Starts a savepoint, which can be released or rolled back on its own
+/// without ending this transaction. Fails with a `Misuse` error while a
+/// savepoint of the same name is open.
+ Future<LibsqlSavepoint>  savepoint({required String name })=>RustLib.instance.api.crateApiTransactionLibsqlTransactionSavepoint(that: this, name: name);
+
+            }
@@ -8,890 +8,823 @@
 
 import 'api/connection.dart';
 import 'api/libsql.dart';
+import 'api/pool.dart';
+import 'api/rows.dart';
+import 'api/savepoint.dart';
 import 'api/statement.dart';
 import 'api/transaction.dart';
 import 'dart:async';
 import 'dart:convert';
 import 'frb_generated.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated_web.dart';
+import 'utils/changes.dart';
+import 'utils/error.dart';
 import 'utils/params.dart';
 import 'utils/result.dart';
+import 'utils/retry.dart';
 import 'utils/return_value.dart';
+import 'utils/sync.dart';
 
-abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
-  RustLibApiImplPlatform({
-    required super.handler,
-    required super.wire,
-    required super.generalizedFrbRustBinding,
-    required super.portManager,
-  });
-
-  CrossPlatformFinalizerArg
-      get rust_arc_decrement_strong_count_InnerConnectionPtr => wire
-          .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerInnerConnection;
-
-  CrossPlatformFinalizerArg
-      get rust_arc_decrement_strong_count_InnerDatabasePtr => wire
-          .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerInnerDatabase;
-
-  CrossPlatformFinalizerArg
-      get rust_arc_decrement_strong_count_InnerStatementPtr => wire
-          .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerInnerStatement;
-
-  CrossPlatformFinalizerArg
-      get rust_arc_decrement_strong_count_InnerTransactionPtr => wire
-          .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerInnerTransaction;
-
-  CrossPlatformFinalizerArg
-      get rust_arc_decrement_strong_count_LibsqlConnectionPtr => wire
-          .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlConnection;
-
-  CrossPlatformFinalizerArg
-      get rust_arc_decrement_strong_count_LibsqlStatementPtr => wire
-          .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlStatement;
-
-  CrossPlatformFinalizerArg
-      get rust_arc_decrement_strong_count_LibsqlTransactionPtr => wire
-          .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlTransaction;
-
-  @protected
-  InnerConnection
-      dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerInnerConnection(
-          dynamic raw);
-
-  @protected
-  InnerDatabase
-      dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerInnerDatabase(
-          dynamic raw);
-
-  @protected
-  InnerStatement
-      dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerInnerStatement(
-          dynamic raw);
-
-  @protected
-  InnerTransaction
-      dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerInnerTransaction(
-          dynamic raw);
-
-  @protected
-  LibsqlConnection
-      dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlConnection(
-          dynamic raw);
-
-  @protected
-  LibsqlStatement
-      dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlStatement(
-          dynamic raw);
-
-  @protected
-  LibsqlTransaction
-      dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlTransaction(
-          dynamic raw);
-
-  @protected
-  LibsqlTransaction
-      dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlTransaction(
-          dynamic raw);
-
-  @protected
-  LibsqlConnection
-      dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlConnection(
-          dynamic raw);
-
-  @protected
-  LibsqlStatement
-      dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlStatement(
-          dynamic raw);
+                abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
+                  RustLibApiImplPlatform({
+                    required super.handler,
+                    required super.wire,
+                    required super.generalizedFrbRustBinding,
+                    required super.portManager,
+                  });
 
-  @protected
-  LibsqlTransaction
-      dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlTransaction(
-          dynamic raw);
-
-  @protected
-  Map<String, LibsqlReturnValue> dco_decode_Map_String_libsql_return_value_None(
-      dynamic raw);
+                  CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_LibsqlConnectionPtr => wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlConnection;
 
-  @protected
-  Map<String, LibsqlValue> dco_decode_Map_String_libsql_value_None(dynamic raw);
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_LibsqlPoolPtr => wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlPool;
 
-  @protected
-  InnerConnection
-      dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerInnerConnection(
-          dynamic raw);
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_LibsqlRowsPtr => wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlRows;
 
-  @protected
-  InnerDatabase
-      dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerInnerDatabase(
-          dynamic raw);
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_LibsqlSavepointPtr => wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlSavepoint;
 
-  @protected
-  InnerStatement
-      dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerInnerStatement(
-          dynamic raw);
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_LibsqlStatementPtr => wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlStatement;
 
-  @protected
-  InnerTransaction
-      dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerInnerTransaction(
-          dynamic raw);
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_LibsqlTransactionPtr => wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlTransaction;
 
-  @protected
-  LibsqlConnection
-      dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlConnection(
-          dynamic raw);
+                  @protected AnyhowException dco_decode_AnyhowException(dynamic raw);
 
-  @protected
-  LibsqlStatement
-      dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlStatement(
-          dynamic raw);
+@protected LibsqlConnection dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlConnection(dynamic raw);
 
-  @protected
-  LibsqlTransaction
-      dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlTransaction(
-          dynamic raw);
+@protected LibsqlPool dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlPool(dynamic raw);
 
-  @protected
-  String dco_decode_String(dynamic raw);
+@protected LibsqlRows dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlRows(dynamic raw);
 
-  @protected
-  bool dco_decode_bool(dynamic raw);
+@protected LibsqlSavepoint dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlSavepoint(dynamic raw);
 
-  @protected
-  bool dco_decode_box_autoadd_bool(dynamic raw);
+@protected LibsqlStatement dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlStatement(dynamic raw);
 
-  @protected
-  ConnectArgs dco_decode_box_autoadd_connect_args(dynamic raw);
+@protected LibsqlTransaction dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlTransaction(dynamic raw);
 
-  @protected
-  LibsqlOpenFlags dco_decode_box_autoadd_libsql_open_flags(dynamic raw);
+@protected LibsqlTransaction dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlTransaction(dynamic raw);
 
-  @protected
-  LibsqlParams dco_decode_box_autoadd_libsql_params(dynamic raw);
+@protected LibsqlConnection dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlConnection(dynamic raw);
 
-  @protected
-  LibsqlTransactionBehavior dco_decode_box_autoadd_libsql_transaction_behavior(
-      dynamic raw);
+@protected LibsqlPool dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlPool(dynamic raw);
 
-  @protected
-  BigInt dco_decode_box_autoadd_u_64(dynamic raw);
+@protected LibsqlRows dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlRows(dynamic raw);
 
-  @protected
-  ConnectArgs dco_decode_connect_args(dynamic raw);
+@protected LibsqlSavepoint dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlSavepoint(dynamic raw);
 
-  @protected
-  ExecuteResult dco_decode_execute_result(dynamic raw);
+@protected LibsqlStatement dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlStatement(dynamic raw);
 
-  @protected
-  double dco_decode_f_64(dynamic raw);
+@protected LibsqlTransaction dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlTransaction(dynamic raw);
 
-  @protected
-  int dco_decode_i_32(dynamic raw);
+@protected DateTime dco_decode_Chrono_Utc(dynamic raw);
 
-  @protected
-  PlatformInt64 dco_decode_i_64(dynamic raw);
+@protected FutureOr<LibsqlValue> Function(Object) dco_decode_DartFn_Inputs_DartOpaque_Output_libsql_value_AnyhowException(dynamic raw);
 
-  @protected
-  LibsqlOpenFlags dco_decode_libsql_open_flags(dynamic raw);
+@protected FutureOr<Object> Function(Object, List<LibsqlReturnValue>) dco_decode_DartFn_Inputs_DartOpaque_list_libsql_return_value_Output_DartOpaque_AnyhowException(dynamic raw);
 
-  @protected
-  LibsqlParams dco_decode_libsql_params(dynamic raw);
+@protected FutureOr<int> Function(String, String) dco_decode_DartFn_Inputs_String_String_Output_i_32_AnyhowException(dynamic raw);
 
-  @protected
-  LibsqlReturnValue dco_decode_libsql_return_value(dynamic raw);
+@protected FutureOr<Object> Function() dco_decode_DartFn_Inputs__Output_DartOpaque_AnyhowException(dynamic raw);
 
-  @protected
-  LibsqlTransactionBehavior dco_decode_libsql_transaction_behavior(dynamic raw);
+@protected FutureOr<void> Function() dco_decode_DartFn_Inputs__Output_unit_AnyhowException(dynamic raw);
 
-  @protected
-  LibsqlValue dco_decode_libsql_value(dynamic raw);
+@protected FutureOr<LibsqlValue> Function(List<LibsqlReturnValue>) dco_decode_DartFn_Inputs_list_libsql_return_value_Output_libsql_value_AnyhowException(dynamic raw);
 
-  @protected
-  List<Map<String, LibsqlReturnValue>>
-      dco_decode_list_Map_String_libsql_return_value_None(dynamic raw);
+@protected Object dco_decode_DartOpaque(dynamic raw);
 
-  @protected
-  List<String> dco_decode_list_String(dynamic raw);
+@protected Map<String, LibsqlReturnValue> dco_decode_Map_String_libsql_return_value_None(dynamic raw);
 
-  @protected
-  List<LibsqlValue> dco_decode_list_libsql_value(dynamic raw);
+@protected Map<String, LibsqlValue> dco_decode_Map_String_libsql_value_None(dynamic raw);
 
-  @protected
-  Uint8List dco_decode_list_prim_u_8_strict(dynamic raw);
+@protected LibsqlConnection dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlConnection(dynamic raw);
 
-  @protected
-  List<(String, LibsqlReturnValue)>
-      dco_decode_list_record_string_libsql_return_value(dynamic raw);
+@protected LibsqlPool dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlPool(dynamic raw);
 
-  @protected
-  List<(String, LibsqlValue)> dco_decode_list_record_string_libsql_value(
-      dynamic raw);
+@protected LibsqlRows dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlRows(dynamic raw);
 
-  @protected
-  Map<String, LibsqlValue>? dco_decode_opt_Map_String_libsql_value_None(
-      dynamic raw);
+@protected LibsqlSavepoint dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlSavepoint(dynamic raw);
 
-  @protected
-  String? dco_decode_opt_String(dynamic raw);
+@protected LibsqlStatement dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlStatement(dynamic raw);
 
-  @protected
-  bool? dco_decode_opt_box_autoadd_bool(dynamic raw);
+@protected LibsqlTransaction dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlTransaction(dynamic raw);
 
-  @protected
-  LibsqlOpenFlags? dco_decode_opt_box_autoadd_libsql_open_flags(dynamic raw);
+@protected RustStreamSink<LibsqlSyncEvent> dco_decode_StreamSink_libsql_sync_event_Sse(dynamic raw);
 
-  @protected
-  LibsqlParams? dco_decode_opt_box_autoadd_libsql_params(dynamic raw);
+@protected RustStreamSink<List<LibsqlUpdate>> dco_decode_StreamSink_list_libsql_update_Sse(dynamic raw);
 
-  @protected
-  LibsqlTransactionBehavior?
-      dco_decode_opt_box_autoadd_libsql_transaction_behavior(dynamic raw);
+@protected RustStreamSink<QueryResult> dco_decode_StreamSink_query_result_Sse(dynamic raw);
 
-  @protected
-  BigInt? dco_decode_opt_box_autoadd_u_64(dynamic raw);
+@protected String dco_decode_String(dynamic raw);
 
-  @protected
-  List<LibsqlValue>? dco_decode_opt_list_libsql_value(dynamic raw);
+@protected BatchResult dco_decode_batch_result(dynamic raw);
 
-  @protected
-  QueryResult dco_decode_query_result(dynamic raw);
+@protected bool dco_decode_bool(dynamic raw);
 
-  @protected
-  (String, LibsqlReturnValue) dco_decode_record_string_libsql_return_value(
-      dynamic raw);
+@protected DateTime dco_decode_box_autoadd_Chrono_Utc(dynamic raw);
 
-  @protected
-  (String, LibsqlValue) dco_decode_record_string_libsql_value(dynamic raw);
+@protected bool dco_decode_box_autoadd_bool(dynamic raw);
 
-  @protected
-  BigInt dco_decode_u_64(dynamic raw);
+@protected ConnectArgs dco_decode_box_autoadd_connect_args(dynamic raw);
 
-  @protected
-  int dco_decode_u_8(dynamic raw);
+@protected int dco_decode_box_autoadd_i_32(dynamic raw);
 
-  @protected
-  void dco_decode_unit(dynamic raw);
+@protected LibsqlBusyRetry dco_decode_box_autoadd_libsql_busy_retry(dynamic raw);
 
-  @protected
-  BigInt dco_decode_usize(dynamic raw);
+@protected LibsqlCancelToken dco_decode_box_autoadd_libsql_cancel_token(dynamic raw);
 
-  @protected
-  InnerConnection
-      sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerInnerConnection(
-          SseDeserializer deserializer);
+@protected LibsqlDateTimeUnit dco_decode_box_autoadd_libsql_date_time_unit(dynamic raw);
 
-  @protected
-  InnerDatabase
-      sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerInnerDatabase(
-          SseDeserializer deserializer);
+@protected LibsqlDecodeMode dco_decode_box_autoadd_libsql_decode_mode(dynamic raw);
 
-  @protected
-  InnerStatement
-      sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerInnerStatement(
-          SseDeserializer deserializer);
+@protected LibsqlError dco_decode_box_autoadd_libsql_error(dynamic raw);
 
-  @protected
-  InnerTransaction
-      sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerInnerTransaction(
-          SseDeserializer deserializer);
+@protected LibsqlOpenFlags dco_decode_box_autoadd_libsql_open_flags(dynamic raw);
 
-  @protected
-  LibsqlConnection
-      sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlConnection(
-          SseDeserializer deserializer);
+@protected LibsqlParams dco_decode_box_autoadd_libsql_params(dynamic raw);
 
-  @protected
-  LibsqlStatement
-      sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlStatement(
-          SseDeserializer deserializer);
+@protected LibsqlSyncConflict dco_decode_box_autoadd_libsql_sync_conflict(dynamic raw);
 
-  @protected
-  LibsqlTransaction
-      sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlTransaction(
-          SseDeserializer deserializer);
+@protected LibsqlSyncConflictPolicy dco_decode_box_autoadd_libsql_sync_conflict_policy(dynamic raw);
 
-  @protected
-  LibsqlTransaction
-      sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlTransaction(
-          SseDeserializer deserializer);
+@protected LibsqlTransactionBehavior dco_decode_box_autoadd_libsql_transaction_behavior(dynamic raw);
 
-  @protected
-  LibsqlConnection
-      sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlConnection(
-          SseDeserializer deserializer);
+@protected SyncResult dco_decode_box_autoadd_sync_result(dynamic raw);
 
-  @protected
-  LibsqlStatement
-      sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlStatement(
-          SseDeserializer deserializer);
+@protected int dco_decode_box_autoadd_u_32(dynamic raw);
 
-  @protected
-  LibsqlTransaction
-      sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlTransaction(
-          SseDeserializer deserializer);
+@protected BigInt dco_decode_box_autoadd_u_64(dynamic raw);
 
-  @protected
-  Map<String, LibsqlReturnValue> sse_decode_Map_String_libsql_return_value_None(
-      SseDeserializer deserializer);
+@protected ColumnarQueryResult dco_decode_columnar_query_result(dynamic raw);
 
-  @protected
-  Map<String, LibsqlValue> sse_decode_Map_String_libsql_value_None(
-      SseDeserializer deserializer);
+@protected ConnectArgs dco_decode_connect_args(dynamic raw);
 
-  @protected
-  InnerConnection
-      sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerInnerConnection(
-          SseDeserializer deserializer);
+@protected ExecuteResult dco_decode_execute_result(dynamic raw);
 
-  @protected
-  InnerDatabase
-      sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerInnerDatabase(
-          SseDeserializer deserializer);
+@protected double dco_decode_f_64(dynamic raw);
 
-  @protected
-  InnerStatement
-      sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerInnerStatement(
-          SseDeserializer deserializer);
+@protected int dco_decode_i_32(dynamic raw);
 
-  @protected
-  InnerTransaction
-      sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerInnerTransaction(
-          SseDeserializer deserializer);
+@protected PlatformInt64 dco_decode_i_64(dynamic raw);
 
-  @protected
-  LibsqlConnection
-      sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlConnection(
-          SseDeserializer deserializer);
+@protected PlatformInt64 dco_decode_isize(dynamic raw);
 
-  @protected
-  LibsqlStatement
-      sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlStatement(
-          SseDeserializer deserializer);
+@protected LibsqlBatchStatement dco_decode_libsql_batch_statement(dynamic raw);
 
-  @protected
-  LibsqlTransaction
-      sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlTransaction(
-          SseDeserializer deserializer);
+@protected LibsqlBusyRetry dco_decode_libsql_busy_retry(dynamic raw);
 
-  @protected
-  String sse_decode_String(SseDeserializer deserializer);
-
-  @protected
-  bool sse_decode_bool(SseDeserializer deserializer);
+@protected LibsqlCancelToken dco_decode_libsql_cancel_token(dynamic raw);
 
-  @protected
-  bool sse_decode_box_autoadd_bool(SseDeserializer deserializer);
+@protected LibsqlCollation dco_decode_libsql_collation(dynamic raw);
 
-  @protected
-  ConnectArgs sse_decode_box_autoadd_connect_args(SseDeserializer deserializer);
+@protected LibsqlColumn dco_decode_libsql_column(dynamic raw);
 
-  @protected
-  LibsqlOpenFlags sse_decode_box_autoadd_libsql_open_flags(
-      SseDeserializer deserializer);
+@protected LibsqlDateTimeFormat dco_decode_libsql_date_time_format(dynamic raw);
 
-  @protected
-  LibsqlParams sse_decode_box_autoadd_libsql_params(
-      SseDeserializer deserializer);
+@protected LibsqlDateTimeUnit dco_decode_libsql_date_time_unit(dynamic raw);
 
-  @protected
-  LibsqlTransactionBehavior sse_decode_box_autoadd_libsql_transaction_behavior(
-      SseDeserializer deserializer);
+@protected LibsqlDecodeMode dco_decode_libsql_decode_mode(dynamic raw);
 
-  @protected
-  BigInt sse_decode_box_autoadd_u_64(SseDeserializer deserializer);
+@protected LibsqlError dco_decode_libsql_error(dynamic raw);
 
-  @protected
-  ConnectArgs sse_decode_connect_args(SseDeserializer deserializer);
+@protected LibsqlErrorKind dco_decode_libsql_error_kind(dynamic raw);
 
-  @protected
-  ExecuteResult sse_decode_execute_result(SseDeserializer deserializer);
+@protected LibsqlOpenFlags dco_decode_libsql_open_flags(dynamic raw);
 
-  @protected
-  double sse_decode_f_64(SseDeserializer deserializer);
+@protected LibsqlParams dco_decode_libsql_params(dynamic raw);
 
-  @protected
-  int sse_decode_i_32(SseDeserializer deserializer);
+@protected LibsqlReturnValue dco_decode_libsql_return_value(dynamic raw);
 
-  @protected
-  PlatformInt64 sse_decode_i_64(SseDeserializer deserializer);
+@protected LibsqlSyncConflict dco_decode_libsql_sync_conflict(dynamic raw);
 
-  @protected
-  LibsqlOpenFlags sse_decode_libsql_open_flags(SseDeserializer deserializer);
+@protected LibsqlSyncConflictPolicy dco_decode_libsql_sync_conflict_policy(dynamic raw);
 
-  @protected
-  LibsqlParams sse_decode_libsql_params(SseDeserializer deserializer);
+@protected LibsqlSyncEvent dco_decode_libsql_sync_event(dynamic raw);
 
-  @protected
-  LibsqlReturnValue sse_decode_libsql_return_value(
-      SseDeserializer deserializer);
+@protected LibsqlSyncStatus dco_decode_libsql_sync_status(dynamic raw);
 
-  @protected
-  LibsqlTransactionBehavior sse_decode_libsql_transaction_behavior(
-      SseDeserializer deserializer);
+@protected LibsqlSyncTrigger dco_decode_libsql_sync_trigger(dynamic raw);
 
-  @protected
-  LibsqlValue sse_decode_libsql_value(SseDeserializer deserializer);
+@protected LibsqlTransactionBehavior dco_decode_libsql_transaction_behavior(dynamic raw);
 
-  @protected
-  List<Map<String, LibsqlReturnValue>>
-      sse_decode_list_Map_String_libsql_return_value_None(
-          SseDeserializer deserializer);
+@protected LibsqlUpdate dco_decode_libsql_update(dynamic raw);
 
-  @protected
-  List<String> sse_decode_list_String(SseDeserializer deserializer);
+@protected LibsqlUpdateOperation dco_decode_libsql_update_operation(dynamic raw);
 
-  @protected
-  List<LibsqlValue> sse_decode_list_libsql_value(SseDeserializer deserializer);
+@protected LibsqlValue dco_decode_libsql_value(dynamic raw);
 
-  @protected
-  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer);
+@protected List<Map<String, LibsqlReturnValue>> dco_decode_list_Map_String_libsql_return_value_None(dynamic raw);
 
-  @protected
-  List<(String, LibsqlReturnValue)>
-      sse_decode_list_record_string_libsql_return_value(
-          SseDeserializer deserializer);
+@protected List<String> dco_decode_list_String(dynamic raw);
 
-  @protected
-  List<(String, LibsqlValue)> sse_decode_list_record_string_libsql_value(
-      SseDeserializer deserializer);
+@protected List<LibsqlBatchStatement> dco_decode_list_libsql_batch_statement(dynamic raw);
 
-  @protected
-  Map<String, LibsqlValue>? sse_decode_opt_Map_String_libsql_value_None(
-      SseDeserializer deserializer);
+@protected List<LibsqlColumn> dco_decode_list_libsql_column(dynamic raw);
 
-  @protected
-  String? sse_decode_opt_String(SseDeserializer deserializer);
+@protected List<LibsqlReturnValue> dco_decode_list_libsql_return_value(dynamic raw);
 
-  @protected
-  bool? sse_decode_opt_box_autoadd_bool(SseDeserializer deserializer);
+@protected List<LibsqlUpdate> dco_decode_list_libsql_update(dynamic raw);
 
-  @protected
-  LibsqlOpenFlags? sse_decode_opt_box_autoadd_libsql_open_flags(
-      SseDeserializer deserializer);
+@protected List<LibsqlValue> dco_decode_list_libsql_value(dynamic raw);
 
-  @protected
-  LibsqlParams? sse_decode_opt_box_autoadd_libsql_params(
-      SseDeserializer deserializer);
+@protected List<String?> dco_decode_list_opt_String(dynamic raw);
 
-  @protected
-  LibsqlTransactionBehavior?
-      sse_decode_opt_box_autoadd_libsql_transaction_behavior(
-          SseDeserializer deserializer);
+@protected Uint8List dco_decode_list_prim_u_8_strict(dynamic raw);
 
-  @protected
-  BigInt? sse_decode_opt_box_autoadd_u_64(SseDeserializer deserializer);
+@protected List<QueryResult> dco_decode_list_query_result(dynamic raw);
 
-  @protected
-  List<LibsqlValue>? sse_decode_opt_list_libsql_value(
-      SseDeserializer deserializer);
+@protected List<(String,LibsqlReturnValue)> dco_decode_list_record_string_libsql_return_value(dynamic raw);
 
-  @protected
-  QueryResult sse_decode_query_result(SseDeserializer deserializer);
+@protected List<(String,LibsqlValue)> dco_decode_list_record_string_libsql_value(dynamic raw);
 
-  @protected
-  (String, LibsqlReturnValue) sse_decode_record_string_libsql_return_value(
-      SseDeserializer deserializer);
+@protected Map<String, LibsqlReturnValue>? dco_decode_opt_Map_String_libsql_return_value_None(dynamic raw);
 
-  @protected
-  (String, LibsqlValue) sse_decode_record_string_libsql_value(
-      SseDeserializer deserializer);
+@protected Map<String, LibsqlValue>? dco_decode_opt_Map_String_libsql_value_None(dynamic raw);
 
-  @protected
-  BigInt sse_decode_u_64(SseDeserializer deserializer);
+@protected String? dco_decode_opt_String(dynamic raw);
 
-  @protected
-  int sse_decode_u_8(SseDeserializer deserializer);
+@protected DateTime? dco_decode_opt_box_autoadd_Chrono_Utc(dynamic raw);
 
-  @protected
-  void sse_decode_unit(SseDeserializer deserializer);
-
-  @protected
-  BigInt sse_decode_usize(SseDeserializer deserializer);
-
-  @protected
-  void
-      sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerInnerConnection(
-          InnerConnection self, SseSerializer serializer);
-
-  @protected
-  void
-      sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerInnerDatabase(
-          InnerDatabase self, SseSerializer serializer);
-
-  @protected
-  void
-      sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerInnerStatement(
-          InnerStatement self, SseSerializer serializer);
-
-  @protected
-  void
-      sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerInnerTransaction(
-          InnerTransaction self, SseSerializer serializer);
-
-  @protected
-  void
-      sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlConnection(
-          LibsqlConnection self, SseSerializer serializer);
-
-  @protected
-  void
-      sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlStatement(
-          LibsqlStatement self, SseSerializer serializer);
-
-  @protected
-  void
-      sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlTransaction(
-          LibsqlTransaction self, SseSerializer serializer);
+@protected bool? dco_decode_opt_box_autoadd_bool(dynamic raw);
 
-  @protected
-  void
-      sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlTransaction(
-          LibsqlTransaction self, SseSerializer serializer);
-
-  @protected
-  void
-      sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlConnection(
-          LibsqlConnection self, SseSerializer serializer);
+@protected int? dco_decode_opt_box_autoadd_i_32(dynamic raw);
 
-  @protected
-  void
-      sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlStatement(
-          LibsqlStatement self, SseSerializer serializer);
+@protected LibsqlBusyRetry? dco_decode_opt_box_autoadd_libsql_busy_retry(dynamic raw);
 
-  @protected
-  void
-      sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlTransaction(
-          LibsqlTransaction self, SseSerializer serializer);
-
-  @protected
-  void sse_encode_Map_String_libsql_return_value_None(
-      Map<String, LibsqlReturnValue> self, SseSerializer serializer);
+@protected LibsqlCancelToken? dco_decode_opt_box_autoadd_libsql_cancel_token(dynamic raw);
 
-  @protected
-  void sse_encode_Map_String_libsql_value_None(
-      Map<String, LibsqlValue> self, SseSerializer serializer);
+@protected LibsqlDateTimeUnit? dco_decode_opt_box_autoadd_libsql_date_time_unit(dynamic raw);
 
-  @protected
-  void
-      sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerInnerConnection(
-          InnerConnection self, SseSerializer serializer);
+@protected LibsqlDecodeMode? dco_decode_opt_box_autoadd_libsql_decode_mode(dynamic raw);
 
-  @protected
-  void
-      sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerInnerDatabase(
-          InnerDatabase self, SseSerializer serializer);
+@protected LibsqlError? dco_decode_opt_box_autoadd_libsql_error(dynamic raw);
 
-  @protected
-  void
-      sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerInnerStatement(
-          InnerStatement self, SseSerializer serializer);
+@protected LibsqlOpenFlags? dco_decode_opt_box_autoadd_libsql_open_flags(dynamic raw);
 
-  @protected
-  void
-      sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerInnerTransaction(
-          InnerTransaction self, SseSerializer serializer);
+@protected LibsqlParams? dco_decode_opt_box_autoadd_libsql_params(dynamic raw);
 
-  @protected
-  void
-      sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlConnection(
-          LibsqlConnection self, SseSerializer serializer);
+@protected LibsqlSyncConflict? dco_decode_opt_box_autoadd_libsql_sync_conflict(dynamic raw);
 
-  @protected
-  void
-      sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlStatement(
-          LibsqlStatement self, SseSerializer serializer);
+@protected LibsqlSyncConflictPolicy? dco_decode_opt_box_autoadd_libsql_sync_conflict_policy(dynamic raw);
 
-  @protected
-  void
-      sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlTransaction(
-          LibsqlTransaction self, SseSerializer serializer);
-
-  @protected
-  void sse_encode_String(String self, SseSerializer serializer);
-
-  @protected
-  void sse_encode_bool(bool self, SseSerializer serializer);
+@protected LibsqlTransactionBehavior? dco_decode_opt_box_autoadd_libsql_transaction_behavior(dynamic raw);
 
-  @protected
-  void sse_encode_box_autoadd_bool(bool self, SseSerializer serializer);
+@protected SyncResult? dco_decode_opt_box_autoadd_sync_result(dynamic raw);
 
-  @protected
-  void sse_encode_box_autoadd_connect_args(
-      ConnectArgs self, SseSerializer serializer);
+@protected int? dco_decode_opt_box_autoadd_u_32(dynamic raw);
 
-  @protected
-  void sse_encode_box_autoadd_libsql_open_flags(
-      LibsqlOpenFlags self, SseSerializer serializer);
+@protected BigInt? dco_decode_opt_box_autoadd_u_64(dynamic raw);
 
-  @protected
-  void sse_encode_box_autoadd_libsql_params(
-      LibsqlParams self, SseSerializer serializer);
+@protected List<LibsqlValue>? dco_decode_opt_list_libsql_value(dynamic raw);
 
-  @protected
-  void sse_encode_box_autoadd_libsql_transaction_behavior(
-      LibsqlTransactionBehavior self, SseSerializer serializer);
+@protected QueryResult dco_decode_query_result(dynamic raw);
 
-  @protected
-  void sse_encode_box_autoadd_u_64(BigInt self, SseSerializer serializer);
+@protected (String,LibsqlReturnValue) dco_decode_record_string_libsql_return_value(dynamic raw);
 
-  @protected
-  void sse_encode_connect_args(ConnectArgs self, SseSerializer serializer);
+@protected (String,LibsqlValue) dco_decode_record_string_libsql_value(dynamic raw);
 
-  @protected
-  void sse_encode_execute_result(ExecuteResult self, SseSerializer serializer);
+@protected SyncResult dco_decode_sync_result(dynamic raw);
 
-  @protected
-  void sse_encode_f_64(double self, SseSerializer serializer);
+@protected int dco_decode_u_32(dynamic raw);
 
-  @protected
-  void sse_encode_i_32(int self, SseSerializer serializer);
+@protected BigInt dco_decode_u_64(dynamic raw);
 
-  @protected
-  void sse_encode_i_64(PlatformInt64 self, SseSerializer serializer);
+@protected int dco_decode_u_8(dynamic raw);
 
-  @protected
-  void sse_encode_libsql_open_flags(
-      LibsqlOpenFlags self, SseSerializer serializer);
+@protected void dco_decode_unit(dynamic raw);
 
-  @protected
-  void sse_encode_libsql_params(LibsqlParams self, SseSerializer serializer);
+@protected BigInt dco_decode_usize(dynamic raw);
 
-  @protected
-  void sse_encode_libsql_return_value(
-      LibsqlReturnValue self, SseSerializer serializer);
+@protected AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer);
 
-  @protected
-  void sse_encode_libsql_transaction_behavior(
-      LibsqlTransactionBehavior self, SseSerializer serializer);
+@protected LibsqlConnection sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlConnection(SseDeserializer deserializer);
 
-  @protected
-  void sse_encode_libsql_value(LibsqlValue self, SseSerializer serializer);
+@protected LibsqlPool sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlPool(SseDeserializer deserializer);
 
-  @protected
-  void sse_encode_list_Map_String_libsql_return_value_None(
-      List<Map<String, LibsqlReturnValue>> self, SseSerializer serializer);
+@protected LibsqlRows sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlRows(SseDeserializer deserializer);
 
-  @protected
-  void sse_encode_list_String(List<String> self, SseSerializer serializer);
+@protected LibsqlSavepoint sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlSavepoint(SseDeserializer deserializer);
 
-  @protected
-  void sse_encode_list_libsql_value(
-      List<LibsqlValue> self, SseSerializer serializer);
+@protected LibsqlStatement sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlStatement(SseDeserializer deserializer);
 
-  @protected
-  void sse_encode_list_prim_u_8_strict(
-      Uint8List self, SseSerializer serializer);
+@protected LibsqlTransaction sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlTransaction(SseDeserializer deserializer);
 
-  @protected
-  void sse_encode_list_record_string_libsql_return_value(
-      List<(String, LibsqlReturnValue)> self, SseSerializer serializer);
+@protected LibsqlTransaction sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlTransaction(SseDeserializer deserializer);
 
-  @protected
-  void sse_encode_list_record_string_libsql_value(
-      List<(String, LibsqlValue)> self, SseSerializer serializer);
+@protected LibsqlConnection sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlConnection(SseDeserializer deserializer);
 
-  @protected
-  void sse_encode_opt_Map_String_libsql_value_None(
-      Map<String, LibsqlValue>? self, SseSerializer serializer);
+@protected LibsqlPool sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlPool(SseDeserializer deserializer);
 
-  @protected
-  void sse_encode_opt_String(String? self, SseSerializer serializer);
+@protected LibsqlRows sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlRows(SseDeserializer deserializer);
 
-  @protected
-  void sse_encode_opt_box_autoadd_bool(bool? self, SseSerializer serializer);
+@protected LibsqlSavepoint sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlSavepoint(SseDeserializer deserializer);
 
-  @protected
-  void sse_encode_opt_box_autoadd_libsql_open_flags(
-      LibsqlOpenFlags? self, SseSerializer serializer);
+@protected LibsqlStatement sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlStatement(SseDeserializer deserializer);
 
-  @protected
-  void sse_encode_opt_box_autoadd_libsql_params(
-      LibsqlParams? self, SseSerializer serializer);
+@protected LibsqlTransaction sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlTransaction(SseDeserializer deserializer);
 
-  @protected
-  void sse_encode_opt_box_autoadd_libsql_transaction_behavior(
-      LibsqlTransactionBehavior? self, SseSerializer serializer);
+@protected DateTime sse_decode_Chrono_Utc(SseDeserializer deserializer);
 
-  @protected
-  void sse_encode_opt_box_autoadd_u_64(BigInt? self, SseSerializer serializer);
+@protected Object sse_decode_DartOpaque(SseDeserializer deserializer);
 
-  @protected
-  void sse_encode_opt_list_libsql_value(
-      List<LibsqlValue>? self, SseSerializer serializer);
+@protected Map<String, LibsqlReturnValue> sse_decode_Map_String_libsql_return_value_None(SseDeserializer deserializer);
 
-  @protected
-  void sse_encode_query_result(QueryResult self, SseSerializer serializer);
+@protected Map<String, LibsqlValue> sse_decode_Map_String_libsql_value_None(SseDeserializer deserializer);
 
-  @protected
-  void sse_encode_record_string_libsql_return_value(
-      (String, LibsqlReturnValue) self, SseSerializer serializer);
+@protected LibsqlConnection sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlConnection(SseDeserializer deserializer);
 
-  @protected
-  void sse_encode_record_string_libsql_value(
-      (String, LibsqlValue) self, SseSerializer serializer);
+@protected LibsqlPool sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlPool(SseDeserializer deserializer);
 
-  @protected
-  void sse_encode_u_64(BigInt self, SseSerializer serializer);
+@protected LibsqlRows sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlRows(SseDeserializer deserializer);
 
-  @protected
-  void sse_encode_u_8(int self, SseSerializer serializer);
+@protected LibsqlSavepoint sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlSavepoint(SseDeserializer deserializer);
 
-  @protected
-  void sse_encode_unit(void self, SseSerializer serializer);
+@protected LibsqlStatement sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlStatement(SseDeserializer deserializer);
 
-  @protected
-  void sse_encode_usize(BigInt self, SseSerializer serializer);
-}
+@protected LibsqlTransaction sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlTransaction(SseDeserializer deserializer);
+
+@protected RustStreamSink<LibsqlSyncEvent> sse_decode_StreamSink_libsql_sync_event_Sse(SseDeserializer deserializer);
+
+@protected RustStreamSink<List<LibsqlUpdate>> sse_decode_StreamSink_list_libsql_update_Sse(SseDeserializer deserializer);
+
+@protected RustStreamSink<QueryResult> sse_decode_StreamSink_query_result_Sse(SseDeserializer deserializer);
+
+@protected String sse_decode_String(SseDeserializer deserializer);
+
+@protected BatchResult sse_decode_batch_result(SseDeserializer deserializer);
+
+@protected bool sse_decode_bool(SseDeserializer deserializer);
+
+@protected DateTime sse_decode_box_autoadd_Chrono_Utc(SseDeserializer deserializer);
+
+@protected bool sse_decode_box_autoadd_bool(SseDeserializer deserializer);
+
+@protected ConnectArgs sse_decode_box_autoadd_connect_args(SseDeserializer deserializer);
+
+@protected int sse_decode_box_autoadd_i_32(SseDeserializer deserializer);
+
+@protected LibsqlBusyRetry sse_decode_box_autoadd_libsql_busy_retry(SseDeserializer deserializer);
+
+@protected LibsqlCancelToken sse_decode_box_autoadd_libsql_cancel_token(SseDeserializer deserializer);
+
+@protected LibsqlDateTimeUnit sse_decode_box_autoadd_libsql_date_time_unit(SseDeserializer deserializer);
+
+@protected LibsqlDecodeMode sse_decode_box_autoadd_libsql_decode_mode(SseDeserializer deserializer);
+
+@protected LibsqlError sse_decode_box_autoadd_libsql_error(SseDeserializer deserializer);
+
+@protected LibsqlOpenFlags sse_decode_box_autoadd_libsql_open_flags(SseDeserializer deserializer);
+
+@protected LibsqlParams sse_decode_box_autoadd_libsql_params(SseDeserializer deserializer);
+
+@protected LibsqlSyncConflict sse_decode_box_autoadd_libsql_sync_conflict(SseDeserializer deserializer);
+
+@protected LibsqlSyncConflictPolicy sse_decode_box_autoadd_libsql_sync_conflict_policy(SseDeserializer deserializer);
+
+@protected LibsqlTransactionBehavior sse_decode_box_autoadd_libsql_transaction_behavior(SseDeserializer deserializer);
+
+@protected SyncResult sse_decode_box_autoadd_sync_result(SseDeserializer deserializer);
+
+@protected int sse_decode_box_autoadd_u_32(SseDeserializer deserializer);
+
+@protected BigInt sse_decode_box_autoadd_u_64(SseDeserializer deserializer);
+
+@protected ColumnarQueryResult sse_decode_columnar_query_result(SseDeserializer deserializer);
+
+@protected ConnectArgs sse_decode_connect_args(SseDeserializer deserializer);
+
+@protected ExecuteResult sse_decode_execute_result(SseDeserializer deserializer);
+
+@protected double sse_decode_f_64(SseDeserializer deserializer);
+
+@protected int sse_decode_i_32(SseDeserializer deserializer);
+
+@protected PlatformInt64 sse_decode_i_64(SseDeserializer deserializer);
+
+@protected PlatformInt64 sse_decode_isize(SseDeserializer deserializer);
+
+@protected LibsqlBatchStatement sse_decode_libsql_batch_statement(SseDeserializer deserializer);
+
+@protected LibsqlBusyRetry sse_decode_libsql_busy_retry(SseDeserializer deserializer);
+
+@protected LibsqlCancelToken sse_decode_libsql_cancel_token(SseDeserializer deserializer);
+
+@protected LibsqlCollation sse_decode_libsql_collation(SseDeserializer deserializer);
+
+@protected LibsqlColumn sse_decode_libsql_column(SseDeserializer deserializer);
+
+@protected LibsqlDateTimeFormat sse_decode_libsql_date_time_format(SseDeserializer deserializer);
+
+@protected LibsqlDateTimeUnit sse_decode_libsql_date_time_unit(SseDeserializer deserializer);
+
+@protected LibsqlDecodeMode sse_decode_libsql_decode_mode(SseDeserializer deserializer);
+
+@protected LibsqlError sse_decode_libsql_error(SseDeserializer deserializer);
+
+@protected LibsqlErrorKind sse_decode_libsql_error_kind(SseDeserializer deserializer);
+
+@protected LibsqlOpenFlags sse_decode_libsql_open_flags(SseDeserializer deserializer);
+
+@protected LibsqlParams sse_decode_libsql_params(SseDeserializer deserializer);
+
+@protected LibsqlReturnValue sse_decode_libsql_return_value(SseDeserializer deserializer);
+
+@protected LibsqlSyncConflict sse_decode_libsql_sync_conflict(SseDeserializer deserializer);
+
+@protected LibsqlSyncConflictPolicy sse_decode_libsql_sync_conflict_policy(SseDeserializer deserializer);
+
+@protected LibsqlSyncEvent sse_decode_libsql_sync_event(SseDeserializer deserializer);
+
+@protected LibsqlSyncStatus sse_decode_libsql_sync_status(SseDeserializer deserializer);
+
+@protected LibsqlSyncTrigger sse_decode_libsql_sync_trigger(SseDeserializer deserializer);
+
+@protected LibsqlTransactionBehavior sse_decode_libsql_transaction_behavior(SseDeserializer deserializer);
+
+@protected LibsqlUpdate sse_decode_libsql_update(SseDeserializer deserializer);
+
+@protected LibsqlUpdateOperation sse_decode_libsql_update_operation(SseDeserializer deserializer);
+
+@protected LibsqlValue sse_decode_libsql_value(SseDeserializer deserializer);
+
+@protected List<Map<String, LibsqlReturnValue>> sse_decode_list_Map_String_libsql_return_value_None(SseDeserializer deserializer);
+
+@protected List<String> sse_decode_list_String(SseDeserializer deserializer);
+
+@protected List<LibsqlBatchStatement> sse_decode_list_libsql_batch_statement(SseDeserializer deserializer);
+
+@protected List<LibsqlColumn> sse_decode_list_libsql_column(SseDeserializer deserializer);
+
+@protected List<LibsqlReturnValue> sse_decode_list_libsql_return_value(SseDeserializer deserializer);
+
+@protected List<LibsqlUpdate> sse_decode_list_libsql_update(SseDeserializer deserializer);
+
+@protected List<LibsqlValue> sse_decode_list_libsql_value(SseDeserializer deserializer);
+
+@protected List<String?> sse_decode_list_opt_String(SseDeserializer deserializer);
+
+@protected Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer);
+
+@protected List<QueryResult> sse_decode_list_query_result(SseDeserializer deserializer);
+
+@protected List<(String,LibsqlReturnValue)> sse_decode_list_record_string_libsql_return_value(SseDeserializer deserializer);
+
+@protected List<(String,LibsqlValue)> sse_decode_list_record_string_libsql_value(SseDeserializer deserializer);
+
+@protected Map<String, LibsqlReturnValue>? sse_decode_opt_Map_String_libsql_return_value_None(SseDeserializer deserializer);
+
+@protected Map<String, LibsqlValue>? sse_decode_opt_Map_String_libsql_value_None(SseDeserializer deserializer);
+
+@protected String? sse_decode_opt_String(SseDeserializer deserializer);
+
+@protected DateTime? sse_decode_opt_box_autoadd_Chrono_Utc(SseDeserializer deserializer);
+
+@protected bool? sse_decode_opt_box_autoadd_bool(SseDeserializer deserializer);
+
+@protected int? sse_decode_opt_box_autoadd_i_32(SseDeserializer deserializer);
+
+@protected LibsqlBusyRetry? sse_decode_opt_box_autoadd_libsql_busy_retry(SseDeserializer deserializer);
+
+@protected LibsqlCancelToken? sse_decode_opt_box_autoadd_libsql_cancel_token(SseDeserializer deserializer);
+
+@protected LibsqlDateTimeUnit? sse_decode_opt_box_autoadd_libsql_date_time_unit(SseDeserializer deserializer);
+
+@protected LibsqlDecodeMode? sse_decode_opt_box_autoadd_libsql_decode_mode(SseDeserializer deserializer);
+
+@protected LibsqlError? sse_decode_opt_box_autoadd_libsql_error(SseDeserializer deserializer);
+
+@protected LibsqlOpenFlags? sse_decode_opt_box_autoadd_libsql_open_flags(SseDeserializer deserializer);
+
+@protected LibsqlParams? sse_decode_opt_box_autoadd_libsql_params(SseDeserializer deserializer);
+
+@protected LibsqlSyncConflict? sse_decode_opt_box_autoadd_libsql_sync_conflict(SseDeserializer deserializer);
+
+@protected LibsqlSyncConflictPolicy? sse_decode_opt_box_autoadd_libsql_sync_conflict_policy(SseDeserializer deserializer);
+
+@protected LibsqlTransactionBehavior? sse_decode_opt_box_autoadd_libsql_transaction_behavior(SseDeserializer deserializer);
+
+@protected SyncResult? sse_decode_opt_box_autoadd_sync_result(SseDeserializer deserializer);
+
+@protected int? sse_decode_opt_box_autoadd_u_32(SseDeserializer deserializer);
+
+@protected BigInt? sse_decode_opt_box_autoadd_u_64(SseDeserializer deserializer);
+
+@protected List<LibsqlValue>? sse_decode_opt_list_libsql_value(SseDeserializer deserializer);
+
+@protected QueryResult sse_decode_query_result(SseDeserializer deserializer);
+
+@protected (String,LibsqlReturnValue) sse_decode_record_string_libsql_return_value(SseDeserializer deserializer);
+
+@protected (String,LibsqlValue) sse_decode_record_string_libsql_value(SseDeserializer deserializer);
+
+@protected SyncResult sse_decode_sync_result(SseDeserializer deserializer);
+
+@protected int sse_decode_u_32(SseDeserializer deserializer);
+
+@protected BigInt sse_decode_u_64(SseDeserializer deserializer);
+
+@protected int sse_decode_u_8(SseDeserializer deserializer);
+
+@protected void sse_decode_unit(SseDeserializer deserializer);
+
+@protected BigInt sse_decode_usize(SseDeserializer deserializer);
+
+@protected void sse_encode_AnyhowException(AnyhowException self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlConnection(LibsqlConnection self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlPool(LibsqlPool self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlRows(LibsqlRows self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlSavepoint(LibsqlSavepoint self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlStatement(LibsqlStatement self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlTransaction(LibsqlTransaction self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlTransaction(LibsqlTransaction self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlConnection(LibsqlConnection self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlPool(LibsqlPool self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlRows(LibsqlRows self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlSavepoint(LibsqlSavepoint self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlStatement(LibsqlStatement self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlTransaction(LibsqlTransaction self, SseSerializer serializer);
+
+@protected void sse_encode_Chrono_Utc(DateTime self, SseSerializer serializer);
+
+@protected void sse_encode_DartFn_Inputs_DartOpaque_Output_libsql_value_AnyhowException(FutureOr<LibsqlValue> Function(Object) self, SseSerializer serializer);
+
+@protected void sse_encode_DartFn_Inputs_DartOpaque_list_libsql_return_value_Output_DartOpaque_AnyhowException(FutureOr<Object> Function(Object, List<LibsqlReturnValue>) self, SseSerializer serializer);
+
+@protected void sse_encode_DartFn_Inputs_String_String_Output_i_32_AnyhowException(FutureOr<int> Function(String, String) self, SseSerializer serializer);
+
+@protected void sse_encode_DartFn_Inputs__Output_DartOpaque_AnyhowException(FutureOr<Object> Function() self, SseSerializer serializer);
+
+@protected void sse_encode_DartFn_Inputs__Output_unit_AnyhowException(FutureOr<void> Function() self, SseSerializer serializer);
+
+@protected void sse_encode_DartFn_Inputs_list_libsql_return_value_Output_libsql_value_AnyhowException(FutureOr<LibsqlValue> Function(List<LibsqlReturnValue>) self, SseSerializer serializer);
+
+@protected void sse_encode_DartOpaque(Object self, SseSerializer serializer);
+
+@protected void sse_encode_Map_String_libsql_return_value_None(Map<String, LibsqlReturnValue> self, SseSerializer serializer);
+
+@protected void sse_encode_Map_String_libsql_value_None(Map<String, LibsqlValue> self, SseSerializer serializer);
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlConnection(LibsqlConnection self, SseSerializer serializer);
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlPool(LibsqlPool self, SseSerializer serializer);
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlRows(LibsqlRows self, SseSerializer serializer);
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlSavepoint(LibsqlSavepoint self, SseSerializer serializer);
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlStatement(LibsqlStatement self, SseSerializer serializer);
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlTransaction(LibsqlTransaction self, SseSerializer serializer);
+
+@protected void sse_encode_StreamSink_libsql_sync_event_Sse(RustStreamSink<LibsqlSyncEvent> self, SseSerializer serializer);
+
+@protected void sse_encode_StreamSink_list_libsql_update_Sse(RustStreamSink<List<LibsqlUpdate>> self, SseSerializer serializer);
+
+@protected void sse_encode_StreamSink_query_result_Sse(RustStreamSink<QueryResult> self, SseSerializer serializer);
+
+@protected void sse_encode_String(String self, SseSerializer serializer);
+
+@protected void sse_encode_batch_result(BatchResult self, SseSerializer serializer);
+
+@protected void sse_encode_bool(bool self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_Chrono_Utc(DateTime self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_bool(bool self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_connect_args(ConnectArgs self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_i_32(int self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_libsql_busy_retry(LibsqlBusyRetry self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_libsql_cancel_token(LibsqlCancelToken self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_libsql_date_time_unit(LibsqlDateTimeUnit self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_libsql_decode_mode(LibsqlDecodeMode self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_libsql_error(LibsqlError self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_libsql_open_flags(LibsqlOpenFlags self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_libsql_params(LibsqlParams self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_libsql_sync_conflict(LibsqlSyncConflict self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_libsql_sync_conflict_policy(LibsqlSyncConflictPolicy self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_libsql_transaction_behavior(LibsqlTransactionBehavior self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_sync_result(SyncResult self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_u_32(int self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_u_64(BigInt self, SseSerializer serializer);
+
+@protected void sse_encode_columnar_query_result(ColumnarQueryResult self, SseSerializer serializer);
+
+@protected void sse_encode_connect_args(ConnectArgs self, SseSerializer serializer);
+
+@protected void sse_encode_execute_result(ExecuteResult self, SseSerializer serializer);
+
+@protected void sse_encode_f_64(double self, SseSerializer serializer);
+
+@protected void sse_encode_i_32(int self, SseSerializer serializer);
+
+@protected void sse_encode_i_64(PlatformInt64 self, SseSerializer serializer);
+
+@protected void sse_encode_isize(PlatformInt64 self, SseSerializer serializer);
+
+@protected void sse_encode_libsql_batch_statement(LibsqlBatchStatement self, SseSerializer serializer);
+
+@protected void sse_encode_libsql_busy_retry(LibsqlBusyRetry self, SseSerializer serializer);
+
+@protected void sse_encode_libsql_cancel_token(LibsqlCancelToken self, SseSerializer serializer);
+
+@protected void sse_encode_libsql_collation(LibsqlCollation self, SseSerializer serializer);
+
+@protected void sse_encode_libsql_column(LibsqlColumn self, SseSerializer serializer);
+
+@protected void sse_encode_libsql_date_time_format(LibsqlDateTimeFormat self, SseSerializer serializer);
+
+@protected void sse_encode_libsql_date_time_unit(LibsqlDateTimeUnit self, SseSerializer serializer);
+
+@protected void sse_encode_libsql_decode_mode(LibsqlDecodeMode self, SseSerializer serializer);
+
+@protected void sse_encode_libsql_error(LibsqlError self, SseSerializer serializer);
+
+@protected void sse_encode_libsql_error_kind(LibsqlErrorKind self, SseSerializer serializer);
+
+@protected void sse_encode_libsql_open_flags(LibsqlOpenFlags self, SseSerializer serializer);
+
+@protected void sse_encode_libsql_params(LibsqlParams self, SseSerializer serializer);
+
+@protected void sse_encode_libsql_return_value(LibsqlReturnValue self, SseSerializer serializer);
+
+@protected void sse_encode_libsql_sync_conflict(LibsqlSyncConflict self, SseSerializer serializer);
+
+@protected void sse_encode_libsql_sync_conflict_policy(LibsqlSyncConflictPolicy self, SseSerializer serializer);
+
+@protected void sse_encode_libsql_sync_event(LibsqlSyncEvent self, SseSerializer serializer);
+
+@protected void sse_encode_libsql_sync_status(LibsqlSyncStatus self, SseSerializer serializer);
+
+@protected void sse_encode_libsql_sync_trigger(LibsqlSyncTrigger self, SseSerializer serializer);
+
+@protected void sse_encode_libsql_transaction_behavior(LibsqlTransactionBehavior self, SseSerializer serializer);
+
+@protected void sse_encode_libsql_update(LibsqlUpdate self, SseSerializer serializer);
+
+@protected void sse_encode_libsql_update_operation(LibsqlUpdateOperation self, SseSerializer serializer);
+
+@protected void sse_encode_libsql_value(LibsqlValue self, SseSerializer serializer);
+
+@protected void sse_encode_list_Map_String_libsql_return_value_None(List<Map<String, LibsqlReturnValue>> self, SseSerializer serializer);
+
+@protected void sse_encode_list_String(List<String> self, SseSerializer serializer);
+
+@protected void sse_encode_list_libsql_batch_statement(List<LibsqlBatchStatement> self, SseSerializer serializer);
+
+@protected void sse_encode_list_libsql_column(List<LibsqlColumn> self, SseSerializer serializer);
+
+@protected void sse_encode_list_libsql_return_value(List<LibsqlReturnValue> self, SseSerializer serializer);
+
+@protected void sse_encode_list_libsql_update(List<LibsqlUpdate> self, SseSerializer serializer);
+
+@protected void sse_encode_list_libsql_value(List<LibsqlValue> self, SseSerializer serializer);
+
+@protected void sse_encode_list_opt_String(List<String?> self, SseSerializer serializer);
+
+@protected void sse_encode_list_prim_u_8_strict(Uint8List self, SseSerializer serializer);
+
+@protected void sse_encode_list_query_result(List<QueryResult> self, SseSerializer serializer);
+
+@protected void sse_encode_list_record_string_libsql_return_value(List<(String,LibsqlReturnValue)> self, SseSerializer serializer);
+
+@protected void sse_encode_list_record_string_libsql_value(List<(String,LibsqlValue)> self, SseSerializer serializer);
+
+@protected void sse_encode_opt_Map_String_libsql_return_value_None(Map<String, LibsqlReturnValue>? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_Map_String_libsql_value_None(Map<String, LibsqlValue>? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_String(String? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_Chrono_Utc(DateTime? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_bool(bool? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_i_32(int? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_libsql_busy_retry(LibsqlBusyRetry? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_libsql_cancel_token(LibsqlCancelToken? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_libsql_date_time_unit(LibsqlDateTimeUnit? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_libsql_decode_mode(LibsqlDecodeMode? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_libsql_error(LibsqlError? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_libsql_open_flags(LibsqlOpenFlags? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_libsql_params(LibsqlParams? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_libsql_sync_conflict(LibsqlSyncConflict? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_libsql_sync_conflict_policy(LibsqlSyncConflictPolicy? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_libsql_transaction_behavior(LibsqlTransactionBehavior? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_sync_result(SyncResult? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_u_32(int? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_u_64(BigInt? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_list_libsql_value(List<LibsqlValue>? self, SseSerializer serializer);
+
+@protected void sse_encode_query_result(QueryResult self, SseSerializer serializer);
+
+@protected void sse_encode_record_string_libsql_return_value((String,LibsqlReturnValue) self, SseSerializer serializer);
+
+@protected void sse_encode_record_string_libsql_value((String,LibsqlValue) self, SseSerializer serializer);
+
+@protected void sse_encode_sync_result(SyncResult self, SseSerializer serializer);
+
+@protected void sse_encode_u_32(int self, SseSerializer serializer);
+
+@protected void sse_encode_u_64(BigInt self, SseSerializer serializer);
+
+@protected void sse_encode_u_8(int self, SseSerializer serializer);
+
+@protected void sse_encode_unit(void self, SseSerializer serializer);
+
+@protected void sse_encode_usize(BigInt self, SseSerializer serializer);
+                }
 
 // Section: wire_class
 
 class RustLibWire implements BaseWire {
-  RustLibWire.fromExternalLibrary(ExternalLibrary lib);
-
-  void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerInnerConnection(
-          int ptr) =>
-      wasmModule
-          .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerInnerConnection(
-              ptr);
-
-  void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerInnerConnection(
-          int ptr) =>
-      wasmModule
-          .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerInnerConnection(
-              ptr);
-
-  void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerInnerDatabase(
-          int ptr) =>
-      wasmModule
-          .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerInnerDatabase(
-              ptr);
-
-  void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerInnerDatabase(
-          int ptr) =>
-      wasmModule
-          .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerInnerDatabase(
-              ptr);
-
-  void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerInnerStatement(
-          int ptr) =>
-      wasmModule
-          .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerInnerStatement(
-              ptr);
-
-  void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerInnerStatement(
-          int ptr) =>
-      wasmModule
-          .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerInnerStatement(
-              ptr);
-
-  void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerInnerTransaction(
-          int ptr) =>
-      wasmModule
-          .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerInnerTransaction(
-              ptr);
-
-  void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerInnerTransaction(
-          int ptr) =>
-      wasmModule
-          .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerInnerTransaction(
-              ptr);
-
-  void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlConnection(
-          int ptr) =>
-      wasmModule
-          .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlConnection(
-              ptr);
-
-  void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlConnection(
-          int ptr) =>
-      wasmModule
-          .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlConnection(
-              ptr);
-
-  void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlStatement(
-          int ptr) =>
-      wasmModule
-          .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlStatement(
-              ptr);
-
-  void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlStatement(
-          int ptr) =>
-      wasmModule
-          .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlStatement(
-              ptr);
-
-  void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlTransaction(
-          int ptr) =>
-      wasmModule
-          .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlTransaction(
-              ptr);
-
-  void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlTransaction(
-          int ptr) =>
-      wasmModule
-          .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlTransaction(
-              ptr);
-}
-
-@JS('wasm_bindgen')
-external RustLibWasmModule get wasmModule;
-
-@JS()
-@anonymous
-extension type RustLibWasmModule._(JSObject _) implements JSObject {
-  external void
-      rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerInnerConnection(
-          int ptr);
-
-  external void
-      rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerInnerConnection(
-          int ptr);
-
-  external void
-      rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerInnerDatabase(
-          int ptr);
-
-  external void
-      rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerInnerDatabase(
-          int ptr);
-
-  external void
-      rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerInnerStatement(
-          int ptr);
-
-  external void
-      rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerInnerStatement(
-          int ptr);
-
-  external void
-      rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerInnerTransaction(
-          int ptr);
-
-  external void
-      rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerInnerTransaction(
-          int ptr);
-
-  external void
-      rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlConnection(
-          int ptr);
-
-  external void
-      rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlConnection(
-          int ptr);
-
-  external void
-      rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlStatement(
-          int ptr);
-
-  external void
-      rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlStatement(
-          int ptr);
-
-  external void
-      rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlTransaction(
-          int ptr);
-
-  external void
-      rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlTransaction(
-          int ptr);
-}
+            RustLibWire.fromExternalLibrary(ExternalLibrary lib);
+
+            void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlConnection(int ptr) => wasmModule.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlConnection(ptr);
+
+void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlConnection(int ptr) => wasmModule.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlConnection(ptr);
+
+void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlPool(int ptr) => wasmModule.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlPool(ptr);
+
+void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlPool(int ptr) => wasmModule.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlPool(ptr);
+
+void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlRows(int ptr) => wasmModule.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlRows(ptr);
+
+void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlRows(int ptr) => wasmModule.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlRows(ptr);
+
+void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlSavepoint(int ptr) => wasmModule.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlSavepoint(ptr);
+
+void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlSavepoint(int ptr) => wasmModule.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlSavepoint(ptr);
+
+void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlStatement(int ptr) => wasmModule.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlStatement(ptr);
+
+void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlStatement(int ptr) => wasmModule.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlStatement(ptr);
+
+void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlTransaction(int ptr) => wasmModule.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlTransaction(ptr);
+
+void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlTransaction(int ptr) => wasmModule.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlTransaction(ptr);
+        }
+        @JS('wasm_bindgen') external RustLibWasmModule get wasmModule;
+
+        @JS() @anonymous extension type RustLibWasmModule._(JSObject _) implements JSObject {
+            external void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlConnection(int ptr);
+
+external void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlConnection(int ptr);
+
+external void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlPool(int ptr);
+
+external void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlPool(int ptr);
+
+external void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlRows(int ptr);
+
+external void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlRows(int ptr);
+
+external void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlSavepoint(int ptr);
+
+external void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlSavepoint(int ptr);
+
+external void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlStatement(int ptr);
+
+external void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlStatement(int ptr);
+
+external void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlTransaction(int ptr);
+
+external void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLibsqlTransaction(int ptr);
+        }
@@ -0,0 +1,36 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.10.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+            class LibsqlUpdate  {
+                final LibsqlUpdateOperation operation;
+/// `main`, `temp` or the name of an attached database
+final String database;
+final String table;
+final PlatformInt64 rowid;
+
+                const LibsqlUpdate({required this.operation ,required this.database ,required this.table ,required this.rowid ,});
+
+        @override
+        int get hashCode => operation.hashCode^database.hashCode^table.hashCode^rowid.hashCode;
+
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is LibsqlUpdate &&
+                runtimeType == other.runtimeType
+                && operation == other.operation&& database == other.database&& table == other.table&& rowid == other.rowid;
+
+            }
+
+enum LibsqlUpdateOperation {
+                    insert,
+update,
+delete,
+                    ;
+
+                }
@@ -0,0 +1,74 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.10.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+            class LibsqlError implements FrbException {
+                final LibsqlErrorKind kind;
+/// SQLite extended result code, when the failure came from SQLite
+final int? code;
+final String message;
+/// Offset of the offending token in the SQL text, if known
+final int? offset;
+/// Set on `SyncConflict` errors
+final LibsqlSyncConflict? conflict;
+
+                const LibsqlError({required this.kind ,this.code ,required this.message ,this.offset ,this.conflict ,});
+
+        @override
+        int get hashCode => kind.hashCode^code.hashCode^message.hashCode^offset.hashCode^conflict.hashCode;
+
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is LibsqlError &&
+                runtimeType == other.runtimeType
+                && kind == other.kind&& code == other.code&& message == other.message&& offset == other.offset&& conflict == other.conflict;
+
+            }
+
+enum LibsqlErrorKind {
+                    sql,
+constraint,
+busy,
+io,
+network,
+auth,
+sync_,
+misuse,
+closed,
+transactionFinished,
+interrupted,
+/// The remote rejected pushed changes, see `LibsqlError::conflict`
+syncConflict,
+                    ;
+
+                }
+
+/// Local frames of a synced database the remote refused because it moved
+/// on since they were written.
+class LibsqlSyncConflict  {
+                /// First frame of the rejected push, every later local frame is
+/// rejected as well
+final BigInt firstRejectedFrame;
+/// Last frame written locally
+final BigInt lastLocalFrame;
+/// Last frame the remote has committed
+final BigInt remoteFrameNo;
+
+                const LibsqlSyncConflict({required this.firstRejectedFrame ,required this.lastLocalFrame ,required this.remoteFrameNo ,});
+
+        @override
+        int get hashCode => firstRejectedFrame.hashCode^lastLocalFrame.hashCode^remoteFrameNo.hashCode;
+
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is LibsqlSyncConflict &&
+                runtimeType == other.runtimeType
+                && firstRejectedFrame == other.firstRejectedFrame&& lastLocalFrame == other.lastLocalFrame&& remoteFrameNo == other.remoteFrameNo;
+
+            }
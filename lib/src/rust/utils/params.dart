@@ -8,42 +8,69 @@ import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
 part 'params.freezed.dart';
 
-class LibsqlParams {
-  final List<LibsqlValue>? positional;
-  final Map<String, LibsqlValue>? named;
-
-  const LibsqlParams({
-    this.positional,
-    this.named,
-  });
-
-  @override
-  int get hashCode => positional.hashCode ^ named.hashCode;
-
-  @override
-  bool operator ==(Object other) =>
-      identical(this, other) ||
-      other is LibsqlParams &&
-          runtimeType == other.runtimeType &&
-          positional == other.positional &&
-          named == other.named;
-}
+            class LibsqlBatchStatement  {
+                final String sql;
+final LibsqlParams? parameters;
+
+                const LibsqlBatchStatement({required this.sql ,this.parameters ,});
+
+        @override
+        int get hashCode => sql.hashCode^parameters.hashCode;
+
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is LibsqlBatchStatement &&
+                runtimeType == other.runtimeType
+                && sql == other.sql&& parameters == other.parameters;
+
+            }
+
+/// How `LibsqlValue::DateTime` is written to the database.
+enum LibsqlDateTimeFormat {
+                    /// Integer milliseconds since the unix epoch
+unixMillis,
+/// ISO-8601 text in UTC, e.g. `2024-01-31T12:00:00.000Z`
+iso8601,
+                    ;
+
+                }
+
+class LibsqlParams  {
+                final List<LibsqlValue>? positional;
+final Map<String, LibsqlValue>? named;
+
+                const LibsqlParams({this.positional ,this.named ,});
+
+        @override
+        int get hashCode => positional.hashCode^named.hashCode;
+
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is LibsqlParams &&
+                runtimeType == other.runtimeType
+                && positional == other.positional&& named == other.named;
+
+            }
 
 @freezed
-sealed class LibsqlValue with _$LibsqlValue {
-  const LibsqlValue._();
-
-  const factory LibsqlValue.integer(
-    PlatformInt64 field0,
-  ) = LibsqlValue_Integer;
-  const factory LibsqlValue.real(
-    double field0,
-  ) = LibsqlValue_Real;
-  const factory LibsqlValue.text(
-    String field0,
-  ) = LibsqlValue_Text;
-  const factory LibsqlValue.blob(
-    Uint8List field0,
-  ) = LibsqlValue_Blob;
-  const factory LibsqlValue.null_() = LibsqlValue_Null;
-}
+                sealed class LibsqlValue with _$LibsqlValue  {
+                    const LibsqlValue._();
+
+                     const factory LibsqlValue.integer(  PlatformInt64 field0,) = LibsqlValue_Integer;
+ const factory LibsqlValue.real(  double field0,) = LibsqlValue_Real;
+ const factory LibsqlValue.text(  String field0,) = LibsqlValue_Text;
+ const factory LibsqlValue.blob(  Uint8List field0,) = LibsqlValue_Blob;
+ const factory LibsqlValue.null_() = LibsqlValue_Null;
+ /// Stored as the integer 0 or 1
+const factory LibsqlValue.bool(  bool field0,) = LibsqlValue_Bool;
+ const factory LibsqlValue.dateTime({   required DateTime value ,  required LibsqlDateTimeFormat format , }) = LibsqlValue_DateTime;
+ /// Decimal digits of an integer, stored as an integer. Fails with a
+ /// `Misuse` error when it does not fit in 64 bits, rather than being
+ /// stored as text that no longer compares as a number.
+const factory LibsqlValue.bigInt(  String field0,) = LibsqlValue_BigInt;
+ /// JSON text, stored as-is
+const factory LibsqlValue.json(  String field0,) = LibsqlValue_Json;
+
+                }
@@ -23,6 +23,11 @@ mixin _$LibsqlValue {
     required TResult Function(String field0) text,
     required TResult Function(Uint8List field0) blob,
     required TResult Function() null_,
+    required TResult Function(bool field0) bool,
+    required TResult Function(DateTime value, LibsqlDateTimeFormat format)
+        dateTime,
+    required TResult Function(String field0) bigInt,
+    required TResult Function(String field0) json,
   }) =>
       throw _privateConstructorUsedError;
   @optionalTypeArgs
@@ -32,6 +37,10 @@ mixin _$LibsqlValue {
     TResult? Function(String field0)? text,
     TResult? Function(Uint8List field0)? blob,
     TResult? Function()? null_,
+    TResult? Function(bool field0)? bool,
+    TResult? Function(DateTime value, LibsqlDateTimeFormat format)? dateTime,
+    TResult? Function(String field0)? bigInt,
+    TResult? Function(String field0)? json,
   }) =>
       throw _privateConstructorUsedError;
   @optionalTypeArgs
@@ -41,6 +50,10 @@ mixin _$LibsqlValue {
     TResult Function(String field0)? text,
     TResult Function(Uint8List field0)? blob,
     TResult Function()? null_,
+    TResult Function(bool field0)? bool,
+    TResult Function(DateTime value, LibsqlDateTimeFormat format)? dateTime,
+    TResult Function(String field0)? bigInt,
+    TResult Function(String field0)? json,
     required TResult orElse(),
   }) =>
       throw _privateConstructorUsedError;
@@ -51,6 +64,10 @@ mixin _$LibsqlValue {
     required TResult Function(LibsqlValue_Text value) text,
     required TResult Function(LibsqlValue_Blob value) blob,
     required TResult Function(LibsqlValue_Null value) null_,
+    required TResult Function(LibsqlValue_Bool value) bool,
+    required TResult Function(LibsqlValue_DateTime value) dateTime,
+    required TResult Function(LibsqlValue_BigInt value) bigInt,
+    required TResult Function(LibsqlValue_Json value) json,
   }) =>
       throw _privateConstructorUsedError;
   @optionalTypeArgs
@@ -60,6 +77,10 @@ mixin _$LibsqlValue {
     TResult? Function(LibsqlValue_Text value)? text,
     TResult? Function(LibsqlValue_Blob value)? blob,
     TResult? Function(LibsqlValue_Null value)? null_,
+    TResult? Function(LibsqlValue_Bool value)? bool,
+    TResult? Function(LibsqlValue_DateTime value)? dateTime,
+    TResult? Function(LibsqlValue_BigInt value)? bigInt,
+    TResult? Function(LibsqlValue_Json value)? json,
   }) =>
       throw _privateConstructorUsedError;
   @optionalTypeArgs
@@ -69,6 +90,10 @@ mixin _$LibsqlValue {
     TResult Function(LibsqlValue_Text value)? text,
     TResult Function(LibsqlValue_Blob value)? blob,
     TResult Function(LibsqlValue_Null value)? null_,
+    TResult Function(LibsqlValue_Bool value)? bool,
+    TResult Function(LibsqlValue_DateTime value)? dateTime,
+    TResult Function(LibsqlValue_BigInt value)? bigInt,
+    TResult Function(LibsqlValue_Json value)? json,
     required TResult orElse(),
   }) =>
       throw _privateConstructorUsedError;
@@ -169,6 +194,11 @@ class _$LibsqlValue_IntegerImpl extends LibsqlValue_Integer {
     required TResult Function(String field0) text,
     required TResult Function(Uint8List field0) blob,
     required TResult Function() null_,
+    required TResult Function(bool field0) bool,
+    required TResult Function(DateTime value, LibsqlDateTimeFormat format)
+        dateTime,
+    required TResult Function(String field0) bigInt,
+    required TResult Function(String field0) json,
   }) {
     return integer(field0);
   }
@@ -181,6 +211,10 @@ class _$LibsqlValue_IntegerImpl extends LibsqlValue_Integer {
     TResult? Function(String field0)? text,
     TResult? Function(Uint8List field0)? blob,
     TResult? Function()? null_,
+    TResult? Function(bool field0)? bool,
+    TResult? Function(DateTime value, LibsqlDateTimeFormat format)? dateTime,
+    TResult? Function(String field0)? bigInt,
+    TResult? Function(String field0)? json,
   }) {
     return integer?.call(field0);
   }
@@ -193,6 +227,10 @@ class _$LibsqlValue_IntegerImpl extends LibsqlValue_Integer {
     TResult Function(String field0)? text,
     TResult Function(Uint8List field0)? blob,
     TResult Function()? null_,
+    TResult Function(bool field0)? bool,
+    TResult Function(DateTime value, LibsqlDateTimeFormat format)? dateTime,
+    TResult Function(String field0)? bigInt,
+    TResult Function(String field0)? json,
     required TResult orElse(),
   }) {
     if (integer != null) {
@@ -209,6 +247,10 @@ class _$LibsqlValue_IntegerImpl extends LibsqlValue_Integer {
     required TResult Function(LibsqlValue_Text value) text,
     required TResult Function(LibsqlValue_Blob value) blob,
     required TResult Function(LibsqlValue_Null value) null_,
+    required TResult Function(LibsqlValue_Bool value) bool,
+    required TResult Function(LibsqlValue_DateTime value) dateTime,
+    required TResult Function(LibsqlValue_BigInt value) bigInt,
+    required TResult Function(LibsqlValue_Json value) json,
   }) {
     return integer(this);
   }
@@ -221,6 +263,10 @@ class _$LibsqlValue_IntegerImpl extends LibsqlValue_Integer {
     TResult? Function(LibsqlValue_Text value)? text,
     TResult? Function(LibsqlValue_Blob value)? blob,
     TResult? Function(LibsqlValue_Null value)? null_,
+    TResult? Function(LibsqlValue_Bool value)? bool,
+    TResult? Function(LibsqlValue_DateTime value)? dateTime,
+    TResult? Function(LibsqlValue_BigInt value)? bigInt,
+    TResult? Function(LibsqlValue_Json value)? json,
   }) {
     return integer?.call(this);
   }
@@ -233,6 +279,10 @@ class _$LibsqlValue_IntegerImpl extends LibsqlValue_Integer {
     TResult Function(LibsqlValue_Text value)? text,
     TResult Function(LibsqlValue_Blob value)? blob,
     TResult Function(LibsqlValue_Null value)? null_,
+    TResult Function(LibsqlValue_Bool value)? bool,
+    TResult Function(LibsqlValue_DateTime value)? dateTime,
+    TResult Function(LibsqlValue_BigInt value)? bigInt,
+    TResult Function(LibsqlValue_Json value)? json,
     required TResult orElse(),
   }) {
     if (integer != null) {
@@ -330,6 +380,11 @@ class _$LibsqlValue_RealImpl extends LibsqlValue_Real {
     required TResult Function(String field0) text,
     required TResult Function(Uint8List field0) blob,
     required TResult Function() null_,
+    required TResult Function(bool field0) bool,
+    required TResult Function(DateTime value, LibsqlDateTimeFormat format)
+        dateTime,
+    required TResult Function(String field0) bigInt,
+    required TResult Function(String field0) json,
   }) {
     return real(field0);
   }
@@ -342,6 +397,10 @@ class _$LibsqlValue_RealImpl extends LibsqlValue_Real {
     TResult? Function(String field0)? text,
     TResult? Function(Uint8List field0)? blob,
     TResult? Function()? null_,
+    TResult? Function(bool field0)? bool,
+    TResult? Function(DateTime value, LibsqlDateTimeFormat format)? dateTime,
+    TResult? Function(String field0)? bigInt,
+    TResult? Function(String field0)? json,
   }) {
     return real?.call(field0);
   }
@@ -354,6 +413,10 @@ class _$LibsqlValue_RealImpl extends LibsqlValue_Real {
     TResult Function(String field0)? text,
     TResult Function(Uint8List field0)? blob,
     TResult Function()? null_,
+    TResult Function(bool field0)? bool,
+    TResult Function(DateTime value, LibsqlDateTimeFormat format)? dateTime,
+    TResult Function(String field0)? bigInt,
+    TResult Function(String field0)? json,
     required TResult orElse(),
   }) {
     if (real != null) {
@@ -370,6 +433,10 @@ class _$LibsqlValue_RealImpl extends LibsqlValue_Real {
     required TResult Function(LibsqlValue_Text value) text,
     required TResult Function(LibsqlValue_Blob value) blob,
     required TResult Function(LibsqlValue_Null value) null_,
+    required TResult Function(LibsqlValue_Bool value) bool,
+    required TResult Function(LibsqlValue_DateTime value) dateTime,
+    required TResult Function(LibsqlValue_BigInt value) bigInt,
+    required TResult Function(LibsqlValue_Json value) json,
   }) {
     return real(this);
   }
@@ -382,6 +449,10 @@ class _$LibsqlValue_RealImpl extends LibsqlValue_Real {
     TResult? Function(LibsqlValue_Text value)? text,
     TResult? Function(LibsqlValue_Blob value)? blob,
     TResult? Function(LibsqlValue_Null value)? null_,
+    TResult? Function(LibsqlValue_Bool value)? bool,
+    TResult? Function(LibsqlValue_DateTime value)? dateTime,
+    TResult? Function(LibsqlValue_BigInt value)? bigInt,
+    TResult? Function(LibsqlValue_Json value)? json,
   }) {
     return real?.call(this);
   }
@@ -394,6 +465,10 @@ class _$LibsqlValue_RealImpl extends LibsqlValue_Real {
     TResult Function(LibsqlValue_Text value)? text,
     TResult Function(LibsqlValue_Blob value)? blob,
     TResult Function(LibsqlValue_Null value)? null_,
+    TResult Function(LibsqlValue_Bool value)? bool,
+    TResult Function(LibsqlValue_DateTime value)? dateTime,
+    TResult Function(LibsqlValue_BigInt value)? bigInt,
+    TResult Function(LibsqlValue_Json value)? json,
     required TResult orElse(),
   }) {
     if (real != null) {
@@ -490,6 +565,11 @@ class _$LibsqlValue_TextImpl extends LibsqlValue_Text {
     required TResult Function(String field0) text,
     required TResult Function(Uint8List field0) blob,
     required TResult Function() null_,
+    required TResult Function(bool field0) bool,
+    required TResult Function(DateTime value, LibsqlDateTimeFormat format)
+        dateTime,
+    required TResult Function(String field0) bigInt,
+    required TResult Function(String field0) json,
   }) {
     return text(field0);
   }
@@ -502,6 +582,10 @@ class _$LibsqlValue_TextImpl extends LibsqlValue_Text {
     TResult? Function(String field0)? text,
     TResult? Function(Uint8List field0)? blob,
     TResult? Function()? null_,
+    TResult? Function(bool field0)? bool,
+    TResult? Function(DateTime value, LibsqlDateTimeFormat format)? dateTime,
+    TResult? Function(String field0)? bigInt,
+    TResult? Function(String field0)? json,
   }) {
     return text?.call(field0);
   }
@@ -514,6 +598,10 @@ class _$LibsqlValue_TextImpl extends LibsqlValue_Text {
     TResult Function(String field0)? text,
     TResult Function(Uint8List field0)? blob,
     TResult Function()? null_,
+    TResult Function(bool field0)? bool,
+    TResult Function(DateTime value, LibsqlDateTimeFormat format)? dateTime,
+    TResult Function(String field0)? bigInt,
+    TResult Function(String field0)? json,
     required TResult orElse(),
   }) {
     if (text != null) {
@@ -530,6 +618,10 @@ class _$LibsqlValue_TextImpl extends LibsqlValue_Text {
     required TResult Function(LibsqlValue_Text value) text,
     required TResult Function(LibsqlValue_Blob value) blob,
     required TResult Function(LibsqlValue_Null value) null_,
+    required TResult Function(LibsqlValue_Bool value) bool,
+    required TResult Function(LibsqlValue_DateTime value) dateTime,
+    required TResult Function(LibsqlValue_BigInt value) bigInt,
+    required TResult Function(LibsqlValue_Json value) json,
   }) {
     return text(this);
   }
@@ -542,6 +634,10 @@ class _$LibsqlValue_TextImpl extends LibsqlValue_Text {
     TResult? Function(LibsqlValue_Text value)? text,
     TResult? Function(LibsqlValue_Blob value)? blob,
     TResult? Function(LibsqlValue_Null value)? null_,
+    TResult? Function(LibsqlValue_Bool value)? bool,
+    TResult? Function(LibsqlValue_DateTime value)? dateTime,
+    TResult? Function(LibsqlValue_BigInt value)? bigInt,
+    TResult? Function(LibsqlValue_Json value)? json,
   }) {
     return text?.call(this);
   }
@@ -554,6 +650,10 @@ class _$LibsqlValue_TextImpl extends LibsqlValue_Text {
     TResult Function(LibsqlValue_Text value)? text,
     TResult Function(LibsqlValue_Blob value)? blob,
     TResult Function(LibsqlValue_Null value)? null_,
+    TResult Function(LibsqlValue_Bool value)? bool,
+    TResult Function(LibsqlValue_DateTime value)? dateTime,
+    TResult Function(LibsqlValue_BigInt value)? bigInt,
+    TResult Function(LibsqlValue_Json value)? json,
     required TResult orElse(),
   }) {
     if (text != null) {
@@ -651,6 +751,11 @@ class _$LibsqlValue_BlobImpl extends LibsqlValue_Blob {
     required TResult Function(String field0) text,
     required TResult Function(Uint8List field0) blob,
     required TResult Function() null_,
+    required TResult Function(bool field0) bool,
+    required TResult Function(DateTime value, LibsqlDateTimeFormat format)
+        dateTime,
+    required TResult Function(String field0) bigInt,
+    required TResult Function(String field0) json,
   }) {
     return blob(field0);
   }
@@ -663,6 +768,10 @@ class _$LibsqlValue_BlobImpl extends LibsqlValue_Blob {
     TResult? Function(String field0)? text,
     TResult? Function(Uint8List field0)? blob,
     TResult? Function()? null_,
+    TResult? Function(bool field0)? bool,
+    TResult? Function(DateTime value, LibsqlDateTimeFormat format)? dateTime,
+    TResult? Function(String field0)? bigInt,
+    TResult? Function(String field0)? json,
   }) {
     return blob?.call(field0);
   }
@@ -675,6 +784,10 @@ class _$LibsqlValue_BlobImpl extends LibsqlValue_Blob {
     TResult Function(String field0)? text,
     TResult Function(Uint8List field0)? blob,
     TResult Function()? null_,
+    TResult Function(bool field0)? bool,
+    TResult Function(DateTime value, LibsqlDateTimeFormat format)? dateTime,
+    TResult Function(String field0)? bigInt,
+    TResult Function(String field0)? json,
     required TResult orElse(),
   }) {
     if (blob != null) {
@@ -691,6 +804,10 @@ class _$LibsqlValue_BlobImpl extends LibsqlValue_Blob {
     required TResult Function(LibsqlValue_Text value) text,
     required TResult Function(LibsqlValue_Blob value) blob,
     required TResult Function(LibsqlValue_Null value) null_,
+    required TResult Function(LibsqlValue_Bool value) bool,
+    required TResult Function(LibsqlValue_DateTime value) dateTime,
+    required TResult Function(LibsqlValue_BigInt value) bigInt,
+    required TResult Function(LibsqlValue_Json value) json,
   }) {
     return blob(this);
   }
@@ -703,6 +820,10 @@ class _$LibsqlValue_BlobImpl extends LibsqlValue_Blob {
     TResult? Function(LibsqlValue_Text value)? text,
     TResult? Function(LibsqlValue_Blob value)? blob,
     TResult? Function(LibsqlValue_Null value)? null_,
+    TResult? Function(LibsqlValue_Bool value)? bool,
+    TResult? Function(LibsqlValue_DateTime value)? dateTime,
+    TResult? Function(LibsqlValue_BigInt value)? bigInt,
+    TResult? Function(LibsqlValue_Json value)? json,
   }) {
     return blob?.call(this);
   }
@@ -715,6 +836,10 @@ class _$LibsqlValue_BlobImpl extends LibsqlValue_Blob {
     TResult Function(LibsqlValue_Text value)? text,
     TResult Function(LibsqlValue_Blob value)? blob,
     TResult Function(LibsqlValue_Null value)? null_,
+    TResult Function(LibsqlValue_Bool value)? bool,
+    TResult Function(LibsqlValue_DateTime value)? dateTime,
+    TResult Function(LibsqlValue_BigInt value)? bigInt,
+    TResult Function(LibsqlValue_Json value)? json,
     required TResult orElse(),
   }) {
     if (blob != null) {
@@ -784,6 +909,11 @@ class _$LibsqlValue_NullImpl extends LibsqlValue_Null {
     required TResult Function(String field0) text,
     required TResult Function(Uint8List field0) blob,
     required TResult Function() null_,
+    required TResult Function(bool field0) bool,
+    required TResult Function(DateTime value, LibsqlDateTimeFormat format)
+        dateTime,
+    required TResult Function(String field0) bigInt,
+    required TResult Function(String field0) json,
   }) {
     return null_();
   }
@@ -796,6 +926,10 @@ class _$LibsqlValue_NullImpl extends LibsqlValue_Null {
     TResult? Function(String field0)? text,
     TResult? Function(Uint8List field0)? blob,
     TResult? Function()? null_,
+    TResult? Function(bool field0)? bool,
+    TResult? Function(DateTime value, LibsqlDateTimeFormat format)? dateTime,
+    TResult? Function(String field0)? bigInt,
+    TResult? Function(String field0)? json,
   }) {
     return null_?.call();
   }
@@ -808,6 +942,10 @@ class _$LibsqlValue_NullImpl extends LibsqlValue_Null {
     TResult Function(String field0)? text,
     TResult Function(Uint8List field0)? blob,
     TResult Function()? null_,
+    TResult Function(bool field0)? bool,
+    TResult Function(DateTime value, LibsqlDateTimeFormat format)? dateTime,
+    TResult Function(String field0)? bigInt,
+    TResult Function(String field0)? json,
     required TResult orElse(),
   }) {
     if (null_ != null) {
@@ -824,6 +962,10 @@ class _$LibsqlValue_NullImpl extends LibsqlValue_Null {
     required TResult Function(LibsqlValue_Text value) text,
     required TResult Function(LibsqlValue_Blob value) blob,
     required TResult Function(LibsqlValue_Null value) null_,
+    required TResult Function(LibsqlValue_Bool value) bool,
+    required TResult Function(LibsqlValue_DateTime value) dateTime,
+    required TResult Function(LibsqlValue_BigInt value) bigInt,
+    required TResult Function(LibsqlValue_Json value) json,
   }) {
     return null_(this);
   }
@@ -836,6 +978,10 @@ class _$LibsqlValue_NullImpl extends LibsqlValue_Null {
     TResult? Function(LibsqlValue_Text value)? text,
     TResult? Function(LibsqlValue_Blob value)? blob,
     TResult? Function(LibsqlValue_Null value)? null_,
+    TResult? Function(LibsqlValue_Bool value)? bool,
+    TResult? Function(LibsqlValue_DateTime value)? dateTime,
+    TResult? Function(LibsqlValue_BigInt value)? bigInt,
+    TResult? Function(LibsqlValue_Json value)? json,
   }) {
     return null_?.call(this);
   }
@@ -848,6 +994,10 @@ class _$LibsqlValue_NullImpl extends LibsqlValue_Null {
     TResult Function(LibsqlValue_Text value)? text,
     TResult Function(LibsqlValue_Blob value)? blob,
     TResult Function(LibsqlValue_Null value)? null_,
+    TResult Function(LibsqlValue_Bool value)? bool,
+    TResult Function(LibsqlValue_DateTime value)? dateTime,
+    TResult Function(LibsqlValue_BigInt value)? bigInt,
+    TResult Function(LibsqlValue_Json value)? json,
     required TResult orElse(),
   }) {
     if (null_ != null) {
@@ -861,3 +1011,756 @@ abstract class LibsqlValue_Null extends LibsqlValue {
   const factory LibsqlValue_Null() = _$LibsqlValue_NullImpl;
   const LibsqlValue_Null._() : super._();
 }
+
+/// @nodoc
+abstract class _$$LibsqlValue_BoolImplCopyWith<$Res> {
+  factory _$$LibsqlValue_BoolImplCopyWith(_$LibsqlValue_BoolImpl value,
+          $Res Function(_$LibsqlValue_BoolImpl) then) =
+      __$$LibsqlValue_BoolImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({bool field0});
+}
+
+/// @nodoc
+class __$$LibsqlValue_BoolImplCopyWithImpl<$Res>
+    extends _$LibsqlValueCopyWithImpl<$Res, _$LibsqlValue_BoolImpl>
+    implements _$$LibsqlValue_BoolImplCopyWith<$Res> {
+  __$$LibsqlValue_BoolImplCopyWithImpl(_$LibsqlValue_BoolImpl _value,
+      $Res Function(_$LibsqlValue_BoolImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of LibsqlValue
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? field0 = null,
+  }) {
+    return _then(_$LibsqlValue_BoolImpl(
+      null == field0
+          ? _value.field0
+          : field0 // ignore: cast_nullable_to_non_nullable
+              as bool,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$LibsqlValue_BoolImpl extends LibsqlValue_Bool {
+  const _$LibsqlValue_BoolImpl(this.field0) : super._();
+
+  @override
+  final bool field0;
+
+  @override
+  String toString() {
+    return 'LibsqlValue.bool(field0: $field0)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$LibsqlValue_BoolImpl &&
+            (identical(other.field0, field0) || other.field0 == field0));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, field0);
+
+  /// Create a copy of LibsqlValue
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$LibsqlValue_BoolImplCopyWith<_$LibsqlValue_BoolImpl> get copyWith =>
+      __$$LibsqlValue_BoolImplCopyWithImpl<_$LibsqlValue_BoolImpl>(
+          this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(int field0) integer,
+    required TResult Function(double field0) real,
+    required TResult Function(String field0) text,
+    required TResult Function(Uint8List field0) blob,
+    required TResult Function() null_,
+    required TResult Function(bool field0) bool,
+    required TResult Function(DateTime value, LibsqlDateTimeFormat format)
+        dateTime,
+    required TResult Function(String field0) bigInt,
+    required TResult Function(String field0) json,
+  }) {
+    return bool(field0);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(int field0)? integer,
+    TResult? Function(double field0)? real,
+    TResult? Function(String field0)? text,
+    TResult? Function(Uint8List field0)? blob,
+    TResult? Function()? null_,
+    TResult? Function(bool field0)? bool,
+    TResult? Function(DateTime value, LibsqlDateTimeFormat format)? dateTime,
+    TResult? Function(String field0)? bigInt,
+    TResult? Function(String field0)? json,
+  }) {
+    return bool?.call(field0);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(int field0)? integer,
+    TResult Function(double field0)? real,
+    TResult Function(String field0)? text,
+    TResult Function(Uint8List field0)? blob,
+    TResult Function()? null_,
+    TResult Function(bool field0)? bool,
+    TResult Function(DateTime value, LibsqlDateTimeFormat format)? dateTime,
+    TResult Function(String field0)? bigInt,
+    TResult Function(String field0)? json,
+    required TResult orElse(),
+  }) {
+    if (bool != null) {
+      return bool(field0);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(LibsqlValue_Integer value) integer,
+    required TResult Function(LibsqlValue_Real value) real,
+    required TResult Function(LibsqlValue_Text value) text,
+    required TResult Function(LibsqlValue_Blob value) blob,
+    required TResult Function(LibsqlValue_Null value) null_,
+    required TResult Function(LibsqlValue_Bool value) bool,
+    required TResult Function(LibsqlValue_DateTime value) dateTime,
+    required TResult Function(LibsqlValue_BigInt value) bigInt,
+    required TResult Function(LibsqlValue_Json value) json,
+  }) {
+    return bool(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(LibsqlValue_Integer value)? integer,
+    TResult? Function(LibsqlValue_Real value)? real,
+    TResult? Function(LibsqlValue_Text value)? text,
+    TResult? Function(LibsqlValue_Blob value)? blob,
+    TResult? Function(LibsqlValue_Null value)? null_,
+    TResult? Function(LibsqlValue_Bool value)? bool,
+    TResult? Function(LibsqlValue_DateTime value)? dateTime,
+    TResult? Function(LibsqlValue_BigInt value)? bigInt,
+    TResult? Function(LibsqlValue_Json value)? json,
+  }) {
+    return bool?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(LibsqlValue_Integer value)? integer,
+    TResult Function(LibsqlValue_Real value)? real,
+    TResult Function(LibsqlValue_Text value)? text,
+    TResult Function(LibsqlValue_Blob value)? blob,
+    TResult Function(LibsqlValue_Null value)? null_,
+    TResult Function(LibsqlValue_Bool value)? bool,
+    TResult Function(LibsqlValue_DateTime value)? dateTime,
+    TResult Function(LibsqlValue_BigInt value)? bigInt,
+    TResult Function(LibsqlValue_Json value)? json,
+    required TResult orElse(),
+  }) {
+    if (bool != null) {
+      return bool(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class LibsqlValue_Bool extends LibsqlValue {
+  const factory LibsqlValue_Bool(final bool field0) = _$LibsqlValue_BoolImpl;
+  const LibsqlValue_Bool._() : super._();
+
+  bool get field0;
+
+  /// Create a copy of LibsqlValue
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$LibsqlValue_BoolImplCopyWith<_$LibsqlValue_BoolImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$LibsqlValue_DateTimeImplCopyWith<$Res> {
+  factory _$$LibsqlValue_DateTimeImplCopyWith(_$LibsqlValue_DateTimeImpl value,
+          $Res Function(_$LibsqlValue_DateTimeImpl) then) =
+      __$$LibsqlValue_DateTimeImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({DateTime value, LibsqlDateTimeFormat format});
+}
+
+/// @nodoc
+class __$$LibsqlValue_DateTimeImplCopyWithImpl<$Res>
+    extends _$LibsqlValueCopyWithImpl<$Res, _$LibsqlValue_DateTimeImpl>
+    implements _$$LibsqlValue_DateTimeImplCopyWith<$Res> {
+  __$$LibsqlValue_DateTimeImplCopyWithImpl(_$LibsqlValue_DateTimeImpl _value,
+      $Res Function(_$LibsqlValue_DateTimeImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of LibsqlValue
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? value = null,
+    Object? format = null,
+  }) {
+    return _then(_$LibsqlValue_DateTimeImpl(
+      value: null == value
+          ? _value.value
+          : value // ignore: cast_nullable_to_non_nullable
+              as DateTime,
+      format: null == format
+          ? _value.format
+          : format // ignore: cast_nullable_to_non_nullable
+              as LibsqlDateTimeFormat,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$LibsqlValue_DateTimeImpl extends LibsqlValue_DateTime {
+  const _$LibsqlValue_DateTimeImpl({required this.value, required this.format})
+      : super._();
+
+  @override
+  final DateTime value;
+  @override
+  final LibsqlDateTimeFormat format;
+
+  @override
+  String toString() {
+    return 'LibsqlValue.dateTime(value: $value, format: $format)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$LibsqlValue_DateTimeImpl &&
+            (identical(other.value, value) || other.value == value) &&
+            (identical(other.format, format) || other.format == format));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, value, format);
+
+  /// Create a copy of LibsqlValue
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$LibsqlValue_DateTimeImplCopyWith<_$LibsqlValue_DateTimeImpl>
+      get copyWith => __$$LibsqlValue_DateTimeImplCopyWithImpl<
+          _$LibsqlValue_DateTimeImpl>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(int field0) integer,
+    required TResult Function(double field0) real,
+    required TResult Function(String field0) text,
+    required TResult Function(Uint8List field0) blob,
+    required TResult Function() null_,
+    required TResult Function(bool field0) bool,
+    required TResult Function(DateTime value, LibsqlDateTimeFormat format)
+        dateTime,
+    required TResult Function(String field0) bigInt,
+    required TResult Function(String field0) json,
+  }) {
+    return dateTime(value, format);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(int field0)? integer,
+    TResult? Function(double field0)? real,
+    TResult? Function(String field0)? text,
+    TResult? Function(Uint8List field0)? blob,
+    TResult? Function()? null_,
+    TResult? Function(bool field0)? bool,
+    TResult? Function(DateTime value, LibsqlDateTimeFormat format)? dateTime,
+    TResult? Function(String field0)? bigInt,
+    TResult? Function(String field0)? json,
+  }) {
+    return dateTime?.call(value, format);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(int field0)? integer,
+    TResult Function(double field0)? real,
+    TResult Function(String field0)? text,
+    TResult Function(Uint8List field0)? blob,
+    TResult Function()? null_,
+    TResult Function(bool field0)? bool,
+    TResult Function(DateTime value, LibsqlDateTimeFormat format)? dateTime,
+    TResult Function(String field0)? bigInt,
+    TResult Function(String field0)? json,
+    required TResult orElse(),
+  }) {
+    if (dateTime != null) {
+      return dateTime(value, format);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(LibsqlValue_Integer value) integer,
+    required TResult Function(LibsqlValue_Real value) real,
+    required TResult Function(LibsqlValue_Text value) text,
+    required TResult Function(LibsqlValue_Blob value) blob,
+    required TResult Function(LibsqlValue_Null value) null_,
+    required TResult Function(LibsqlValue_Bool value) bool,
+    required TResult Function(LibsqlValue_DateTime value) dateTime,
+    required TResult Function(LibsqlValue_BigInt value) bigInt,
+    required TResult Function(LibsqlValue_Json value) json,
+  }) {
+    return dateTime(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(LibsqlValue_Integer value)? integer,
+    TResult? Function(LibsqlValue_Real value)? real,
+    TResult? Function(LibsqlValue_Text value)? text,
+    TResult? Function(LibsqlValue_Blob value)? blob,
+    TResult? Function(LibsqlValue_Null value)? null_,
+    TResult? Function(LibsqlValue_Bool value)? bool,
+    TResult? Function(LibsqlValue_DateTime value)? dateTime,
+    TResult? Function(LibsqlValue_BigInt value)? bigInt,
+    TResult? Function(LibsqlValue_Json value)? json,
+  }) {
+    return dateTime?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(LibsqlValue_Integer value)? integer,
+    TResult Function(LibsqlValue_Real value)? real,
+    TResult Function(LibsqlValue_Text value)? text,
+    TResult Function(LibsqlValue_Blob value)? blob,
+    TResult Function(LibsqlValue_Null value)? null_,
+    TResult Function(LibsqlValue_Bool value)? bool,
+    TResult Function(LibsqlValue_DateTime value)? dateTime,
+    TResult Function(LibsqlValue_BigInt value)? bigInt,
+    TResult Function(LibsqlValue_Json value)? json,
+    required TResult orElse(),
+  }) {
+    if (dateTime != null) {
+      return dateTime(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class LibsqlValue_DateTime extends LibsqlValue {
+  const factory LibsqlValue_DateTime(
+      {required final DateTime value,
+      required final LibsqlDateTimeFormat format}) = _$LibsqlValue_DateTimeImpl;
+  const LibsqlValue_DateTime._() : super._();
+
+  DateTime get value;
+  LibsqlDateTimeFormat get format;
+
+  /// Create a copy of LibsqlValue
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$LibsqlValue_DateTimeImplCopyWith<_$LibsqlValue_DateTimeImpl>
+      get copyWith => throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$LibsqlValue_BigIntImplCopyWith<$Res> {
+  factory _$$LibsqlValue_BigIntImplCopyWith(_$LibsqlValue_BigIntImpl value,
+          $Res Function(_$LibsqlValue_BigIntImpl) then) =
+      __$$LibsqlValue_BigIntImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({String field0});
+}
+
+/// @nodoc
+class __$$LibsqlValue_BigIntImplCopyWithImpl<$Res>
+    extends _$LibsqlValueCopyWithImpl<$Res, _$LibsqlValue_BigIntImpl>
+    implements _$$LibsqlValue_BigIntImplCopyWith<$Res> {
+  __$$LibsqlValue_BigIntImplCopyWithImpl(_$LibsqlValue_BigIntImpl _value,
+      $Res Function(_$LibsqlValue_BigIntImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of LibsqlValue
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? field0 = null,
+  }) {
+    return _then(_$LibsqlValue_BigIntImpl(
+      null == field0
+          ? _value.field0
+          : field0 // ignore: cast_nullable_to_non_nullable
+              as String,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$LibsqlValue_BigIntImpl extends LibsqlValue_BigInt {
+  const _$LibsqlValue_BigIntImpl(this.field0) : super._();
+
+  @override
+  final String field0;
+
+  @override
+  String toString() {
+    return 'LibsqlValue.bigInt(field0: $field0)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$LibsqlValue_BigIntImpl &&
+            (identical(other.field0, field0) || other.field0 == field0));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, field0);
+
+  /// Create a copy of LibsqlValue
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$LibsqlValue_BigIntImplCopyWith<_$LibsqlValue_BigIntImpl> get copyWith =>
+      __$$LibsqlValue_BigIntImplCopyWithImpl<_$LibsqlValue_BigIntImpl>(
+          this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(int field0) integer,
+    required TResult Function(double field0) real,
+    required TResult Function(String field0) text,
+    required TResult Function(Uint8List field0) blob,
+    required TResult Function() null_,
+    required TResult Function(bool field0) bool,
+    required TResult Function(DateTime value, LibsqlDateTimeFormat format)
+        dateTime,
+    required TResult Function(String field0) bigInt,
+    required TResult Function(String field0) json,
+  }) {
+    return bigInt(field0);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(int field0)? integer,
+    TResult? Function(double field0)? real,
+    TResult? Function(String field0)? text,
+    TResult? Function(Uint8List field0)? blob,
+    TResult? Function()? null_,
+    TResult? Function(bool field0)? bool,
+    TResult? Function(DateTime value, LibsqlDateTimeFormat format)? dateTime,
+    TResult? Function(String field0)? bigInt,
+    TResult? Function(String field0)? json,
+  }) {
+    return bigInt?.call(field0);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(int field0)? integer,
+    TResult Function(double field0)? real,
+    TResult Function(String field0)? text,
+    TResult Function(Uint8List field0)? blob,
+    TResult Function()? null_,
+    TResult Function(bool field0)? bool,
+    TResult Function(DateTime value, LibsqlDateTimeFormat format)? dateTime,
+    TResult Function(String field0)? bigInt,
+    TResult Function(String field0)? json,
+    required TResult orElse(),
+  }) {
+    if (bigInt != null) {
+      return bigInt(field0);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(LibsqlValue_Integer value) integer,
+    required TResult Function(LibsqlValue_Real value) real,
+    required TResult Function(LibsqlValue_Text value) text,
+    required TResult Function(LibsqlValue_Blob value) blob,
+    required TResult Function(LibsqlValue_Null value) null_,
+    required TResult Function(LibsqlValue_Bool value) bool,
+    required TResult Function(LibsqlValue_DateTime value) dateTime,
+    required TResult Function(LibsqlValue_BigInt value) bigInt,
+    required TResult Function(LibsqlValue_Json value) json,
+  }) {
+    return bigInt(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(LibsqlValue_Integer value)? integer,
+    TResult? Function(LibsqlValue_Real value)? real,
+    TResult? Function(LibsqlValue_Text value)? text,
+    TResult? Function(LibsqlValue_Blob value)? blob,
+    TResult? Function(LibsqlValue_Null value)? null_,
+    TResult? Function(LibsqlValue_Bool value)? bool,
+    TResult? Function(LibsqlValue_DateTime value)? dateTime,
+    TResult? Function(LibsqlValue_BigInt value)? bigInt,
+    TResult? Function(LibsqlValue_Json value)? json,
+  }) {
+    return bigInt?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(LibsqlValue_Integer value)? integer,
+    TResult Function(LibsqlValue_Real value)? real,
+    TResult Function(LibsqlValue_Text value)? text,
+    TResult Function(LibsqlValue_Blob value)? blob,
+    TResult Function(LibsqlValue_Null value)? null_,
+    TResult Function(LibsqlValue_Bool value)? bool,
+    TResult Function(LibsqlValue_DateTime value)? dateTime,
+    TResult Function(LibsqlValue_BigInt value)? bigInt,
+    TResult Function(LibsqlValue_Json value)? json,
+    required TResult orElse(),
+  }) {
+    if (bigInt != null) {
+      return bigInt(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class LibsqlValue_BigInt extends LibsqlValue {
+  const factory LibsqlValue_BigInt(final String field0) =
+      _$LibsqlValue_BigIntImpl;
+  const LibsqlValue_BigInt._() : super._();
+
+  String get field0;
+
+  /// Create a copy of LibsqlValue
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$LibsqlValue_BigIntImplCopyWith<_$LibsqlValue_BigIntImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$LibsqlValue_JsonImplCopyWith<$Res> {
+  factory _$$LibsqlValue_JsonImplCopyWith(_$LibsqlValue_JsonImpl value,
+          $Res Function(_$LibsqlValue_JsonImpl) then) =
+      __$$LibsqlValue_JsonImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({String field0});
+}
+
+/// @nodoc
+class __$$LibsqlValue_JsonImplCopyWithImpl<$Res>
+    extends _$LibsqlValueCopyWithImpl<$Res, _$LibsqlValue_JsonImpl>
+    implements _$$LibsqlValue_JsonImplCopyWith<$Res> {
+  __$$LibsqlValue_JsonImplCopyWithImpl(_$LibsqlValue_JsonImpl _value,
+      $Res Function(_$LibsqlValue_JsonImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of LibsqlValue
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? field0 = null,
+  }) {
+    return _then(_$LibsqlValue_JsonImpl(
+      null == field0
+          ? _value.field0
+          : field0 // ignore: cast_nullable_to_non_nullable
+              as String,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$LibsqlValue_JsonImpl extends LibsqlValue_Json {
+  const _$LibsqlValue_JsonImpl(this.field0) : super._();
+
+  @override
+  final String field0;
+
+  @override
+  String toString() {
+    return 'LibsqlValue.json(field0: $field0)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$LibsqlValue_JsonImpl &&
+            (identical(other.field0, field0) || other.field0 == field0));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, field0);
+
+  /// Create a copy of LibsqlValue
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$LibsqlValue_JsonImplCopyWith<_$LibsqlValue_JsonImpl> get copyWith =>
+      __$$LibsqlValue_JsonImplCopyWithImpl<_$LibsqlValue_JsonImpl>(
+          this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(int field0) integer,
+    required TResult Function(double field0) real,
+    required TResult Function(String field0) text,
+    required TResult Function(Uint8List field0) blob,
+    required TResult Function() null_,
+    required TResult Function(bool field0) bool,
+    required TResult Function(DateTime value, LibsqlDateTimeFormat format)
+        dateTime,
+    required TResult Function(String field0) bigInt,
+    required TResult Function(String field0) json,
+  }) {
+    return json(field0);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(int field0)? integer,
+    TResult? Function(double field0)? real,
+    TResult? Function(String field0)? text,
+    TResult? Function(Uint8List field0)? blob,
+    TResult? Function()? null_,
+    TResult? Function(bool field0)? bool,
+    TResult? Function(DateTime value, LibsqlDateTimeFormat format)? dateTime,
+    TResult? Function(String field0)? bigInt,
+    TResult? Function(String field0)? json,
+  }) {
+    return json?.call(field0);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(int field0)? integer,
+    TResult Function(double field0)? real,
+    TResult Function(String field0)? text,
+    TResult Function(Uint8List field0)? blob,
+    TResult Function()? null_,
+    TResult Function(bool field0)? bool,
+    TResult Function(DateTime value, LibsqlDateTimeFormat format)? dateTime,
+    TResult Function(String field0)? bigInt,
+    TResult Function(String field0)? json,
+    required TResult orElse(),
+  }) {
+    if (json != null) {
+      return json(field0);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(LibsqlValue_Integer value) integer,
+    required TResult Function(LibsqlValue_Real value) real,
+    required TResult Function(LibsqlValue_Text value) text,
+    required TResult Function(LibsqlValue_Blob value) blob,
+    required TResult Function(LibsqlValue_Null value) null_,
+    required TResult Function(LibsqlValue_Bool value) bool,
+    required TResult Function(LibsqlValue_DateTime value) dateTime,
+    required TResult Function(LibsqlValue_BigInt value) bigInt,
+    required TResult Function(LibsqlValue_Json value) json,
+  }) {
+    return json(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(LibsqlValue_Integer value)? integer,
+    TResult? Function(LibsqlValue_Real value)? real,
+    TResult? Function(LibsqlValue_Text value)? text,
+    TResult? Function(LibsqlValue_Blob value)? blob,
+    TResult? Function(LibsqlValue_Null value)? null_,
+    TResult? Function(LibsqlValue_Bool value)? bool,
+    TResult? Function(LibsqlValue_DateTime value)? dateTime,
+    TResult? Function(LibsqlValue_BigInt value)? bigInt,
+    TResult? Function(LibsqlValue_Json value)? json,
+  }) {
+    return json?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(LibsqlValue_Integer value)? integer,
+    TResult Function(LibsqlValue_Real value)? real,
+    TResult Function(LibsqlValue_Text value)? text,
+    TResult Function(LibsqlValue_Blob value)? blob,
+    TResult Function(LibsqlValue_Null value)? null_,
+    TResult Function(LibsqlValue_Bool value)? bool,
+    TResult Function(LibsqlValue_DateTime value)? dateTime,
+    TResult Function(LibsqlValue_BigInt value)? bigInt,
+    TResult Function(LibsqlValue_Json value)? json,
+    required TResult orElse(),
+  }) {
+    if (json != null) {
+      return json(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class LibsqlValue_Json extends LibsqlValue {
+  const factory LibsqlValue_Json(final String field0) = _$LibsqlValue_JsonImpl;
+  const LibsqlValue_Json._() : super._();
+
+  String get field0;
+
+  /// Create a copy of LibsqlValue
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$LibsqlValue_JsonImplCopyWith<_$LibsqlValue_JsonImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
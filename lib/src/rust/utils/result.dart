@@ -7,51 +7,138 @@ import '../frb_generated.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 import 'return_value.dart';
 
-class ExecuteResult {
-  final BigInt rowsAffected;
-
-  const ExecuteResult({
-    required this.rowsAffected,
-  });
-
-  @override
-  int get hashCode => rowsAffected.hashCode;
-
-  @override
-  bool operator ==(Object other) =>
-      identical(this, other) ||
-      other is ExecuteResult &&
-          runtimeType == other.runtimeType &&
-          rowsAffected == other.rowsAffected;
-}
-
-class QueryResult {
-  final List<Map<String, LibsqlReturnValue>> rows;
-  final List<String> columns;
-  final BigInt rowsAffected;
-  final PlatformInt64 lastInsertRowid;
-
-  const QueryResult({
-    required this.rows,
-    required this.columns,
-    required this.rowsAffected,
-    required this.lastInsertRowid,
-  });
-
-  @override
-  int get hashCode =>
-      rows.hashCode ^
-      columns.hashCode ^
-      rowsAffected.hashCode ^
-      lastInsertRowid.hashCode;
-
-  @override
-  bool operator ==(Object other) =>
-      identical(this, other) ||
-      other is QueryResult &&
-          runtimeType == other.runtimeType &&
-          rows == other.rows &&
-          columns == other.columns &&
-          rowsAffected == other.rowsAffected &&
-          lastInsertRowid == other.lastInsertRowid;
-}
+            class BatchResult  {
+                /// One entry per statement, in the order they were given
+final List<QueryResult> results;
+
+                const BatchResult({required this.results ,});
+
+        @override
+        int get hashCode => results.hashCode;
+
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is BatchResult &&
+                runtimeType == other.runtimeType
+                && results == other.results;
+
+            }
+
+/// Same as `QueryResult`, but column names are sent once and values are laid
+/// out row by row in a single flat vector, so row `r` spans
+/// `values[r * columns.len()..(r + 1) * columns.len()]`.
+///
+/// Unlike the map based rows, duplicate column names are kept.
+class ColumnarQueryResult  {
+                final List<String> columns;
+final List<String?> declTypes;
+final List<LibsqlReturnValue> values;
+final BigInt rowCount;
+final BigInt rowsAffected;
+final PlatformInt64 lastInsertRowid;
+final BigInt totalChanges;
+
+                const ColumnarQueryResult({required this.columns ,required this.declTypes ,required this.values ,required this.rowCount ,required this.rowsAffected ,required this.lastInsertRowid ,required this.totalChanges ,});
+
+        @override
+        int get hashCode => columns.hashCode^declTypes.hashCode^values.hashCode^rowCount.hashCode^rowsAffected.hashCode^lastInsertRowid.hashCode^totalChanges.hashCode;
+
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is ColumnarQueryResult &&
+                runtimeType == other.runtimeType
+                && columns == other.columns&& declTypes == other.declTypes&& values == other.values&& rowCount == other.rowCount&& rowsAffected == other.rowsAffected&& lastInsertRowid == other.lastInsertRowid&& totalChanges == other.totalChanges;
+
+            }
+
+class ExecuteResult  {
+                final BigInt rowsAffected;
+final PlatformInt64 lastInsertRowid;
+final BigInt totalChanges;
+
+                const ExecuteResult({required this.rowsAffected ,required this.lastInsertRowid ,required this.totalChanges ,});
+
+        @override
+        int get hashCode => rowsAffected.hashCode^lastInsertRowid.hashCode^totalChanges.hashCode;
+
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is ExecuteResult &&
+                runtimeType == other.runtimeType
+                && rowsAffected == other.rowsAffected&& lastInsertRowid == other.lastInsertRowid&& totalChanges == other.totalChanges;
+
+            }
+
+class LibsqlColumn  {
+                final String name;
+/// Column in the table the value is read from, `None` for expressions
+final String? originName;
+final String? tableName;
+final String? databaseName;
+/// Type the column was declared with in `CREATE TABLE`
+final String? declType;
+
+                const LibsqlColumn({required this.name ,this.originName ,this.tableName ,this.databaseName ,this.declType ,});
+
+        @override
+        int get hashCode => name.hashCode^originName.hashCode^tableName.hashCode^databaseName.hashCode^declType.hashCode;
+
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is LibsqlColumn &&
+                runtimeType == other.runtimeType
+                && name == other.name&& originName == other.originName&& tableName == other.tableName&& databaseName == other.databaseName&& declType == other.declType;
+
+            }
+
+class QueryResult  {
+                final List<Map<String, LibsqlReturnValue>> rows;
+final List<String> columns;
+/// Declared type of each column, `None` for expressions. Empty in the
+/// results of a remote `batch_statements`
+final List<String?> declTypes;
+final BigInt rowsAffected;
+final PlatformInt64 lastInsertRowid;
+final BigInt totalChanges;
+
+                const QueryResult({required this.rows ,required this.columns ,required this.declTypes ,required this.rowsAffected ,required this.lastInsertRowid ,required this.totalChanges ,});
+
+        @override
+        int get hashCode => rows.hashCode^columns.hashCode^declTypes.hashCode^rowsAffected.hashCode^lastInsertRowid.hashCode^totalChanges.hashCode;
+
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is QueryResult &&
+                runtimeType == other.runtimeType
+                && rows == other.rows&& columns == other.columns&& declTypes == other.declTypes&& rowsAffected == other.rowsAffected&& lastInsertRowid == other.lastInsertRowid&& totalChanges == other.totalChanges;
+
+            }
+
+/// Outcome of syncing an embedded replica or synced database.
+class SyncResult  {
+                /// Frames pulled from the remote during this sync
+final BigInt framesSynced;
+/// Frame number committed locally after this sync, if any
+final BigInt? frameNo;
+/// Replication index of the local replica after this sync, if any
+final BigInt? replicationIndex;
+final BigInt durationMs;
+
+                const SyncResult({required this.framesSynced ,this.frameNo ,this.replicationIndex ,required this.durationMs ,});
+
+        @override
+        int get hashCode => framesSynced.hashCode^frameNo.hashCode^replicationIndex.hashCode^durationMs.hashCode;
+
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is SyncResult &&
+                runtimeType == other.runtimeType
+                && framesSynced == other.framesSynced&& frameNo == other.frameNo&& replicationIndex == other.replicationIndex&& durationMs == other.durationMs;
+
+            }
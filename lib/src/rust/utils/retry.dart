@@ -0,0 +1,30 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.10.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+            /// Retries operations failing with a `Busy` error, which covers both
+/// `SQLITE_BUSY` and `SQLITE_LOCKED`, waiting twice as long after each
+/// attempt.
+class LibsqlBusyRetry  {
+                /// Attempts including the first one
+final int maxAttempts;
+final BigInt initialDelayMs;
+final BigInt maxDelayMs;
+
+                const LibsqlBusyRetry({required this.maxAttempts ,required this.initialDelayMs ,required this.maxDelayMs ,});
+
+        @override
+        int get hashCode => maxAttempts.hashCode^initialDelayMs.hashCode^maxDelayMs.hashCode;
+
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is LibsqlBusyRetry &&
+                runtimeType == other.runtimeType
+                && maxAttempts == other.maxAttempts&& initialDelayMs == other.initialDelayMs&& maxDelayMs == other.maxDelayMs;
+
+            }
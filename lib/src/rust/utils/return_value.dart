@@ -8,21 +8,44 @@ import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
 part 'return_value.freezed.dart';
 
+            /// Unit of unix timestamps stored as integers in date and time columns.
+enum LibsqlDateTimeUnit {
+                    /// Milliseconds since the unix epoch, as written by `LibsqlDateTimeFormat::UnixMillis`
+milliseconds,
+/// Seconds since the unix epoch, as returned by SQLite's `unixepoch()`
+seconds,
+                    ;
+
+                }
+
+enum LibsqlDecodeMode {
+                    /// Values come back as one of SQLite's five storage classes
+storageClass,
+/// Values are also converted according to the declared type of their
+/// column: `BOOL`/`BOOLEAN` to `Bool`, `DATE`/`DATETIME`/`TIMESTAMP` to
+/// `DateTime` (ISO-8601 text or unix time) and `JSON` to `Json`. Only
+/// these exact type names are matched, ignoring case and any size such
+/// as `TIMESTAMP(3)`. Values that don't fit the declared type are left
+/// untouched.
+declaredType,
+                    ;
+
+                }
+
 @freezed
-sealed class LibsqlReturnValue with _$LibsqlReturnValue {
-  const LibsqlReturnValue._();
-
-  const factory LibsqlReturnValue.integer(
-    PlatformInt64 field0,
-  ) = LibsqlReturnValue_Integer;
-  const factory LibsqlReturnValue.real(
-    double field0,
-  ) = LibsqlReturnValue_Real;
-  const factory LibsqlReturnValue.text(
-    String field0,
-  ) = LibsqlReturnValue_Text;
-  const factory LibsqlReturnValue.blob(
-    Uint8List field0,
-  ) = LibsqlReturnValue_Blob;
-  const factory LibsqlReturnValue.null_() = LibsqlReturnValue_Null;
-}
+                sealed class LibsqlReturnValue with _$LibsqlReturnValue  {
+                    const LibsqlReturnValue._();
+
+                     const factory LibsqlReturnValue.integer(  PlatformInt64 field0,) = LibsqlReturnValue_Integer;
+ const factory LibsqlReturnValue.real(  double field0,) = LibsqlReturnValue_Real;
+ const factory LibsqlReturnValue.text(  String field0,) = LibsqlReturnValue_Text;
+ const factory LibsqlReturnValue.blob(  Uint8List field0,) = LibsqlReturnValue_Blob;
+ const factory LibsqlReturnValue.null_() = LibsqlReturnValue_Null;
+ /// Only produced with `LibsqlDecodeMode::DeclaredType`
+const factory LibsqlReturnValue.bool(  bool field0,) = LibsqlReturnValue_Bool;
+ /// Only produced with `LibsqlDecodeMode::DeclaredType`
+const factory LibsqlReturnValue.dateTime(  DateTime field0,) = LibsqlReturnValue_DateTime;
+ /// Only produced with `LibsqlDecodeMode::DeclaredType`, holds the JSON text
+const factory LibsqlReturnValue.json(  String field0,) = LibsqlReturnValue_Json;
+
+                }
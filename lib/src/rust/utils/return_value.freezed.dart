@@ -23,6 +23,9 @@ mixin _$LibsqlReturnValue {
     required TResult Function(String field0) text,
     required TResult Function(Uint8List field0) blob,
     required TResult Function() null_,
+    required TResult Function(bool field0) bool,
+    required TResult Function(DateTime field0) dateTime,
+    required TResult Function(String field0) json,
   }) =>
       throw _privateConstructorUsedError;
   @optionalTypeArgs
@@ -32,6 +35,9 @@ mixin _$LibsqlReturnValue {
     TResult? Function(String field0)? text,
     TResult? Function(Uint8List field0)? blob,
     TResult? Function()? null_,
+    TResult? Function(bool field0)? bool,
+    TResult? Function(DateTime field0)? dateTime,
+    TResult? Function(String field0)? json,
   }) =>
       throw _privateConstructorUsedError;
   @optionalTypeArgs
@@ -41,6 +47,9 @@ mixin _$LibsqlReturnValue {
     TResult Function(String field0)? text,
     TResult Function(Uint8List field0)? blob,
     TResult Function()? null_,
+    TResult Function(bool field0)? bool,
+    TResult Function(DateTime field0)? dateTime,
+    TResult Function(String field0)? json,
     required TResult orElse(),
   }) =>
       throw _privateConstructorUsedError;
@@ -51,6 +60,9 @@ mixin _$LibsqlReturnValue {
     required TResult Function(LibsqlReturnValue_Text value) text,
     required TResult Function(LibsqlReturnValue_Blob value) blob,
     required TResult Function(LibsqlReturnValue_Null value) null_,
+    required TResult Function(LibsqlReturnValue_Bool value) bool,
+    required TResult Function(LibsqlReturnValue_DateTime value) dateTime,
+    required TResult Function(LibsqlReturnValue_Json value) json,
   }) =>
       throw _privateConstructorUsedError;
   @optionalTypeArgs
@@ -60,6 +72,9 @@ mixin _$LibsqlReturnValue {
     TResult? Function(LibsqlReturnValue_Text value)? text,
     TResult? Function(LibsqlReturnValue_Blob value)? blob,
     TResult? Function(LibsqlReturnValue_Null value)? null_,
+    TResult? Function(LibsqlReturnValue_Bool value)? bool,
+    TResult? Function(LibsqlReturnValue_DateTime value)? dateTime,
+    TResult? Function(LibsqlReturnValue_Json value)? json,
   }) =>
       throw _privateConstructorUsedError;
   @optionalTypeArgs
@@ -69,6 +84,9 @@ mixin _$LibsqlReturnValue {
     TResult Function(LibsqlReturnValue_Text value)? text,
     TResult Function(LibsqlReturnValue_Blob value)? blob,
     TResult Function(LibsqlReturnValue_Null value)? null_,
+    TResult Function(LibsqlReturnValue_Bool value)? bool,
+    TResult Function(LibsqlReturnValue_DateTime value)? dateTime,
+    TResult Function(LibsqlReturnValue_Json value)? json,
     required TResult orElse(),
   }) =>
       throw _privateConstructorUsedError;
@@ -172,6 +190,9 @@ class _$LibsqlReturnValue_IntegerImpl extends LibsqlReturnValue_Integer {
     required TResult Function(String field0) text,
     required TResult Function(Uint8List field0) blob,
     required TResult Function() null_,
+    required TResult Function(bool field0) bool,
+    required TResult Function(DateTime field0) dateTime,
+    required TResult Function(String field0) json,
   }) {
     return integer(field0);
   }
@@ -184,6 +205,9 @@ class _$LibsqlReturnValue_IntegerImpl extends LibsqlReturnValue_Integer {
     TResult? Function(String field0)? text,
     TResult? Function(Uint8List field0)? blob,
     TResult? Function()? null_,
+    TResult? Function(bool field0)? bool,
+    TResult? Function(DateTime field0)? dateTime,
+    TResult? Function(String field0)? json,
   }) {
     return integer?.call(field0);
   }
@@ -196,6 +220,9 @@ class _$LibsqlReturnValue_IntegerImpl extends LibsqlReturnValue_Integer {
     TResult Function(String field0)? text,
     TResult Function(Uint8List field0)? blob,
     TResult Function()? null_,
+    TResult Function(bool field0)? bool,
+    TResult Function(DateTime field0)? dateTime,
+    TResult Function(String field0)? json,
     required TResult orElse(),
   }) {
     if (integer != null) {
@@ -212,6 +239,9 @@ class _$LibsqlReturnValue_IntegerImpl extends LibsqlReturnValue_Integer {
     required TResult Function(LibsqlReturnValue_Text value) text,
     required TResult Function(LibsqlReturnValue_Blob value) blob,
     required TResult Function(LibsqlReturnValue_Null value) null_,
+    required TResult Function(LibsqlReturnValue_Bool value) bool,
+    required TResult Function(LibsqlReturnValue_DateTime value) dateTime,
+    required TResult Function(LibsqlReturnValue_Json value) json,
   }) {
     return integer(this);
   }
@@ -224,6 +254,9 @@ class _$LibsqlReturnValue_IntegerImpl extends LibsqlReturnValue_Integer {
     TResult? Function(LibsqlReturnValue_Text value)? text,
     TResult? Function(LibsqlReturnValue_Blob value)? blob,
     TResult? Function(LibsqlReturnValue_Null value)? null_,
+    TResult? Function(LibsqlReturnValue_Bool value)? bool,
+    TResult? Function(LibsqlReturnValue_DateTime value)? dateTime,
+    TResult? Function(LibsqlReturnValue_Json value)? json,
   }) {
     return integer?.call(this);
   }
@@ -236,6 +269,9 @@ class _$LibsqlReturnValue_IntegerImpl extends LibsqlReturnValue_Integer {
     TResult Function(LibsqlReturnValue_Text value)? text,
     TResult Function(LibsqlReturnValue_Blob value)? blob,
     TResult Function(LibsqlReturnValue_Null value)? null_,
+    TResult Function(LibsqlReturnValue_Bool value)? bool,
+    TResult Function(LibsqlReturnValue_DateTime value)? dateTime,
+    TResult Function(LibsqlReturnValue_Json value)? json,
     required TResult orElse(),
   }) {
     if (integer != null) {
@@ -335,6 +371,9 @@ class _$LibsqlReturnValue_RealImpl extends LibsqlReturnValue_Real {
     required TResult Function(String field0) text,
     required TResult Function(Uint8List field0) blob,
     required TResult Function() null_,
+    required TResult Function(bool field0) bool,
+    required TResult Function(DateTime field0) dateTime,
+    required TResult Function(String field0) json,
   }) {
     return real(field0);
   }
@@ -347,6 +386,9 @@ class _$LibsqlReturnValue_RealImpl extends LibsqlReturnValue_Real {
     TResult? Function(String field0)? text,
     TResult? Function(Uint8List field0)? blob,
     TResult? Function()? null_,
+    TResult? Function(bool field0)? bool,
+    TResult? Function(DateTime field0)? dateTime,
+    TResult? Function(String field0)? json,
   }) {
     return real?.call(field0);
   }
@@ -359,6 +401,9 @@ class _$LibsqlReturnValue_RealImpl extends LibsqlReturnValue_Real {
     TResult Function(String field0)? text,
     TResult Function(Uint8List field0)? blob,
     TResult Function()? null_,
+    TResult Function(bool field0)? bool,
+    TResult Function(DateTime field0)? dateTime,
+    TResult Function(String field0)? json,
     required TResult orElse(),
   }) {
     if (real != null) {
@@ -375,6 +420,9 @@ class _$LibsqlReturnValue_RealImpl extends LibsqlReturnValue_Real {
     required TResult Function(LibsqlReturnValue_Text value) text,
     required TResult Function(LibsqlReturnValue_Blob value) blob,
     required TResult Function(LibsqlReturnValue_Null value) null_,
+    required TResult Function(LibsqlReturnValue_Bool value) bool,
+    required TResult Function(LibsqlReturnValue_DateTime value) dateTime,
+    required TResult Function(LibsqlReturnValue_Json value) json,
   }) {
     return real(this);
   }
@@ -387,6 +435,9 @@ class _$LibsqlReturnValue_RealImpl extends LibsqlReturnValue_Real {
     TResult? Function(LibsqlReturnValue_Text value)? text,
     TResult? Function(LibsqlReturnValue_Blob value)? blob,
     TResult? Function(LibsqlReturnValue_Null value)? null_,
+    TResult? Function(LibsqlReturnValue_Bool value)? bool,
+    TResult? Function(LibsqlReturnValue_DateTime value)? dateTime,
+    TResult? Function(LibsqlReturnValue_Json value)? json,
   }) {
     return real?.call(this);
   }
@@ -399,6 +450,9 @@ class _$LibsqlReturnValue_RealImpl extends LibsqlReturnValue_Real {
     TResult Function(LibsqlReturnValue_Text value)? text,
     TResult Function(LibsqlReturnValue_Blob value)? blob,
     TResult Function(LibsqlReturnValue_Null value)? null_,
+    TResult Function(LibsqlReturnValue_Bool value)? bool,
+    TResult Function(LibsqlReturnValue_DateTime value)? dateTime,
+    TResult Function(LibsqlReturnValue_Json value)? json,
     required TResult orElse(),
   }) {
     if (real != null) {
@@ -498,6 +552,9 @@ class _$LibsqlReturnValue_TextImpl extends LibsqlReturnValue_Text {
     required TResult Function(String field0) text,
     required TResult Function(Uint8List field0) blob,
     required TResult Function() null_,
+    required TResult Function(bool field0) bool,
+    required TResult Function(DateTime field0) dateTime,
+    required TResult Function(String field0) json,
   }) {
     return text(field0);
   }
@@ -510,6 +567,9 @@ class _$LibsqlReturnValue_TextImpl extends LibsqlReturnValue_Text {
     TResult? Function(String field0)? text,
     TResult? Function(Uint8List field0)? blob,
     TResult? Function()? null_,
+    TResult? Function(bool field0)? bool,
+    TResult? Function(DateTime field0)? dateTime,
+    TResult? Function(String field0)? json,
   }) {
     return text?.call(field0);
   }
@@ -522,6 +582,9 @@ class _$LibsqlReturnValue_TextImpl extends LibsqlReturnValue_Text {
     TResult Function(String field0)? text,
     TResult Function(Uint8List field0)? blob,
     TResult Function()? null_,
+    TResult Function(bool field0)? bool,
+    TResult Function(DateTime field0)? dateTime,
+    TResult Function(String field0)? json,
     required TResult orElse(),
   }) {
     if (text != null) {
@@ -538,6 +601,9 @@ class _$LibsqlReturnValue_TextImpl extends LibsqlReturnValue_Text {
     required TResult Function(LibsqlReturnValue_Text value) text,
     required TResult Function(LibsqlReturnValue_Blob value) blob,
     required TResult Function(LibsqlReturnValue_Null value) null_,
+    required TResult Function(LibsqlReturnValue_Bool value) bool,
+    required TResult Function(LibsqlReturnValue_DateTime value) dateTime,
+    required TResult Function(LibsqlReturnValue_Json value) json,
   }) {
     return text(this);
   }
@@ -550,6 +616,9 @@ class _$LibsqlReturnValue_TextImpl extends LibsqlReturnValue_Text {
     TResult? Function(LibsqlReturnValue_Text value)? text,
     TResult? Function(LibsqlReturnValue_Blob value)? blob,
     TResult? Function(LibsqlReturnValue_Null value)? null_,
+    TResult? Function(LibsqlReturnValue_Bool value)? bool,
+    TResult? Function(LibsqlReturnValue_DateTime value)? dateTime,
+    TResult? Function(LibsqlReturnValue_Json value)? json,
   }) {
     return text?.call(this);
   }
@@ -562,6 +631,9 @@ class _$LibsqlReturnValue_TextImpl extends LibsqlReturnValue_Text {
     TResult Function(LibsqlReturnValue_Text value)? text,
     TResult Function(LibsqlReturnValue_Blob value)? blob,
     TResult Function(LibsqlReturnValue_Null value)? null_,
+    TResult Function(LibsqlReturnValue_Bool value)? bool,
+    TResult Function(LibsqlReturnValue_DateTime value)? dateTime,
+    TResult Function(LibsqlReturnValue_Json value)? json,
     required TResult orElse(),
   }) {
     if (text != null) {
@@ -662,6 +734,9 @@ class _$LibsqlReturnValue_BlobImpl extends LibsqlReturnValue_Blob {
     required TResult Function(String field0) text,
     required TResult Function(Uint8List field0) blob,
     required TResult Function() null_,
+    required TResult Function(bool field0) bool,
+    required TResult Function(DateTime field0) dateTime,
+    required TResult Function(String field0) json,
   }) {
     return blob(field0);
   }
@@ -674,6 +749,9 @@ class _$LibsqlReturnValue_BlobImpl extends LibsqlReturnValue_Blob {
     TResult? Function(String field0)? text,
     TResult? Function(Uint8List field0)? blob,
     TResult? Function()? null_,
+    TResult? Function(bool field0)? bool,
+    TResult? Function(DateTime field0)? dateTime,
+    TResult? Function(String field0)? json,
   }) {
     return blob?.call(field0);
   }
@@ -686,6 +764,9 @@ class _$LibsqlReturnValue_BlobImpl extends LibsqlReturnValue_Blob {
     TResult Function(String field0)? text,
     TResult Function(Uint8List field0)? blob,
     TResult Function()? null_,
+    TResult Function(bool field0)? bool,
+    TResult Function(DateTime field0)? dateTime,
+    TResult Function(String field0)? json,
     required TResult orElse(),
   }) {
     if (blob != null) {
@@ -702,6 +783,9 @@ class _$LibsqlReturnValue_BlobImpl extends LibsqlReturnValue_Blob {
     required TResult Function(LibsqlReturnValue_Text value) text,
     required TResult Function(LibsqlReturnValue_Blob value) blob,
     required TResult Function(LibsqlReturnValue_Null value) null_,
+    required TResult Function(LibsqlReturnValue_Bool value) bool,
+    required TResult Function(LibsqlReturnValue_DateTime value) dateTime,
+    required TResult Function(LibsqlReturnValue_Json value) json,
   }) {
     return blob(this);
   }
@@ -714,6 +798,9 @@ class _$LibsqlReturnValue_BlobImpl extends LibsqlReturnValue_Blob {
     TResult? Function(LibsqlReturnValue_Text value)? text,
     TResult? Function(LibsqlReturnValue_Blob value)? blob,
     TResult? Function(LibsqlReturnValue_Null value)? null_,
+    TResult? Function(LibsqlReturnValue_Bool value)? bool,
+    TResult? Function(LibsqlReturnValue_DateTime value)? dateTime,
+    TResult? Function(LibsqlReturnValue_Json value)? json,
   }) {
     return blob?.call(this);
   }
@@ -726,6 +813,9 @@ class _$LibsqlReturnValue_BlobImpl extends LibsqlReturnValue_Blob {
     TResult Function(LibsqlReturnValue_Text value)? text,
     TResult Function(LibsqlReturnValue_Blob value)? blob,
     TResult Function(LibsqlReturnValue_Null value)? null_,
+    TResult Function(LibsqlReturnValue_Bool value)? bool,
+    TResult Function(LibsqlReturnValue_DateTime value)? dateTime,
+    TResult Function(LibsqlReturnValue_Json value)? json,
     required TResult orElse(),
   }) {
     if (blob != null) {
@@ -798,6 +888,9 @@ class _$LibsqlReturnValue_NullImpl extends LibsqlReturnValue_Null {
     required TResult Function(String field0) text,
     required TResult Function(Uint8List field0) blob,
     required TResult Function() null_,
+    required TResult Function(bool field0) bool,
+    required TResult Function(DateTime field0) dateTime,
+    required TResult Function(String field0) json,
   }) {
     return null_();
   }
@@ -810,6 +903,9 @@ class _$LibsqlReturnValue_NullImpl extends LibsqlReturnValue_Null {
     TResult? Function(String field0)? text,
     TResult? Function(Uint8List field0)? blob,
     TResult? Function()? null_,
+    TResult? Function(bool field0)? bool,
+    TResult? Function(DateTime field0)? dateTime,
+    TResult? Function(String field0)? json,
   }) {
     return null_?.call();
   }
@@ -822,6 +918,9 @@ class _$LibsqlReturnValue_NullImpl extends LibsqlReturnValue_Null {
     TResult Function(String field0)? text,
     TResult Function(Uint8List field0)? blob,
     TResult Function()? null_,
+    TResult Function(bool field0)? bool,
+    TResult Function(DateTime field0)? dateTime,
+    TResult Function(String field0)? json,
     required TResult orElse(),
   }) {
     if (null_ != null) {
@@ -838,6 +937,9 @@ class _$LibsqlReturnValue_NullImpl extends LibsqlReturnValue_Null {
     required TResult Function(LibsqlReturnValue_Text value) text,
     required TResult Function(LibsqlReturnValue_Blob value) blob,
     required TResult Function(LibsqlReturnValue_Null value) null_,
+    required TResult Function(LibsqlReturnValue_Bool value) bool,
+    required TResult Function(LibsqlReturnValue_DateTime value) dateTime,
+    required TResult Function(LibsqlReturnValue_Json value) json,
   }) {
     return null_(this);
   }
@@ -850,6 +952,9 @@ class _$LibsqlReturnValue_NullImpl extends LibsqlReturnValue_Null {
     TResult? Function(LibsqlReturnValue_Text value)? text,
     TResult? Function(LibsqlReturnValue_Blob value)? blob,
     TResult? Function(LibsqlReturnValue_Null value)? null_,
+    TResult? Function(LibsqlReturnValue_Bool value)? bool,
+    TResult? Function(LibsqlReturnValue_DateTime value)? dateTime,
+    TResult? Function(LibsqlReturnValue_Json value)? json,
   }) {
     return null_?.call(this);
   }
@@ -862,6 +967,9 @@ class _$LibsqlReturnValue_NullImpl extends LibsqlReturnValue_Null {
     TResult Function(LibsqlReturnValue_Text value)? text,
     TResult Function(LibsqlReturnValue_Blob value)? blob,
     TResult Function(LibsqlReturnValue_Null value)? null_,
+    TResult Function(LibsqlReturnValue_Bool value)? bool,
+    TResult Function(LibsqlReturnValue_DateTime value)? dateTime,
+    TResult Function(LibsqlReturnValue_Json value)? json,
     required TResult orElse(),
   }) {
     if (null_ != null) {
@@ -875,3 +983,547 @@ abstract class LibsqlReturnValue_Null extends LibsqlReturnValue {
   const factory LibsqlReturnValue_Null() = _$LibsqlReturnValue_NullImpl;
   const LibsqlReturnValue_Null._() : super._();
 }
+
+/// @nodoc
+abstract class _$$LibsqlReturnValue_BoolImplCopyWith<$Res> {
+  factory _$$LibsqlReturnValue_BoolImplCopyWith(
+          _$LibsqlReturnValue_BoolImpl value,
+          $Res Function(_$LibsqlReturnValue_BoolImpl) then) =
+      __$$LibsqlReturnValue_BoolImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({bool field0});
+}
+
+/// @nodoc
+class __$$LibsqlReturnValue_BoolImplCopyWithImpl<$Res>
+    extends _$LibsqlReturnValueCopyWithImpl<$Res, _$LibsqlReturnValue_BoolImpl>
+    implements _$$LibsqlReturnValue_BoolImplCopyWith<$Res> {
+  __$$LibsqlReturnValue_BoolImplCopyWithImpl(
+      _$LibsqlReturnValue_BoolImpl _value,
+      $Res Function(_$LibsqlReturnValue_BoolImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of LibsqlReturnValue
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? field0 = null,
+  }) {
+    return _then(_$LibsqlReturnValue_BoolImpl(
+      null == field0
+          ? _value.field0
+          : field0 // ignore: cast_nullable_to_non_nullable
+              as bool,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$LibsqlReturnValue_BoolImpl extends LibsqlReturnValue_Bool {
+  const _$LibsqlReturnValue_BoolImpl(this.field0) : super._();
+
+  @override
+  final bool field0;
+
+  @override
+  String toString() {
+    return 'LibsqlReturnValue.bool(field0: $field0)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$LibsqlReturnValue_BoolImpl &&
+            (identical(other.field0, field0) || other.field0 == field0));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, field0);
+
+  /// Create a copy of LibsqlReturnValue
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$LibsqlReturnValue_BoolImplCopyWith<_$LibsqlReturnValue_BoolImpl>
+      get copyWith => __$$LibsqlReturnValue_BoolImplCopyWithImpl<
+          _$LibsqlReturnValue_BoolImpl>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(int field0) integer,
+    required TResult Function(double field0) real,
+    required TResult Function(String field0) text,
+    required TResult Function(Uint8List field0) blob,
+    required TResult Function() null_,
+    required TResult Function(bool field0) bool,
+    required TResult Function(DateTime field0) dateTime,
+    required TResult Function(String field0) json,
+  }) {
+    return bool(field0);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(int field0)? integer,
+    TResult? Function(double field0)? real,
+    TResult? Function(String field0)? text,
+    TResult? Function(Uint8List field0)? blob,
+    TResult? Function()? null_,
+    TResult? Function(bool field0)? bool,
+    TResult? Function(DateTime field0)? dateTime,
+    TResult? Function(String field0)? json,
+  }) {
+    return bool?.call(field0);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(int field0)? integer,
+    TResult Function(double field0)? real,
+    TResult Function(String field0)? text,
+    TResult Function(Uint8List field0)? blob,
+    TResult Function()? null_,
+    TResult Function(bool field0)? bool,
+    TResult Function(DateTime field0)? dateTime,
+    TResult Function(String field0)? json,
+    required TResult orElse(),
+  }) {
+    if (bool != null) {
+      return bool(field0);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(LibsqlReturnValue_Integer value) integer,
+    required TResult Function(LibsqlReturnValue_Real value) real,
+    required TResult Function(LibsqlReturnValue_Text value) text,
+    required TResult Function(LibsqlReturnValue_Blob value) blob,
+    required TResult Function(LibsqlReturnValue_Null value) null_,
+    required TResult Function(LibsqlReturnValue_Bool value) bool,
+    required TResult Function(LibsqlReturnValue_DateTime value) dateTime,
+    required TResult Function(LibsqlReturnValue_Json value) json,
+  }) {
+    return bool(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(LibsqlReturnValue_Integer value)? integer,
+    TResult? Function(LibsqlReturnValue_Real value)? real,
+    TResult? Function(LibsqlReturnValue_Text value)? text,
+    TResult? Function(LibsqlReturnValue_Blob value)? blob,
+    TResult? Function(LibsqlReturnValue_Null value)? null_,
+    TResult? Function(LibsqlReturnValue_Bool value)? bool,
+    TResult? Function(LibsqlReturnValue_DateTime value)? dateTime,
+    TResult? Function(LibsqlReturnValue_Json value)? json,
+  }) {
+    return bool?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(LibsqlReturnValue_Integer value)? integer,
+    TResult Function(LibsqlReturnValue_Real value)? real,
+    TResult Function(LibsqlReturnValue_Text value)? text,
+    TResult Function(LibsqlReturnValue_Blob value)? blob,
+    TResult Function(LibsqlReturnValue_Null value)? null_,
+    TResult Function(LibsqlReturnValue_Bool value)? bool,
+    TResult Function(LibsqlReturnValue_DateTime value)? dateTime,
+    TResult Function(LibsqlReturnValue_Json value)? json,
+    required TResult orElse(),
+  }) {
+    if (bool != null) {
+      return bool(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class LibsqlReturnValue_Bool extends LibsqlReturnValue {
+  const factory LibsqlReturnValue_Bool(final bool field0) =
+      _$LibsqlReturnValue_BoolImpl;
+  const LibsqlReturnValue_Bool._() : super._();
+
+  bool get field0;
+
+  /// Create a copy of LibsqlReturnValue
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$LibsqlReturnValue_BoolImplCopyWith<_$LibsqlReturnValue_BoolImpl>
+      get copyWith => throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$LibsqlReturnValue_DateTimeImplCopyWith<$Res> {
+  factory _$$LibsqlReturnValue_DateTimeImplCopyWith(
+          _$LibsqlReturnValue_DateTimeImpl value,
+          $Res Function(_$LibsqlReturnValue_DateTimeImpl) then) =
+      __$$LibsqlReturnValue_DateTimeImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({DateTime field0});
+}
+
+/// @nodoc
+class __$$LibsqlReturnValue_DateTimeImplCopyWithImpl<$Res>
+    extends _$LibsqlReturnValueCopyWithImpl<$Res,
+        _$LibsqlReturnValue_DateTimeImpl>
+    implements _$$LibsqlReturnValue_DateTimeImplCopyWith<$Res> {
+  __$$LibsqlReturnValue_DateTimeImplCopyWithImpl(
+      _$LibsqlReturnValue_DateTimeImpl _value,
+      $Res Function(_$LibsqlReturnValue_DateTimeImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of LibsqlReturnValue
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? field0 = null,
+  }) {
+    return _then(_$LibsqlReturnValue_DateTimeImpl(
+      null == field0
+          ? _value.field0
+          : field0 // ignore: cast_nullable_to_non_nullable
+              as DateTime,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$LibsqlReturnValue_DateTimeImpl extends LibsqlReturnValue_DateTime {
+  const _$LibsqlReturnValue_DateTimeImpl(this.field0) : super._();
+
+  @override
+  final DateTime field0;
+
+  @override
+  String toString() {
+    return 'LibsqlReturnValue.dateTime(field0: $field0)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$LibsqlReturnValue_DateTimeImpl &&
+            (identical(other.field0, field0) || other.field0 == field0));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, field0);
+
+  /// Create a copy of LibsqlReturnValue
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$LibsqlReturnValue_DateTimeImplCopyWith<_$LibsqlReturnValue_DateTimeImpl>
+      get copyWith => __$$LibsqlReturnValue_DateTimeImplCopyWithImpl<
+          _$LibsqlReturnValue_DateTimeImpl>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(int field0) integer,
+    required TResult Function(double field0) real,
+    required TResult Function(String field0) text,
+    required TResult Function(Uint8List field0) blob,
+    required TResult Function() null_,
+    required TResult Function(bool field0) bool,
+    required TResult Function(DateTime field0) dateTime,
+    required TResult Function(String field0) json,
+  }) {
+    return dateTime(field0);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(int field0)? integer,
+    TResult? Function(double field0)? real,
+    TResult? Function(String field0)? text,
+    TResult? Function(Uint8List field0)? blob,
+    TResult? Function()? null_,
+    TResult? Function(bool field0)? bool,
+    TResult? Function(DateTime field0)? dateTime,
+    TResult? Function(String field0)? json,
+  }) {
+    return dateTime?.call(field0);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(int field0)? integer,
+    TResult Function(double field0)? real,
+    TResult Function(String field0)? text,
+    TResult Function(Uint8List field0)? blob,
+    TResult Function()? null_,
+    TResult Function(bool field0)? bool,
+    TResult Function(DateTime field0)? dateTime,
+    TResult Function(String field0)? json,
+    required TResult orElse(),
+  }) {
+    if (dateTime != null) {
+      return dateTime(field0);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(LibsqlReturnValue_Integer value) integer,
+    required TResult Function(LibsqlReturnValue_Real value) real,
+    required TResult Function(LibsqlReturnValue_Text value) text,
+    required TResult Function(LibsqlReturnValue_Blob value) blob,
+    required TResult Function(LibsqlReturnValue_Null value) null_,
+    required TResult Function(LibsqlReturnValue_Bool value) bool,
+    required TResult Function(LibsqlReturnValue_DateTime value) dateTime,
+    required TResult Function(LibsqlReturnValue_Json value) json,
+  }) {
+    return dateTime(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(LibsqlReturnValue_Integer value)? integer,
+    TResult? Function(LibsqlReturnValue_Real value)? real,
+    TResult? Function(LibsqlReturnValue_Text value)? text,
+    TResult? Function(LibsqlReturnValue_Blob value)? blob,
+    TResult? Function(LibsqlReturnValue_Null value)? null_,
+    TResult? Function(LibsqlReturnValue_Bool value)? bool,
+    TResult? Function(LibsqlReturnValue_DateTime value)? dateTime,
+    TResult? Function(LibsqlReturnValue_Json value)? json,
+  }) {
+    return dateTime?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(LibsqlReturnValue_Integer value)? integer,
+    TResult Function(LibsqlReturnValue_Real value)? real,
+    TResult Function(LibsqlReturnValue_Text value)? text,
+    TResult Function(LibsqlReturnValue_Blob value)? blob,
+    TResult Function(LibsqlReturnValue_Null value)? null_,
+    TResult Function(LibsqlReturnValue_Bool value)? bool,
+    TResult Function(LibsqlReturnValue_DateTime value)? dateTime,
+    TResult Function(LibsqlReturnValue_Json value)? json,
+    required TResult orElse(),
+  }) {
+    if (dateTime != null) {
+      return dateTime(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class LibsqlReturnValue_DateTime extends LibsqlReturnValue {
+  const factory LibsqlReturnValue_DateTime(final DateTime field0) =
+      _$LibsqlReturnValue_DateTimeImpl;
+  const LibsqlReturnValue_DateTime._() : super._();
+
+  DateTime get field0;
+
+  /// Create a copy of LibsqlReturnValue
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$LibsqlReturnValue_DateTimeImplCopyWith<_$LibsqlReturnValue_DateTimeImpl>
+      get copyWith => throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$LibsqlReturnValue_JsonImplCopyWith<$Res> {
+  factory _$$LibsqlReturnValue_JsonImplCopyWith(
+          _$LibsqlReturnValue_JsonImpl value,
+          $Res Function(_$LibsqlReturnValue_JsonImpl) then) =
+      __$$LibsqlReturnValue_JsonImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({String field0});
+}
+
+/// @nodoc
+class __$$LibsqlReturnValue_JsonImplCopyWithImpl<$Res>
+    extends _$LibsqlReturnValueCopyWithImpl<$Res, _$LibsqlReturnValue_JsonImpl>
+    implements _$$LibsqlReturnValue_JsonImplCopyWith<$Res> {
+  __$$LibsqlReturnValue_JsonImplCopyWithImpl(
+      _$LibsqlReturnValue_JsonImpl _value,
+      $Res Function(_$LibsqlReturnValue_JsonImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of LibsqlReturnValue
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? field0 = null,
+  }) {
+    return _then(_$LibsqlReturnValue_JsonImpl(
+      null == field0
+          ? _value.field0
+          : field0 // ignore: cast_nullable_to_non_nullable
+              as String,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$LibsqlReturnValue_JsonImpl extends LibsqlReturnValue_Json {
+  const _$LibsqlReturnValue_JsonImpl(this.field0) : super._();
+
+  @override
+  final String field0;
+
+  @override
+  String toString() {
+    return 'LibsqlReturnValue.json(field0: $field0)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$LibsqlReturnValue_JsonImpl &&
+            (identical(other.field0, field0) || other.field0 == field0));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, field0);
+
+  /// Create a copy of LibsqlReturnValue
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$LibsqlReturnValue_JsonImplCopyWith<_$LibsqlReturnValue_JsonImpl>
+      get copyWith => __$$LibsqlReturnValue_JsonImplCopyWithImpl<
+          _$LibsqlReturnValue_JsonImpl>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(int field0) integer,
+    required TResult Function(double field0) real,
+    required TResult Function(String field0) text,
+    required TResult Function(Uint8List field0) blob,
+    required TResult Function() null_,
+    required TResult Function(bool field0) bool,
+    required TResult Function(DateTime field0) dateTime,
+    required TResult Function(String field0) json,
+  }) {
+    return json(field0);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(int field0)? integer,
+    TResult? Function(double field0)? real,
+    TResult? Function(String field0)? text,
+    TResult? Function(Uint8List field0)? blob,
+    TResult? Function()? null_,
+    TResult? Function(bool field0)? bool,
+    TResult? Function(DateTime field0)? dateTime,
+    TResult? Function(String field0)? json,
+  }) {
+    return json?.call(field0);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(int field0)? integer,
+    TResult Function(double field0)? real,
+    TResult Function(String field0)? text,
+    TResult Function(Uint8List field0)? blob,
+    TResult Function()? null_,
+    TResult Function(bool field0)? bool,
+    TResult Function(DateTime field0)? dateTime,
+    TResult Function(String field0)? json,
+    required TResult orElse(),
+  }) {
+    if (json != null) {
+      return json(field0);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(LibsqlReturnValue_Integer value) integer,
+    required TResult Function(LibsqlReturnValue_Real value) real,
+    required TResult Function(LibsqlReturnValue_Text value) text,
+    required TResult Function(LibsqlReturnValue_Blob value) blob,
+    required TResult Function(LibsqlReturnValue_Null value) null_,
+    required TResult Function(LibsqlReturnValue_Bool value) bool,
+    required TResult Function(LibsqlReturnValue_DateTime value) dateTime,
+    required TResult Function(LibsqlReturnValue_Json value) json,
+  }) {
+    return json(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(LibsqlReturnValue_Integer value)? integer,
+    TResult? Function(LibsqlReturnValue_Real value)? real,
+    TResult? Function(LibsqlReturnValue_Text value)? text,
+    TResult? Function(LibsqlReturnValue_Blob value)? blob,
+    TResult? Function(LibsqlReturnValue_Null value)? null_,
+    TResult? Function(LibsqlReturnValue_Bool value)? bool,
+    TResult? Function(LibsqlReturnValue_DateTime value)? dateTime,
+    TResult? Function(LibsqlReturnValue_Json value)? json,
+  }) {
+    return json?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(LibsqlReturnValue_Integer value)? integer,
+    TResult Function(LibsqlReturnValue_Real value)? real,
+    TResult Function(LibsqlReturnValue_Text value)? text,
+    TResult Function(LibsqlReturnValue_Blob value)? blob,
+    TResult Function(LibsqlReturnValue_Null value)? null_,
+    TResult Function(LibsqlReturnValue_Bool value)? bool,
+    TResult Function(LibsqlReturnValue_DateTime value)? dateTime,
+    TResult Function(LibsqlReturnValue_Json value)? json,
+    required TResult orElse(),
+  }) {
+    if (json != null) {
+      return json(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class LibsqlReturnValue_Json extends LibsqlReturnValue {
+  const factory LibsqlReturnValue_Json(final String field0) =
+      _$LibsqlReturnValue_JsonImpl;
+  const LibsqlReturnValue_Json._() : super._();
+
+  String get field0;
+
+  /// Create a copy of LibsqlReturnValue
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$LibsqlReturnValue_JsonImplCopyWith<_$LibsqlReturnValue_JsonImpl>
+      get copyWith => throw _privateConstructorUsedError;
+}
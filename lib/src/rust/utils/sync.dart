@@ -0,0 +1,67 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.10.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'error.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
+import 'result.dart';
+part 'sync.freezed.dart';
+
+            /// What `LibsqlConnection::push` does when the remote rejects local changes.
+enum LibsqlSyncConflictPolicy {
+                    /// Fail with a `SyncConflict` error and keep the local changes
+fail,
+/// Discard the local database and start over from the remote's state
+remoteWins,
+/// Discard the local database, pull the remote's state, write the
+/// changes again with the callback set by
+/// `LibsqlConnection::set_conflict_replay` and push them, giving up
+/// with the `SyncConflict` error after a few attempts
+retryAfterPull,
+                    ;
+
+                }
+
+@freezed
+                sealed class LibsqlSyncEvent with _$LibsqlSyncEvent  {
+                    const LibsqlSyncEvent._();
+
+                     const factory LibsqlSyncEvent.synced({   required LibsqlSyncTrigger trigger ,  required SyncResult result , }) = LibsqlSyncEvent_Synced;
+ const factory LibsqlSyncEvent.failed({   required LibsqlSyncTrigger trigger ,  required LibsqlError error , }) = LibsqlSyncEvent_Failed;
+
+                }
+
+class LibsqlSyncStatus  {
+                /// Whether a sync is running right now
+final bool syncing;
+final DateTime? lastSyncedAt;
+/// Result of the last sync that succeeded
+final SyncResult? lastResult;
+/// Error of the last sync, cleared once one succeeds
+final LibsqlError? lastError;
+
+                const LibsqlSyncStatus({required this.syncing ,this.lastSyncedAt ,this.lastResult ,this.lastError ,});
+
+        @override
+        int get hashCode => syncing.hashCode^lastSyncedAt.hashCode^lastResult.hashCode^lastError.hashCode;
+
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is LibsqlSyncStatus &&
+                runtimeType == other.runtimeType
+                && syncing == other.syncing&& lastSyncedAt == other.lastSyncedAt&& lastResult == other.lastResult&& lastError == other.lastError;
+
+            }
+
+enum LibsqlSyncTrigger {
+                    /// A call to `LibsqlConnection::sync`
+manual,
+/// The timer started by `sync_interval_seconds`
+periodic,
+                    ;
+
+                }
@@ -0,0 +1,398 @@
+// coverage:ignore-file
+// GENERATED CODE - DO NOT MODIFY BY HAND
+// ignore_for_file: type=lint
+// ignore_for_file: unused_element, deprecated_member_use, deprecated_member_use_from_same_package, use_function_type_syntax_for_parameters, unnecessary_const, avoid_init_to_null, invalid_override_different_default_values_named, prefer_expression_function_bodies, annotate_overrides, invalid_annotation_target, unnecessary_question_mark
+
+part of 'sync.dart';
+
+// **************************************************************************
+// FreezedGenerator
+// **************************************************************************
+
+T _$identity<T>(T value) => value;
+
+final _privateConstructorUsedError = UnsupportedError(
+    'It seems like you constructed your class using `MyClass._()`. This constructor is only meant to be used by freezed and you are not supposed to need it nor use it.\nPlease check the documentation here for more information: https://github.com/rrousselGit/freezed#adding-getters-and-methods-to-our-models');
+
+/// @nodoc
+mixin _$LibsqlSyncEvent {
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(LibsqlSyncTrigger trigger, SyncResult result)
+        synced,
+    required TResult Function(LibsqlSyncTrigger trigger, LibsqlError error)
+        failed,
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(LibsqlSyncTrigger trigger, SyncResult result)? synced,
+    TResult? Function(LibsqlSyncTrigger trigger, LibsqlError error)? failed,
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(LibsqlSyncTrigger trigger, SyncResult result)? synced,
+    TResult Function(LibsqlSyncTrigger trigger, LibsqlError error)? failed,
+    required TResult orElse(),
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(LibsqlSyncEvent_Synced value) synced,
+    required TResult Function(LibsqlSyncEvent_Failed value) failed,
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(LibsqlSyncEvent_Synced value)? synced,
+    TResult? Function(LibsqlSyncEvent_Failed value)? failed,
+  }) =>
+      throw _privateConstructorUsedError;
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(LibsqlSyncEvent_Synced value)? synced,
+    TResult Function(LibsqlSyncEvent_Failed value)? failed,
+    required TResult orElse(),
+  }) =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class $LibsqlSyncEventCopyWith<$Res> {
+  factory $LibsqlSyncEventCopyWith(
+          LibsqlSyncEvent value, $Res Function(LibsqlSyncEvent) then) =
+      _$LibsqlSyncEventCopyWithImpl<$Res, LibsqlSyncEvent>;
+}
+
+/// @nodoc
+class _$LibsqlSyncEventCopyWithImpl<$Res, $Val extends LibsqlSyncEvent>
+    implements $LibsqlSyncEventCopyWith<$Res> {
+  _$LibsqlSyncEventCopyWithImpl(this._value, this._then);
+
+  // ignore: unused_field
+  final $Val _value;
+  // ignore: unused_field
+  final $Res Function($Val) _then;
+
+  /// Create a copy of LibsqlSyncEvent
+  /// with the given fields replaced by the non-null parameter values.
+}
+
+/// @nodoc
+abstract class _$$LibsqlSyncEvent_SyncedImplCopyWith<$Res> {
+  factory _$$LibsqlSyncEvent_SyncedImplCopyWith(
+          _$LibsqlSyncEvent_SyncedImpl value,
+          $Res Function(_$LibsqlSyncEvent_SyncedImpl) then) =
+      __$$LibsqlSyncEvent_SyncedImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({LibsqlSyncTrigger trigger, SyncResult result});
+}
+
+/// @nodoc
+class __$$LibsqlSyncEvent_SyncedImplCopyWithImpl<$Res>
+    extends _$LibsqlSyncEventCopyWithImpl<$Res, _$LibsqlSyncEvent_SyncedImpl>
+    implements _$$LibsqlSyncEvent_SyncedImplCopyWith<$Res> {
+  __$$LibsqlSyncEvent_SyncedImplCopyWithImpl(
+      _$LibsqlSyncEvent_SyncedImpl _value,
+      $Res Function(_$LibsqlSyncEvent_SyncedImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of LibsqlSyncEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? trigger = null,
+    Object? result = null,
+  }) {
+    return _then(_$LibsqlSyncEvent_SyncedImpl(
+      trigger: null == trigger
+          ? _value.trigger
+          : trigger // ignore: cast_nullable_to_non_nullable
+              as LibsqlSyncTrigger,
+      result: null == result
+          ? _value.result
+          : result // ignore: cast_nullable_to_non_nullable
+              as SyncResult,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$LibsqlSyncEvent_SyncedImpl extends LibsqlSyncEvent_Synced {
+  const _$LibsqlSyncEvent_SyncedImpl(
+      {required this.trigger, required this.result})
+      : super._();
+
+  @override
+  final LibsqlSyncTrigger trigger;
+  @override
+  final SyncResult result;
+
+  @override
+  String toString() {
+    return 'LibsqlSyncEvent.synced(trigger: $trigger, result: $result)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$LibsqlSyncEvent_SyncedImpl &&
+            (identical(other.trigger, trigger) || other.trigger == trigger) &&
+            (identical(other.result, result) || other.result == result));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, trigger, result);
+
+  /// Create a copy of LibsqlSyncEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$LibsqlSyncEvent_SyncedImplCopyWith<_$LibsqlSyncEvent_SyncedImpl>
+      get copyWith => __$$LibsqlSyncEvent_SyncedImplCopyWithImpl<
+          _$LibsqlSyncEvent_SyncedImpl>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(LibsqlSyncTrigger trigger, SyncResult result)
+        synced,
+    required TResult Function(LibsqlSyncTrigger trigger, LibsqlError error)
+        failed,
+  }) {
+    return synced(trigger, result);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(LibsqlSyncTrigger trigger, SyncResult result)? synced,
+    TResult? Function(LibsqlSyncTrigger trigger, LibsqlError error)? failed,
+  }) {
+    return synced?.call(trigger, result);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(LibsqlSyncTrigger trigger, SyncResult result)? synced,
+    TResult Function(LibsqlSyncTrigger trigger, LibsqlError error)? failed,
+    required TResult orElse(),
+  }) {
+    if (synced != null) {
+      return synced(trigger, result);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(LibsqlSyncEvent_Synced value) synced,
+    required TResult Function(LibsqlSyncEvent_Failed value) failed,
+  }) {
+    return synced(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(LibsqlSyncEvent_Synced value)? synced,
+    TResult? Function(LibsqlSyncEvent_Failed value)? failed,
+  }) {
+    return synced?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(LibsqlSyncEvent_Synced value)? synced,
+    TResult Function(LibsqlSyncEvent_Failed value)? failed,
+    required TResult orElse(),
+  }) {
+    if (synced != null) {
+      return synced(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class LibsqlSyncEvent_Synced extends LibsqlSyncEvent {
+  const factory LibsqlSyncEvent_Synced(
+      {required final LibsqlSyncTrigger trigger,
+      required final SyncResult result}) = _$LibsqlSyncEvent_SyncedImpl;
+  const LibsqlSyncEvent_Synced._() : super._();
+
+  LibsqlSyncTrigger get trigger;
+  SyncResult get result;
+
+  /// Create a copy of LibsqlSyncEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$LibsqlSyncEvent_SyncedImplCopyWith<_$LibsqlSyncEvent_SyncedImpl>
+      get copyWith => throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$LibsqlSyncEvent_FailedImplCopyWith<$Res> {
+  factory _$$LibsqlSyncEvent_FailedImplCopyWith(
+          _$LibsqlSyncEvent_FailedImpl value,
+          $Res Function(_$LibsqlSyncEvent_FailedImpl) then) =
+      __$$LibsqlSyncEvent_FailedImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({LibsqlSyncTrigger trigger, LibsqlError error});
+}
+
+/// @nodoc
+class __$$LibsqlSyncEvent_FailedImplCopyWithImpl<$Res>
+    extends _$LibsqlSyncEventCopyWithImpl<$Res, _$LibsqlSyncEvent_FailedImpl>
+    implements _$$LibsqlSyncEvent_FailedImplCopyWith<$Res> {
+  __$$LibsqlSyncEvent_FailedImplCopyWithImpl(
+      _$LibsqlSyncEvent_FailedImpl _value,
+      $Res Function(_$LibsqlSyncEvent_FailedImpl) _then)
+      : super(_value, _then);
+
+  /// Create a copy of LibsqlSyncEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? trigger = null,
+    Object? error = null,
+  }) {
+    return _then(_$LibsqlSyncEvent_FailedImpl(
+      trigger: null == trigger
+          ? _value.trigger
+          : trigger // ignore: cast_nullable_to_non_nullable
+              as LibsqlSyncTrigger,
+      error: null == error
+          ? _value.error
+          : error // ignore: cast_nullable_to_non_nullable
+              as LibsqlError,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$LibsqlSyncEvent_FailedImpl extends LibsqlSyncEvent_Failed {
+  const _$LibsqlSyncEvent_FailedImpl(
+      {required this.trigger, required this.error})
+      : super._();
+
+  @override
+  final LibsqlSyncTrigger trigger;
+  @override
+  final LibsqlError error;
+
+  @override
+  String toString() {
+    return 'LibsqlSyncEvent.failed(trigger: $trigger, error: $error)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$LibsqlSyncEvent_FailedImpl &&
+            (identical(other.trigger, trigger) || other.trigger == trigger) &&
+            (identical(other.error, error) || other.error == error));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, trigger, error);
+
+  /// Create a copy of LibsqlSyncEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$LibsqlSyncEvent_FailedImplCopyWith<_$LibsqlSyncEvent_FailedImpl>
+      get copyWith => __$$LibsqlSyncEvent_FailedImplCopyWithImpl<
+          _$LibsqlSyncEvent_FailedImpl>(this, _$identity);
+
+  @override
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(LibsqlSyncTrigger trigger, SyncResult result)
+        synced,
+    required TResult Function(LibsqlSyncTrigger trigger, LibsqlError error)
+        failed,
+  }) {
+    return failed(trigger, error);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(LibsqlSyncTrigger trigger, SyncResult result)? synced,
+    TResult? Function(LibsqlSyncTrigger trigger, LibsqlError error)? failed,
+  }) {
+    return failed?.call(trigger, error);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(LibsqlSyncTrigger trigger, SyncResult result)? synced,
+    TResult Function(LibsqlSyncTrigger trigger, LibsqlError error)? failed,
+    required TResult orElse(),
+  }) {
+    if (failed != null) {
+      return failed(trigger, error);
+    }
+    return orElse();
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(LibsqlSyncEvent_Synced value) synced,
+    required TResult Function(LibsqlSyncEvent_Failed value) failed,
+  }) {
+    return failed(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(LibsqlSyncEvent_Synced value)? synced,
+    TResult? Function(LibsqlSyncEvent_Failed value)? failed,
+  }) {
+    return failed?.call(this);
+  }
+
+  @override
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(LibsqlSyncEvent_Synced value)? synced,
+    TResult Function(LibsqlSyncEvent_Failed value)? failed,
+    required TResult orElse(),
+  }) {
+    if (failed != null) {
+      return failed(this);
+    }
+    return orElse();
+  }
+}
+
+abstract class LibsqlSyncEvent_Failed extends LibsqlSyncEvent {
+  const factory LibsqlSyncEvent_Failed(
+      {required final LibsqlSyncTrigger trigger,
+      required final LibsqlError error}) = _$LibsqlSyncEvent_FailedImpl;
+  const LibsqlSyncEvent_Failed._() : super._();
+
+  LibsqlSyncTrigger get trigger;
+  LibsqlError get error;
+
+  /// Create a copy of LibsqlSyncEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  _$$LibsqlSyncEvent_FailedImplCopyWith<_$LibsqlSyncEvent_FailedImpl>
+      get copyWith => throw _privateConstructorUsedError;
+}
@@ -1,21 +1,29 @@
 import 'package:libsql_dart/src/helpers.dart';
+import 'package:libsql_dart/src/rows.dart';
 import 'package:libsql_dart/src/rust/api/api.dart';
 import 'package:libsql_dart/src/rust/utils/params.dart';
+import 'package:libsql_dart/src/rust/utils/result.dart';
 
 // This is for internal only
 class Statement {
-  Statement(this.inner);
+  Statement(
+    this.inner, {
+    this.dateTimeFormat = LibsqlDateTimeFormat.unixMillis,
+  });
 
   final LibsqlStatement inner;
 
+  // How DateTime parameters are written
+  final LibsqlDateTimeFormat dateTimeFormat;
+
   // Finalize statement
   Future<void> finalize() async {
-    inner.finalize();
+    await inner.finalize();
   }
 
   // Reset statement
   Future<void> reset() async {
-    inner.reset();
+    await inner.reset();
   }
 
   /// Query the statement, you can provide either named or positional parameters
@@ -23,37 +31,87 @@ class Statement {
   /// # Args
   /// * `named` - Named parameters
   /// * `positional` - Positional parameters
+  /// * `timeout` - Fails the call with an `interrupted` error after this long
+  /// * `cancel` - Token that stops the call once cancelled
   ///
   /// # Returns
   /// Returns a list of object, eg: [{'id': 1, 'name': 'John'}, {'id': 2, 'name': 'Jane'}]
   Future<List<Map<String, dynamic>>> query({
     Map<String, dynamic>? named,
     List<dynamic>? positional,
+    Duration? timeout,
+    LibsqlCancelToken? cancel,
   }) async {
     final res = await inner.query(
-      parameters: LibsqlParams(
-        named: named?.map((k, v) => MapEntry(k, toLibsqlValue(v))),
-        positional: positional?.map(toLibsqlValue).toList(),
+      parameters: toLibsqlParams(
+        named: named,
+        positional: positional,
+        dateTimeFormat: dateTimeFormat,
+      ),
+      timeoutMs: toTimeoutMs(timeout),
+      cancel: cancel,
+    );
+    return res.rows.map(toDartRow).toList();
+  }
+
+  /// Query the statement, streaming the rows instead of reading them all
+  /// at once, you can provide either named or positional parameters
+  ///
+  /// # Args
+  /// * `named` - Named parameters
+  /// * `positional` - Positional parameters
+  /// * `pageSize` - Rows fetched at a time while the stream is listened to
+  /// * `timeout` - Fails the call with an `interrupted` error after this long
+  /// * `cancel` - Token that stops the call once cancelled
+  ///
+  /// # Returns
+  /// Returns a stream of object, eg: {'id': 1, 'name': 'John'}, {'id': 2, 'name': 'Jane'}
+  Stream<Map<String, dynamic>> queryStream({
+    Map<String, dynamic>? named,
+    List<dynamic>? positional,
+    int pageSize = 100,
+    Duration? timeout,
+    LibsqlCancelToken? cancel,
+  }) async* {
+    final rows = await inner.queryStream(
+      parameters: toLibsqlParams(
+        named: named,
+        positional: positional,
+        dateTimeFormat: dateTimeFormat,
+      ),
+      timeoutMs: toTimeoutMs(timeout),
+      cancel: cancel,
+    );
+    yield* rowStream(rows, pageSize: pageSize);
+  }
+
+  /// Query the statement, returning the values in columnar form instead of
+  /// a map per row, you can provide either named or positional parameters
+  ///
+  /// # Args
+  /// * `named` - Named parameters
+  /// * `positional` - Positional parameters
+  /// * `timeout` - Fails the call with an `interrupted` error after this long
+  /// * `cancel` - Token that stops the call once cancelled
+  ///
+  /// # Returns
+  /// Column names once and the values of every row in a single flat list,
+  /// row `r` spans `values[r * columns.length..(r + 1) * columns.length]`
+  Future<ColumnarQueryResult> queryColumnar({
+    Map<String, dynamic>? named,
+    List<dynamic>? positional,
+    Duration? timeout,
+    LibsqlCancelToken? cancel,
+  }) async {
+    return inner.queryColumnar(
+      parameters: toLibsqlParams(
+        named: named,
+        positional: positional,
+        dateTimeFormat: dateTimeFormat,
       ),
+      timeoutMs: toTimeoutMs(timeout),
+      cancel: cancel,
     );
-    return res.rows
-        .map(
-          (row) => Map.fromEntries(
-            row.entries.map(
-              (entry) => MapEntry(
-                entry.key,
-                entry.value.mapOrNull(
-                  integer: (integer) => integer.field0,
-                  real: (real) => real.field0,
-                  text: (text) => text.field0,
-                  blob: (blob) => blob.field0,
-                  null_: (_) => null,
-                ),
-              ),
-            ),
-          ),
-        )
-        .toList();
   }
 
   /// Execute the statement, you can provide either named or positional parameters
@@ -62,18 +120,25 @@ class Statement {
   /// * `sql` - SQL query
   /// * `named` - Named parameters
   /// * `positional` - Positional parameters
+  /// * `timeout` - Fails the call with an `interrupted` error after this long
+  /// * `cancel` - Token that stops the call once cancelled
   ///
   /// # Returns
   /// Number of rows affected by the statement
   Future<int> execute({
     Map<String, dynamic>? named,
     List<dynamic>? positional,
+    Duration? timeout,
+    LibsqlCancelToken? cancel,
   }) async {
     final res = await inner.execute(
-      parameters: LibsqlParams(
-        named: named?.map((k, v) => MapEntry(k, toLibsqlValue(v))),
-        positional: positional?.map(toLibsqlValue).toList(),
+      parameters: toLibsqlParams(
+        named: named,
+        positional: positional,
+        dateTimeFormat: dateTimeFormat,
       ),
+      timeoutMs: toTimeoutMs(timeout),
+      cancel: cancel,
     );
     return res.rowsAffected.toInt();
   }
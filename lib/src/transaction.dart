@@ -1,55 +1,75 @@
 import 'package:libsql_dart/src/helpers.dart';
+import 'package:libsql_dart/src/rust/api/libsql.dart';
 import 'package:libsql_dart/src/rust/api/transaction.dart';
 import 'package:libsql_dart/src/rust/utils/params.dart';
+import 'package:libsql_dart/src/rust/utils/result.dart';
 
 class Transaction {
-  Transaction(this.inner);
+  Transaction(
+    this.inner, {
+    this.dateTimeFormat = LibsqlDateTimeFormat.unixMillis,
+  });
 
   final LibsqlTransaction inner;
 
+  // How DateTime parameters are written
+  final LibsqlDateTimeFormat dateTimeFormat;
+
   Future<List<Map<String, dynamic>>> query(
     String sql, {
     Map<String, dynamic>? named,
     List<dynamic>? positional,
+    Duration? timeout,
+    LibsqlCancelToken? cancel,
   }) async {
     final res = await inner.query(
       sql: sql,
-      parameters: LibsqlParams(
-        named: named?.map((k, v) => MapEntry(k, toLibsqlValue(v))),
-        positional: positional?.map(toLibsqlValue).toList(),
+      parameters: toLibsqlParams(
+        named: named,
+        positional: positional,
+        dateTimeFormat: dateTimeFormat,
+      ),
+      timeoutMs: toTimeoutMs(timeout),
+      cancel: cancel,
+    );
+    return res.rows.map(toDartRow).toList();
+  }
+
+  Future<ColumnarQueryResult> queryColumnar(
+    String sql, {
+    Map<String, dynamic>? named,
+    List<dynamic>? positional,
+    Duration? timeout,
+    LibsqlCancelToken? cancel,
+  }) async {
+    return inner.queryColumnar(
+      sql: sql,
+      parameters: toLibsqlParams(
+        named: named,
+        positional: positional,
+        dateTimeFormat: dateTimeFormat,
       ),
+      timeoutMs: toTimeoutMs(timeout),
+      cancel: cancel,
     );
-    return res.rows
-        .map(
-          (row) => Map.fromEntries(
-            row.entries.map(
-              (entry) => MapEntry(
-                entry.key,
-                entry.value.mapOrNull(
-                  integer: (integer) => integer.field0,
-                  real: (real) => real.field0,
-                  text: (text) => text.field0,
-                  blob: (blob) => blob.field0,
-                  null_: (_) => null,
-                ),
-              ),
-            ),
-          ),
-        )
-        .toList();
   }
 
   Future<int> execute(
     String sql, {
     Map<String, dynamic>? named,
     List<dynamic>? positional,
+    Duration? timeout,
+    LibsqlCancelToken? cancel,
   }) async {
     final res = await inner.execute(
       sql: sql,
-      parameters: LibsqlParams(
-        named: named?.map((k, v) => MapEntry(k, toLibsqlValue(v))),
-        positional: positional?.map(toLibsqlValue).toList(),
+      parameters: toLibsqlParams(
+        named: named,
+        positional: positional,
+        dateTimeFormat: dateTimeFormat,
       ),
+      timeoutMs: toTimeoutMs(timeout),
+      cancel: cancel,
     );
     return res.rowsAffected.toInt();
   }
@@ -1,105 +1,1828 @@
-use std::path::Path;
+use std::{
+    cmp::Ordering,
+    future::{self, Future},
+    path::Path,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
 
 use super::{
-    statement::LibsqlStatement,
-    transaction::{LibsqlTransaction, LibsqlTransactionBehavior},
+    libsql::LibsqlCancelToken,
+    rows::{InnerRows, LibsqlRows},
+    statement::{InnerStatement, LibsqlStatement},
+    transaction::{LibsqlTransaction, LibsqlTransactionBehavior, TransactionState},
 };
+use crate::frb_generated::StreamSink;
 use crate::utils::{
-    params::LibsqlParams,
-    result::{ExecuteResult, QueryResult},
+    changes::{ChangeTracker, LibsqlUpdate},
+    error::{LibsqlError, LibsqlErrorKind, LibsqlSyncConflict},
+    helpers::{query_connection, row_to_map, rows_columns},
+    interrupt::Interrupts,
+    lock::Locks,
+    params::{bind_params, inline_params, LibsqlBatchStatement, LibsqlParams, LibsqlValue},
+    raw::{self, AggregateFunction, Collation, RawConnection},
+    result::{BatchResult, ColumnarQueryResult, ExecuteResult, QueryResult, SyncResult},
+    retry::{retry_busy, LibsqlBusyRetry},
+    return_value::{DecodeOptions, LibsqlReturnValue, ValueDecoder},
+    sql::{is_readonly, referenced_tables},
+    sync::{
+        self, LibsqlSyncConflictPolicy, LibsqlSyncEvent, LibsqlSyncStatus, LibsqlSyncTrigger,
+        SyncTracker, SyncedReplica,
+    },
+    watch::{self, WatchedQuery},
 };
-use flutter_rust_bridge::{frb, RustAutoOpaqueNom};
+use flutter_rust_bridge::{frb, DartFnFuture, DartOpaque, RustAutoOpaqueNom};
+use futures::executor::block_on;
 pub use libsql::TransactionBehavior;
 pub use libsql::{Connection as InnerConnection, Database as InnerDatabase};
 
+/// A connection to a local, remote or replicated database.
+///
+/// Calls may overlap from Dart: each one waits for the calls ahead of it
+/// that need the connection exclusively, such as `close`, in the order they
+/// were made, and fails with an `Interrupted` error once the connection's
+/// `lock_timeout_ms` runs out.
 #[frb(opaque)]
 pub struct LibsqlConnection {
-    connection: RustAutoOpaqueNom<InnerConnection>,
-    database: RustAutoOpaqueNom<InnerDatabase>,
+    // both are taken on `close`, taking the database stops the periodic sync task
+    connection: RustAutoOpaqueNom<Option<InnerConnection>>,
+    database: RustAutoOpaqueNom<Option<InnerDatabase>>,
+    handles: Arc<Mutex<OpenHandles>>,
+    decoding: DecodeOptions,
+    changes: Arc<ChangeTracker>,
+    // stops the calls in flight, also holds the SQLite handle of local
+    // connections, replaced along with `connection` when a synced database
+    // is reopened
+    interrupts: Arc<Interrupts>,
+    // shared with the handles made from this connection
+    locks: Locks,
+    busy_retry: Option<LibsqlBusyRetry>,
+    // reapplied when a synced database is reopened
+    busy_timeout: Mutex<Option<Duration>>,
+    syncs: Arc<SyncTracker>,
+    // only set for synced databases opened with `offline`
+    synced_replica: Option<SyncedReplica>,
+    // writes rejected changes again for `LibsqlSyncConflictPolicy::RetryAfterPull`
+    replay: Mutex<Option<Arc<Replay>>>,
+    // set for connections to a remote database, which run over HTTP
+    remote: bool,
+}
+
+type Replay = dyn Fn() -> DartFnFuture<()> + Send + Sync;
+
+// pushes made again by `LibsqlSyncConflictPolicy::RetryAfterPull` before
+// it gives up
+const PUSH_RETRIES: usize = 3;
+
+/// Statements, row cursors and transactions handed out to Dart, so `close`
+/// can release them without waiting for Dart's finalizers.
+#[derive(Default)]
+#[frb(ignore)]
+pub(crate) struct OpenHandles {
+    statements: Vec<RustAutoOpaqueNom<Option<InnerStatement>>>,
+    rows: Vec<RustAutoOpaqueNom<Option<InnerRows>>>,
+    transactions: Vec<RustAutoOpaqueNom<TransactionState>>,
+}
+
+impl OpenHandles {
+    pub(crate) fn add_statement(&mut self, statement: RustAutoOpaqueNom<Option<InnerStatement>>) {
+        // finalized or dropped statements leave an empty slot behind
+        self.statements
+            .retain(|statement| !matches!(statement.try_read().as_deref(), Ok(None)));
+        self.statements.push(statement);
+    }
+
+    pub(crate) fn add_rows(&mut self, rows: RustAutoOpaqueNom<Option<InnerRows>>) {
+        // so do exhausted, closed or dropped cursors
+        self.rows
+            .retain(|rows| !matches!(rows.try_read().as_deref(), Ok(None)));
+        self.rows.push(rows);
+    }
+
+    fn add_transaction(&mut self, transaction: RustAutoOpaqueNom<TransactionState>) {
+        self.transactions.retain(|transaction| {
+            !matches!(transaction.try_read().map(|t| t.is_active()), Ok(false))
+        });
+        self.transactions.push(transaction);
+    }
 }
 
 impl LibsqlConnection {
-    pub fn new(connection: InnerConnection, database: InnerDatabase) -> LibsqlConnection {
+    pub(crate) fn new(connection: InnerConnection, database: InnerDatabase) -> LibsqlConnection {
         LibsqlConnection {
-            connection: RustAutoOpaqueNom::new(connection),
-            database: RustAutoOpaqueNom::new(database),
+            connection: RustAutoOpaqueNom::new(Some(connection)),
+            database: RustAutoOpaqueNom::new(Some(database)),
+            handles: Arc::new(Mutex::new(OpenHandles::default())),
+            decoding: DecodeOptions::default(),
+            changes: Arc::new(ChangeTracker::default()),
+            interrupts: Arc::new(Interrupts::default()),
+            locks: Locks::default(),
+            busy_retry: None,
+            busy_timeout: Mutex::new(None),
+            syncs: Arc::new(SyncTracker::default()),
+            synced_replica: None,
+            replay: Mutex::new(None),
+            remote: false,
         }
     }
 
-    pub async fn sync(&self) {
-        self.database.try_read().unwrap().sync().await.unwrap();
+    pub(crate) fn with_decoding(mut self, decoding: DecodeOptions) -> Self {
+        self.decoding = decoding;
+        self
     }
 
-    pub async fn query(&self, sql: String, parameters: Option<LibsqlParams>) -> QueryResult {
-        self.prepare(sql).await.query(parameters).await
+    pub(crate) fn with_lock_timeout(self, timeout: Option<Duration>) -> Self {
+        self.locks.set_timeout(timeout);
+        self
     }
 
-    pub async fn execute(&self, sql: String, parameters: Option<LibsqlParams>) -> ExecuteResult {
-        self.prepare(sql).await.execute(parameters).await
+    pub(crate) fn with_busy_retry(mut self, busy_retry: Option<LibsqlBusyRetry>) -> Self {
+        self.busy_retry = busy_retry;
+        self
     }
 
-    pub async fn prepare(&self, sql: String) -> LibsqlStatement {
-        let statement = self
-            .connection
-            .try_read()
-            .unwrap()
-            .prepare(&sql)
+    /// Starts syncing the database every `interval` in the background.
+    pub(crate) fn with_sync_interval(self, interval: Option<Duration>) -> Self {
+        if let Some(interval) = interval {
+            flutter_rust_bridge::spawn(sync::run_periodic(
+                self.database.clone(),
+                Arc::downgrade(&self.syncs),
+                interval,
+            ));
+        }
+        self
+    }
+
+    pub(crate) fn with_raw(self, raw: Option<RawConnection>) -> Self {
+        self.interrupts.set_raw(raw);
+        self
+    }
+
+    pub(crate) fn with_remote(mut self, remote: bool) -> Self {
+        self.remote = remote;
+        self
+    }
+
+    pub(crate) fn with_synced_replica(mut self, synced_replica: Option<SyncedReplica>) -> Self {
+        self.synced_replica = synced_replica;
+        self
+    }
+
+    /// Rolls back open transactions, finalizes prepared statements and
+    /// releases the connection and database. Any later call on this
+    /// connection, or on statements prepared from it, fails with a
+    /// `Closed` error. Closing twice is a no-op.
+    pub async fn close(&self) -> Result<(), LibsqlError> {
+        self.release_handles().await?;
+        let mut connection = self.locks.write(&self.connection).await?;
+        if connection.is_some() {
+            self.detach_raw();
+        }
+        connection.take();
+        drop(connection);
+        self.changes.close();
+        self.locks.write(&self.database).await?.take();
+        self.syncs.close();
+        Ok(())
+    }
+
+    /// Rolls back the open transactions, closes the row cursors and
+    /// finalizes the prepared statements handed out so far. Handles still in
+    /// use when the lock timeout runs out are kept for the next attempt.
+    async fn release_handles(&self) -> Result<(), LibsqlError> {
+        let mut handles = std::mem::take(&mut *self.handles.lock().unwrap());
+        let released = async {
+            while let Some(transaction) = handles.transactions.last() {
+                let finished = self
+                    .locks
+                    .write(transaction)
+                    .await?
+                    .finish(TransactionState::RolledBack);
+                if let Ok(transaction) = finished {
+                    // the connection is going away either way, a failed
+                    // rollback is settled by SQLite when the handle is closed
+                    let _ = transaction.rollback().await;
+                }
+                handles.transactions.pop();
+            }
+            while let Some(rows) = handles.rows.last() {
+                self.locks.write(rows).await?.take();
+                handles.rows.pop();
+            }
+            while let Some(statement) = handles.statements.last() {
+                if let Some(mut statement) = self.locks.write(statement).await?.take() {
+                    statement.finalize();
+                }
+                handles.statements.pop();
+            }
+            Ok(())
+        }
+        .await;
+        if released.is_err() {
+            let mut open = self.handles.lock().unwrap();
+            open.transactions.append(&mut handles.transactions);
+            open.rows.append(&mut handles.rows);
+            open.statements.append(&mut handles.statements);
+        }
+        released
+    }
+
+    /// Pulls the latest changes of an embedded replica or synced database
+    /// from its remote.
+    pub async fn sync(&self) -> Result<SyncResult, LibsqlError> {
+        let database = self.locks.read(&self.database).await?;
+        let database = database.as_ref().ok_or_else(LibsqlError::closed)?;
+        sync::sync(
+            database,
+            &self.syncs,
+            LibsqlSyncTrigger::Manual,
+            None,
+            None,
+            self.synced_replica.is_some(),
+        )
+        .await
+    }
+
+    /// Syncs an embedded replica until it has caught up with
+    /// `replication_index`, such as one returned by
+    /// `max_write_replication_index` on another device, so reads made
+    /// afterwards see that write.
+    ///
+    /// Waits for as long as it takes unless `timeout_ms` is given, after
+    /// which it fails with an `Interrupted` error.
+    pub async fn sync_until(
+        &self,
+        replication_index: u64,
+        timeout_ms: Option<u64>,
+    ) -> Result<SyncResult, LibsqlError> {
+        let database = self.locks.read(&self.database).await?;
+        let database = database.as_ref().ok_or_else(LibsqlError::closed)?;
+        sync::sync(
+            database,
+            &self.syncs,
+            LibsqlSyncTrigger::Manual,
+            Some(replication_index),
+            timeout_ms.map(Duration::from_millis),
+            self.synced_replica.is_some(),
+        )
+        .await
+    }
+
+    /// Sends the changes committed locally to a database opened with
+    /// `offline` to its remote. Returns right away when nothing was
+    /// committed since the last push or pull, and fails with a `Network`
+    /// error, keeping the changes for the next push, when the remote can't
+    /// be reached.
+    ///
+    /// When the remote has moved on since the changes were written it
+    /// rejects them, and `conflict_policy` decides what happens: by default
+    /// this fails with a `SyncConflict` error describing the rejected
+    /// frames, while `RemoteWins` discards the local database, as with
+    /// `discard_local_changes`, and returns the result of pulling it afresh.
+    /// `RetryAfterPull` does the same, then runs the callback set with
+    /// `set_conflict_replay` to write the changes again and pushes those,
+    /// up to three times before failing with the last `SyncConflict` error.
+    /// libsql does not say why it failed a push, so a pull into a scratch
+    /// copy next to the database finds out whether the remote moved on.
+    ///
+    /// libsql has a single sync for these databases, which pushes when there
+    /// are local frames the remote has not seen and pulls otherwise. `push`
+    /// and `pull` both run it, so before this connection has pushed or
+    /// pulled once, `push` may pull instead when there is nothing to send.
+    pub async fn push(
+        &self,
+        conflict_policy: Option<LibsqlSyncConflictPolicy>,
+    ) -> Result<SyncResult, LibsqlError> {
+        let conflict_policy = conflict_policy.unwrap_or_default();
+        let replay = self.replay.lock().unwrap().clone();
+        if matches!(conflict_policy, LibsqlSyncConflictPolicy::RetryAfterPull) && replay.is_none() {
+            return Err(LibsqlError::misuse(
+                "RetryAfterPull needs a callback set with set_conflict_replay",
+            ));
+        }
+        let mut retries = 0;
+        loop {
+            let error = match self.push_frames().await {
+                Err(error) if error.conflict.is_some() => error,
+                result => return result,
+            };
+            match (conflict_policy, &replay) {
+                (LibsqlSyncConflictPolicy::RemoteWins, _) => {
+                    self.discard_local_changes().await?;
+                    return self.pull().await;
+                }
+                (LibsqlSyncConflictPolicy::RetryAfterPull, Some(replay))
+                    if retries < PUSH_RETRIES =>
+                {
+                    retries += 1;
+                    self.discard_local_changes().await?;
+                    self.pull().await?;
+                    replay().await;
+                }
+                _ => return Err(error),
+            }
+        }
+    }
+
+    /// Sets the callback `push` runs under `RetryAfterPull` once the local
+    /// database was replaced with the remote's, to write the rejected
+    /// changes again. It runs with nothing locked and can use this
+    /// connection, unlike the callbacks of SQL functions.
+    pub async fn set_conflict_replay(
+        &self,
+        replay: impl Fn() -> DartFnFuture<()> + Send + Sync + 'static,
+    ) {
+        *self.replay.lock().unwrap() = Some(Arc::new(replay));
+    }
+
+    /// Fetches the changes made on the remote of a database opened with
+    /// `offline`. This runs the same sync as `push`, for when there is
+    /// nothing local to send: it fails with a `Misuse` error while there are
+    /// local changes that have not been pushed yet, which libsql would push
+    /// instead, as it can only apply the remote's changes to a copy without
+    /// changes of its own.
+    ///
+    /// Local changes can only be told apart once this connection has pushed
+    /// or pulled, before that libsql pushes any it finds first.
+    pub async fn pull(&self) -> Result<SyncResult, LibsqlError> {
+        self.synced_replica()?;
+        let database = self.locks.read(&self.database).await?;
+        let database = database.as_ref().ok_or_else(LibsqlError::closed)?;
+        let frames = self.local_frames().await?;
+        if self
+            .syncs
+            .synced_frames()
+            .is_some_and(|synced| frames > synced)
+        {
+            return Err(LibsqlError::misuse(
+                "local changes have to be pushed before pulling",
+            ));
+        }
+        let result = sync::sync(
+            database,
+            &self.syncs,
+            LibsqlSyncTrigger::Manual,
+            None,
+            None,
+            true,
+        )
+        .await?;
+        self.syncs
+            .set_synced_frames(Some(self.local_frames().await?));
+        Ok(result)
+    }
+
+    /// Throws away the local copy of a database opened with `offline`,
+    /// including changes that were never pushed, and opens it again from
+    /// the remote.
+    ///
+    /// Like `close`, this rolls back open transactions and finalizes
+    /// prepared statements. Functions and collations created on this
+    /// connection and its busy timeout have to be set up again, `updates`
+    /// and `watch` streams keep running. When the fresh copy can't be
+    /// opened, the local one is kept and opened again, and if even that
+    /// fails the connection is left closed.
+    pub async fn discard_local_changes(&self) -> Result<(), LibsqlError> {
+        let replica = self.synced_replica()?;
+        self.release_handles().await?;
+        let mut connection = self.locks.write(&self.connection).await?;
+        let mut database = self.locks.write(&self.database).await?;
+        if connection.is_none() {
+            return Err(LibsqlError::closed());
+        }
+        self.detach_raw();
+        connection.take();
+        database.take();
+        // calls still running keep their own clone of the connection, so the
+        // files are moved out of their way rather than deleted under them
+        let discarded = match replica.set_aside_files() {
+            Ok(()) => {
+                let opened = self.open_replica(replica).await;
+                if opened.is_err() {
+                    // the connection stays closed if the files can't be put back
+                    replica.restore_files()?;
+                }
+                opened
+            }
+            Err(error) => Err(error),
+        };
+        let (reopened, reconnected, result) = match discarded {
+            Ok((reopened, reconnected)) => {
+                // the old copy is replaced either way
+                let _ = replica.remove_set_aside_files();
+                (reopened, reconnected, Ok(()))
+            }
+            Err(error) => {
+                let (reopened, reconnected) = self.open_replica(replica).await?;
+                (reopened, reconnected, Err(error))
+            }
+        };
+        *connection = Some(reconnected);
+        *database = Some(reopened);
+        if result.is_ok() {
+            self.syncs.set_synced_frames(Some(self.raw_frames()?));
+        }
+        result
+    }
+
+    /// Pushes the local frames, failing with a `SyncConflict` error when the
+    /// remote rejects them.
+    async fn push_frames(&self) -> Result<SyncResult, LibsqlError> {
+        let replica = self.synced_replica()?;
+        let synced = self.syncs.synced_frames();
+        // libsql leaves it as is when the push fails
+        let position = replica.position()?;
+        let frames = self.local_frames().await?;
+        if synced == Some(frames) {
+            return Ok(SyncResult::default());
+        }
+        let result = {
+            let database = self.locks.read(&self.database).await?;
+            let database = database.as_ref().ok_or_else(LibsqlError::closed)?;
+            // libsql pushes whenever there are frames the remote has not seen
+            sync::sync(
+                database,
+                &self.syncs,
+                LibsqlSyncTrigger::Manual,
+                None,
+                None,
+                true,
+            )
             .await
-            .unwrap();
-        LibsqlStatement::new(statement)
+        };
+        match result {
+            Ok(result) => {
+                self.syncs
+                    .set_synced_frames(Some(self.local_frames().await?));
+                Ok(result)
+            }
+            Err(error) => {
+                // whatever went wrong, the frames are still to be pushed
+                self.syncs.set_synced_frames(synced);
+                let (Some(position), LibsqlErrorKind::Sync) = (position, error.kind) else {
+                    return Err(error);
+                };
+                match replica.remote_position().await {
+                    Ok(remote)
+                        if remote.generation != position.generation
+                            || remote.durable_frame_num > position.durable_frame_num =>
+                    {
+                        Err(error.with_conflict(LibsqlSyncConflict {
+                            first_rejected_frame: position.durable_frame_num as u64 + 1,
+                            last_local_frame: frames as u64,
+                            remote_frame_no: remote.durable_frame_num as u64,
+                        }))
+                    }
+                    // the remote is where the push expected, or can't be
+                    // reached to tell
+                    _ => Err(error),
+                }
+            }
+        }
     }
 
-    pub async fn batch(&self, sql: String) {
-        self.connection
-            .try_read()
+    /// Opens the files of `replica`, bootstrapping them from the remote when
+    /// they are gone, and hooks the new connection up like the old one.
+    async fn open_replica(
+        &self,
+        replica: &SyncedReplica,
+    ) -> Result<(InnerDatabase, InnerConnection), LibsqlError> {
+        let database = replica.open().await?;
+        let (connection, raw_connection) = raw::connect(&database, false).await?;
+        if let (Some(raw_connection), true) = (raw_connection, self.changes.has_listeners()) {
+            raw_connection.set_change_hooks(Some(&self.changes));
+        }
+        if let (Some(raw_connection), Some(timeout)) =
+            (raw_connection, *self.busy_timeout.lock().unwrap())
+        {
+            raw_connection.set_busy_timeout(timeout)?;
+        }
+        self.interrupts.set_raw(raw_connection);
+        Ok((database, connection))
+    }
+
+    /// Unhooks the raw connection ahead of dropping it.
+    fn detach_raw(&self) {
+        if let Some(raw) = self.interrupts.set_raw(None) {
+            raw.set_change_hooks(None);
+        }
+    }
+
+    /// Local frame count, for callers already holding the connection lock.
+    fn raw_frames(&self) -> Result<u32, LibsqlError> {
+        let raw = self.interrupts.raw();
+        raw.ok_or_else(|| LibsqlError::misuse("only supported on local databases"))?
+            .wal_frame_count()
+    }
+
+    fn synced_replica(&self) -> Result<&SyncedReplica, LibsqlError> {
+        self.synced_replica
+            .as_ref()
+            .ok_or_else(|| LibsqlError::misuse("only supported on databases opened with offline"))
+    }
+
+    /// Highest replication index returned by writes made through this
+    /// connection's database, `None` before the first write. Only embedded
+    /// replicas, whose writes are sent to the primary, report one.
+    pub async fn max_write_replication_index(&self) -> Result<Option<u64>, LibsqlError> {
+        Ok(self
+            .locks
+            .read(&self.database)
+            .await?
+            .as_ref()
+            .ok_or_else(LibsqlError::closed)?
+            .max_write_replication_index())
+    }
+
+    /// Outcome of the latest syncs, whether started by `sync` or by
+    /// `sync_interval_seconds`.
+    pub async fn sync_status(&self) -> LibsqlSyncStatus {
+        self.syncs.status()
+    }
+
+    /// Streams the outcome of every sync from now on, manual or periodic. The
+    /// stream ends when the connection is closed.
+    pub async fn sync_events(&self, sink: StreamSink<LibsqlSyncEvent>) {
+        self.syncs.subscribe(sink);
+    }
+
+    pub async fn query(
+        &self,
+        sql: String,
+        parameters: Option<LibsqlParams>,
+        timeout_ms: Option<u64>,
+        cancel: Option<LibsqlCancelToken>,
+    ) -> Result<QueryResult, LibsqlError> {
+        self.interruptible(timeout_ms, cancel, async {
+            self.statement(&sql)
+                .await?
+                .query(parameters, None, None)
+                .await
+        })
+        .await
+    }
+
+    pub async fn query_columnar(
+        &self,
+        sql: String,
+        parameters: Option<LibsqlParams>,
+        timeout_ms: Option<u64>,
+        cancel: Option<LibsqlCancelToken>,
+    ) -> Result<ColumnarQueryResult, LibsqlError> {
+        self.interruptible(timeout_ms, cancel, async {
+            self.statement(&sql)
+                .await?
+                .query_columnar(parameters, None, None)
+                .await
+        })
+        .await
+    }
+
+    pub async fn query_stream(
+        &self,
+        sql: String,
+        parameters: Option<LibsqlParams>,
+        timeout_ms: Option<u64>,
+        cancel: Option<LibsqlCancelToken>,
+    ) -> Result<LibsqlRows, LibsqlError> {
+        self.interruptible(timeout_ms, cancel, async {
+            self.statement(&sql)
+                .await?
+                .query_stream(parameters, None, None)
+                .await
+        })
+        .await
+    }
+
+    pub async fn execute(
+        &self,
+        sql: String,
+        parameters: Option<LibsqlParams>,
+        timeout_ms: Option<u64>,
+        cancel: Option<LibsqlCancelToken>,
+    ) -> Result<ExecuteResult, LibsqlError> {
+        self.interruptible(timeout_ms, cancel, async {
+            retry_busy(self.busy_retry, || async {
+                self.statement(&sql)
+                    .await?
+                    .execute(parameters.clone(), None, None)
+                    .await
+            })
+            .await
+        })
+        .await
+    }
+
+    pub async fn prepare(&self, sql: String) -> Result<LibsqlStatement, LibsqlError> {
+        let statement = self.statement(&sql).await?.with_busy_retry(self.busy_retry);
+        self.handles
+            .lock()
             .unwrap()
-            .execute_batch(&sql)
+            .add_statement(statement.handle());
+        Ok(statement)
+    }
+
+    /// Prepares a statement for internal one-off use, without tracking it.
+    /// Row cursors it starts are still tracked.
+    async fn statement(&self, sql: &str) -> Result<LibsqlStatement, LibsqlError> {
+        let connection = self.locks.read(&self.connection).await?;
+        let statement = connection
+            .as_ref()
+            .ok_or_else(LibsqlError::closed)?
+            .prepare(sql)
+            .await?;
+        Ok(LibsqlStatement::new(
+            statement,
+            sql.to_string(),
+            self.decoding,
+            self.connection.clone(),
+            self.interrupts.clone(),
+            self.locks.clone(),
+            self.handles.clone(),
+        ))
+    }
+
+    pub async fn batch(
+        &self,
+        sql: String,
+        timeout_ms: Option<u64>,
+        cancel: Option<LibsqlCancelToken>,
+    ) -> Result<(), LibsqlError> {
+        let connection = self.connection().await?;
+        self.interruptible(timeout_ms, cancel, async {
+            connection.execute_batch(&sql).await?;
+            Ok(())
+        })
+        .await
+    }
+
+    /// Runs `statements` in order inside a single transaction and returns a
+    /// result for each of them. If any statement fails the transaction is
+    /// rolled back and none of the changes are kept.
+    ///
+    /// Remote connections send the whole batch in a single request, with the
+    /// parameters written into the SQL, so each statement must be a single
+    /// one. Columns are not decoded by their declared type there, and the
+    /// results have empty `decl_types`.
+    pub async fn batch_statements(
+        &self,
+        statements: Vec<LibsqlBatchStatement>,
+        behavior: Option<LibsqlTransactionBehavior>,
+        timeout_ms: Option<u64>,
+        cancel: Option<LibsqlCancelToken>,
+    ) -> Result<BatchResult, LibsqlError> {
+        self.interruptible(timeout_ms, cancel, self.run_batch(statements, behavior))
             .await
-            .unwrap();
     }
 
-    pub async fn transaction(
+    async fn run_batch(
+        &self,
+        statements: Vec<LibsqlBatchStatement>,
+        behavior: Option<LibsqlTransactionBehavior>,
+    ) -> Result<BatchResult, LibsqlError> {
+        if self.remote {
+            return self.run_remote_batch(statements, behavior).await;
+        }
+        let transaction = self.begin(behavior).await?;
+        let mut results = Vec::with_capacity(statements.len());
+        for statement in statements {
+            let result = query_connection(
+                &transaction,
+                &statement.sql,
+                statement.parameters,
+                self.decoding,
+            )
+            .await;
+            match result {
+                Ok(result) => results.push(result),
+                Err(error) => {
+                    // the statement's error is the one worth reporting, and a
+                    // failed rollback leaves nothing committed either way
+                    let _ = transaction.rollback().await;
+                    return Err(error);
+                }
+            }
+        }
+        transaction.commit().await?;
+        Ok(BatchResult { results })
+    }
+
+    /// Sends the batch as one request, reading the counters after each
+    /// statement from a query sent right after it. Like local statements,
+    /// those that changed nothing report no affected rows, rather than the
+    /// count `changes()` keeps from the last write.
+    async fn run_remote_batch(
         &self,
+        statements: Vec<LibsqlBatchStatement>,
         behavior: Option<LibsqlTransactionBehavior>,
-    ) -> LibsqlTransaction {
-        let behavior_ = match behavior {
-            Some(LibsqlTransactionBehavior::Deferred) => TransactionBehavior::Deferred,
-            Some(LibsqlTransactionBehavior::Exclusive) => TransactionBehavior::Exclusive,
-            Some(LibsqlTransactionBehavior::Immediate) => TransactionBehavior::Immediate,
-            Some(LibsqlTransactionBehavior::ReadOnly) => TransactionBehavior::ReadOnly,
-            _ => TransactionBehavior::Deferred,
+    ) -> Result<BatchResult, LibsqlError> {
+        let begin = match behavior.unwrap_or(LibsqlTransactionBehavior::Deferred) {
+            LibsqlTransactionBehavior::Deferred => "BEGIN DEFERRED",
+            LibsqlTransactionBehavior::Immediate => "BEGIN IMMEDIATE",
+            LibsqlTransactionBehavior::Exclusive => "BEGIN EXCLUSIVE",
+            LibsqlTransactionBehavior::ReadOnly => "BEGIN READONLY",
         };
-        let transaction = self
-            .connection
-            .try_read()
+        let mut sql = format!("{begin};SELECT total_changes();");
+        for statement in &statements {
+            sql.push_str(&inline_params(
+                &statement.sql,
+                statement.parameters.clone(),
+            )?);
+            sql.push_str(";SELECT changes(), last_insert_rowid(), total_changes();");
+        }
+        sql.push_str("COMMIT");
+        // a failed step skips the rest, and the stream is closed along with
+        // the request, which rolls the transaction back
+        let mut batch = self.connection().await?.execute_batch(&sql).await?;
+        batch.next_stmt_row();
+        let mut total_changes = match batch.next_stmt_row() {
+            Some(Some(mut rows)) => rows.next().await?.map(|row| row.get::<u64>(0)),
+            _ => None,
+        }
+        .transpose()?
+        .ok_or_else(|| LibsqlError::new(LibsqlErrorKind::Network, "the remote sent no counters"))?;
+        let decoder = ValueDecoder::new(Vec::new(), self.decoding);
+        let mut results = Vec::with_capacity(statements.len());
+        for _ in &statements {
+            let (Some(Some(mut rows)), Some(Some(mut counters))) =
+                (batch.next_stmt_row(), batch.next_stmt_row())
+            else {
+                return Err(LibsqlError::new(
+                    LibsqlErrorKind::Network,
+                    "the remote sent fewer results than statements",
+                ));
+            };
+            let columns = rows_columns(&rows);
+            let mut result_rows = Vec::new();
+            while let Some(row) = rows.next().await? {
+                result_rows.push(row_to_map(&row, &decoder)?);
+            }
+            let counters = counters.next().await?.ok_or_else(|| {
+                LibsqlError::new(LibsqlErrorKind::Network, "the remote sent no counters")
+            })?;
+            let changed = counters.get::<u64>(2)? != total_changes;
+            total_changes = counters.get::<u64>(2)?;
+            let result = QueryResult {
+                rows: result_rows,
+                columns,
+                decl_types: Vec::new(),
+                rows_affected: if changed { counters.get::<u64>(0)? } else { 0 },
+                last_insert_rowid: counters.get::<i64>(1)?,
+                total_changes,
+            };
+            results.push(result);
+        }
+        Ok(BatchResult { results })
+    }
+
+    pub async fn transaction(
+        &self,
+        behavior: Option<LibsqlTransactionBehavior>,
+    ) -> Result<LibsqlTransaction, LibsqlError> {
+        let transaction = self.begin(behavior).await?;
+        let transaction = LibsqlTransaction::new(
+            transaction,
+            self.connection.clone(),
+            self.decoding,
+            self.interrupts.clone(),
+            self.locks.clone(),
+            self.handles.clone(),
+        )
+        .with_busy_retry(self.busy_retry);
+        self.handles
+            .lock()
             .unwrap()
-            .transaction_with_behavior(behavior_)
+            .add_transaction(transaction.handle());
+        Ok(transaction)
+    }
+
+    pub async fn enable_extension(&self) -> Result<(), LibsqlError> {
+        self.locks
+            .read(&self.connection)
+            .await?
+            .as_ref()
+            .ok_or_else(LibsqlError::closed)?
+            .load_extension_enable()?;
+        Ok(())
+    }
+
+    pub async fn disable_extension(&self) -> Result<(), LibsqlError> {
+        self.locks
+            .read(&self.connection)
+            .await?
+            .as_ref()
+            .ok_or_else(LibsqlError::closed)?
+            .load_extension_disable()?;
+        Ok(())
+    }
+
+    pub async fn load_extension(
+        &self,
+        path: String,
+        entry_point: Option<String>,
+    ) -> Result<(), LibsqlError> {
+        self.locks
+            .read(&self.connection)
+            .await?
+            .as_ref()
+            .ok_or_else(LibsqlError::closed)?
+            .load_extension(Path::new(&path), entry_point.as_deref())?;
+        Ok(())
+    }
+
+    /// Registers `callback` as the SQL function `name` taking `n_args`
+    /// arguments, or any number of them when `n_args` is -1. Deterministic
+    /// functions can be used in indexes and generated columns.
+    ///
+    /// Only supported on local databases and embedded replicas. Each call
+    /// blocks a Rust worker thread until the Dart callback completes, with
+    /// the connection locked by the calling statement, so it should return
+    /// quickly. The callback must not use this connection, or its
+    /// statements, cursors and transactions: that call waits for the
+    /// statement, which waits for the callback, and both hang unless
+    /// `lock_timeout_ms` is set.
+    pub async fn create_scalar_function(
+        &self,
+        name: String,
+        n_args: i32,
+        deterministic: bool,
+        callback: impl Fn(Vec<LibsqlReturnValue>) -> DartFnFuture<LibsqlValue> + Send + Sync + 'static,
+    ) -> Result<(), LibsqlError> {
+        self.on_raw(|raw| {
+            raw.create_scalar_function(&name, n_args, deterministic, Box::new(callback))
+        })
+        .await
+    }
+
+    /// Registers an aggregate SQL function. `init` creates the accumulator of
+    /// each group, any Dart object, `step` folds a row into it and returns
+    /// the new accumulator, and `finalize` turns it into the result.
+    ///
+    /// Only supported on local databases and embedded replicas. Every row
+    /// waits for a round trip to Dart, and the callbacks must not use this
+    /// connection, as with `create_scalar_function`.
+    pub async fn create_aggregate_function(
+        &self,
+        name: String,
+        n_args: i32,
+        deterministic: bool,
+        init: impl Fn() -> DartFnFuture<DartOpaque> + Send + Sync + 'static,
+        step: impl Fn(DartOpaque, Vec<LibsqlReturnValue>) -> DartFnFuture<DartOpaque>
+            + Send
+            + Sync
+            + 'static,
+        finalize: impl Fn(DartOpaque) -> DartFnFuture<LibsqlValue> + Send + Sync + 'static,
+    ) -> Result<(), LibsqlError> {
+        let function = AggregateFunction {
+            init: Box::new(init),
+            step: Box::new(step),
+            finalize: Box::new(finalize),
+            inverse: None,
+            value: None,
+        };
+        self.on_raw(|raw| raw.create_aggregate_function(&name, n_args, deterministic, function))
+            .await
+    }
+
+    /// Registers an aggregate that can also be used as a window function
+    /// with `OVER (...)`. On top of the aggregate callbacks, `inverse` removes
+    /// a row leaving the window and `value` computes the result for the
+    /// current window without consuming the accumulator.
+    ///
+    /// Only supported on local databases and embedded replicas. The same
+    /// restrictions as for `create_aggregate_function` apply.
+    #[allow(clippy::too_many_arguments)]
+    pub async fn create_window_function(
+        &self,
+        name: String,
+        n_args: i32,
+        deterministic: bool,
+        init: impl Fn() -> DartFnFuture<DartOpaque> + Send + Sync + 'static,
+        step: impl Fn(DartOpaque, Vec<LibsqlReturnValue>) -> DartFnFuture<DartOpaque>
+            + Send
+            + Sync
+            + 'static,
+        inverse: impl Fn(DartOpaque, Vec<LibsqlReturnValue>) -> DartFnFuture<DartOpaque>
+            + Send
+            + Sync
+            + 'static,
+        value: impl Fn(DartOpaque) -> DartFnFuture<LibsqlValue> + Send + Sync + 'static,
+        finalize: impl Fn(DartOpaque) -> DartFnFuture<LibsqlValue> + Send + Sync + 'static,
+    ) -> Result<(), LibsqlError> {
+        let function = AggregateFunction {
+            init: Box::new(init),
+            step: Box::new(step),
+            finalize: Box::new(finalize),
+            inverse: Some(Box::new(inverse)),
+            value: Some(Box::new(value)),
+        };
+        self.on_raw(|raw| raw.create_aggregate_function(&name, n_args, deterministic, function))
+            .await
+    }
+
+    /// Registers `compare` as the collation `name`, for use in `ORDER BY`,
+    /// `COLLATE` clauses and indexes. It returns a negative number, zero or a
+    /// positive number when the first string sorts before, equal to or after
+    /// the second one, and must be consistent across calls.
+    ///
+    /// Only supported on local databases and embedded replicas. Every
+    /// comparison blocks a Rust worker thread on a round trip to the Dart
+    /// isolate, so sorting n rows costs about n log n of them, and `compare`
+    /// must not use this connection, as with `create_scalar_function`.
+    /// Prefer `create_builtin_collation` when one fits.
+    pub async fn create_collation(
+        &self,
+        name: String,
+        compare: impl Fn(String, String) -> DartFnFuture<i32> + Send + Sync + 'static,
+    ) -> Result<(), LibsqlError> {
+        let collation: Collation = Box::new(move |left, right| {
+            block_on(compare(left.to_string(), right.to_string())).cmp(&0)
+        });
+        self.on_raw(|raw| raw.create_collation(&name, collation))
+            .await
+    }
+
+    /// Registers one of the collations implemented in Rust under `name`.
+    pub async fn create_builtin_collation(
+        &self,
+        name: String,
+        collation: LibsqlCollation,
+    ) -> Result<(), LibsqlError> {
+        let collation: Collation = match collation {
+            LibsqlCollation::UnicodeCaseFold => Box::new(unicode_case_fold),
+        };
+        self.on_raw(|raw| raw.create_collation(&name, collation))
+            .await
+    }
+
+    /// Streams the rows inserted, updated or deleted through this connection,
+    /// one list per committed transaction. Changes undone by a rollback, a
+    /// `ROLLBACK TO` or a failed statement are left out, except those of a
+    /// failed statement that fired triggers. Changes to `WITHOUT ROWID`
+    /// tables are not reported.
+    ///
+    /// Only supported on local databases and embedded replicas. The stream
+    /// ends when the connection is closed.
+    pub async fn updates(&self, sink: StreamSink<Vec<LibsqlUpdate>>) -> Result<(), LibsqlError> {
+        self.on_raw(|raw| {
+            self.changes.subscribe(sink);
+            raw.set_change_hooks(Some(&self.changes));
+            Ok(())
+        })
+        .await
+    }
+
+    /// Streams the result of the read-only query `sql`, running it again
+    /// whenever a table it reads from is changed through this connection,
+    /// and after syncs that brought in changes from the remote. Changes less
+    /// than `debounce_ms` (50 by default) apart trigger a single run, and
+    /// results equal to the previous one are not sent again. Errors are sent
+    /// on the stream, which keeps watching.
+    ///
+    /// Queries reading from views run again on any change. Only supported on
+    /// local databases and embedded replicas. The stream ends when the
+    /// connection is closed.
+    pub async fn watch(
+        &self,
+        sql: String,
+        parameters: Option<LibsqlParams>,
+        debounce_ms: Option<u64>,
+        sink: StreamSink<QueryResult>,
+    ) -> Result<(), LibsqlError> {
+        let handle = self.handle();
+        let checkout = move || future::ready(Some(((), handle.clone())));
+        self.watch_on(self, sql, parameters, debounce_ms, sink, checkout)
+            .await
+    }
+
+    /// `watch` for changes made through this connection, checking the query
+    /// on `reader` and running it on the connection `checkout` hands out for
+    /// each run.
+    pub(crate) async fn watch_on<G, F>(
+        &self,
+        reader: &LibsqlConnection,
+        sql: String,
+        parameters: Option<LibsqlParams>,
+        debounce_ms: Option<u64>,
+        sink: StreamSink<QueryResult>,
+        checkout: impl Fn() -> F + Send + 'static,
+    ) -> Result<(), LibsqlError>
+    where
+        G: Send + 'static,
+        F: Future<Output = Option<(G, RustAutoOpaqueNom<Option<InnerConnection>>)>>
+            + Send
+            + 'static,
+    {
+        if !is_readonly(&sql) {
+            return Err(LibsqlError::misuse("only read-only queries can be watched"));
+        }
+        // checks the parameters now rather than on the first run
+        bind_params(
+            parameters.clone(),
+            &reader.connection().await?.prepare(&sql).await?,
+        )?;
+        let mut tables = referenced_tables(&sql);
+        if let Some(names) = &tables {
+            let views = reader
+                .query(
+                    "SELECT lower(name) AS name FROM sqlite_schema WHERE type = 'view'".to_string(),
+                    None,
+                    None,
+                    None,
+                )
+                .await?;
+            let reads_view = views.rows.iter().any(|row| {
+                matches!(row.get("name"), Some(LibsqlReturnValue::Text(view)) if names.contains(view))
+            });
+            if reads_view {
+                tables = None;
+            }
+        }
+        let (wake, changes) = futures::channel::mpsc::unbounded();
+        self.on_raw(|raw| {
+            self.changes
+                .listen(watch::change_listener(tables, wake.clone()));
+            raw.set_change_hooks(Some(&self.changes));
+            Ok(())
+        })
+        .await?;
+        self.syncs.watch(wake);
+        let query = WatchedQuery {
+            sql,
+            parameters,
+            decoding: reader.decoding,
+            debounce: debounce_ms.map_or(watch::DEFAULT_DEBOUNCE, Duration::from_millis),
+        };
+        flutter_rust_bridge::spawn(watch::run(query, checkout, changes, sink));
+        Ok(())
+    }
+
+    /// Sets how long local statements wait for a lock held by another
+    /// connection before failing with a `Busy` error. Fails with `Misuse` on
+    /// remote connections, whose locks are up to the server.
+    pub async fn set_busy_timeout(&self, timeout_ms: u64) -> Result<(), LibsqlError> {
+        let timeout = Duration::from_millis(timeout_ms);
+        self.on_raw(|raw| raw.set_busy_timeout(timeout)).await?;
+        *self.busy_timeout.lock().unwrap() = Some(timeout);
+        Ok(())
+    }
+
+    /// Starts a transaction, retrying while busy if configured to.
+    async fn begin(
+        &self,
+        behavior: Option<LibsqlTransactionBehavior>,
+    ) -> Result<libsql::Transaction, LibsqlError> {
+        let connection = self.connection().await?;
+        retry_busy(self.busy_retry, || async {
+            let behavior = behavior.map_or(TransactionBehavior::Deferred, Into::into);
+            Ok(connection.transaction_with_behavior(behavior).await?)
+        })
+        .await
+    }
+
+    /// Stops the queries and statements running on this connection, which
+    /// fail with an `Interrupted` error. Local statements are interrupted by
+    /// SQLite, remote requests are aborted.
+    ///
+    /// Dart futures can't be cancelled, so abandoning one leaves its query
+    /// running. Use this, a `timeout_ms` or a `LibsqlCancelToken` to stop it.
+    pub async fn interrupt(&self) -> Result<(), LibsqlError> {
+        let connection = self.connection().await?;
+        self.interrupts.interrupt();
+        // remote requests are aborted by the notification alone
+        if let Err(error) = connection.interrupt() {
+            if !self.remote {
+                return Err(error.into());
+            }
+        }
+        Ok(())
+    }
+
+    /// Runs `future`, failing with `Interrupted` once `interrupt` is called,
+    /// `timeout_ms` elapses or `cancel` is cancelled.
+    async fn interruptible<T>(
+        &self,
+        timeout_ms: Option<u64>,
+        cancel: Option<LibsqlCancelToken>,
+        future: impl Future<Output = Result<T, LibsqlError>>,
+    ) -> Result<T, LibsqlError> {
+        let timeout = timeout_ms.map(Duration::from_millis);
+        self.interrupts.run(timeout, cancel, future).await
+    }
+
+    /// The shared handle on the connection, emptied by `close`.
+    pub(crate) fn handle(&self) -> RustAutoOpaqueNom<Option<InnerConnection>> {
+        self.connection.clone()
+    }
+
+    /// An owned handle on the connection, failing once it is closed.
+    async fn connection(&self) -> Result<InnerConnection, LibsqlError> {
+        self.locks
+            .read(&self.connection)
+            .await?
+            .clone()
+            .ok_or_else(LibsqlError::closed)
+    }
+
+    /// Frames in the local write-ahead log of a database opened with
+    /// `offline`.
+    async fn local_frames(&self) -> Result<u32, LibsqlError> {
+        self.synced_replica()?;
+        self.on_raw(|raw| raw.wal_frame_count()).await
+    }
+
+    /// Runs `f` with the SQLite handle, keeping the connection open meanwhile.
+    async fn on_raw<T>(
+        &self,
+        f: impl FnOnce(RawConnection) -> Result<T, LibsqlError>,
+    ) -> Result<T, LibsqlError> {
+        let guard = self.locks.read(&self.connection).await?;
+        guard.as_ref().ok_or_else(LibsqlError::closed)?;
+        let raw = self.interrupts.raw().ok_or_else(|| {
+            LibsqlError::misuse("only supported on local databases and embedded replicas")
+        })?;
+        f(raw)
+    }
+}
+
+impl Drop for LibsqlConnection {
+    fn drop(&mut self) {
+        // releases the hooks' reference to `changes`, statements may still
+        // hold on to the connection. If the lock is taken the hooks keep
+        // running until SQLite closes the connection.
+        if let (Ok(connection), Some(raw)) = (self.connection.try_read(), self.interrupts.raw()) {
+            if connection.is_some() {
+                raw.set_change_hooks(None);
+            }
+        }
+    }
+}
+
+pub enum LibsqlCollation {
+    /// Case-insensitive ordering that lowercases every Unicode character
+    /// before comparing, where `NOCASE` only folds ASCII letters. It is not
+    /// locale-aware, use `create_collation` for that
+    UnicodeCaseFold,
+}
+
+fn unicode_case_fold(left: &str, right: &str) -> Ordering {
+    left.chars()
+        .flat_map(char::to_lowercase)
+        .cmp(right.chars().flat_map(char::to_lowercase))
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::{LibsqlCollation, LibsqlConnection};
+    use crate::{
+        api::{
+            libsql::{connect, ConnectArgs, LibsqlCancelToken},
+            transaction::LibsqlTransactionBehavior,
+        },
+        utils::{
+            error::LibsqlErrorKind, retry::LibsqlBusyRetry, return_value::LibsqlReturnValue,
+            sync::LibsqlSyncConflictPolicy,
+        },
+    };
+    use std::{
+        sync::{
+            atomic::{AtomicUsize, Ordering::SeqCst},
+            Arc, Mutex,
+        },
+        time::Duration,
+    };
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+    /// Arguments for the local database at `url`, with every option unset.
+    pub(crate) fn local(url: &str) -> ConnectArgs {
+        ConnectArgs {
+            url: url.to_string(),
+            auth_token: None,
+            sync_url: None,
+            sync_interval_seconds: None,
+            encryption_key: None,
+            read_your_writes: None,
+            open_flags: None,
+            offline: None,
+            decode_mode: None,
+            datetime_unit: None,
+            busy_timeout_ms: None,
+            busy_retry: None,
+            lock_timeout_ms: None,
+        }
+    }
+
+    /// A fresh database file named after `name` in the temporary directory.
+    pub(crate) fn temp_path(name: &str) -> String {
+        let path = std::env::temp_dir().join(format!("{name}-{}.db", std::process::id()));
+        let path = path.to_str().unwrap().to_string();
+        for suffix in ["", "-wal", "-shm", "-info"] {
+            let _ = std::fs::remove_file(format!("{path}{suffix}"));
+        }
+        path
+    }
+
+    async fn open() -> LibsqlConnection {
+        let connection = connect(local(":memory:")).await.unwrap();
+        connection
+            .execute("CREATE TABLE t (x)".to_string(), None, None, None)
             .await
             .unwrap();
-        LibsqlTransaction::new(transaction)
+        connection
     }
 
-    pub async fn enable_extension(&self) {
-        self.connection
-            .try_read()
+    /// Opens a database with `offline` whose remote can't be reached. The
+    /// files libsql would bootstrap from the remote are made up, with an
+    /// empty database at generation 1.
+    async fn open_offline(name: &str) -> LibsqlConnection {
+        let path = temp_path(name);
+        std::fs::write(&path, b"").unwrap();
+        std::fs::write(
+            format!("{path}-info"),
+            r#"{"hash":3278479626,"version":0,"durable_frame_num":0,"generation":1}"#,
+        )
+        .unwrap();
+        connect(ConnectArgs {
+            // nothing listens on port 1
+            sync_url: Some("http://127.0.0.1:1".to_string()),
+            offline: Some(true),
+            ..local(&path)
+        })
+        .await
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn overlapping_calls_share_the_connection() {
+        let connection = open().await;
+        let (inserted, selected) = tokio::join!(
+            connection.execute("INSERT INTO t VALUES (1)".to_string(), None, None, None),
+            connection.query("SELECT x FROM t".to_string(), None, None, None),
+        );
+        assert_eq!(inserted.unwrap().rows_affected, 1);
+        assert!(selected.unwrap().rows.len() <= 1);
+    }
+
+    #[tokio::test]
+    async fn close_times_out_behind_a_statement_in_use() {
+        let connection = open().await;
+        let statement = connection
+            .prepare("SELECT x FROM t".to_string())
+            .await
+            .unwrap();
+        let handle = statement.handle();
+        connection
+            .locks
+            .set_timeout(Some(Duration::from_millis(10)));
+        let in_use = handle.read().await;
+        let error = connection.close().await.unwrap_err();
+        assert!(matches!(error.kind, LibsqlErrorKind::Interrupted));
+        // nothing was released, the statement is closed by the next attempt
+        connection
+            .query("SELECT x FROM t".to_string(), None, None, None)
+            .await
+            .unwrap();
+        drop(in_use);
+        connection.close().await.unwrap();
+        assert!(statement.query(None, None, None).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn commit_keeps_the_transaction_when_it_times_out() {
+        let connection = open().await;
+        let mut transaction = connection.transaction(None).await.unwrap();
+        let statement = transaction
+            .prepare("INSERT INTO t VALUES (1)".to_string())
+            .await
+            .unwrap();
+        let handle = statement.handle();
+        connection
+            .locks
+            .set_timeout(Some(Duration::from_millis(10)));
+        let in_use = handle.read().await;
+        let error = transaction.commit().await.unwrap_err();
+        assert!(matches!(error.kind, LibsqlErrorKind::Interrupted));
+        assert!(transaction.is_active().await.unwrap());
+        drop(in_use);
+        statement.execute(None, None, None).await.unwrap();
+        transaction.commit().await.unwrap();
+        let rows = connection
+            .query("SELECT x FROM t".to_string(), None, None, None)
+            .await
             .unwrap()
-            .load_extension_enable()
+            .rows;
+        assert_eq!(rows.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn sends_changes_once_their_transaction_committed() {
+        let connection = open().await;
+        let (sender, received) = std::sync::mpsc::channel();
+        connection
+            .changes
+            .listen(Box::new(move |updates| sender.send(updates.len()).is_ok()));
+        connection
+            .on_raw(|raw| {
+                raw.set_change_hooks(Some(&connection.changes));
+                Ok(())
+            })
+            .await
+            .unwrap();
+        let run = |sql: &str| connection.execute(sql.to_string(), None, None, None);
+        run("INSERT INTO t VALUES (1)").await.unwrap();
+        assert_eq!(received.try_iter().collect::<Vec<_>>(), [1]);
+        run("BEGIN").await.unwrap();
+        run("INSERT INTO t VALUES (2)").await.unwrap();
+        run("INSERT INTO t VALUES (3)").await.unwrap();
+        assert!(received.try_recv().is_err());
+        run("COMMIT").await.unwrap();
+        run("BEGIN").await.unwrap();
+        run("INSERT INTO t VALUES (4)").await.unwrap();
+        run("ROLLBACK").await.unwrap();
+        assert_eq!(received.try_iter().collect::<Vec<_>>(), [2]);
+    }
+
+    #[tokio::test]
+    async fn drops_changes_rolled_back_to_a_commented_savepoint() {
+        let connection = open().await;
+        let (sender, received) = std::sync::mpsc::channel();
+        connection
+            .changes
+            .listen(Box::new(move |updates| sender.send(updates.len()).is_ok()));
+        connection
+            .on_raw(|raw| {
+                raw.set_change_hooks(Some(&connection.changes));
+                Ok(())
+            })
+            .await
+            .unwrap();
+        let run = |sql: &str| connection.execute(sql.to_string(), None, None, None);
+        run("BEGIN").await.unwrap();
+        run("INSERT INTO t VALUES (1)").await.unwrap();
+        run("-- before the second row\nSAVEPOINT a").await.unwrap();
+        run("INSERT INTO t VALUES (2)").await.unwrap();
+        run("/* undo it */ ROLLBACK TO a").await.unwrap();
+        run("COMMIT").await.unwrap();
+        assert_eq!(received.try_iter().collect::<Vec<_>>(), [1]);
+    }
+
+    // libsql opens synced connections with `block_in_place`
+    #[tokio::test(flavor = "multi_thread")]
+    async fn offline_sync_fails_while_the_remote_is_unreachable() {
+        let connection = open_offline("offline-sync-unreachable").await;
+        connection
+            .execute("CREATE TABLE t (x)".to_string(), None, None, None)
+            .await
             .unwrap();
+        let error = connection.sync().await.err().unwrap();
+        assert!(matches!(error.kind, LibsqlErrorKind::Network));
+        let status = connection.sync_status().await;
+        assert!(status.last_error.is_some());
+        assert!(status.last_result.is_none());
     }
 
-    pub async fn disable_extension(&self) {
-        self.connection
-            .try_read()
+    #[tokio::test(flavor = "multi_thread")]
+    async fn offline_push_keeps_the_changes_while_the_remote_is_unreachable() {
+        let connection = open_offline("offline-push-unreachable").await;
+        connection
+            .execute("CREATE TABLE t (x)".to_string(), None, None, None)
+            .await
+            .unwrap();
+        for _ in 0..2 {
+            let error = connection.push(None).await.err().unwrap();
+            assert!(matches!(error.kind, LibsqlErrorKind::Network));
+        }
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn discarding_local_changes_keeps_them_when_the_remote_is_unreachable() {
+        let connection = open_offline("offline-discard-unreachable").await;
+        connection
+            .execute("CREATE TABLE t (x)".to_string(), None, None, None)
+            .await
+            .unwrap();
+        connection
+            .execute("INSERT INTO t VALUES (1)".to_string(), None, None, None)
+            .await
+            .unwrap();
+        assert!(connection.discard_local_changes().await.is_err());
+        let rows = connection
+            .query("SELECT x FROM t".to_string(), None, None, None)
+            .await
             .unwrap()
-            .load_extension_disable()
+            .rows;
+        assert_eq!(rows.len(), 1);
+    }
+
+    /// The database file left by running `sql` on a new local database.
+    async fn export(name: &str, sql: &str) -> Vec<u8> {
+        let path = temp_path(name);
+        let connection = connect(local(&path)).await.unwrap();
+        connection.batch(sql.to_string(), None, None).await.unwrap();
+        connection.close().await.unwrap();
+        std::fs::read(&path).unwrap()
+    }
+
+    /// Serves the sync protocol of databases opened with `offline`, with
+    /// `generations[n - 1]` as the database of generation `n` and the last
+    /// one as the current generation. Pushes to the current generation are
+    /// accepted without being applied, pushes to older ones are rejected and
+    /// pulls find nothing past the database of the generation.
+    async fn serve_sync(generations: Arc<Mutex<Vec<Vec<u8>>>>) -> String {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        tokio::spawn(async move {
+            loop {
+                let (mut stream, _) = listener.accept().await.unwrap();
+                let generations = generations.clone();
+                tokio::spawn(async move {
+                    let mut request = Vec::new();
+                    let mut buf = [0; 4096];
+                    let head = loop {
+                        let read = stream.read(&mut buf).await.unwrap();
+                        request.extend_from_slice(&buf[..read]);
+                        if let Some(end) = request.windows(4).position(|w| w == b"\r\n\r\n") {
+                            break String::from_utf8_lossy(&request[..end]).to_lowercase();
+                        }
+                    };
+                    // the pushed frames are not looked at, only read off
+                    let length = head
+                        .lines()
+                        .find_map(|line| line.strip_prefix("content-length: "))
+                        .map_or(0, |length| length.parse().unwrap());
+                    let mut body = request.len() - head.len() - 4;
+                    while body < length {
+                        body += stream.read(&mut buf).await.unwrap();
+                    }
+                    let mut request_line = head.split(' ');
+                    let method = request_line.next().unwrap().to_string();
+                    let path = request_line.next().unwrap().to_string();
+                    let parts: Vec<u32> = path
+                        .split('/')
+                        .filter_map(|part| part.parse().ok())
+                        .collect();
+                    let (status, body) = {
+                        let generations = generations.lock().unwrap();
+                        let current = generations.len() as u32;
+                        let json = |json: String| json.into_bytes();
+                        match (method.as_str(), path.split('/').nth(1), &parts[..]) {
+                            ("get", Some("info"), _) => {
+                                (200, json(format!(r#"{{"current_generation":{current}}}"#)))
+                            }
+                            ("get", Some("export"), &[generation]) => {
+                                (200, generations[generation as usize - 1].clone())
+                            }
+                            ("post", Some("sync"), &[generation, _, end])
+                                if generation == current =>
+                            {
+                                (
+                                    200,
+                                    json(format!(
+                                        r#"{{"status":"ok","generation":{current},"max_frame_no":{}}}"#,
+                                        end - 1
+                                    )),
+                                )
+                            }
+                            ("post", Some("sync"), _) => (
+                                200,
+                                json(format!(
+                                    r#"{{"status":"conflict","generation":{current},"max_frame_no":0}}"#
+                                )),
+                            ),
+                            ("get", Some("sync"), _) => {
+                                (400, json(format!(r#"{{"generation":{current}}}"#)))
+                            }
+                            _ => (404, Vec::new()),
+                        }
+                    };
+                    let head = format!(
+                        "HTTP/1.1 {status} X\r\ncontent-length: {}\r\nconnection: close\r\n\r\n",
+                        body.len()
+                    );
+                    stream.write_all(head.as_bytes()).await.unwrap();
+                    stream.write_all(&body).await.unwrap();
+                    let _ = stream.shutdown().await;
+                });
+            }
+        });
+        url
+    }
+
+    /// Opens a database with `offline` synced with a remote at generation 1,
+    /// holding an empty table `t`, and writes a row the remote doesn't have.
+    /// The returned generations let the remote move on meanwhile.
+    async fn open_behind(name: &str) -> (LibsqlConnection, Arc<Mutex<Vec<Vec<u8>>>>) {
+        let generations = Arc::new(Mutex::new(vec![
+            export(&format!("{name}-1"), "CREATE TABLE t (x)").await,
+        ]));
+        let path = temp_path(name);
+        let connection = connect(ConnectArgs {
+            sync_url: Some(serve_sync(generations.clone()).await),
+            offline: Some(true),
+            ..local(&path)
+        })
+        .await
+        .unwrap();
+        connection
+            .execute("INSERT INTO t VALUES (1)".to_string(), None, None, None)
+            .await
             .unwrap();
+        let moved_on = export(
+            &format!("{name}-2"),
+            "CREATE TABLE t (x); INSERT INTO t VALUES (2)",
+        )
+        .await;
+        generations.lock().unwrap().push(moved_on);
+        (connection, generations)
     }
 
-    pub async fn load_extension(&self, path: String, entry_point: Option<String>) {
-        self.connection
-            .try_read()
+    async fn values(connection: &LibsqlConnection) -> Vec<i64> {
+        let rows = connection
+            .query("SELECT x FROM t ORDER BY x".to_string(), None, None, None)
+            .await
             .unwrap()
-            .load_extension(Path::new(&path), entry_point.as_deref())
+            .rows;
+        rows.iter()
+            .map(|row| match row["x"] {
+                LibsqlReturnValue::Integer(x) => x,
+                _ => unreachable!(),
+            })
+            .collect()
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn push_reports_the_frames_a_remote_that_moved_on_rejects() {
+        let (connection, _generations) = open_behind("push-conflict").await;
+        let frames = connection.local_frames().await.unwrap() as u64;
+        let error = connection.push(None).await.err().unwrap();
+        assert!(matches!(error.kind, LibsqlErrorKind::SyncConflict));
+        let conflict = error.conflict.unwrap();
+        assert_eq!(conflict.first_rejected_frame, 1);
+        assert_eq!(conflict.last_local_frame, frames);
+        assert_eq!(conflict.remote_frame_no, 0);
+        assert_eq!(values(&connection).await, [1]);
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn retry_after_pull_replays_the_changes_on_the_remote_state() {
+        let (connection, _generations) = open_behind("push-retry").await;
+        let connection = Arc::new(connection);
+        let replays = Arc::new(AtomicUsize::new(0));
+        connection
+            .set_conflict_replay({
+                let connection = connection.clone();
+                let replays = replays.clone();
+                move || {
+                    let connection = connection.clone();
+                    replays.fetch_add(1, SeqCst);
+                    Box::pin(async move {
+                        connection
+                            .execute("INSERT INTO t VALUES (1)".to_string(), None, None, None)
+                            .await
+                            .unwrap();
+                    })
+                }
+            })
+            .await;
+        let result = connection
+            .push(Some(LibsqlSyncConflictPolicy::RetryAfterPull))
+            .await
+            .unwrap();
+        assert!(result.frames_synced > 0);
+        assert_eq!(replays.load(SeqCst), 1);
+        assert_eq!(values(&connection).await, [1, 2]);
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn retry_after_pull_needs_a_replay() {
+        let connection = open_offline("push-retry-no-replay").await;
+        let error = connection
+            .push(Some(LibsqlSyncConflictPolicy::RetryAfterPull))
+            .await
+            .err()
+            .unwrap();
+        assert!(matches!(error.kind, LibsqlErrorKind::Misuse));
+    }
+
+    #[tokio::test]
+    async fn busy_timeout_waits_for_other_connections() {
+        let path = temp_path("busy-timeout");
+        let holder = connect(local(&path)).await.unwrap();
+        holder
+            .execute("CREATE TABLE t (x)".to_string(), None, None, None)
+            .await
+            .unwrap();
+        let waiting = connect(ConnectArgs {
+            busy_timeout_ms: Some(10),
+            ..local(&path)
+        })
+        .await
+        .unwrap();
+        let _transaction = holder
+            .transaction(Some(LibsqlTransactionBehavior::Immediate))
+            .await
+            .unwrap();
+        let started = std::time::Instant::now();
+        let error = waiting
+            .execute("INSERT INTO t VALUES (1)".to_string(), None, None, None)
+            .await
+            .err()
+            .unwrap();
+        assert!(matches!(error.kind, LibsqlErrorKind::Busy));
+        assert!(started.elapsed() >= Duration::from_millis(10));
+    }
+
+    #[tokio::test]
+    async fn busy_timeout_is_not_supported_on_remote_connections() {
+        let connection = connect(ConnectArgs {
+            // nothing listens on port 1, and nothing is sent before a query
+            url: "http://127.0.0.1:1".to_string(),
+            ..local("")
+        })
+        .await
+        .unwrap();
+        let error = connection.set_busy_timeout(10).await.unwrap_err();
+        assert!(matches!(error.kind, LibsqlErrorKind::Misuse));
+    }
+
+    #[tokio::test]
+    async fn prepared_statements_and_transactions_retry_while_busy() {
+        let path = temp_path("busy-retry");
+        let holder = connect(local(&path)).await.unwrap();
+        holder
+            .execute("CREATE TABLE t (x)".to_string(), None, None, None)
+            .await
+            .unwrap();
+        let waiting = connect(ConnectArgs {
+            busy_retry: Some(LibsqlBusyRetry {
+                max_attempts: 100,
+                initial_delay_ms: 1,
+                max_delay_ms: 5,
+            }),
+            ..local(&path)
+        })
+        .await
+        .unwrap();
+        let statement = waiting
+            .prepare("INSERT INTO t VALUES (1)".to_string())
+            .await
+            .unwrap();
+        let transaction = waiting.transaction(None).await.unwrap();
+        let mut holding = holder
+            .transaction(Some(LibsqlTransactionBehavior::Immediate))
+            .await
+            .unwrap();
+        let (executed, committed) = tokio::join!(
+            transaction.execute("INSERT INTO t VALUES (2)".to_string(), None, None, None),
+            async {
+                tokio::time::sleep(Duration::from_millis(20)).await;
+                holding.commit().await
+            },
+        );
+        committed.unwrap();
+        executed.unwrap();
+        let mut transaction = transaction;
+        transaction.commit().await.unwrap();
+        let mut holding = holder
+            .transaction(Some(LibsqlTransactionBehavior::Immediate))
+            .await
+            .unwrap();
+        let (executed, committed) = tokio::join!(statement.execute(None, None, None), async {
+            tokio::time::sleep(Duration::from_millis(20)).await;
+            holding.commit().await
+        });
+        committed.unwrap();
+        assert_eq!(executed.unwrap().rows_affected, 1);
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn cancel_tokens_stop_local_queries() {
+        let connection = open().await;
+        let token = LibsqlCancelToken::create().await;
+        tokio::spawn(async move {
+            tokio::time::sleep(Duration::from_millis(50)).await;
+            token.cancel().await;
+        });
+        let sql = "WITH RECURSIVE n(i) AS (SELECT 1 UNION ALL SELECT i + 1 FROM n) \
+                   SELECT count(*) FROM n";
+        let error = connection
+            .query(sql.to_string(), None, None, Some(token))
+            .await
+            .err()
+            .unwrap();
+        assert!(matches!(error.kind, LibsqlErrorKind::Interrupted));
+        // the connection is usable again, without the token
+        connection
+            .query("SELECT x FROM t".to_string(), None, None, None)
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn columnar_results_keep_duplicate_columns() {
+        let connection = open().await;
+        connection
+            .execute(
+                "INSERT INTO t VALUES (1), (2)".to_string(),
+                None,
+                None,
+                None,
+            )
+            .await
+            .unwrap();
+        let sql = "SELECT x, x * 10 AS x FROM t ORDER BY 1".to_string();
+        let expected = [1, 10, 2, 20].map(LibsqlReturnValue::Integer).to_vec();
+        let result = connection
+            .query_columnar(sql.clone(), None, None, None)
+            .await
+            .unwrap();
+        assert_eq!(result.columns, vec!["x".to_string(), "x".to_string()]);
+        assert_eq!(result.row_count, 2);
+        assert!(result.values == expected);
+        let statement = connection.prepare(sql).await.unwrap();
+        let result = statement.query_columnar(None, None, None).await.unwrap();
+        assert_eq!(result.columns.len(), 2);
+        assert!(result.values == expected);
+    }
+
+    #[tokio::test]
+    async fn unicode_case_fold_ignores_case_beyond_ascii() {
+        let connection = open().await;
+        connection
+            .create_builtin_collation("fold".to_string(), LibsqlCollation::UnicodeCaseFold)
+            .await
+            .unwrap();
+        let result = connection
+            .query(
+                "SELECT 'ÄBC' = 'äbc' COLLATE fold AS folded, 'ÄBC' = 'äbc' COLLATE NOCASE AS nocase"
+                    .to_string(),
+                None,
+                None,
+                None,
+            )
+            .await
+            .unwrap();
+        assert!(result.rows[0]["folded"] == LibsqlReturnValue::Integer(1));
+        assert!(result.rows[0]["nocase"] == LibsqlReturnValue::Integer(0));
+        connection
+            .execute(
+                "INSERT INTO t VALUES ('b'), ('Ä'), ('A'), ('ä')".to_string(),
+                None,
+                None,
+                None,
+            )
+            .await
+            .unwrap();
+        let result = connection
+            .query_columnar(
+                "SELECT x FROM t ORDER BY x COLLATE fold, x".to_string(),
+                None,
+                None,
+                None,
+            )
+            .await
+            .unwrap();
+        let expected = ["A", "b", "Ä", "ä"].map(|x| LibsqlReturnValue::Text(x.to_string()));
+        assert!(result.values == expected);
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn local_databases_have_no_write_replication_index() {
+        let connection = connect(local(":memory:")).await.unwrap();
+        connection
+            .execute("CREATE TABLE t (x)".to_string(), None, None, None)
+            .await
+            .unwrap();
+        assert_eq!(
+            connection.max_write_replication_index().await.unwrap(),
+            None
+        );
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn sync_until_is_only_supported_on_replicas() {
+        let connection = connect(local(":memory:")).await.unwrap();
+        let error = connection.sync_until(1, None).await.err().unwrap();
+        assert!(matches!(error.kind, LibsqlErrorKind::Sync));
+        let connection = open_offline("offline-sync-until").await;
+        let error = connection.sync_until(1, Some(100)).await.err().unwrap();
+        assert!(matches!(error.kind, LibsqlErrorKind::Sync));
+        let status = connection.sync_status().await;
+        assert!(status.last_error.is_some());
+        assert!(!status.syncing);
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn replication_calls_fail_after_close() {
+        let connection = connect(local(":memory:")).await.unwrap();
+        connection.close().await.unwrap();
+        let error = connection.sync_until(1, None).await.err().unwrap();
+        assert!(matches!(error.kind, LibsqlErrorKind::Closed));
+        let error = connection
+            .max_write_replication_index()
+            .await
+            .err()
             .unwrap();
+        assert!(matches!(error.kind, LibsqlErrorKind::Closed));
     }
 }
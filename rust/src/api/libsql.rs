@@ -2,18 +2,28 @@ use libsql::Builder;
 use std::time::Duration;
 
 pub use super::connection::LibsqlConnection;
+pub use super::pool::LibsqlPool;
+use crate::utils::{
+    error::LibsqlError,
+    interrupt, raw,
+    retry::LibsqlBusyRetry,
+    return_value::{DecodeOptions, LibsqlDateTimeUnit, LibsqlDecodeMode},
+    sync::SyncedReplica,
+};
 
 #[flutter_rust_bridge::frb(init)]
 pub async fn init_app() {
     flutter_rust_bridge::setup_default_user_utils();
 }
 
+#[derive(Clone)]
 pub enum LibsqlOpenFlags {
     ReadOnly,
     ReadWrite,
     Create,
 }
 
+#[derive(Clone)]
 pub struct ConnectArgs {
     pub url: String,
     pub auth_token: Option<String>,
@@ -23,24 +33,60 @@ pub struct ConnectArgs {
     pub read_your_writes: Option<bool>,
     pub open_flags: Option<LibsqlOpenFlags>,
     pub offline: Option<bool>,
+    pub decode_mode: Option<LibsqlDecodeMode>,
+    /// Unit of integers read as `DateTime` under
+    /// `LibsqlDecodeMode::DeclaredType`. Milliseconds by default
+    pub datetime_unit: Option<LibsqlDateTimeUnit>,
+    /// How long a local connection waits for a lock held by another
+    /// connection before failing with a `Busy` error. Not supported on
+    /// remote connections
+    pub busy_timeout_ms: Option<u64>,
+    /// Retries `execute`, on the connection, its prepared statements and
+    /// transactions, and starting a transaction when they are busy
+    pub busy_retry: Option<LibsqlBusyRetry>,
+    /// How long a call waits for other calls on the same connection, or on
+    /// the statement, cursor or transaction it uses, before failing with an
+    /// `Interrupted` error. Calls wait indefinitely by default
+    pub lock_timeout_ms: Option<u64>,
 }
 
-pub async fn connect(args: ConnectArgs) -> LibsqlConnection {
-    let database = if args.sync_url.is_some() && args.offline.is_some_and(|offline| offline) {
-        let connector = hyper_rustls::HttpsConnectorBuilder::new()
-            .with_webpki_roots()
-            .https_or_http()
-            .enable_http1()
-            .build();
+/// Cancels the calls it is passed to, for example once the Dart future
+/// awaiting them is abandoned. Cancelled calls fail with an `Interrupted`
+/// error, and so do calls made with the token after it was cancelled.
+#[derive(Clone, Copy)]
+pub struct LibsqlCancelToken {
+    pub id: u64,
+}
 
-        let builder = Builder::new_synced_database(
-            args.url,
-            args.sync_url.unwrap(),
-            args.auth_token.unwrap_or("".to_string()),
-        )
-        .connector(connector);
+impl LibsqlCancelToken {
+    pub async fn create() -> LibsqlCancelToken {
+        LibsqlCancelToken {
+            id: interrupt::new_token(),
+        }
+    }
 
-        builder.build().await
+    /// Stops the calls running with this token.
+    pub async fn cancel(&self) {
+        interrupt::cancel(self.id);
+    }
+}
+
+pub async fn connect(args: ConnectArgs) -> Result<LibsqlConnection, LibsqlError> {
+    let mut sync_interval = None;
+    let mut synced_replica = None;
+    let mut remote = false;
+    let database = if args.sync_url.is_some() && args.offline.is_some_and(|offline| offline) {
+        let sync_url = args
+            .sync_url
+            .ok_or_else(|| LibsqlError::misuse("sync_url is required in offline mode"))?;
+        let replica = SyncedReplica {
+            path: args.url,
+            sync_url,
+            auth_token: args.auth_token.unwrap_or("".to_string()),
+        };
+        let database = replica.open().await;
+        synced_replica = Some(replica);
+        database
     } else if let Some(sync_url) = args.sync_url {
         let connector = hyper_rustls::HttpsConnectorBuilder::new()
             .with_webpki_roots()
@@ -55,9 +101,8 @@ pub async fn connect(args: ConnectArgs) -> LibsqlConnection {
         )
         .connector(connector);
 
-        if let Some(interval) = args.sync_interval_seconds {
-            builder = builder.sync_interval(Duration::from_secs(interval))
-        }
+        // synced by `LibsqlConnection` itself so each sync can be reported
+        sync_interval = args.sync_interval_seconds.map(Duration::from_secs);
 
         if let Some(key) = args.encryption_key {
             builder = builder.encryption_config(libsql::EncryptionConfig::new(
@@ -79,6 +124,7 @@ pub async fn connect(args: ConnectArgs) -> LibsqlConnection {
             .enable_http1()
             .build();
 
+        remote = true;
         Builder::new_remote(args.url, args.auth_token.unwrap_or("".to_string()))
             .connector(connector)
             .build()
@@ -99,8 +145,30 @@ pub async fn connect(args: ConnectArgs) -> LibsqlConnection {
         }
 
         builder.build().await
+    }?;
+    let (connection, raw_connection) = raw::connect(&database, remote).await?;
+    let connection = LibsqlConnection::new(connection, database)
+        .with_decoding(DecodeOptions {
+            mode: args.decode_mode.unwrap_or_default(),
+            datetime_unit: args.datetime_unit.unwrap_or_default(),
+        })
+        .with_raw(raw_connection)
+        .with_busy_retry(args.busy_retry)
+        .with_lock_timeout(args.lock_timeout_ms.map(Duration::from_millis))
+        .with_remote(remote)
+        .with_sync_interval(sync_interval)
+        .with_synced_replica(synced_replica);
+    if let Some(timeout_ms) = args.busy_timeout_ms {
+        connection.set_busy_timeout(timeout_ms).await?;
     }
-    .unwrap();
-    let connection = database.connect().unwrap();
-    LibsqlConnection::new(connection, database)
+    Ok(connection)
+}
+
+/// Opens the local database file at `args.url` as a pool of one writer and
+/// `readers` read-only connections, 4 by default.
+pub async fn connect_pool(
+    args: ConnectArgs,
+    readers: Option<u32>,
+) -> Result<LibsqlPool, LibsqlError> {
+    LibsqlPool::open(args, readers.unwrap_or(4)).await
 }
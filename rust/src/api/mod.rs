@@ -1,4 +1,7 @@
 pub mod connection;
 pub mod libsql;
+pub mod pool;
+pub mod rows;
+pub mod savepoint;
 pub mod statement;
 pub mod transaction;
@@ -0,0 +1,393 @@
+use std::{
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+
+use flutter_rust_bridge::frb;
+use tokio::sync::{Mutex as AsyncMutex, OwnedMutexGuard, OwnedSemaphorePermit, Semaphore};
+
+use super::{
+    connection::LibsqlConnection,
+    libsql::{connect, ConnectArgs, LibsqlCancelToken},
+    transaction::{LibsqlTransaction, LibsqlTransactionBehavior},
+};
+use crate::frb_generated::StreamSink;
+use crate::utils::{
+    error::{LibsqlError, LibsqlErrorKind},
+    params::{LibsqlBatchStatement, LibsqlParams},
+    result::{BatchResult, ColumnarQueryResult, ExecuteResult, QueryResult},
+};
+
+/// Connections to a local database file in WAL mode: one writer and a fixed
+/// number of read-only readers. Reads run on an idle reader, or wait for
+/// one, and never block on writes. Writes, including transactions from
+/// begin to commit or rollback, run one at a time on the writer in the
+/// order they were made.
+///
+/// Every method can be called concurrently. Up to one read per reader runs
+/// at once.
+#[frb(opaque)]
+pub struct LibsqlPool {
+    writer: LibsqlConnection,
+    // held for the whole of a write
+    writer_queue: Arc<AsyncMutex<()>>,
+    readers: Vec<LibsqlConnection>,
+    idle_readers: Arc<IdleReaders>,
+}
+
+/// Indexes into the pool's readers of those not in use, with one permit for
+/// each of them.
+struct IdleReaders {
+    indexes: Mutex<Vec<usize>>,
+    permits: Arc<Semaphore>,
+}
+
+impl IdleReaders {
+    async fn checkout(self: Arc<Self>) -> Result<Reader, LibsqlError> {
+        // the semaphore is only closed by `close`
+        let permit = self
+            .permits
+            .clone()
+            .acquire_owned()
+            .await
+            .map_err(|_| LibsqlError::closed())?;
+        let index = self.indexes.lock().unwrap().pop().unwrap();
+        Ok(Reader {
+            idle: self,
+            index,
+            _permit: permit,
+        })
+    }
+}
+
+/// A reader checked out of the pool, returned to it on drop.
+struct Reader {
+    idle: Arc<IdleReaders>,
+    index: usize,
+    _permit: OwnedSemaphorePermit,
+}
+
+impl Drop for Reader {
+    fn drop(&mut self) {
+        self.idle.indexes.lock().unwrap().push(self.index);
+    }
+}
+
+impl LibsqlPool {
+    pub(crate) async fn open(args: ConnectArgs, readers: u32) -> Result<LibsqlPool, LibsqlError> {
+        let remote = ["libsql://", "http://", "https://"]
+            .iter()
+            .any(|scheme| args.url.starts_with(scheme));
+        if remote || args.sync_url.is_some() || args.url == ":memory:" {
+            return Err(LibsqlError::misuse(
+                "pools are only supported on local database files",
+            ));
+        }
+        if readers == 0 {
+            return Err(LibsqlError::misuse("a pool needs at least one reader"));
+        }
+        let writer = connect(args.clone()).await?;
+        // readers only see committed data without blocking the writer in WAL mode
+        writer
+            .query("PRAGMA journal_mode = WAL".to_string(), None, None, None)
+            .await?;
+        let mut connections = Vec::with_capacity(readers as usize);
+        for _ in 0..readers {
+            let reader = connect(args.clone()).await?;
+            reader
+                .batch("PRAGMA query_only = ON".to_string(), None, None)
+                .await?;
+            connections.push(reader);
+        }
+        Ok(LibsqlPool {
+            writer,
+            writer_queue: Arc::new(AsyncMutex::new(())),
+            idle_readers: Arc::new(IdleReaders {
+                indexes: Mutex::new((0..connections.len()).collect()),
+                permits: Arc::new(Semaphore::new(connections.len())),
+            }),
+            readers: connections,
+        })
+    }
+
+    pub async fn query(
+        &self,
+        sql: String,
+        parameters: Option<LibsqlParams>,
+        timeout_ms: Option<u64>,
+        cancel: Option<LibsqlCancelToken>,
+    ) -> Result<QueryResult, LibsqlError> {
+        let reader = self.reader().await?;
+        self.readers[reader.index]
+            .query(sql, parameters, timeout_ms, cancel)
+            .await
+    }
+
+    pub async fn query_columnar(
+        &self,
+        sql: String,
+        parameters: Option<LibsqlParams>,
+        timeout_ms: Option<u64>,
+        cancel: Option<LibsqlCancelToken>,
+    ) -> Result<ColumnarQueryResult, LibsqlError> {
+        let reader = self.reader().await?;
+        self.readers[reader.index]
+            .query_columnar(sql, parameters, timeout_ms, cancel)
+            .await
+    }
+
+    /// Runs `sql` on the writer once the writes queued before it are done.
+    /// `timeout_ms` covers the wait too, which fails with an `Interrupted`
+    /// error.
+    pub async fn execute(
+        &self,
+        sql: String,
+        parameters: Option<LibsqlParams>,
+        timeout_ms: Option<u64>,
+        cancel: Option<LibsqlCancelToken>,
+    ) -> Result<ExecuteResult, LibsqlError> {
+        let (_writer, timeout_ms) = self.queue_writer(timeout_ms).await?;
+        self.writer
+            .execute(sql, parameters, timeout_ms, cancel)
+            .await
+    }
+
+    /// Like `execute`, for several statements without parameters.
+    pub async fn batch(
+        &self,
+        sql: String,
+        timeout_ms: Option<u64>,
+        cancel: Option<LibsqlCancelToken>,
+    ) -> Result<(), LibsqlError> {
+        let (_writer, timeout_ms) = self.queue_writer(timeout_ms).await?;
+        self.writer.batch(sql, timeout_ms, cancel).await
+    }
+
+    pub async fn batch_statements(
+        &self,
+        statements: Vec<LibsqlBatchStatement>,
+        behavior: Option<LibsqlTransactionBehavior>,
+        timeout_ms: Option<u64>,
+        cancel: Option<LibsqlCancelToken>,
+    ) -> Result<BatchResult, LibsqlError> {
+        let (_writer, timeout_ms) = self.queue_writer(timeout_ms).await?;
+        self.writer
+            .batch_statements(statements, behavior, timeout_ms, cancel)
+            .await
+    }
+
+    /// Starts a transaction on the writer once the writes queued before it
+    /// are done. Other writes wait until it is committed or rolled back.
+    ///
+    /// Always commit or roll back the transaction: one that is merely
+    /// dropped in Dart holds up every later write on the pool until Dart's
+    /// finalizer releases it. Fails with an `Interrupted` error if the writer
+    /// is not free within `timeout_ms`, when given.
+    pub async fn transaction(
+        &self,
+        behavior: Option<LibsqlTransactionBehavior>,
+        timeout_ms: Option<u64>,
+    ) -> Result<LibsqlTransaction, LibsqlError> {
+        let (writer, _) = self.queue_writer(timeout_ms).await?;
+        let transaction = self.writer.transaction(behavior).await?;
+        Ok(transaction.with_writer(writer))
+    }
+
+    /// Streams the result of the read-only query `sql` like
+    /// `LibsqlConnection::watch`, running it again whenever a table it reads
+    /// from is changed through this pool. Each run waits for an idle reader
+    /// like `query` does, so it only sees committed writes.
+    pub async fn watch(
+        &self,
+        sql: String,
+        parameters: Option<LibsqlParams>,
+        debounce_ms: Option<u64>,
+        sink: StreamSink<QueryResult>,
+    ) -> Result<(), LibsqlError> {
+        let reader = self.reader().await?;
+        let idle = self.idle_readers.clone();
+        let handles: Arc<Vec<_>> = Arc::new(self.readers.iter().map(|r| r.handle()).collect());
+        let checkout = move || {
+            let (idle, handles) = (idle.clone(), handles.clone());
+            async move {
+                let reader = idle.checkout().await.ok()?;
+                let handle = handles[reader.index].clone();
+                Some((reader, handle))
+            }
+        };
+        self.writer
+            .watch_on(
+                &self.readers[reader.index],
+                sql,
+                parameters,
+                debounce_ms,
+                sink,
+                checkout,
+            )
+            .await
+    }
+
+    /// Closes the writer and every reader.
+    pub async fn close(&self) -> Result<(), LibsqlError> {
+        self.idle_readers.permits.close();
+        self.writer.close().await?;
+        for reader in &self.readers {
+            reader.close().await?;
+        }
+        Ok(())
+    }
+
+    /// Waits for the writes queued before this one, failing with an
+    /// `Interrupted` error once `timeout_ms` elapses, like the lock timeout.
+    /// `Busy` is left to locks held by other connections, which get retried. Returns what is left of it.
+    async fn queue_writer(
+        &self,
+        timeout_ms: Option<u64>,
+    ) -> Result<(OwnedMutexGuard<()>, Option<u64>), LibsqlError> {
+        let queued = self.writer_queue.clone().lock_owned();
+        let Some(timeout) = timeout_ms.map(Duration::from_millis) else {
+            return Ok((queued.await, None));
+        };
+        let started = Instant::now();
+        let writer = tokio::time::timeout(timeout, queued).await.map_err(|_| {
+            LibsqlError::new(
+                LibsqlErrorKind::Interrupted,
+                "timed out waiting for the writer",
+            )
+        })?;
+        let left = timeout.saturating_sub(started.elapsed());
+        Ok((writer, Some(left.as_millis() as u64)))
+    }
+
+    async fn reader(&self) -> Result<Reader, LibsqlError> {
+        self.idle_readers.clone().checkout().await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::LibsqlPool;
+    use crate::{
+        api::connection::tests::{local, temp_path},
+        utils::{error::LibsqlErrorKind, return_value::LibsqlReturnValue},
+    };
+
+    async fn open(name: &str, readers: u32) -> LibsqlPool {
+        let pool = LibsqlPool::open(local(&temp_path(name)), readers)
+            .await
+            .unwrap();
+        pool.execute("CREATE TABLE t (x)".to_string(), None, None, None)
+            .await
+            .unwrap();
+        pool
+    }
+
+    #[tokio::test]
+    async fn reads_run_alongside_a_transaction() {
+        let pool = open("pool-reads", 2).await;
+        let mut transaction = pool.transaction(None, None).await.unwrap();
+        transaction
+            .execute("INSERT INTO t VALUES (1)".to_string(), None, None, None)
+            .await
+            .unwrap();
+        let (first, second) = tokio::join!(
+            pool.query("SELECT x FROM t".to_string(), None, None, None),
+            pool.query("SELECT x FROM t".to_string(), None, None, None),
+        );
+        // readers only see committed writes
+        assert!(first.unwrap().rows.is_empty());
+        assert!(second.unwrap().rows.is_empty());
+        transaction.commit().await.unwrap();
+        let rows = pool
+            .query("SELECT x FROM t".to_string(), None, None, None)
+            .await
+            .unwrap()
+            .rows;
+        assert_eq!(rows.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn readers_reject_writes() {
+        let pool = open("pool-query-only", 1).await;
+        let error = pool
+            .query(
+                "INSERT INTO t VALUES (1) RETURNING x".to_string(),
+                None,
+                None,
+                None,
+            )
+            .await
+            .err()
+            .unwrap();
+        assert!(matches!(error.kind, LibsqlErrorKind::Sql));
+    }
+
+    #[tokio::test]
+    async fn writes_wait_for_the_writer() {
+        let pool = open("pool-writer-queue", 1).await;
+        let mut transaction = pool.transaction(None, None).await.unwrap();
+        let error = pool
+            .execute("INSERT INTO t VALUES (1)".to_string(), None, Some(10), None)
+            .await
+            .err()
+            .unwrap();
+        assert!(matches!(error.kind, LibsqlErrorKind::Interrupted));
+        let error = pool
+            .batch("INSERT INTO t VALUES (1)".to_string(), Some(10), None)
+            .await
+            .err()
+            .unwrap();
+        assert!(matches!(error.kind, LibsqlErrorKind::Interrupted));
+        let (executed, committed) = tokio::join!(
+            pool.execute("INSERT INTO t VALUES (2)".to_string(), None, None, None),
+            async {
+                tokio::time::sleep(Duration::from_millis(10)).await;
+                transaction
+                    .execute("INSERT INTO t VALUES (1)".to_string(), None, None, None)
+                    .await?;
+                transaction.commit().await
+            },
+        );
+        committed.unwrap();
+        executed.unwrap();
+        let rows = pool
+            .query(
+                "SELECT x FROM t ORDER BY rowid".to_string(),
+                None,
+                None,
+                None,
+            )
+            .await
+            .unwrap()
+            .rows;
+        let values: Vec<_> = rows
+            .iter()
+            .map(|row| match row["x"] {
+                LibsqlReturnValue::Integer(x) => x,
+                _ => unreachable!(),
+            })
+            .collect();
+        // the queued write ran after the transaction committed
+        assert_eq!(values, [1, 2]);
+    }
+
+    #[tokio::test]
+    async fn close_closes_every_connection() {
+        let pool = open("pool-close", 2).await;
+        pool.close().await.unwrap();
+        let error = pool
+            .query("SELECT x FROM t".to_string(), None, None, None)
+            .await
+            .err()
+            .unwrap();
+        assert!(matches!(error.kind, LibsqlErrorKind::Closed));
+        let error = pool
+            .execute("INSERT INTO t VALUES (1)".to_string(), None, None, None)
+            .await
+            .err()
+            .unwrap();
+        assert!(matches!(error.kind, LibsqlErrorKind::Closed));
+    }
+}
@@ -0,0 +1,185 @@
+use std::collections::HashMap;
+
+use flutter_rust_bridge::{frb, RustAutoOpaqueNom};
+pub use libsql::Rows as InnerRows;
+
+use super::connection::InnerConnection;
+use crate::utils::{
+    error::LibsqlError,
+    helpers::{row_to_map, rows_columns},
+    lock::Locks,
+    return_value::{LibsqlReturnValue, ValueDecoder},
+};
+
+/// A cursor over the rows of a query, read on demand instead of being
+/// collected into a `QueryResult` up front.
+///
+/// Rows are only fetched when `next` or `next_page` is awaited, so a Dart
+/// `Stream` built by pulling pages gets back-pressure for free.
+///
+/// Overlapping `next` and `next_page` calls take turns, so every row is
+/// returned exactly once in order.
+#[frb(opaque)]
+pub struct LibsqlRows {
+    rows: RustAutoOpaqueNom<Option<InnerRows>>,
+    columns: Vec<String>,
+    decoder: ValueDecoder,
+    // shared with the owning `LibsqlConnection`, empty once it is closed
+    connection: RustAutoOpaqueNom<Option<InnerConnection>>,
+    // the owning connection's
+    locks: Locks,
+}
+
+impl LibsqlRows {
+    pub(crate) fn new(
+        rows: InnerRows,
+        decoder: ValueDecoder,
+        connection: RustAutoOpaqueNom<Option<InnerConnection>>,
+        locks: Locks,
+    ) -> LibsqlRows {
+        let columns = rows_columns(&rows);
+        LibsqlRows {
+            rows: RustAutoOpaqueNom::new(Some(rows)),
+            columns,
+            decoder,
+            connection,
+            locks,
+        }
+    }
+
+    pub(crate) fn handle(&self) -> RustAutoOpaqueNom<Option<InnerRows>> {
+        self.rows.clone()
+    }
+
+    pub async fn columns(&self) -> Vec<String> {
+        self.columns.clone()
+    }
+
+    pub async fn decl_types(&self) -> Vec<Option<String>> {
+        self.decoder.decl_types()
+    }
+
+    /// Returns the next row, or `None` once the cursor is exhausted or closed.
+    pub async fn next(&self) -> Result<Option<HashMap<String, LibsqlReturnValue>>, LibsqlError> {
+        let mut guard = self.locks.write(&self.rows).await?;
+        if self.locks.read(&self.connection).await?.is_none() {
+            guard.take();
+            return Err(LibsqlError::closed());
+        }
+        let Some(rows) = guard.as_mut() else {
+            return Ok(None);
+        };
+        match rows.next().await? {
+            Some(row) => Ok(Some(row_to_map(&row, &self.decoder)?)),
+            None => {
+                *guard = None;
+                Ok(None)
+            }
+        }
+    }
+
+    /// Returns up to `size` rows. An empty page means the cursor is exhausted
+    /// or closed.
+    pub async fn next_page(
+        &self,
+        size: u32,
+    ) -> Result<Vec<HashMap<String, LibsqlReturnValue>>, LibsqlError> {
+        let mut guard = self.locks.write(&self.rows).await?;
+        if self.locks.read(&self.connection).await?.is_none() {
+            guard.take();
+            return Err(LibsqlError::closed());
+        }
+        let Some(rows) = guard.as_mut() else {
+            return Ok(Vec::new());
+        };
+        let mut page = Vec::with_capacity(size as usize);
+        while page.len() < size as usize {
+            match rows.next().await? {
+                Some(row) => page.push(row_to_map(&row, &self.decoder)?),
+                None => {
+                    *guard = None;
+                    break;
+                }
+            }
+        }
+        Ok(page)
+    }
+
+    /// Releases the underlying statement. Safe to call more than once.
+    pub async fn close(&self) -> Result<(), LibsqlError> {
+        self.locks.write(&self.rows).await?.take();
+        Ok(())
+    }
+}
+
+impl Drop for LibsqlRows {
+    fn drop(&mut self) {
+        // leaves an empty slot so the connection stops tracking this cursor
+        if let Ok(mut rows) = self.rows.try_write() {
+            rows.take();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::LibsqlRows;
+    use crate::{
+        api::{
+            connection::{tests::local, LibsqlConnection},
+            libsql::connect,
+        },
+        utils::{error::LibsqlErrorKind, return_value::LibsqlReturnValue},
+    };
+
+    async fn open() -> (LibsqlConnection, LibsqlRows) {
+        let connection = connect(local(":memory:")).await.unwrap();
+        let sql = "WITH RECURSIVE n(x) AS (SELECT 1 UNION ALL SELECT x + 1 FROM n WHERE x < 5) \
+                   SELECT x FROM n";
+        let rows = connection
+            .query_stream(sql.to_string(), None, None, None)
+            .await
+            .unwrap();
+        (connection, rows)
+    }
+
+    fn values(page: &[std::collections::HashMap<String, LibsqlReturnValue>]) -> Vec<i64> {
+        page.iter()
+            .map(|row| match row["x"] {
+                LibsqlReturnValue::Integer(x) => x,
+                _ => panic!("x is not an integer"),
+            })
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn pages_return_every_row_once_in_order() {
+        let (_connection, rows) = open().await;
+        assert_eq!(rows.columns().await, vec!["x".to_string()]);
+        assert_eq!(values(&rows.next_page(2).await.unwrap()), vec![1, 2]);
+        let row = rows.next().await.unwrap().unwrap();
+        assert_eq!(values(&[row]), vec![3]);
+        assert_eq!(values(&rows.next_page(10).await.unwrap()), vec![4, 5]);
+        assert!(rows.next_page(10).await.unwrap().is_empty());
+        assert!(rows.next().await.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn closed_cursors_return_no_rows() {
+        let (_connection, rows) = open().await;
+        assert_eq!(values(&rows.next_page(1).await.unwrap()), vec![1]);
+        rows.close().await.unwrap();
+        assert!(rows.next_page(10).await.unwrap().is_empty());
+        assert!(rows.next().await.unwrap().is_none());
+        rows.close().await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn cursors_fail_once_the_connection_is_closed() {
+        let (connection, rows) = open().await;
+        rows.next_page(1).await.unwrap();
+        connection.close().await.unwrap();
+        let error = rows.next_page(1).await.err().unwrap();
+        assert!(matches!(error.kind, LibsqlErrorKind::Closed));
+    }
+}
@@ -0,0 +1,401 @@
+use std::{
+    future::Future,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc, Mutex,
+    },
+    time::Duration,
+};
+
+use flutter_rust_bridge::{frb, RustAutoOpaqueNom};
+
+use super::{libsql::LibsqlCancelToken, transaction::TransactionState};
+use crate::utils::{
+    error::LibsqlError,
+    helpers::{execute_connection, query_connection},
+    interrupt::Interrupts,
+    lock::Locks,
+    params::LibsqlParams,
+    result::{ExecuteResult, QueryResult},
+    return_value::DecodeOptions,
+};
+
+/// A named savepoint inside a `LibsqlTransaction`.
+///
+/// Savepoints nest: statements run through any savepoint belong to the same
+/// transaction, and `release` or `rollback_to` only settle the work done
+/// since this savepoint was created. Releasing a savepoint, or rolling back
+/// to it, ends the savepoints created after it, whose handles then fail
+/// with a `Misuse` error.
+///
+/// The transaction can't be committed while any of its savepoints is open,
+/// including one whose handle was dropped by Dart's finalizer without being
+/// released or rolled back to. Such a savepoint is only ended by ending a
+/// savepoint enclosing it, or by rolling back the transaction.
+///
+/// Calls through a savepoint share the transaction's lock, so they are
+/// ordered against `commit` and `rollback` like calls on the transaction.
+#[frb(opaque)]
+pub struct LibsqlSavepoint {
+    transaction: RustAutoOpaqueNom<TransactionState>,
+    name: String,
+    decoding: DecodeOptions,
+    // also set once an enclosing savepoint is released or rolled back to
+    finished: Arc<AtomicBool>,
+    // the owning transaction's
+    savepoints: SavepointStack,
+    // the owning connection's, stops the calls in flight
+    interrupts: Arc<Interrupts>,
+    // the owning connection's
+    locks: Locks,
+}
+
+impl LibsqlSavepoint {
+    pub(crate) async fn begin(
+        transaction: RustAutoOpaqueNom<TransactionState>,
+        savepoints: SavepointStack,
+        name: String,
+        decoding: DecodeOptions,
+        interrupts: Arc<Interrupts>,
+        locks: Locks,
+    ) -> Result<LibsqlSavepoint, LibsqlError> {
+        // SQLite settles the innermost savepoint of a name, which would
+        // leave the handle of the outer one pointing at the wrong savepoint
+        let finished = savepoints.open(&name)?;
+        let savepoint = LibsqlSavepoint {
+            transaction,
+            name,
+            decoding,
+            finished,
+            savepoints,
+            interrupts,
+            locks,
+        };
+        let begun = savepoint
+            .run(&format!("SAVEPOINT {}", quote(&savepoint.name)))
+            .await;
+        if let Err(error) = begun {
+            // never opened, so there is nothing to leave open on drop
+            savepoint.savepoints.unwind(&savepoint.finished, true);
+            return Err(error);
+        }
+        Ok(savepoint)
+    }
+
+    pub async fn name(&self) -> String {
+        self.name.clone()
+    }
+
+    /// Starts a savepoint nested in this one, like
+    /// `LibsqlTransaction::savepoint`.
+    pub async fn savepoint(&self, name: String) -> Result<LibsqlSavepoint, LibsqlError> {
+        self.ensure_active()?;
+        LibsqlSavepoint::begin(
+            self.transaction.clone(),
+            self.savepoints.clone(),
+            name,
+            self.decoding,
+            self.interrupts.clone(),
+            self.locks.clone(),
+        )
+        .await
+    }
+
+    pub async fn query(
+        &self,
+        sql: String,
+        parameters: Option<LibsqlParams>,
+        timeout_ms: Option<u64>,
+        cancel: Option<LibsqlCancelToken>,
+    ) -> Result<QueryResult, LibsqlError> {
+        self.ensure_active()?;
+        self.interruptible(timeout_ms, cancel, async {
+            let guard = self.locks.read(&self.transaction).await?;
+            query_connection(guard.active()?, &sql, parameters, self.decoding).await
+        })
+        .await
+    }
+
+    pub async fn execute(
+        &self,
+        sql: String,
+        parameters: Option<LibsqlParams>,
+        timeout_ms: Option<u64>,
+        cancel: Option<LibsqlCancelToken>,
+    ) -> Result<ExecuteResult, LibsqlError> {
+        self.ensure_active()?;
+        self.interruptible(timeout_ms, cancel, async {
+            let guard = self.locks.read(&self.transaction).await?;
+            execute_connection(guard.active()?, &sql, parameters).await
+        })
+        .await
+    }
+
+    /// Keeps the changes made since the savepoint as part of the enclosing
+    /// transaction or savepoint, ending this savepoint.
+    pub async fn release(&self) -> Result<(), LibsqlError> {
+        self.ensure_active()?;
+        self.run(&format!("RELEASE SAVEPOINT {}", quote(&self.name)))
+            .await?;
+        self.savepoints.unwind(&self.finished, true);
+        Ok(())
+    }
+
+    /// Discards the changes made since the savepoint. The savepoint stays
+    /// open, so it can be rolled back to again or released, and so does the
+    /// enclosing transaction.
+    pub async fn rollback_to(&self) -> Result<(), LibsqlError> {
+        self.ensure_active()?;
+        self.run(&format!("ROLLBACK TO SAVEPOINT {}", quote(&self.name)))
+            .await?;
+        self.savepoints.unwind(&self.finished, false);
+        Ok(())
+    }
+
+    fn ensure_active(&self) -> Result<(), LibsqlError> {
+        if self.finished.load(Ordering::SeqCst) {
+            return Err(LibsqlError::misuse(format!(
+                "savepoint {} has already been released or rolled back",
+                self.name
+            )));
+        }
+        Ok(())
+    }
+
+    /// Runs `future`, failing with `Interrupted` once the owning connection
+    /// is interrupted, `timeout_ms` elapses or `cancel` is cancelled.
+    async fn interruptible<T>(
+        &self,
+        timeout_ms: Option<u64>,
+        cancel: Option<LibsqlCancelToken>,
+        future: impl Future<Output = Result<T, LibsqlError>>,
+    ) -> Result<T, LibsqlError> {
+        let timeout = timeout_ms.map(Duration::from_millis);
+        self.interrupts.run(timeout, cancel, future).await
+    }
+
+    async fn run(&self, sql: &str) -> Result<(), LibsqlError> {
+        self.locks
+            .read(&self.transaction)
+            .await?
+            .active()?
+            .execute(sql, ())
+            .await?;
+        Ok(())
+    }
+}
+
+impl Drop for LibsqlSavepoint {
+    fn drop(&mut self) {
+        // left open in SQLite, for the transaction to settle
+        self.savepoints.abandon(&self.finished);
+    }
+}
+
+/// The savepoints open in a transaction, innermost last.
+#[derive(Clone, Default)]
+pub(crate) struct SavepointStack(Arc<Mutex<Vec<OpenSavepoint>>>);
+
+struct OpenSavepoint {
+    name: String,
+    // checked by the savepoint's handle
+    finished: Arc<AtomicBool>,
+    // set once the handle is dropped with the savepoint still open
+    abandoned: bool,
+}
+
+impl SavepointStack {
+    /// Adds the savepoint `name`, failing if one of that name is open.
+    fn open(&self, name: &str) -> Result<Arc<AtomicBool>, LibsqlError> {
+        let mut savepoints = self.0.lock().unwrap();
+        if savepoints
+            .iter()
+            .any(|open| open.name.eq_ignore_ascii_case(name))
+        {
+            return Err(LibsqlError::misuse(format!(
+                "savepoint {name} is already open"
+            )));
+        }
+        let finished = Arc::new(AtomicBool::new(false));
+        savepoints.push(OpenSavepoint {
+            name: name.to_string(),
+            finished: finished.clone(),
+            abandoned: false,
+        });
+        Ok(finished)
+    }
+
+    /// Marks `savepoint` as open without a handle, if it is still open.
+    fn abandon(&self, savepoint: &Arc<AtomicBool>) {
+        let mut savepoints = self.0.lock().unwrap();
+        if let Some(open) = savepoints
+            .iter_mut()
+            .find(|open| Arc::ptr_eq(&open.finished, savepoint))
+        {
+            open.abandoned = true;
+        }
+    }
+
+    /// Fails with a `Misuse` error while a savepoint is open.
+    pub(crate) fn ensure_settled(&self) -> Result<(), LibsqlError> {
+        let savepoints = self.0.lock().unwrap();
+        let Some(open) = savepoints.first() else {
+            return Ok(());
+        };
+        let message = if open.abandoned {
+            format!(
+                "savepoint {} was dropped while open, end a savepoint enclosing it or roll back",
+                open.name
+            )
+        } else {
+            format!(
+                "savepoint {} is still open, release or roll back to it first",
+                open.name
+            )
+        };
+        Err(LibsqlError::misuse(message))
+    }
+
+    /// Ends every savepoint, as the transaction ends.
+    pub(crate) fn clear(&self) {
+        for open in self.0.lock().unwrap().drain(..) {
+            open.finished.store(true, Ordering::SeqCst);
+        }
+    }
+
+    /// Ends the savepoints opened after `savepoint`, and `savepoint` itself
+    /// when `inclusive`.
+    fn unwind(&self, savepoint: &Arc<AtomicBool>, inclusive: bool) {
+        let mut savepoints = self.0.lock().unwrap();
+        let Some(index) = savepoints
+            .iter()
+            .position(|open| Arc::ptr_eq(&open.finished, savepoint))
+        else {
+            return;
+        };
+        let keep = if inclusive { index } else { index + 1 };
+        for open in savepoints.drain(keep..) {
+            open.finished.store(true, Ordering::SeqCst);
+        }
+    }
+}
+
+fn quote(name: &str) -> String {
+    format!("\"{}\"", name.replace('"', "\"\""))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        api::{
+            connection::{tests::local, LibsqlConnection},
+            libsql::connect,
+            transaction::LibsqlTransaction,
+        },
+        utils::{error::LibsqlErrorKind, result::QueryResult},
+    };
+
+    async fn open() -> (LibsqlConnection, LibsqlTransaction) {
+        let connection = connect(local(":memory:")).await.unwrap();
+        connection
+            .execute("CREATE TABLE t (x)".to_string(), None, None, None)
+            .await
+            .unwrap();
+        let transaction = connection.transaction(None).await.unwrap();
+        (connection, transaction)
+    }
+
+    async fn insert(transaction: &LibsqlTransaction, x: i64) {
+        transaction
+            .execute(format!("INSERT INTO t VALUES ({x})"), None, None, None)
+            .await
+            .unwrap();
+    }
+
+    async fn count(transaction: &LibsqlTransaction) -> usize {
+        let result: QueryResult = transaction
+            .query("SELECT x FROM t".to_string(), None, None, None)
+            .await
+            .unwrap();
+        result.rows.len()
+    }
+
+    #[tokio::test]
+    async fn rollback_to_keeps_the_savepoint() {
+        let (_connection, transaction) = open().await;
+        let savepoint = transaction.savepoint("a".to_string()).await.unwrap();
+        insert(&transaction, 1).await;
+        savepoint.rollback_to().await.unwrap();
+        assert_eq!(count(&transaction).await, 0);
+        insert(&transaction, 2).await;
+        savepoint.rollback_to().await.unwrap();
+        assert_eq!(count(&transaction).await, 0);
+        insert(&transaction, 3).await;
+        savepoint.release().await.unwrap();
+        assert_eq!(count(&transaction).await, 1);
+    }
+
+    #[tokio::test]
+    async fn ending_a_savepoint_ends_the_ones_inside_it() {
+        let (_connection, transaction) = open().await;
+        let outer = transaction.savepoint("outer".to_string()).await.unwrap();
+        let inner = outer.savepoint("inner".to_string()).await.unwrap();
+        outer.rollback_to().await.unwrap();
+        let error = inner.release().await.unwrap_err();
+        assert!(matches!(error.kind, LibsqlErrorKind::Misuse));
+        let inner = outer.savepoint("inner".to_string()).await.unwrap();
+        outer.release().await.unwrap();
+        let error = inner.rollback_to().await.unwrap_err();
+        assert!(matches!(error.kind, LibsqlErrorKind::Misuse));
+    }
+
+    #[tokio::test]
+    async fn open_names_are_not_reused() {
+        let (_connection, transaction) = open().await;
+        let _outer = transaction.savepoint("a".to_string()).await.unwrap();
+        let error = transaction.savepoint("A".to_string()).await.err().unwrap();
+        assert!(matches!(error.kind, LibsqlErrorKind::Misuse));
+    }
+
+    #[tokio::test]
+    async fn commit_waits_for_open_savepoints() {
+        let (_connection, mut transaction) = open().await;
+        let savepoint = transaction.savepoint("a".to_string()).await.unwrap();
+        insert(&transaction, 1).await;
+        let error = transaction.commit().await.unwrap_err();
+        assert!(matches!(error.kind, LibsqlErrorKind::Misuse));
+        savepoint.release().await.unwrap();
+        transaction.commit().await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn dropped_savepoints_stay_open_until_settled() {
+        let (_connection, mut transaction) = open().await;
+        let outer = transaction.savepoint("outer".to_string()).await.unwrap();
+        let inner = outer.savepoint("inner".to_string()).await.unwrap();
+        insert(&transaction, 1).await;
+        drop(inner);
+        // nothing is rolled back behind the caller's back
+        assert_eq!(count(&transaction).await, 1);
+        let error = transaction.commit().await.unwrap_err();
+        assert!(matches!(error.kind, LibsqlErrorKind::Misuse));
+        let error = outer.savepoint("inner".to_string()).await.err().unwrap();
+        assert!(matches!(error.kind, LibsqlErrorKind::Misuse));
+        // releasing the enclosing savepoint releases the dropped one
+        outer.release().await.unwrap();
+        transaction.commit().await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn rollback_ends_dropped_savepoints() {
+        let (connection, mut transaction) = open().await;
+        drop(transaction.savepoint("a".to_string()).await.unwrap());
+        insert(&transaction, 1).await;
+        transaction.rollback().await.unwrap();
+        let result = connection
+            .query("SELECT x FROM t".to_string(), None, None, None)
+            .await
+            .unwrap();
+        assert!(result.rows.is_empty());
+    }
+}
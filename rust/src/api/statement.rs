@@ -1,64 +1,388 @@
+use std::{
+    future::Future,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
 use flutter_rust_bridge::{frb, RustAutoOpaqueNom};
 #[frb(name = "_Statement")]
 pub use libsql::Statement as InnerStatement;
 
+use super::{
+    connection::{InnerConnection, OpenHandles},
+    libsql::LibsqlCancelToken,
+    rows::LibsqlRows,
+    transaction::TransactionState,
+};
 use crate::utils::{
-    helpers::rows_to_query_result,
-    params::LibsqlParams,
-    result::{ExecuteResult, QueryResult},
+    error::LibsqlError,
+    helpers::{execute_result, rows_to_columnar_result, rows_to_query_result},
+    interrupt::Interrupts,
+    lock::Locks,
+    params::{bind_params, LibsqlParams},
+    result::{ColumnarQueryResult, ExecuteResult, LibsqlColumn, QueryResult},
+    retry::{retry_busy, LibsqlBusyRetry},
+    return_value::{DecodeOptions, ValueDecoder},
 };
 
+/// A prepared statement.
+///
+/// Overlapping calls run one at a time in the order they were made, since
+/// each of them rebinds and resets the same statement.
 #[frb(opaque)]
 pub struct LibsqlStatement {
-    statement: RustAutoOpaqueNom<InnerStatement>,
+    statement: RustAutoOpaqueNom<Option<InnerStatement>>,
+    sql: String,
+    decoding: DecodeOptions,
+    // shared with the owning `LibsqlConnection`, empty once it is closed
+    connection: RustAutoOpaqueNom<Option<InnerConnection>>,
+    // set when prepared on a transaction, which must still be active to use it
+    transaction: Option<RustAutoOpaqueNom<TransactionState>>,
+    // the owning connection's, stops the calls in flight
+    interrupts: Arc<Interrupts>,
+    // the owning connection's
+    locks: Locks,
+    busy_retry: Option<LibsqlBusyRetry>,
+    // the owning connection's, row cursors are closed along with it
+    handles: Arc<Mutex<OpenHandles>>,
 }
 
 impl LibsqlStatement {
-    pub fn new(statement: InnerStatement) -> LibsqlStatement {
+    pub(crate) fn new(
+        statement: InnerStatement,
+        sql: String,
+        decoding: DecodeOptions,
+        connection: RustAutoOpaqueNom<Option<InnerConnection>>,
+        interrupts: Arc<Interrupts>,
+        locks: Locks,
+        handles: Arc<Mutex<OpenHandles>>,
+    ) -> LibsqlStatement {
         LibsqlStatement {
-            statement: RustAutoOpaqueNom::new(statement),
+            statement: RustAutoOpaqueNom::new(Some(statement)),
+            sql,
+            decoding,
+            connection,
+            transaction: None,
+            interrupts,
+            locks,
+            busy_retry: None,
+            handles,
+        }
+    }
+
+    /// Ties the statement to `transaction`, which must still be active to
+    /// use it.
+    pub(crate) fn with_transaction(
+        mut self,
+        transaction: RustAutoOpaqueNom<TransactionState>,
+    ) -> LibsqlStatement {
+        self.transaction = Some(transaction);
+        self
+    }
+
+    /// Retries `execute` while it is busy as `busy_retry` allows.
+    pub(crate) fn with_busy_retry(mut self, busy_retry: Option<LibsqlBusyRetry>) -> Self {
+        self.busy_retry = busy_retry;
+        self
+    }
+
+    pub(crate) fn handle(&self) -> RustAutoOpaqueNom<Option<InnerStatement>> {
+        self.statement.clone()
+    }
+
+    pub async fn columns(&self) -> Result<Vec<LibsqlColumn>, LibsqlError> {
+        let columns = self
+            .locks
+            .read(&self.statement)
+            .await?
+            .as_ref()
+            .ok_or_else(finalized)?
+            .columns()
+            .iter()
+            .map(|column| LibsqlColumn {
+                name: column.name().to_string(),
+                origin_name: column.origin_name().map(str::to_string),
+                table_name: column.table_name().map(str::to_string),
+                database_name: column.database_name().map(str::to_string),
+                decl_type: column.decl_type().map(str::to_string),
+            })
+            .collect();
+        Ok(columns)
+    }
+
+    pub async fn parameter_count(&self) -> Result<u32, LibsqlError> {
+        Ok(self
+            .locks
+            .read(&self.statement)
+            .await?
+            .as_ref()
+            .ok_or_else(finalized)?
+            .parameter_count() as u32)
+    }
+
+    /// Name of the parameter at `index`, counting from 1 like SQLite does.
+    /// Positional `?` parameters have no name.
+    pub async fn parameter_name(&self, index: u32) -> Result<Option<String>, LibsqlError> {
+        Ok(self
+            .locks
+            .read(&self.statement)
+            .await?
+            .as_ref()
+            .ok_or_else(finalized)?
+            .parameter_name(index as i32)
+            .map(str::to_string))
+    }
+
+    /// Whether the statement leaves the database as it is, as SQLite tells.
+    /// Only supported on local databases and embedded replicas.
+    pub async fn is_readonly(&self) -> Result<bool, LibsqlError> {
+        let statement = self.locks.read(&self.statement).await?;
+        statement.as_ref().ok_or_else(finalized)?;
+        // keeps the SQLite handle open meanwhile
+        let connection = self.locks.read(&self.connection).await?;
+        connection.as_ref().ok_or_else(LibsqlError::closed)?;
+        let raw = self.interrupts.raw().ok_or_else(|| {
+            LibsqlError::misuse("only supported on local databases and embedded replicas")
+        })?;
+        raw.is_readonly(&self.sql)
+    }
+
+    pub async fn finalize(&self) -> Result<(), LibsqlError> {
+        if let Some(mut statement) = self.locks.write(&self.statement).await?.take() {
+            statement.finalize();
         }
+        Ok(())
     }
 
-    pub async fn finalize(&self) {
-        self.statement.try_write().unwrap().finalize();
+    pub async fn reset(&self) -> Result<(), LibsqlError> {
+        self.locks
+            .write(&self.statement)
+            .await?
+            .as_ref()
+            .ok_or_else(finalized)?
+            .reset();
+        Ok(())
     }
 
-    pub async fn reset(&self) {
-        self.statement.try_write().unwrap().reset();
+    pub async fn query(
+        &self,
+        parameters: Option<LibsqlParams>,
+        timeout_ms: Option<u64>,
+        cancel: Option<LibsqlCancelToken>,
+    ) -> Result<QueryResult, LibsqlError> {
+        self.interruptible(timeout_ms, cancel, async {
+            let connection = self.connection().await?;
+            let guard = self.locks.write(&self.statement).await?;
+            let total_changes = connection.total_changes();
+            let statement = guard.as_ref().ok_or_else(finalized)?;
+            let params = bind_params(parameters, statement)?;
+            let decoder = ValueDecoder::from_statement(statement, self.decoding);
+            let result = statement.query(params).await?;
+            rows_to_query_result(result, &decoder, &connection, total_changes).await
+        })
+        .await
     }
 
-    pub async fn query(&self, parameters: Option<LibsqlParams>) -> QueryResult {
-        let params: libsql::params::Params = parameters
-            .unwrap_or(LibsqlParams {
-                positional: None,
-                named: None,
+    pub async fn query_columnar(
+        &self,
+        parameters: Option<LibsqlParams>,
+        timeout_ms: Option<u64>,
+        cancel: Option<LibsqlCancelToken>,
+    ) -> Result<ColumnarQueryResult, LibsqlError> {
+        self.interruptible(timeout_ms, cancel, async {
+            let connection = self.connection().await?;
+            let guard = self.locks.write(&self.statement).await?;
+            let total_changes = connection.total_changes();
+            let statement = guard.as_ref().ok_or_else(finalized)?;
+            let params = bind_params(parameters, statement)?;
+            let decoder = ValueDecoder::from_statement(statement, self.decoding);
+            let result = statement.query(params).await?;
+            rows_to_columnar_result(result, &decoder, &connection, total_changes).await
+        })
+        .await
+    }
+
+    /// Starts the query, leaving its rows to be read page by page. The
+    /// timeout only covers starting it.
+    pub async fn query_stream(
+        &self,
+        parameters: Option<LibsqlParams>,
+        timeout_ms: Option<u64>,
+        cancel: Option<LibsqlCancelToken>,
+    ) -> Result<LibsqlRows, LibsqlError> {
+        self.interruptible(timeout_ms, cancel, async {
+            self.connection().await?;
+            let guard = self.locks.write(&self.statement).await?;
+            let statement = guard.as_ref().ok_or_else(finalized)?;
+            let params = bind_params(parameters, statement)?;
+            let decoder = ValueDecoder::from_statement(statement, self.decoding);
+            let rows = statement.query(params).await?;
+            let rows = LibsqlRows::new(rows, decoder, self.connection.clone(), self.locks.clone());
+            self.handles.lock().unwrap().add_rows(rows.handle());
+            Ok(rows)
+        })
+        .await
+    }
+
+    pub async fn execute(
+        &self,
+        parameters: Option<LibsqlParams>,
+        timeout_ms: Option<u64>,
+        cancel: Option<LibsqlCancelToken>,
+    ) -> Result<ExecuteResult, LibsqlError> {
+        self.interruptible(timeout_ms, cancel, async {
+            retry_busy(self.busy_retry, || async {
+                let connection = self.connection().await?;
+                let guard = self.locks.write(&self.statement).await?;
+                let statement = guard.as_ref().ok_or_else(finalized)?;
+                let params = bind_params(parameters.clone(), statement)?;
+                let rows_affected = statement.execute(params).await? as u64;
+                Ok(execute_result(&connection, rows_affected))
             })
-            .into();
-        let result = self
-            .statement
-            .try_write()
-            .unwrap()
-            .query(params)
+            .await
+        })
+        .await
+    }
+
+    /// Runs `future`, failing with `Interrupted` once the owning connection
+    /// is interrupted, `timeout_ms` elapses or `cancel` is cancelled.
+    async fn interruptible<T>(
+        &self,
+        timeout_ms: Option<u64>,
+        cancel: Option<LibsqlCancelToken>,
+        future: impl Future<Output = Result<T, LibsqlError>>,
+    ) -> Result<T, LibsqlError> {
+        let timeout = timeout_ms.map(Duration::from_millis);
+        self.interrupts.run(timeout, cancel, future).await
+    }
+
+    /// The connection statements run on, failing once the owning connection
+    /// is closed or the owning transaction has finished.
+    async fn connection(&self) -> Result<InnerConnection, LibsqlError> {
+        let connection = self
+            .locks
+            .read(&self.connection)
+            .await?
+            .clone()
+            .ok_or_else(LibsqlError::closed)?;
+        match &self.transaction {
+            Some(transaction) => Ok(InnerConnection::clone(
+                self.locks.read(transaction).await?.active()?,
+            )),
+            None => Ok(connection),
+        }
+    }
+}
+
+impl Drop for LibsqlStatement {
+    fn drop(&mut self) {
+        // leaves an empty slot so the connection stops tracking this statement
+        if let Ok(mut statement) = self.statement.try_write() {
+            statement.take();
+        }
+    }
+}
+
+fn finalized() -> LibsqlError {
+    LibsqlError::misuse("statement has been finalized")
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        api::{
+            connection::{tests::local, LibsqlConnection},
+            libsql::connect,
+        },
+        utils::error::LibsqlErrorKind,
+    };
+
+    async fn open() -> LibsqlConnection {
+        let connection = connect(local(":memory:")).await.unwrap();
+        connection
+            .execute(
+                "CREATE TABLE t (id INTEGER, name TEXT)".to_string(),
+                None,
+                None,
+                None,
+            )
             .await
             .unwrap();
-        rows_to_query_result(result).await
+        connection
     }
 
-    pub async fn execute(&self, parameters: Option<LibsqlParams>) -> ExecuteResult {
-        let params: libsql::params::Params = parameters
-            .unwrap_or(LibsqlParams {
-                positional: None,
-                named: None,
-            })
-            .into();
-        let rows_affected = self
-            .statement
-            .try_write()
-            .unwrap()
-            .execute(params)
+    #[tokio::test]
+    async fn describes_columns() {
+        let connection = open().await;
+        let statement = connection
+            .prepare("SELECT id, name AS label, 1 + 1 AS two FROM t".to_string())
             .await
-            .unwrap() as u64;
-        ExecuteResult { rows_affected }
+            .unwrap();
+        let columns = statement.columns().await.unwrap();
+        let names: Vec<_> = columns.iter().map(|column| column.name.as_str()).collect();
+        assert_eq!(names, ["id", "label", "two"]);
+        assert_eq!(columns[1].origin_name.as_deref(), Some("name"));
+        assert_eq!(columns[1].table_name.as_deref(), Some("t"));
+        assert_eq!(columns[1].database_name.as_deref(), Some("main"));
+        assert_eq!(columns[1].decl_type.as_deref(), Some("TEXT"));
+        assert_eq!(columns[2].origin_name, None);
+        assert_eq!(columns[2].decl_type, None);
+    }
+
+    #[tokio::test]
+    async fn describes_parameters() {
+        let connection = open().await;
+        let statement = connection
+            .prepare("SELECT id FROM t WHERE id = :id AND name = @name".to_string())
+            .await
+            .unwrap();
+        assert_eq!(statement.parameter_count().await.unwrap(), 2);
+        assert_eq!(
+            statement.parameter_name(1).await.unwrap().as_deref(),
+            Some(":id")
+        );
+        assert_eq!(
+            statement.parameter_name(2).await.unwrap().as_deref(),
+            Some("@name")
+        );
+        assert_eq!(statement.parameter_name(3).await.unwrap(), None);
+        let statement = connection
+            .prepare("INSERT INTO t VALUES (?, ?)".to_string())
+            .await
+            .unwrap();
+        assert_eq!(statement.parameter_count().await.unwrap(), 2);
+        assert_eq!(statement.parameter_name(1).await.unwrap(), None);
+    }
+
+    #[tokio::test]
+    async fn tells_readonly_statements_apart() {
+        let connection = open().await;
+        for (sql, readonly) in [
+            ("SELECT id FROM t", true),
+            ("WITH n AS (SELECT 1) SELECT * FROM n", true),
+            ("INSERT INTO t VALUES (1, 'a')", false),
+            ("UPDATE t SET name = 'b'", false),
+            ("PRAGMA user_version", true),
+            ("PRAGMA user_version = 1", false),
+        ] {
+            let statement = connection.prepare(sql.to_string()).await.unwrap();
+            assert_eq!(statement.is_readonly().await.unwrap(), readonly, "{sql}");
+        }
+    }
+
+    #[tokio::test]
+    async fn finalized_statements_cannot_be_described() {
+        let connection = open().await;
+        let statement = connection
+            .prepare("SELECT id FROM t".to_string())
+            .await
+            .unwrap();
+        statement.finalize().await.unwrap();
+        let error = statement.columns().await.err().unwrap();
+        assert!(matches!(error.kind, LibsqlErrorKind::Misuse));
+        let error = statement.parameter_count().await.unwrap_err();
+        assert!(matches!(error.kind, LibsqlErrorKind::Misuse));
+        let error = statement.is_readonly().await.unwrap_err();
+        assert!(matches!(error.kind, LibsqlErrorKind::Misuse));
     }
 }
@@ -1,93 +1,463 @@
-use std::collections::HashMap;
-
 use flutter_rust_bridge::frb;
 use flutter_rust_bridge::RustAutoOpaqueNom;
 pub use libsql::Connection;
 pub use libsql::Transaction as InnerTransaction;
 
+use std::{
+    future::Future,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+use tokio::sync::OwnedMutexGuard;
+
+use super::{
+    connection::{InnerConnection, OpenHandles},
+    libsql::LibsqlCancelToken,
+    savepoint::{LibsqlSavepoint, SavepointStack},
+    statement::{InnerStatement, LibsqlStatement},
+};
 use crate::utils::{
-    helpers::rows_to_query_result,
+    error::LibsqlError,
+    helpers::{execute_connection, query_connection, query_connection_columnar},
+    interrupt::Interrupts,
+    lock::Locks,
     params::LibsqlParams,
-    result::{ExecuteResult, QueryResult},
+    result::{ColumnarQueryResult, ExecuteResult, QueryResult},
+    retry::{retry_busy, LibsqlBusyRetry},
+    return_value::DecodeOptions,
 };
 
+pub(crate) enum TransactionState {
+    Active(InnerTransaction),
+    Committed,
+    RolledBack,
+}
+
+impl TransactionState {
+    pub(crate) fn is_active(&self) -> bool {
+        matches!(self, TransactionState::Active(_))
+    }
+
+    pub(crate) fn active(&self) -> Result<&InnerTransaction, LibsqlError> {
+        match self {
+            TransactionState::Active(transaction) => Ok(transaction),
+            TransactionState::Committed => Err(LibsqlError::transaction_finished("committed")),
+            TransactionState::RolledBack => Err(LibsqlError::transaction_finished("rolled back")),
+        }
+    }
+
+    /// Moves to `next`, handing back the transaction if it was still active.
+    pub(crate) fn finish(
+        &mut self,
+        next: TransactionState,
+    ) -> Result<InnerTransaction, LibsqlError> {
+        self.active()?;
+        match std::mem::replace(self, next) {
+            TransactionState::Active(transaction) => Ok(transaction),
+            _ => unreachable!(),
+        }
+    }
+}
+
+/// A transaction on a `LibsqlConnection`.
+///
+/// Overlapping calls may run together, but `commit` and `rollback` wait
+/// for the calls made before them, and calls made after them fail instead
+/// of running outside the transaction.
 #[frb(opaque)]
 pub struct LibsqlTransaction {
-    // TODO: this is a hack
-    transaction: RustAutoOpaqueNom<HashMap<u8, InnerTransaction>>,
+    transaction: RustAutoOpaqueNom<TransactionState>,
+    // shared with the owning `LibsqlConnection`, empty once it is closed
+    connection: RustAutoOpaqueNom<Option<InnerConnection>>,
+    decoding: DecodeOptions,
+    // prepared on this transaction, finalized once it finishes
+    statements: Mutex<Vec<RustAutoOpaqueNom<Option<InnerStatement>>>>,
+    // open savepoints, created from this transaction or from each other
+    savepoints: SavepointStack,
+    // set when begun on a pool's writer, which is queued until this finishes
+    writer: Option<OwnedMutexGuard<()>>,
+    // the owning connection's, stops the calls in flight
+    interrupts: Arc<Interrupts>,
+    // the owning connection's
+    locks: Locks,
+    busy_retry: Option<LibsqlBusyRetry>,
+    // the owning connection's, released along with this transaction
+    handles: Arc<Mutex<OpenHandles>>,
 }
 
 impl LibsqlTransaction {
-    pub fn new(transaction: InnerTransaction) -> Self {
+    pub(crate) fn new(
+        transaction: InnerTransaction,
+        connection: RustAutoOpaqueNom<Option<InnerConnection>>,
+        decoding: DecodeOptions,
+        interrupts: Arc<Interrupts>,
+        locks: Locks,
+        handles: Arc<Mutex<OpenHandles>>,
+    ) -> Self {
         Self {
-            transaction: RustAutoOpaqueNom::new(HashMap::from([(0, transaction)])),
+            transaction: RustAutoOpaqueNom::new(TransactionState::Active(transaction)),
+            connection,
+            decoding,
+            statements: Mutex::new(Vec::new()),
+            savepoints: SavepointStack::default(),
+            writer: None,
+            interrupts,
+            locks,
+            busy_retry: None,
+            handles,
+        }
+    }
+
+    /// Retries `execute`, here and on the statements prepared on this
+    /// transaction, while it is busy as `busy_retry` allows.
+    pub(crate) fn with_busy_retry(mut self, busy_retry: Option<LibsqlBusyRetry>) -> Self {
+        self.busy_retry = busy_retry;
+        self
+    }
+
+    pub(crate) fn with_writer(mut self, writer: OwnedMutexGuard<()>) -> Self {
+        self.writer = Some(writer);
+        self
+    }
+
+    pub(crate) fn handle(&self) -> RustAutoOpaqueNom<TransactionState> {
+        self.transaction.clone()
+    }
+
+    pub async fn is_active(&self) -> Result<bool, LibsqlError> {
+        Ok(self.locks.read(&self.transaction).await?.is_active())
+    }
+
+    /// Whether the underlying connection is outside of any transaction, which
+    /// is only the case once this transaction has been committed or rolled
+    /// back and no other one has been started.
+    pub async fn is_autocommit(&self) -> Result<bool, LibsqlError> {
+        if let TransactionState::Active(transaction) = &*self.locks.read(&self.transaction).await? {
+            return Ok(transaction.is_autocommit());
         }
+        Ok(self
+            .locks
+            .read(&self.connection)
+            .await?
+            .as_ref()
+            .ok_or_else(LibsqlError::closed)?
+            .is_autocommit())
+    }
+
+    pub async fn query(
+        &self,
+        sql: String,
+        parameters: Option<LibsqlParams>,
+        timeout_ms: Option<u64>,
+        cancel: Option<LibsqlCancelToken>,
+    ) -> Result<QueryResult, LibsqlError> {
+        self.interruptible(timeout_ms, cancel, async {
+            let guard = self.locks.read(&self.transaction).await?;
+            query_connection(guard.active()?, &sql, parameters, self.decoding).await
+        })
+        .await
+    }
+
+    pub async fn query_columnar(
+        &self,
+        sql: String,
+        parameters: Option<LibsqlParams>,
+        timeout_ms: Option<u64>,
+        cancel: Option<LibsqlCancelToken>,
+    ) -> Result<ColumnarQueryResult, LibsqlError> {
+        self.interruptible(timeout_ms, cancel, async {
+            let guard = self.locks.read(&self.transaction).await?;
+            query_connection_columnar(guard.active()?, &sql, parameters, self.decoding).await
+        })
+        .await
     }
 
-    pub async fn query(&self, sql: String, parameters: Option<LibsqlParams>) -> QueryResult {
-        let params: libsql::params::Params = parameters
-            .unwrap_or(LibsqlParams {
-                positional: None,
-                named: None,
+    pub async fn execute(
+        &self,
+        sql: String,
+        parameters: Option<LibsqlParams>,
+        timeout_ms: Option<u64>,
+        cancel: Option<LibsqlCancelToken>,
+    ) -> Result<ExecuteResult, LibsqlError> {
+        self.interruptible(timeout_ms, cancel, async {
+            retry_busy(self.busy_retry, || async {
+                let guard = self.locks.read(&self.transaction).await?;
+                execute_connection(guard.active()?, &sql, parameters.clone()).await
             })
-            .into();
-        let result = self
-            .transaction
-            .try_read()
-            .unwrap()
-            .get(&0)
+            .await
+        })
+        .await
+    }
+
+    /// Prepares a statement that runs inside this transaction. It can be used
+    /// until the transaction is committed or rolled back.
+    pub async fn prepare(&self, sql: String) -> Result<LibsqlStatement, LibsqlError> {
+        let statement = self
+            .locks
+            .read(&self.transaction)
+            .await?
+            .active()?
+            .prepare(&sql)
+            .await?;
+        let statement = LibsqlStatement::new(
+            statement,
+            sql,
+            self.decoding,
+            self.connection.clone(),
+            self.interrupts.clone(),
+            self.locks.clone(),
+            self.handles.clone(),
+        )
+        .with_transaction(self.transaction.clone())
+        .with_busy_retry(self.busy_retry);
+        self.statements.lock().unwrap().push(statement.handle());
+        self.handles
+            .lock()
             .unwrap()
-            .query(&sql, params)
+            .add_statement(statement.handle());
+        Ok(statement)
+    }
+
+    /// Starts a savepoint, which can be released or rolled back on its own
+    /// without ending this transaction. Fails with a `Misuse` error while a
+    /// savepoint of the same name is open.
+    pub async fn savepoint(&self, name: String) -> Result<LibsqlSavepoint, LibsqlError> {
+        LibsqlSavepoint::begin(
+            self.transaction.clone(),
+            self.savepoints.clone(),
+            name,
+            self.decoding,
+            self.interrupts.clone(),
+            self.locks.clone(),
+        )
+        .await
+    }
+
+    /// Fails with a `Misuse` error, leaving the transaction active, while
+    /// one of its savepoints is open.
+    pub async fn commit(&mut self) -> Result<(), LibsqlError> {
+        // waits for the calls in flight before finalizing their statements
+        let mut state = self.locks.write(&self.transaction).await?;
+        state.active()?;
+        self.savepoints.ensure_settled()?;
+        finalize_statements(&self.locks, &self.statements).await?;
+        // lets the next writer in once this returns, the transaction is
+        // settled either way from here on
+        let _writer = self.writer.take();
+        let transaction = state.finish(TransactionState::Committed)?;
+        if let Err(error) = transaction.commit().await {
+            // a failed commit drops the transaction, which rolls it back
+            *state = TransactionState::RolledBack;
+            return Err(error.into());
+        }
+        Ok(())
+    }
+
+    pub async fn rollback(&mut self) -> Result<(), LibsqlError> {
+        let mut state = self.locks.write(&self.transaction).await?;
+        state.active()?;
+        finalize_statements(&self.locks, &self.statements).await?;
+        let _writer = self.writer.take();
+        let transaction = state.finish(TransactionState::RolledBack)?;
+        self.savepoints.clear();
+        transaction.rollback().await?;
+        Ok(())
+    }
+
+    /// Runs `future`, failing with `Interrupted` once the owning connection
+    /// is interrupted, `timeout_ms` elapses or `cancel` is cancelled.
+    async fn interruptible<T>(
+        &self,
+        timeout_ms: Option<u64>,
+        cancel: Option<LibsqlCancelToken>,
+        future: impl Future<Output = Result<T, LibsqlError>>,
+    ) -> Result<T, LibsqlError> {
+        let timeout = timeout_ms.map(Duration::from_millis);
+        self.interrupts.run(timeout, cancel, future).await
+    }
+}
+
+/// Finalizes `statements` once the calls using them are done. Statements
+/// still in use when the lock timeout runs out are kept.
+async fn finalize_statements(
+    locks: &Locks,
+    statements: &Mutex<Vec<RustAutoOpaqueNom<Option<InnerStatement>>>>,
+) -> Result<(), LibsqlError> {
+    let mut pending = std::mem::take(&mut *statements.lock().unwrap());
+    while let Some(statement) = pending.last().cloned() {
+        match locks.write(&statement).await {
+            Ok(mut statement) => {
+                if let Some(mut statement) = statement.take() {
+                    statement.finalize();
+                }
+            }
+            Err(error) => {
+                statements.lock().unwrap().append(&mut pending);
+                return Err(error);
+            }
+        }
+        pending.pop();
+    }
+    Ok(())
+}
+
+impl Drop for LibsqlTransaction {
+    fn drop(&mut self) {
+        if matches!(
+            self.transaction.try_read().map(|state| state.is_active()),
+            Ok(false)
+        ) {
+            return;
+        }
+        let statements = Mutex::new(std::mem::take(&mut *self.statements.lock().unwrap()));
+        let transaction = self.transaction.clone();
+        let locks = self.locks.clone();
+        // held until the rollback is done, so a pool's next writer does not
+        // start inside this transaction
+        let writer = self.writer.take();
+        // calls still holding the locks are waited for in the background
+        flutter_rust_bridge::spawn(async move {
+            let mut state = transaction.write().await;
+            // a statement still busy past the lock timeout is finalized
+            // when it is dropped
+            let _ = finalize_statements(&locks, &statements).await;
+            // dropping an active libsql transaction rolls it back
+            if state.is_active() {
+                *state = TransactionState::RolledBack;
+            }
+            drop(writer);
+        });
+    }
+}
+
+#[derive(Clone, Copy)]
+pub enum LibsqlTransactionBehavior {
+    Deferred,
+    Immediate,
+    Exclusive,
+    ReadOnly,
+}
+
+impl From<LibsqlTransactionBehavior> for libsql::TransactionBehavior {
+    fn from(behavior: LibsqlTransactionBehavior) -> Self {
+        match behavior {
+            LibsqlTransactionBehavior::Deferred => libsql::TransactionBehavior::Deferred,
+            LibsqlTransactionBehavior::Exclusive => libsql::TransactionBehavior::Exclusive,
+            LibsqlTransactionBehavior::Immediate => libsql::TransactionBehavior::Immediate,
+            LibsqlTransactionBehavior::ReadOnly => libsql::TransactionBehavior::ReadOnly,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::LibsqlTransaction;
+    use crate::{
+        api::{
+            connection::{tests::local, LibsqlConnection},
+            libsql::connect,
+        },
+        utils::{error::LibsqlErrorKind, result::QueryResult},
+    };
+
+    async fn open() -> (LibsqlConnection, LibsqlTransaction) {
+        let connection = connect(local(":memory:")).await.unwrap();
+        connection
+            .execute("CREATE TABLE t (x)".to_string(), None, None, None)
+            .await
+            .unwrap();
+        let transaction = connection.transaction(None).await.unwrap();
+        transaction
+            .execute("INSERT INTO t VALUES (1)".to_string(), None, None, None)
             .await
             .unwrap();
-        rows_to_query_result(result).await
+        (connection, transaction)
     }
 
-    pub async fn execute(&self, sql: String, parameters: Option<LibsqlParams>) -> ExecuteResult {
-        let params: libsql::params::Params = parameters
-            .unwrap_or(LibsqlParams {
-                positional: None,
-                named: None,
-            })
-            .into();
-        let rows_affected = self
-            .transaction
-            .try_read()
-            .unwrap()
-            .get(&0)
-            .unwrap()
-            .execute(&sql, params)
+    async fn count(connection: &LibsqlConnection) -> usize {
+        let result: QueryResult = connection
+            .query("SELECT x FROM t".to_string(), None, None, None)
             .await
             .unwrap();
-        ExecuteResult { rows_affected }
+        result.rows.len()
     }
 
-    pub async fn commit(&mut self) {
-        self.transaction
-            .try_write()
-            .unwrap()
-            .remove(&0)
-            .unwrap()
-            .commit()
+    fn is_finished(kind: LibsqlErrorKind) -> bool {
+        matches!(kind, LibsqlErrorKind::TransactionFinished)
+    }
+
+    #[tokio::test]
+    async fn committed_transactions_cannot_be_used() {
+        let (connection, mut transaction) = open().await;
+        assert!(transaction.is_active().await.unwrap());
+        assert!(!transaction.is_autocommit().await.unwrap());
+        transaction.commit().await.unwrap();
+        assert!(!transaction.is_active().await.unwrap());
+        assert!(transaction.is_autocommit().await.unwrap());
+        assert_eq!(count(&connection).await, 1);
+        let error = transaction
+            .query("SELECT x FROM t".to_string(), None, None, None)
             .await
+            .err()
             .unwrap();
+        assert!(is_finished(error.kind));
+        assert!(is_finished(transaction.commit().await.unwrap_err().kind));
+        assert!(is_finished(transaction.rollback().await.unwrap_err().kind));
     }
 
-    pub async fn rollback(&mut self) {
-        self.transaction
-            .try_write()
-            .unwrap()
-            .remove(&0)
-            .unwrap()
-            .rollback()
+    #[tokio::test]
+    async fn rolled_back_transactions_cannot_be_used() {
+        let (connection, mut transaction) = open().await;
+        transaction.rollback().await.unwrap();
+        assert!(!transaction.is_active().await.unwrap());
+        assert!(transaction.is_autocommit().await.unwrap());
+        assert_eq!(count(&connection).await, 0);
+        let error = transaction
+            .execute("INSERT INTO t VALUES (2)".to_string(), None, None, None)
             .await
+            .err()
             .unwrap();
+        assert!(is_finished(error.kind));
+        assert!(is_finished(transaction.commit().await.unwrap_err().kind));
     }
-}
 
-pub enum LibsqlTransactionBehavior {
-    Deferred,
-    Immediate,
-    Exclusive,
-    ReadOnly,
+    #[tokio::test]
+    async fn dropped_transactions_are_rolled_back() {
+        let (connection, transaction) = open().await;
+        drop(transaction);
+        // rolled back in the background
+        for _ in 0..100 {
+            if count(&connection).await == 0 {
+                break;
+            }
+            tokio::time::sleep(Duration::from_millis(1)).await;
+        }
+        assert_eq!(count(&connection).await, 0);
+        connection.transaction(None).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn prepared_statements_run_inside_the_transaction() {
+        let (connection, mut transaction) = open().await;
+        let statement = transaction
+            .prepare("INSERT INTO t VALUES (2)".to_string())
+            .await
+            .unwrap();
+        statement.execute(None, None, None).await.unwrap();
+        statement.reset().await.unwrap();
+        statement.execute(None, None, None).await.unwrap();
+        assert_eq!(count(&connection).await, 3);
+        transaction.rollback().await.unwrap();
+        assert_eq!(count(&connection).await, 0);
+        let error = statement.execute(None, None, None).await.err().unwrap();
+        assert!(is_finished(error.kind));
+        let error = transaction
+            .prepare("SELECT x FROM t".to_string())
+            .await
+            .err()
+            .unwrap();
+        assert!(is_finished(error.kind));
+    }
 }
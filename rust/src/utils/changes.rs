@@ -0,0 +1,193 @@
+use std::{collections::HashMap, sync::Mutex};
+
+use crate::frb_generated::StreamSink;
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum LibsqlUpdateOperation {
+    Insert,
+    Update,
+    Delete,
+}
+
+#[derive(Clone)]
+pub struct LibsqlUpdate {
+    pub operation: LibsqlUpdateOperation,
+    /// `main`, `temp` or the name of an attached database
+    pub database: String,
+    pub table: String,
+    pub rowid: i64,
+}
+
+/// Collects row changes reported by SQLite's hooks and hands them to the
+/// subscribed streams once their transaction commits.
+///
+/// Changes undone before the commit are dropped: those of a rolled back
+/// transaction, of a `ROLLBACK TO` savepoint, and of a statement that
+/// failed and was undone on its own. A failed statement is told apart by
+/// the rows it reports changed, which leaves out the ones written by
+/// triggers. When triggers ran, the changes of the failed statement are
+/// still sent, since a statement writing only through `INSTEAD OF`
+/// triggers looks the same.
+///
+/// SQLite calls the commit hook before the commit is written, so the
+/// changes are only sent once the statement committing them is done and
+/// the connection is back in autocommit mode. A `COMMIT` failing with
+/// `Busy` leaves the transaction open, and its changes pending.
+#[derive(Default)]
+pub struct ChangeTracker {
+    pending: Mutex<Pending>,
+    listeners: Mutex<Vec<Listener>>,
+}
+
+#[derive(Default)]
+struct Pending {
+    updates: Vec<LibsqlUpdate>,
+    // changes of the transaction being committed, until the statement
+    // committing it is done
+    committing: Vec<LibsqlUpdate>,
+    // open savepoints, innermost last, with the number of updates before them
+    savepoints: Vec<(String, usize)>,
+    // statements running, by their SQLite handle
+    statements: HashMap<usize, StatementStart>,
+}
+
+struct StatementStart {
+    updates: usize,
+    total_changes: i64,
+    triggered: bool,
+}
+
+/// Called with the changes of each committed transaction, returns `false`
+/// once it no longer wants them.
+pub type Listener = Box<dyn FnMut(&[LibsqlUpdate]) -> bool + Send>;
+
+impl ChangeTracker {
+    pub(crate) fn subscribe(&self, sink: StreamSink<Vec<LibsqlUpdate>>) {
+        self.listen(Box::new(move |updates| sink.add(updates.to_vec()).is_ok()));
+    }
+
+    pub(crate) fn listen(&self, listener: Listener) {
+        self.listeners.lock().unwrap().push(listener);
+    }
+
+    pub(crate) fn has_listeners(&self) -> bool {
+        !self.listeners.lock().unwrap().is_empty()
+    }
+
+    pub(crate) fn update(&self, update: LibsqlUpdate) {
+        self.pending.lock().unwrap().updates.push(update);
+    }
+
+    /// Called as the statement `statement` starts, with the changes counted
+    /// on its connection so far.
+    pub(crate) fn statement_started(&self, statement: usize, total_changes: i64) {
+        let mut pending = self.pending.lock().unwrap();
+        let start = StatementStart {
+            updates: pending.updates.len(),
+            total_changes,
+            triggered: false,
+        };
+        pending.statements.insert(statement, start);
+    }
+
+    pub(crate) fn trigger_started(&self, statement: usize) {
+        if let Some(start) = self.pending.lock().unwrap().statements.get_mut(&statement) {
+            start.triggered = true;
+        }
+    }
+
+    /// Called once `statement` is done, with whether the connection is in
+    /// autocommit mode. Sends the changes the statement committed, and drops
+    /// its changes if it changed nothing in the end, which means it failed
+    /// and was undone.
+    pub(crate) fn statement_finished(
+        &self,
+        statement: usize,
+        total_changes: i64,
+        autocommit: bool,
+    ) {
+        let committed = {
+            let mut pending = self.pending.lock().unwrap();
+            let mut committed = std::mem::take(&mut pending.committing);
+            if !autocommit {
+                // the commit failed and the transaction is still open
+                committed.append(&mut pending.updates);
+                pending.updates = std::mem::take(&mut committed);
+            }
+            if let Some(start) = pending.statements.remove(&statement) {
+                if total_changes == start.total_changes && !start.triggered {
+                    pending.updates.truncate(start.updates);
+                }
+            }
+            committed
+        };
+        if committed.is_empty() {
+            return;
+        }
+        // streams cancelled on the Dart side fail to send and are dropped
+        self.listeners
+            .lock()
+            .unwrap()
+            .retain_mut(|listener| listener(&committed));
+    }
+
+    pub(crate) fn savepoint(&self, name: String) {
+        let mut pending = self.pending.lock().unwrap();
+        let updates = pending.updates.len();
+        pending.savepoints.push((name, updates));
+    }
+
+    /// Forgets the savepoint `name` and the ones opened after it, keeping
+    /// their changes.
+    pub(crate) fn release(&self, name: &str) {
+        let mut pending = self.pending.lock().unwrap();
+        if let Some(idx) = pending
+            .savepoints
+            .iter()
+            .rposition(|(open, _)| open == name)
+        {
+            pending.savepoints.truncate(idx);
+        }
+    }
+
+    /// Drops the changes made since the savepoint `name`, which stays open.
+    pub(crate) fn rollback_to(&self, name: &str) {
+        let mut pending = self.pending.lock().unwrap();
+        if let Some(idx) = pending
+            .savepoints
+            .iter()
+            .rposition(|(open, _)| open == name)
+        {
+            let updates = pending.savepoints[idx].1;
+            pending.savepoints.truncate(idx + 1);
+            pending.updates.truncate(updates);
+        }
+    }
+
+    /// Called as a transaction starts committing.
+    pub(crate) fn commit(&self) {
+        let mut pending = self.pending.lock().unwrap();
+        pending.savepoints.clear();
+        let mut updates = std::mem::take(&mut pending.updates);
+        pending.committing.append(&mut updates);
+    }
+
+    pub(crate) fn rollback(&self) {
+        let mut pending = self.pending.lock().unwrap();
+        pending.updates.clear();
+        pending.committing.clear();
+        pending.savepoints.clear();
+    }
+
+    /// Forgets the changes seen so far, once SQLite's hooks no longer
+    /// report to this tracker.
+    pub(crate) fn unhooked(&self) {
+        *self.pending.lock().unwrap() = Pending::default();
+    }
+
+    /// Drops every listener, which ends their streams on the Dart side.
+    pub(crate) fn close(&self) {
+        self.listeners.lock().unwrap().clear();
+        self.unhooked();
+    }
+}
@@ -0,0 +1,170 @@
+use std::fmt;
+
+#[derive(Clone, Copy, Debug)]
+pub enum LibsqlErrorKind {
+    Sql,
+    Constraint,
+    Busy,
+    Io,
+    Network,
+    Auth,
+    Sync,
+    Misuse,
+    Closed,
+    TransactionFinished,
+    Interrupted,
+    /// The remote rejected pushed changes, see `LibsqlError::conflict`
+    SyncConflict,
+}
+
+#[derive(Clone, Debug)]
+pub struct LibsqlError {
+    pub kind: LibsqlErrorKind,
+    /// SQLite extended result code, when the failure came from SQLite
+    pub code: Option<i32>,
+    pub message: String,
+    /// Offset of the offending token in the SQL text, if known
+    pub offset: Option<i32>,
+    /// Set on `SyncConflict` errors
+    pub conflict: Option<LibsqlSyncConflict>,
+}
+
+/// Local frames of a synced database the remote refused because it moved
+/// on since they were written.
+#[derive(Clone, Debug)]
+pub struct LibsqlSyncConflict {
+    /// First frame of the rejected push, every later local frame is
+    /// rejected as well
+    pub first_rejected_frame: u64,
+    /// Last frame written locally
+    pub last_local_frame: u64,
+    /// Last frame the remote has committed
+    pub remote_frame_no: u64,
+}
+
+impl LibsqlError {
+    pub fn new(kind: LibsqlErrorKind, message: impl Into<String>) -> Self {
+        Self {
+            kind,
+            code: None,
+            message: message.into(),
+            offset: None,
+            conflict: None,
+        }
+    }
+
+    pub fn misuse(message: impl Into<String>) -> Self {
+        Self::new(LibsqlErrorKind::Misuse, message)
+    }
+
+    pub fn closed() -> Self {
+        Self::new(LibsqlErrorKind::Closed, "connection closed")
+    }
+
+    pub fn transaction_finished(state: &str) -> Self {
+        Self::new(
+            LibsqlErrorKind::TransactionFinished,
+            format!("transaction has already been {state}"),
+        )
+    }
+
+    pub fn interrupted() -> Self {
+        Self::new(LibsqlErrorKind::Interrupted, "operation was interrupted")
+    }
+
+    /// Turns the error of a rejected push into a `SyncConflict` error.
+    pub(crate) fn with_conflict(self, conflict: LibsqlSyncConflict) -> Self {
+        Self {
+            kind: LibsqlErrorKind::SyncConflict,
+            conflict: Some(conflict),
+            ..self
+        }
+    }
+
+    pub(crate) fn from_sqlite_code(code: i32, message: String) -> Self {
+        // the primary result code lives in the low byte of an extended code
+        let kind = match code & 0xff {
+            5 | 6 => LibsqlErrorKind::Busy,
+            9 => LibsqlErrorKind::Interrupted,
+            10 | 13 | 14 | 26 => LibsqlErrorKind::Io,
+            19 => LibsqlErrorKind::Constraint,
+            21 => LibsqlErrorKind::Misuse,
+            23 => LibsqlErrorKind::Auth,
+            _ => LibsqlErrorKind::Sql,
+        };
+        Self {
+            kind,
+            code: Some(code),
+            message,
+            offset: None,
+            conflict: None,
+        }
+    }
+}
+
+impl From<libsql::Error> for LibsqlError {
+    fn from(error: libsql::Error) -> Self {
+        let message = error.to_string();
+        match error {
+            libsql::Error::SqliteFailure(code, _) => Self::from_sqlite_code(code, message),
+            libsql::Error::RemoteSqliteFailure(_, code, _) => Self::from_sqlite_code(code, message),
+            libsql::Error::Sqlite3SyntaxError(_, offset, _) => Self {
+                offset: Some(offset as i32),
+                ..Self::new(LibsqlErrorKind::Sql, message)
+            },
+            libsql::Error::ConnectionFailed(_)
+            | libsql::Error::Hrana(_)
+            | libsql::Error::WriteDelegation(_)
+            | libsql::Error::InvalidTlsConfiguration(_) => {
+                let lower = message.to_lowercase();
+                if lower.contains("401") || lower.contains("unauthorized") {
+                    Self::new(LibsqlErrorKind::Auth, message)
+                } else {
+                    Self::new(LibsqlErrorKind::Network, message)
+                }
+            }
+            libsql::Error::Sync(_)
+            | libsql::Error::Replication(_)
+            | libsql::Error::SyncNotSupported(_)
+            | libsql::Error::WalConflict => Self::new(LibsqlErrorKind::Sync, message),
+            libsql::Error::InvalidUTF8Path => Self::new(LibsqlErrorKind::Io, message),
+            libsql::Error::Misuse(_)
+            | libsql::Error::LoadExtensionNotSupported
+            | libsql::Error::AuthorizerNotSupported
+            | libsql::Error::FreezeNotSupported(_)
+            | libsql::Error::InvalidParserState(_) => Self::new(LibsqlErrorKind::Misuse, message),
+            _ => Self::new(LibsqlErrorKind::Sql, message),
+        }
+    }
+}
+
+impl fmt::Display for LibsqlError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.code {
+            Some(code) => write!(f, "{} error ({}): {}", self.kind, code, self.message),
+            None => write!(f, "{} error: {}", self.kind, self.message),
+        }
+    }
+}
+
+impl fmt::Display for LibsqlErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            LibsqlErrorKind::Sql => "sql",
+            LibsqlErrorKind::Constraint => "constraint",
+            LibsqlErrorKind::Busy => "busy",
+            LibsqlErrorKind::Io => "io",
+            LibsqlErrorKind::Network => "network",
+            LibsqlErrorKind::Auth => "auth",
+            LibsqlErrorKind::Sync => "sync",
+            LibsqlErrorKind::Misuse => "misuse",
+            LibsqlErrorKind::Closed => "closed",
+            LibsqlErrorKind::TransactionFinished => "transaction finished",
+            LibsqlErrorKind::Interrupted => "interrupted",
+            LibsqlErrorKind::SyncConflict => "sync conflict",
+        };
+        f.write_str(name)
+    }
+}
+
+impl std::error::Error for LibsqlError {}
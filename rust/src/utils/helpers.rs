@@ -1,28 +1,139 @@
 use std::collections::HashMap;
 
-use libsql::Rows;
+use libsql::{Connection, Row, Rows};
 
-use super::{result::QueryResult, return_value::LibsqlReturnValue};
+use super::{
+    error::LibsqlError,
+    params::{bind_params, LibsqlParams},
+    result::{ColumnarQueryResult, ExecuteResult, QueryResult},
+    return_value::{DecodeOptions, LibsqlReturnValue, ValueDecoder},
+};
 
-pub async fn rows_to_query_result(mut result: Rows) -> QueryResult {
-    let mut rows: Vec<HashMap<String, LibsqlReturnValue>> = Vec::new();
-    while let Ok(Some(result_row)) = result.next().await {
-        let mut row = HashMap::new();
-        for idx in 0..result_row.column_count() as i32 {
-            let column_name = result_row.column_name(idx).unwrap();
-            let value = result_row.get_value(idx).unwrap().into();
-            row.insert(column_name.to_string(), value);
-        }
-        rows.push(row);
+pub fn row_to_map(
+    result_row: &Row,
+    decoder: &ValueDecoder,
+) -> Result<HashMap<String, LibsqlReturnValue>, LibsqlError> {
+    let mut row = HashMap::new();
+    for idx in 0..result_row.column_count() {
+        let column_name = result_row.column_name(idx).unwrap_or_default();
+        let value = decoder.decode(idx as usize, result_row.get_value(idx)?);
+        row.insert(column_name.to_string(), value);
     }
+    Ok(row)
+}
+
+pub fn rows_columns(result: &Rows) -> Vec<String> {
     let mut columns: Vec<String> = Vec::new();
-    for idx in 0..result.column_count() as i32 {
-        columns.push(result.column_name(idx).unwrap().to_string());
+    for idx in 0..result.column_count() {
+        columns.push(result.column_name(idx).unwrap_or_default().to_string());
+    }
+    columns
+}
+
+/// Drains `result` and reads the change counters off `connection`.
+///
+/// `total_changes` must be sampled before the query ran: `changes()` keeps
+/// reporting the last INSERT/UPDATE/DELETE, so a plain SELECT would otherwise
+/// inherit the row count of whatever statement came before it.
+pub async fn rows_to_query_result(
+    mut result: Rows,
+    decoder: &ValueDecoder,
+    connection: &Connection,
+    total_changes: u64,
+) -> Result<QueryResult, LibsqlError> {
+    let mut rows: Vec<HashMap<String, LibsqlReturnValue>> = Vec::new();
+    while let Some(result_row) = result.next().await? {
+        rows.push(row_to_map(&result_row, decoder)?);
     }
-    QueryResult {
+    Ok(QueryResult {
         rows,
+        columns: rows_columns(&result),
+        decl_types: decoder.decl_types(),
+        rows_affected: rows_affected_since(connection, total_changes),
+        last_insert_rowid: connection.last_insert_rowid(),
+        total_changes: connection.total_changes(),
+    })
+}
+
+pub async fn rows_to_columnar_result(
+    mut result: Rows,
+    decoder: &ValueDecoder,
+    connection: &Connection,
+    total_changes: u64,
+) -> Result<ColumnarQueryResult, LibsqlError> {
+    let columns = rows_columns(&result);
+    let mut values: Vec<LibsqlReturnValue> = Vec::new();
+    let mut row_count = 0;
+    while let Some(result_row) = result.next().await? {
+        for idx in 0..columns.len() {
+            values.push(decoder.decode(idx, result_row.get_value(idx as i32)?));
+        }
+        row_count += 1;
+    }
+    Ok(ColumnarQueryResult {
         columns,
-        rows_affected: 0,
-        last_insert_rowid: 0,
+        decl_types: decoder.decl_types(),
+        values,
+        row_count,
+        rows_affected: rows_affected_since(connection, total_changes),
+        last_insert_rowid: connection.last_insert_rowid(),
+        total_changes: connection.total_changes(),
+    })
+}
+
+/// Prepares and runs `sql` on `connection`, which may be a transaction.
+pub async fn query_connection(
+    connection: &Connection,
+    sql: &str,
+    parameters: Option<LibsqlParams>,
+    decoding: DecodeOptions,
+) -> Result<QueryResult, LibsqlError> {
+    let statement = connection.prepare(sql).await?;
+    let params = bind_params(parameters, &statement)?;
+    let decoder = ValueDecoder::from_statement(&statement, decoding);
+    let total_changes = connection.total_changes();
+    let result = statement.query(params).await?;
+    rows_to_query_result(result, &decoder, connection, total_changes).await
+}
+
+pub async fn query_connection_columnar(
+    connection: &Connection,
+    sql: &str,
+    parameters: Option<LibsqlParams>,
+    decoding: DecodeOptions,
+) -> Result<ColumnarQueryResult, LibsqlError> {
+    let statement = connection.prepare(sql).await?;
+    let params = bind_params(parameters, &statement)?;
+    let decoder = ValueDecoder::from_statement(&statement, decoding);
+    let total_changes = connection.total_changes();
+    let result = statement.query(params).await?;
+    rows_to_columnar_result(result, &decoder, connection, total_changes).await
+}
+
+/// Prepares and executes `sql` on `connection`, which may be a transaction.
+pub async fn execute_connection(
+    connection: &Connection,
+    sql: &str,
+    parameters: Option<LibsqlParams>,
+) -> Result<ExecuteResult, LibsqlError> {
+    let statement = connection.prepare(sql).await?;
+    let params = bind_params(parameters, &statement)?;
+    let rows_affected = statement.execute(params).await? as u64;
+    Ok(execute_result(connection, rows_affected))
+}
+
+fn rows_affected_since(connection: &Connection, total_changes: u64) -> u64 {
+    if connection.total_changes() != total_changes {
+        connection.changes()
+    } else {
+        0
+    }
+}
+
+pub fn execute_result(connection: &Connection, rows_affected: u64) -> ExecuteResult {
+    ExecuteResult {
+        rows_affected,
+        last_insert_rowid: connection.last_insert_rowid(),
+        total_changes: connection.total_changes(),
     }
 }
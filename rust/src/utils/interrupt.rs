@@ -0,0 +1,243 @@
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    future::Future,
+    sync::{
+        atomic::{AtomicBool, AtomicU64, Ordering},
+        Arc, Mutex, Weak,
+    },
+    time::{Duration, Instant},
+};
+
+use tokio::sync::Notify;
+
+use super::{
+    error::LibsqlError,
+    raw::{with_limits, CallLimits, RawConnection},
+};
+use crate::api::libsql::LibsqlCancelToken;
+
+static NEXT_TOKEN: AtomicU64 = AtomicU64::new(1);
+
+static TOKENS: Mutex<Tokens> = Mutex::new(Tokens {
+    running: BTreeMap::new(),
+    cancelled: BTreeSet::new(),
+});
+
+struct Tokens {
+    // shared by the calls running with a token, by token id
+    running: BTreeMap<u64, Weak<Cancellation>>,
+    // kept so calls made after `cancel` fail right away
+    cancelled: BTreeSet<u64>,
+}
+
+/// The id of a new cancel token.
+pub fn new_token() -> u64 {
+    NEXT_TOKEN.fetch_add(1, Ordering::Relaxed)
+}
+
+/// Stops the calls running with the token `id`, and fails the ones made with
+/// it later.
+pub fn cancel(id: u64) {
+    let mut tokens = TOKENS.lock().unwrap();
+    tokens.cancelled.insert(id);
+    if let Some(cancellation) = tokens.running.get(&id).and_then(Weak::upgrade) {
+        cancellation.cancel();
+    }
+}
+
+/// Joins the calls running with the token `id`.
+fn join(id: u64) -> Arc<Cancellation> {
+    let mut tokens = TOKENS.lock().unwrap();
+    tokens.running.retain(|_, calls| calls.strong_count() > 0);
+    if let Some(cancellation) = tokens.running.get(&id).and_then(Weak::upgrade) {
+        return cancellation;
+    }
+    let cancellation = Arc::new(Cancellation::default());
+    if tokens.cancelled.contains(&id) {
+        cancellation.cancel();
+    }
+    tokens.running.insert(id, Arc::downgrade(&cancellation));
+    cancellation
+}
+
+/// Whether the calls running with a token were cancelled.
+#[derive(Default)]
+pub struct Cancellation {
+    cancelled: AtomicBool,
+    notify: Notify,
+}
+
+impl Cancellation {
+    fn cancel(&self) {
+        self.cancelled.store(true, Ordering::Relaxed);
+        self.notify.notify_waiters();
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::Relaxed)
+    }
+
+    async fn cancelled(&self) {
+        loop {
+            let notified = self.notify.notified();
+            let mut notified = std::pin::pin!(notified);
+            // registered before checking, so a `cancel` in between is seen
+            notified.as_mut().enable();
+            if self.is_cancelled() {
+                return;
+            }
+            notified.await;
+        }
+    }
+}
+
+/// Stops the calls running on a connection and on the statements, cursors
+/// and transactions made from it, which all share it. Also holds the
+/// connection's SQLite handle, whose progress handler stops local
+/// statements, installed only while a call with a deadline or a cancel
+/// token runs.
+#[derive(Default)]
+pub struct Interrupts {
+    // woken by the connection's `interrupt`
+    notify: Notify,
+    handle: Mutex<Handle>,
+}
+
+#[derive(Default)]
+struct Handle {
+    // unset for remote connections and once the connection is closed
+    raw: Option<RawConnection>,
+    // calls running with a deadline or a cancel token
+    limited: usize,
+}
+
+impl Interrupts {
+    pub fn raw(&self) -> Option<RawConnection> {
+        self.handle.lock().unwrap().raw
+    }
+
+    /// Replaces the SQLite handle, returning the previous one.
+    pub fn set_raw(&self, raw: Option<RawConnection>) -> Option<RawConnection> {
+        let mut handle = self.handle.lock().unwrap();
+        if let (Some(raw), true) = (raw, handle.limited > 0) {
+            raw.check_call_limits(true);
+        }
+        std::mem::replace(&mut handle.raw, raw)
+    }
+
+    /// Fails the calls running now with an `Interrupted` error.
+    pub fn interrupt(&self) {
+        self.notify.notify_waiters();
+    }
+
+    /// Runs `future`, failing with `Interrupted` once the connection is
+    /// interrupted, `timeout` elapses or `cancel` is cancelled. Dropping the
+    /// future aborts remote requests, local statements step without
+    /// yielding, so the ones stepped by `future` are stopped by SQLite.
+    pub async fn run<T>(
+        &self,
+        timeout: Option<Duration>,
+        cancel: Option<LibsqlCancelToken>,
+        future: impl Future<Output = Result<T, LibsqlError>>,
+    ) -> Result<T, LibsqlError> {
+        let cancellation = cancel.map(|token| join(token.id));
+        if timeout.is_none() && cancellation.is_none() {
+            return tokio::select! {
+                result = future => result,
+                _ = self.notify.notified() => Err(LibsqlError::interrupted()),
+            };
+        }
+        if cancellation.as_ref().is_some_and(|c| c.is_cancelled()) {
+            return Err(LibsqlError::interrupted());
+        }
+        let limits = CallLimits {
+            deadline: timeout.map(|timeout| Instant::now() + timeout),
+            cancellation: cancellation.clone(),
+        };
+        let deadline = limits.deadline;
+        let _checked = self.check_limits();
+        tokio::select! {
+            result = with_limits(limits, future) => result,
+            _ = self.notify.notified() => Err(LibsqlError::interrupted()),
+            _ = async {
+                match deadline {
+                    Some(deadline) => tokio::time::sleep_until(deadline.into()).await,
+                    None => std::future::pending().await,
+                }
+            } => Err(LibsqlError::interrupted()),
+            _ = async {
+                match &cancellation {
+                    Some(cancellation) => cancellation.cancelled().await,
+                    None => std::future::pending().await,
+                }
+            } => Err(LibsqlError::interrupted()),
+        }
+    }
+
+    /// Installs the progress handler until the returned guard is dropped.
+    fn check_limits(&self) -> CheckLimits<'_> {
+        let mut handle = self.handle.lock().unwrap();
+        handle.limited += 1;
+        if let (Some(raw), 1) = (handle.raw, handle.limited) {
+            raw.check_call_limits(true);
+        }
+        CheckLimits(self)
+    }
+}
+
+struct CheckLimits<'a>(&'a Interrupts);
+
+impl Drop for CheckLimits<'_> {
+    fn drop(&mut self) {
+        let mut handle = self.0.handle.lock().unwrap();
+        handle.limited -= 1;
+        if let (Some(raw), 0) = (handle.raw, handle.limited) {
+            raw.check_call_limits(false);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::Interrupts;
+    use crate::{api::libsql::LibsqlCancelToken, utils::error::LibsqlErrorKind};
+
+    #[tokio::test]
+    async fn cancel_stops_running_calls() {
+        let interrupts = Interrupts::default();
+        let token = LibsqlCancelToken::create().await;
+        let (result, _) = tokio::join!(
+            interrupts.run(None, Some(token), async {
+                tokio::time::sleep(Duration::from_secs(10)).await;
+                Ok(())
+            }),
+            async {
+                tokio::task::yield_now().await;
+                token.cancel().await;
+            },
+        );
+        assert!(matches!(
+            result.unwrap_err().kind,
+            LibsqlErrorKind::Interrupted
+        ));
+    }
+
+    #[tokio::test]
+    async fn cancelled_tokens_fail_later_calls() {
+        let interrupts = Interrupts::default();
+        let token = LibsqlCancelToken::create().await;
+        token.cancel().await;
+        let result = interrupts.run(None, Some(token), async { Ok(()) }).await;
+        assert!(matches!(
+            result.unwrap_err().kind,
+            LibsqlErrorKind::Interrupted
+        ));
+        let other = LibsqlCancelToken::create().await;
+        assert!(interrupts
+            .run(None, Some(other), async { Ok(()) })
+            .await
+            .is_ok());
+    }
+}
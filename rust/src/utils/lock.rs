@@ -0,0 +1,128 @@
+use std::{
+    future::Future,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc,
+    },
+    time::Duration,
+};
+
+use flutter_rust_bridge::RustAutoOpaqueNom;
+use tokio::sync::{RwLockReadGuard, RwLockWriteGuard};
+
+use super::error::{LibsqlError, LibsqlErrorKind};
+
+/// Takes the locks of a connection's handles, giving up once the
+/// connection's lock timeout runs out. Shared by the connection and the
+/// statements, cursors and transactions made from it.
+#[derive(Clone, Default)]
+pub struct Locks {
+    // in milliseconds, 0 waits for as long as it takes
+    timeout: Arc<AtomicU64>,
+}
+
+impl Locks {
+    pub fn with_timeout(timeout: Option<Duration>) -> Locks {
+        let locks = Locks::default();
+        locks.set_timeout(timeout);
+        locks
+    }
+
+    pub fn set_timeout(&self, timeout: Option<Duration>) {
+        let millis = timeout.map_or(0, |timeout| timeout.as_millis().max(1) as u64);
+        self.timeout.store(millis, Ordering::Relaxed);
+    }
+
+    /// Shared access to `handle`, waiting behind calls that change it.
+    pub async fn read<'a, T>(
+        &self,
+        handle: &'a RustAutoOpaqueNom<T>,
+    ) -> Result<RwLockReadGuard<'a, T>, LibsqlError> {
+        self.wait(handle.read()).await
+    }
+
+    /// Exclusive access to `handle`, waiting for the calls that use it.
+    pub async fn write<'a, T>(
+        &self,
+        handle: &'a RustAutoOpaqueNom<T>,
+    ) -> Result<RwLockWriteGuard<'a, T>, LibsqlError> {
+        self.wait(handle.write()).await
+    }
+
+    // reported as `Interrupted` rather than `Busy`, which is about locks
+    // held by other connections and gets retried
+    async fn wait<G>(&self, lock: impl Future<Output = G>) -> Result<G, LibsqlError> {
+        match self.timeout.load(Ordering::Relaxed) {
+            0 => Ok(lock.await),
+            millis => tokio::time::timeout(Duration::from_millis(millis), lock)
+                .await
+                .map_err(|_| {
+                    LibsqlError::new(
+                        LibsqlErrorKind::Interrupted,
+                        "timed out waiting for another call on the same handle",
+                    )
+                }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use flutter_rust_bridge::RustAutoOpaqueNom;
+
+    use super::Locks;
+    use crate::utils::error::LibsqlErrorKind;
+
+    #[tokio::test]
+    async fn reads_overlap() {
+        let handle = RustAutoOpaqueNom::new(1);
+        let locks = Locks::with_timeout(Some(Duration::from_millis(10)));
+        let first = locks.read(&handle).await.unwrap();
+        let second = locks.read(&handle).await.unwrap();
+        assert_eq!(*first + *second, 2);
+    }
+
+    #[tokio::test]
+    async fn write_waits_for_reads() {
+        let handle = RustAutoOpaqueNom::new(1);
+        let locks = Locks::default();
+        let reading = locks.read(&handle).await.unwrap();
+        tokio::join!(
+            async { *locks.write(&handle).await.unwrap() += 1 },
+            async move {
+                // the writer has been polled and is still waiting
+                tokio::task::yield_now().await;
+                assert_eq!(*reading, 1);
+            }
+        );
+        assert_eq!(*locks.read(&handle).await.unwrap(), 2);
+    }
+
+    #[tokio::test]
+    async fn times_out_behind_a_writer() {
+        let handle = RustAutoOpaqueNom::new(1);
+        let locks = Locks::with_timeout(Some(Duration::from_millis(10)));
+        let writing = locks.write(&handle).await.unwrap();
+        let error = locks.read(&handle).await.err().unwrap();
+        assert!(matches!(error.kind, LibsqlErrorKind::Interrupted));
+        assert!(matches!(
+            locks.write(&handle).await.err().unwrap().kind,
+            LibsqlErrorKind::Interrupted
+        ));
+        drop(writing);
+        assert_eq!(*locks.read(&handle).await.unwrap(), 1);
+    }
+
+    #[tokio::test]
+    async fn timeouts_are_per_connection() {
+        let handle = RustAutoOpaqueNom::new(1);
+        let writing = Locks::default().write(&handle).await.unwrap();
+        let impatient = Locks::with_timeout(Some(Duration::from_millis(10)));
+        assert!(impatient.read(&handle).await.is_err());
+        let patient = Locks::default();
+        let (_, value) = tokio::join!(async move { drop(writing) }, patient.read(&handle));
+        assert_eq!(*value.unwrap(), 1);
+    }
+}
@@ -1,4 +1,13 @@
+pub mod changes;
+pub mod error;
 pub mod helpers;
+pub mod interrupt;
+pub mod lock;
 pub mod params;
+pub mod raw;
 pub mod result;
+pub mod retry;
 pub mod return_value;
+pub mod sql;
+pub mod sync;
+pub mod watch;
@@ -1,58 +1,417 @@
 use std::collections::HashMap;
 
+use chrono::{DateTime, SecondsFormat, Utc};
+
+use super::{
+    error::LibsqlError,
+    sql::{bare_parameter_name, single_statement_parameters},
+};
+
+#[derive(Clone)]
 pub enum LibsqlValue {
     Integer(i64),
     Real(f64),
     Text(String),
     Blob(Vec<u8>),
     Null,
+    /// Stored as the integer 0 or 1
+    Bool(bool),
+    DateTime {
+        value: DateTime<Utc>,
+        format: LibsqlDateTimeFormat,
+    },
+    /// Decimal digits of an integer, stored as an integer. Fails with a
+    /// `Misuse` error when it does not fit in 64 bits, rather than being
+    /// stored as text that no longer compares as a number.
+    BigInt(String),
+    /// JSON text, stored as-is
+    Json(String),
+}
+
+/// How `LibsqlValue::DateTime` is written to the database.
+#[derive(Clone, Copy)]
+pub enum LibsqlDateTimeFormat {
+    /// Integer milliseconds since the unix epoch
+    UnixMillis,
+    /// ISO-8601 text in UTC, e.g. `2024-01-31T12:00:00.000Z`
+    Iso8601,
+}
+
+impl TryFrom<LibsqlValue> for libsql::Value {
+    type Error = LibsqlError;
+
+    fn try_from(value: LibsqlValue) -> Result<Self, Self::Error> {
+        Ok(match value {
+            LibsqlValue::Integer(i) => libsql::Value::Integer(i),
+            LibsqlValue::Real(f) => libsql::Value::Real(f),
+            LibsqlValue::Null => libsql::Value::Null,
+            LibsqlValue::Blob(b) => libsql::Value::Blob(b),
+            LibsqlValue::Text(t) => libsql::Value::Text(t),
+            LibsqlValue::Bool(b) => libsql::Value::Integer(b as i64),
+            LibsqlValue::DateTime {
+                value,
+                format: LibsqlDateTimeFormat::UnixMillis,
+            } => libsql::Value::Integer(value.timestamp_millis()),
+            LibsqlValue::DateTime {
+                value,
+                format: LibsqlDateTimeFormat::Iso8601,
+            } => libsql::Value::Text(value.to_rfc3339_opts(SecondsFormat::Millis, true)),
+            LibsqlValue::BigInt(digits) => {
+                let unsigned = digits.strip_prefix('-').unwrap_or(&digits);
+                if unsigned.is_empty() || !unsigned.bytes().all(|b| b.is_ascii_digit()) {
+                    return Err(LibsqlError::misuse(format!(
+                        "{digits:?} is not a decimal integer"
+                    )));
+                }
+                let i = digits.parse::<i64>().map_err(|_| {
+                    LibsqlError::misuse(format!("{digits} does not fit in a 64-bit integer"))
+                })?;
+                libsql::Value::Integer(i)
+            }
+            LibsqlValue::Json(json) => libsql::Value::Text(json),
+        })
+    }
 }
 
+#[derive(Clone)]
 pub struct LibsqlParams {
     pub positional: Option<Vec<LibsqlValue>>,
     pub named: Option<HashMap<String, LibsqlValue>>,
 }
 
-impl Into<libsql::params::Params> for LibsqlParams {
-    fn into(self) -> libsql::params::Params {
-        let positional_params = self
+pub struct LibsqlBatchStatement {
+    pub sql: String,
+    pub parameters: Option<LibsqlParams>,
+}
+
+impl TryFrom<LibsqlParams> for libsql::params::Params {
+    type Error = LibsqlError;
+
+    fn try_from(params: LibsqlParams) -> Result<Self, Self::Error> {
+        let positional_params = params
             .positional
-            .as_ref()
             .map(|params| {
                 params
-                    .iter()
-                    .map(|s| match &s {
-                        LibsqlValue::Integer(i) => libsql::Value::Integer(*i),
-                        LibsqlValue::Real(f) => libsql::Value::Real(*f),
-                        LibsqlValue::Null => libsql::Value::Null,
-                        LibsqlValue::Blob(b) => libsql::Value::Blob(b.to_vec()),
-                        LibsqlValue::Text(t) => libsql::Value::Text(t.to_string()),
-                    })
-                    .collect::<Vec<_>>()
+                    .into_iter()
+                    .map(TryInto::try_into)
+                    .collect::<Result<Vec<_>, _>>()
             })
+            .transpose()?
             .unwrap_or_else(Vec::new);
 
-        let named_params = self
+        let named_params = params
             .named
-            .as_ref()
             .map(|params| {
                 params
-                    .iter()
-                    .map(|(k, v)| match &v {
-                        LibsqlValue::Integer(i) => (k.clone(), libsql::Value::Integer(*i)),
-                        LibsqlValue::Real(f) => (k.clone(), libsql::Value::Real(*f)),
-                        LibsqlValue::Null => (k.clone(), libsql::Value::Null),
-                        LibsqlValue::Blob(b) => (k.clone(), libsql::Value::Blob(b.to_vec())),
-                        LibsqlValue::Text(t) => (k.clone(), libsql::Value::Text(t.to_string())),
-                    })
-                    .collect::<Vec<_>>()
+                    .into_iter()
+                    .map(|(k, v)| Ok((k, v.try_into()?)))
+                    .collect::<Result<Vec<_>, LibsqlError>>()
             })
+            .transpose()?
             .unwrap_or_else(Vec::new);
 
         match (positional_params.is_empty(), named_params.is_empty()) {
-            (false, true) => libsql::params::Params::Positional(positional_params),
-            (true, false) => libsql::params::Params::Named(named_params),
-            _ => libsql::params::Params::None,
+            (false, true) => Ok(libsql::params::Params::Positional(positional_params)),
+            (true, false) => Ok(libsql::params::Params::Named(named_params)),
+            (true, true) => Ok(libsql::params::Params::None),
+            (false, false) => Err(LibsqlError::misuse(
+                "positional and named parameters cannot be used together",
+            )),
+        }
+    }
+}
+
+impl LibsqlParams {
+    /// Converts the parameters after checking them against the parameters of
+    /// `statement`. Named parameters match regardless of the `:`, `@`, `$` or
+    /// `?` prefix used on either side, and are bound by position.
+    pub(crate) fn bind(
+        self,
+        statement: &libsql::Statement,
+    ) -> Result<libsql::params::Params, LibsqlError> {
+        let count = statement.parameter_count();
+        let params = self.try_into()?;
+        if count == 0 {
+            // remote statements only learn their parameters once bound, so
+            // leave it to the database to complain
+            return Ok(params);
+        }
+        let slots = (1..=count)
+            .map(|idx| match statement.parameter_name(idx as i32) {
+                Some(name) => Slot::Named(name),
+                // skipped over by a later `?NNN`, so never read
+                None if numbered_after(statement, idx, count) => Slot::Unused,
+                None => Slot::Unnamed,
+            })
+            .collect::<Vec<_>>();
+        let values = slot_values(params, &slots)?;
+        Ok(libsql::params::Params::Positional(values))
+    }
+}
+
+/// A parameter of a statement, as numbered by SQLite.
+#[derive(Clone, Copy)]
+enum Slot<'a> {
+    Named(&'a str),
+    Unnamed,
+    /// A number no parameter uses, read as `NULL`
+    Unused,
+}
+
+/// The values of `params` for `slots`, in order. Named values match
+/// regardless of the `:`, `@`, `$` or `?` prefix used on either side.
+fn slot_values(
+    params: libsql::params::Params,
+    slots: &[Slot],
+) -> Result<Vec<libsql::Value>, LibsqlError> {
+    let count = slots.len();
+    match params {
+        libsql::params::Params::None if count == 0 => Ok(Vec::new()),
+        libsql::params::Params::None => Err(LibsqlError::misuse(format!(
+            "statement expects {count} parameters, got none"
+        ))),
+        libsql::params::Params::Positional(values) => {
+            if values.len() != count {
+                return Err(LibsqlError::misuse(format!(
+                    "statement expects {count} parameters, got {}",
+                    values.len()
+                )));
+            }
+            Ok(values)
+        }
+        libsql::params::Params::Named(values) => {
+            let mut by_name = HashMap::new();
+            for (name, value) in values {
+                let bare = bare_parameter_name(&name).to_string();
+                if by_name.insert(bare, value).is_some() {
+                    return Err(LibsqlError::misuse(format!(
+                        "parameter {name} is given more than once"
+                    )));
+                }
+            }
+            let mut bound = Vec::with_capacity(count);
+            for (idx, slot) in slots.iter().enumerate() {
+                bound.push(match slot {
+                    Slot::Named(name) => by_name
+                        .get(bare_parameter_name(name))
+                        .cloned()
+                        .ok_or_else(|| {
+                            LibsqlError::misuse(format!("missing value for parameter {name}"))
+                        })?,
+                    Slot::Unnamed => {
+                        return Err(LibsqlError::misuse(format!(
+                            "parameter {} has no name, use positional parameters",
+                            idx + 1
+                        )))
+                    }
+                    Slot::Unused => libsql::Value::Null,
+                });
+            }
+            let used = |bare: &str| {
+                slots.iter().any(
+                    |slot| matches!(slot, Slot::Named(name) if bare_parameter_name(name) == bare),
+                )
+            };
+            if let Some(name) = by_name.keys().find(|bare| !used(bare)) {
+                return Err(LibsqlError::misuse(format!(
+                    "statement has no parameter named {name}"
+                )));
+            }
+            Ok(bound)
+        }
+    }
+}
+
+/// `sql` with its parameters replaced by SQL literals of their values, for
+/// batches sent where nothing can be bound. Parameters are numbered the way
+/// SQLite numbers them, so the same values bind as with a prepared statement.
+pub(crate) fn inline_params(
+    sql: &str,
+    parameters: Option<LibsqlParams>,
+) -> Result<String, LibsqlError> {
+    let found = single_statement_parameters(sql)
+        .ok_or_else(|| LibsqlError::misuse(format!("expected a single statement, got {sql:?}")))?;
+    let mut indexes = Vec::with_capacity(found.len());
+    let mut named: HashMap<&str, usize> = HashMap::new();
+    let mut count = 0;
+    for parameter in &found {
+        let index = match parameter.name.as_deref() {
+            None => count + 1,
+            Some(name) => match name.strip_prefix('?').map(str::parse::<usize>) {
+                Some(Ok(index)) if index > 0 => index,
+                Some(_) => {
+                    return Err(LibsqlError::misuse(format!("invalid parameter {name}")));
+                }
+                None => *named.entry(name).or_insert(count + 1),
+            },
+        };
+        count = count.max(index);
+        indexes.push(index);
+    }
+    let mut slots = vec![Slot::Unused; count];
+    for (parameter, &index) in found.iter().zip(&indexes) {
+        slots[index - 1] = match (&parameter.name, slots[index - 1]) {
+            (Some(name), _) => Slot::Named(name),
+            (None, Slot::Named(name)) => Slot::Named(name),
+            (None, _) => Slot::Unnamed,
+        };
+    }
+    let params = match parameters {
+        Some(parameters) => parameters.try_into()?,
+        None => libsql::params::Params::None,
+    };
+    let values = slot_values(params, &slots)?;
+    let mut inlined = String::with_capacity(sql.len());
+    let mut copied = 0;
+    for (parameter, index) in found.iter().zip(indexes) {
+        inlined.push_str(&sql[copied..parameter.span.start]);
+        inlined.push_str(&sql_literal(&values[index - 1]));
+        copied = parameter.span.end;
+    }
+    inlined.push_str(&sql[copied..]);
+    Ok(inlined)
+}
+
+/// `value` written as an SQL literal of the same type.
+fn sql_literal(value: &libsql::Value) -> String {
+    match value {
+        libsql::Value::Null => "NULL".to_string(),
+        libsql::Value::Integer(i) => i.to_string(),
+        // SQLite stores NaN as NULL and reads overflowing literals as infinity
+        libsql::Value::Real(f) if f.is_nan() => "NULL".to_string(),
+        libsql::Value::Real(f) if f.is_infinite() => {
+            if *f > 0.0 { "9e999" } else { "-9e999" }.to_string()
+        }
+        // `Debug` keeps the fraction, so the literal is not read as an integer
+        libsql::Value::Real(f) => format!("{f:?}"),
+        libsql::Value::Text(t) => format!("'{}'", t.replace('\'', "''")),
+        libsql::Value::Blob(b) => {
+            let hex: String = b.iter().map(|byte| format!("{byte:02X}")).collect();
+            format!("X'{hex}'")
+        }
+    }
+}
+
+/// Whether a `?NNN` parameter after `idx` numbers past it, leaving `idx`
+/// as an unused gap.
+fn numbered_after(statement: &libsql::Statement, idx: usize, count: usize) -> bool {
+    (idx + 1..=count)
+        .any(|later| statement.parameter_name(later as i32) == Some(format!("?{later}").as_str()))
+}
+
+/// Shorthand for binding optional parameters, where `None` means no parameters.
+pub(crate) fn bind_params(
+    parameters: Option<LibsqlParams>,
+    statement: &libsql::Statement,
+) -> Result<libsql::params::Params, LibsqlError> {
+    parameters
+        .unwrap_or(LibsqlParams {
+            positional: None,
+            named: None,
+        })
+        .bind(statement)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::{inline_params, LibsqlParams, LibsqlValue};
+    use crate::{
+        api::{connection::tests::local, libsql::connect},
+        utils::{
+            error::{LibsqlError, LibsqlErrorKind},
+            return_value::LibsqlReturnValue,
+        },
+    };
+
+    fn positional(values: &[i64]) -> Option<LibsqlParams> {
+        Some(LibsqlParams {
+            positional: Some(values.iter().map(|&i| LibsqlValue::Integer(i)).collect()),
+            named: None,
+        })
+    }
+
+    fn named(values: &[(&str, i64)]) -> Option<LibsqlParams> {
+        let named: HashMap<_, _> = values
+            .iter()
+            .map(|&(name, i)| (name.to_string(), LibsqlValue::Integer(i)))
+            .collect();
+        Some(LibsqlParams {
+            positional: None,
+            named: Some(named),
+        })
+    }
+
+    fn is_misuse<T>(result: Result<T, LibsqlError>) -> bool {
+        matches!(result, Err(error) if matches!(error.kind, LibsqlErrorKind::Misuse))
+    }
+
+    #[test]
+    fn mixed_parameters_are_rejected() {
+        let params = LibsqlParams {
+            positional: Some(vec![LibsqlValue::Integer(1)]),
+            named: Some(HashMap::from([("a".to_string(), LibsqlValue::Integer(2))])),
+        };
+        assert!(is_misuse(libsql::params::Params::try_from(params)));
+    }
+
+    #[test]
+    fn prefixes_match_uniformly() {
+        let sql = "SELECT :a, @b, $c, :a";
+        let params = named(&[("@a", 1), ("b", 2), (":c", 3)]);
+        assert_eq!(inline_params(sql, params).unwrap(), "SELECT 1, 2, 3, 1");
+        let params = named(&[("1", 1), ("?3", 3)]);
+        assert_eq!(
+            inline_params("SELECT ?1, ?3", params).unwrap(),
+            "SELECT 1, 3"
+        );
+    }
+
+    #[test]
+    fn counts_and_names_are_checked() {
+        assert!(is_misuse(inline_params("SELECT ?, ?", positional(&[1]))));
+        assert!(is_misuse(inline_params("SELECT ?", None)));
+        assert!(is_misuse(inline_params("SELECT :a", named(&[("b", 1)]))));
+        assert!(is_misuse(inline_params(
+            "SELECT :a",
+            named(&[("a", 1), ("b", 2)])
+        )));
+        assert!(is_misuse(inline_params("SELECT :a, ?", named(&[("a", 1)]))));
+        assert!(is_misuse(inline_params(
+            "SELECT :a",
+            named(&[("a", 1), (":a", 2)])
+        )));
+    }
+
+    #[tokio::test]
+    async fn prepared_statements_check_their_parameters() {
+        let connection = connect(local(":memory:")).await.unwrap();
+        let result = connection
+            .query(
+                "SELECT :a + @b AS x".to_string(),
+                named(&[("$a", 1), ("b", 2)]),
+                None,
+                None,
+            )
+            .await
+            .unwrap();
+        assert!(result.rows[0]["x"] == LibsqlReturnValue::Integer(3));
+        let query = |sql: &str, params| connection.query(sql.to_string(), params, None, None);
+        assert!(is_misuse(query("SELECT ?, ?", positional(&[1])).await));
+        assert!(is_misuse(query("SELECT :a", named(&[("b", 1)])).await));
+        assert!(is_misuse(query("SELECT :a", None).await));
+    }
+
+    #[test]
+    fn big_ints_must_fit_in_64_bits() {
+        let value = libsql::Value::try_from(LibsqlValue::BigInt(i64::MIN.to_string())).unwrap();
+        assert!(matches!(value, libsql::Value::Integer(i64::MIN)));
+        for digits in ["9223372036854775808", "-9223372036854775809", "1e3", ""] {
+            let error = libsql::Value::try_from(LibsqlValue::BigInt(digits.to_string()))
+                .err()
+                .unwrap();
+            assert!(matches!(error.kind, LibsqlErrorKind::Misuse));
         }
     }
 }
@@ -0,0 +1,646 @@
+use std::{
+    cell::RefCell,
+    cmp::Ordering,
+    ffi::{c_char, c_int, c_uint, c_void, CStr, CString},
+    future::Future,
+    mem::size_of,
+    panic::{catch_unwind, AssertUnwindSafe},
+    ptr,
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+
+use flutter_rust_bridge::{DartFnFuture, DartOpaque};
+use futures::executor::block_on;
+use libsql::ffi;
+use tokio::sync::OnceCell;
+
+use super::{
+    changes::{ChangeTracker, LibsqlUpdate, LibsqlUpdateOperation},
+    error::LibsqlError,
+    interrupt::Cancellation,
+    params::LibsqlValue,
+    return_value::LibsqlReturnValue,
+    sql::{savepoint_command, SavepointCommand},
+};
+
+pub type ScalarFunction =
+    Box<dyn Fn(Vec<LibsqlReturnValue>) -> DartFnFuture<LibsqlValue> + Send + Sync>;
+pub type AggregateInit = Box<dyn Fn() -> DartFnFuture<DartOpaque> + Send + Sync>;
+pub type AggregateStep =
+    Box<dyn Fn(DartOpaque, Vec<LibsqlReturnValue>) -> DartFnFuture<DartOpaque> + Send + Sync>;
+pub type AggregateResult = Box<dyn Fn(DartOpaque) -> DartFnFuture<LibsqlValue> + Send + Sync>;
+
+pub type Collation = Box<dyn Fn(&str, &str) -> Ordering + Send + Sync>;
+
+/// Callbacks of an aggregate function. The accumulator is an arbitrary Dart
+/// object, created by `init` for every group and threaded through `step`.
+pub struct AggregateFunction {
+    pub init: AggregateInit,
+    pub step: AggregateStep,
+    pub finalize: AggregateResult,
+    /// Window functions only, removes a row leaving the window
+    pub inverse: Option<AggregateStep>,
+    /// Window functions only, the result for the current window
+    pub value: Option<AggregateResult>,
+}
+
+/// Handle of the SQLite connection behind a local `libsql::Connection`.
+///
+/// libsql doesn't hand out the handle, so `connect` catches it with an
+/// auto extension registered only while it opens the connection. It stays
+/// valid for as long as the `libsql::Connection` (or any clone of it) is
+/// alive, callers must hold on to one while using it.
+#[derive(Clone, Copy)]
+pub struct RawConnection(*mut ffi::sqlite3);
+
+// SQLite connections are opened in serialized mode
+unsafe impl Send for RawConnection {}
+unsafe impl Sync for RawConnection {}
+
+// taken while the auto extension is registered, so one `connect` can't
+// cancel it while another one is still opening its connection
+static OPENING: Mutex<()> = Mutex::new(());
+
+static CONFIGURED: OnceCell<()> = OnceCell::const_new();
+
+unsafe extern "C" fn capture_handle(
+    db: *mut ffi::sqlite3,
+    _err: *mut *mut c_char,
+    _api: *const ffi::sqlite3_api_routines,
+) -> c_int {
+    // connections opened by other threads meanwhile are left alone
+    let _ = OPENED.try_with(|opened| {
+        if let Some(opened) = opened.borrow_mut().as_mut() {
+            opened.push(db);
+        }
+    });
+    ffi::SQLITE_OK as c_int
+}
+
+thread_local! {
+    // limits of the call being polled on this thread, if it has any
+    static LIMITS: RefCell<Option<CallLimits>> = const { RefCell::new(None) };
+    // handles of the connections `connect` opened on this thread so far,
+    // only set while it opens one
+    static OPENED: RefCell<Option<Vec<*mut ffi::sqlite3>>> = const { RefCell::new(None) };
+}
+
+/// When the local statements stepped by a call have to stop.
+#[derive(Clone, Default)]
+pub struct CallLimits {
+    pub deadline: Option<Instant>,
+    pub cancellation: Option<Arc<Cancellation>>,
+}
+
+impl CallLimits {
+    fn exceeded(&self) -> bool {
+        self.deadline
+            .is_some_and(|deadline| Instant::now() >= deadline)
+            || self
+                .cancellation
+                .as_ref()
+                .is_some_and(|cancellation| cancellation.is_cancelled())
+    }
+}
+
+/// Runs `future`, making the local statements it steps fail with
+/// `SQLITE_INTERRUPT` once `limits` are exceeded, provided the connection
+/// checks them. Steps of other calls on the same connection happen outside
+/// of `future`'s polls, so they never see these limits.
+pub async fn with_limits<T>(limits: CallLimits, future: impl Future<Output = T>) -> T {
+    struct Restore(Option<CallLimits>);
+
+    impl Drop for Restore {
+        fn drop(&mut self) {
+            LIMITS.set(self.0.take());
+        }
+    }
+
+    let mut future = std::pin::pin!(future);
+    std::future::poll_fn(|cx| {
+        // libsql steps local statements synchronously inside the poll
+        let _restore = Restore(LIMITS.replace(Some(limits.clone())));
+        future.as_mut().poll(cx)
+    })
+    .await
+}
+
+unsafe extern "C" fn check_limits(_: *mut c_void) -> c_int {
+    LIMITS
+        .try_with(|limits| limits.borrow().as_ref().is_some_and(CallLimits::exceeded))
+        .unwrap_or(false) as c_int
+}
+
+/// Connects to `database`, also returning the SQLite handle unless it is
+/// `remote`. Remote connections have no handle.
+pub async fn connect(
+    database: &libsql::Database,
+    remote: bool,
+) -> Result<(libsql::Connection, Option<RawConnection>), LibsqlError> {
+    if remote {
+        return Ok((database.connect()?, None));
+    }
+    // registering an auto extension initializes SQLite, which libsql has to
+    // configure first, as it does when opening its first local database
+    CONFIGURED
+        .get_or_try_init(|| async {
+            libsql::Builder::new_local(":memory:").build().await?;
+            Ok::<_, LibsqlError>(())
+        })
+        .await?;
+    let entry_point = capture_handle
+        as unsafe extern "C" fn(
+            *mut ffi::sqlite3,
+            *mut *mut c_char,
+            *const ffi::sqlite3_api_routines,
+        ) -> c_int;
+    let (connection, opened) = {
+        let _opening = OPENING.lock().unwrap_or_else(|error| error.into_inner());
+        OPENED.set(Some(Vec::new()));
+        #[allow(clippy::missing_transmute_annotations)]
+        unsafe {
+            ffi::sqlite3_auto_extension(std::mem::transmute(entry_point))
+        };
+        // opens the connection on this thread
+        let connection = database.connect();
+        #[allow(clippy::missing_transmute_annotations)]
+        unsafe {
+            ffi::sqlite3_cancel_auto_extension(std::mem::transmute(entry_point))
+        };
+        (connection, OPENED.take().unwrap_or_default())
+    };
+    let connection = connection?;
+    let [handle] = opened[..] else {
+        return Err(LibsqlError::misuse("connection handle not found"));
+    };
+    Ok((connection, Some(RawConnection(handle))))
+}
+
+impl RawConnection {
+    pub fn create_scalar_function(
+        &self,
+        name: &str,
+        n_args: i32,
+        deterministic: bool,
+        function: ScalarFunction,
+    ) -> Result<(), LibsqlError> {
+        let name = function_name(name)?;
+        let function = Box::into_raw(Box::new(function));
+        let rc = unsafe {
+            // SQLite calls `destroy_scalar` once the function is replaced,
+            // the connection is closed or registration fails
+            ffi::sqlite3_create_function_v2(
+                self.0,
+                name.as_ptr(),
+                n_args,
+                text_rep(deterministic),
+                function as *mut c_void,
+                Some(call_scalar),
+                None,
+                None,
+                Some(destroy_scalar),
+            )
+        };
+        self.check(rc)
+    }
+
+    pub fn create_aggregate_function(
+        &self,
+        name: &str,
+        n_args: i32,
+        deterministic: bool,
+        function: AggregateFunction,
+    ) -> Result<(), LibsqlError> {
+        let name = function_name(name)?;
+        let window = function.inverse.is_some() && function.value.is_some();
+        let function = Box::into_raw(Box::new(function));
+        let rc = unsafe {
+            if window {
+                ffi::sqlite3_create_window_function(
+                    self.0,
+                    name.as_ptr(),
+                    n_args,
+                    text_rep(deterministic),
+                    function as *mut c_void,
+                    Some(call_step),
+                    Some(call_final),
+                    Some(call_value),
+                    Some(call_inverse),
+                    Some(destroy_aggregate),
+                )
+            } else {
+                ffi::sqlite3_create_function_v2(
+                    self.0,
+                    name.as_ptr(),
+                    n_args,
+                    text_rep(deterministic),
+                    function as *mut c_void,
+                    None,
+                    Some(call_step),
+                    Some(call_final),
+                    Some(destroy_aggregate),
+                )
+            }
+        };
+        self.check(rc)
+    }
+
+    pub fn create_collation(&self, name: &str, collation: Collation) -> Result<(), LibsqlError> {
+        let name = CString::new(name)
+            .map_err(|_| LibsqlError::misuse("collation name contains a nul byte"))?;
+        let collation = Box::into_raw(Box::new(collation));
+        let rc = unsafe {
+            ffi::sqlite3_create_collation_v2(
+                self.0,
+                name.as_ptr(),
+                ffi::SQLITE_UTF8 as c_int,
+                collation as *mut c_void,
+                Some(call_collation),
+                Some(destroy_collation),
+            )
+        };
+        self.check(rc)
+    }
+
+    /// Points the update, commit and rollback hooks at `tracker`, or clears
+    /// them, along with a trace of the statements run to tell which changes
+    /// were undone and when commits are done. The hooks hold a reference to
+    /// the tracker until they are cleared or replaced, which they do
+    /// themselves once the tracker has no listeners left.
+    pub fn set_change_hooks(&self, tracker: Option<&Arc<ChangeTracker>>) {
+        let previous = unsafe {
+            match tracker {
+                Some(tracker) => {
+                    let tracker = Arc::into_raw(tracker.clone()) as *mut c_void;
+                    ffi::sqlite3_update_hook(self.0, Some(on_update), tracker);
+                    ffi::sqlite3_rollback_hook(self.0, Some(on_rollback), tracker);
+                    ffi::sqlite3_trace_v2(
+                        self.0,
+                        (ffi::SQLITE_TRACE_STMT | ffi::SQLITE_TRACE_PROFILE) as u32,
+                        Some(on_trace),
+                        tracker,
+                    );
+                    ffi::sqlite3_commit_hook(self.0, Some(on_commit), tracker)
+                }
+                None => {
+                    ffi::sqlite3_update_hook(self.0, None, ptr::null_mut());
+                    ffi::sqlite3_rollback_hook(self.0, None, ptr::null_mut());
+                    ffi::sqlite3_trace_v2(self.0, 0, None, ptr::null_mut());
+                    ffi::sqlite3_commit_hook(self.0, None, ptr::null_mut())
+                }
+            }
+        };
+        if !previous.is_null() {
+            drop(unsafe { Arc::from_raw(previous as *const ChangeTracker) });
+        }
+    }
+
+    /// Makes statements check the limits of the call stepping them every
+    /// 1000 virtual machine instructions, or stops them from checking.
+    pub fn check_call_limits(&self, enabled: bool) {
+        let handler = enabled.then_some(check_limits as unsafe extern "C" fn(_) -> _);
+        unsafe { ffi::sqlite3_progress_handler(self.0, 1000, handler, ptr::null_mut()) };
+    }
+
+    /// Sets how long statements wait for a lock held by another connection
+    /// before failing with `SQLITE_BUSY`.
+    pub fn set_busy_timeout(&self, timeout: Duration) -> Result<(), LibsqlError> {
+        let millis = timeout.as_millis().min(c_int::MAX as u128) as c_int;
+        self.check(unsafe { ffi::sqlite3_busy_timeout(self.0, millis) })
+    }
+
+    /// Whether the first statement of `sql` leaves the database as it is,
+    /// as `sqlite3_stmt_readonly` tells once it is prepared here.
+    pub fn is_readonly(&self, sql: &str) -> Result<bool, LibsqlError> {
+        let mut statement = ptr::null_mut();
+        self.check(unsafe {
+            ffi::sqlite3_prepare_v2(
+                self.0,
+                sql.as_ptr() as *const c_char,
+                sql.len() as c_int,
+                &mut statement,
+                ptr::null_mut(),
+            )
+        })?;
+        // SQL without a statement, only comments or whitespace, prepares to null
+        let readonly = statement.is_null() || unsafe { ffi::sqlite3_stmt_readonly(statement) } != 0;
+        unsafe { ffi::sqlite3_finalize(statement) };
+        Ok(readonly)
+    }
+
+    /// Frames in the write-ahead log, 0 when it is not in WAL mode.
+    pub fn wal_frame_count(&self) -> Result<u32, LibsqlError> {
+        let mut frames = 0;
+        self.check(unsafe { ffi::libsql_wal_frame_count(self.0, &mut frames) })?;
+        Ok(frames)
+    }
+
+    fn check(&self, rc: c_int) -> Result<(), LibsqlError> {
+        if rc == ffi::SQLITE_OK as c_int {
+            return Ok(());
+        }
+        let message = unsafe { CStr::from_ptr(ffi::sqlite3_errmsg(self.0)) }
+            .to_string_lossy()
+            .into_owned();
+        Err(LibsqlError::from_sqlite_code(rc, message))
+    }
+}
+
+fn function_name(name: &str) -> Result<CString, LibsqlError> {
+    CString::new(name).map_err(|_| LibsqlError::misuse("function name contains a nul byte"))
+}
+
+fn text_rep(deterministic: bool) -> c_int {
+    let mut flags = ffi::SQLITE_UTF8 as c_int;
+    if deterministic {
+        flags |= ffi::SQLITE_DETERMINISTIC as c_int;
+    }
+    flags
+}
+
+unsafe extern "C" fn call_scalar(
+    ctx: *mut ffi::sqlite3_context,
+    argc: c_int,
+    argv: *mut *mut ffi::sqlite3_value,
+) {
+    let function = &*(ffi::sqlite3_user_data(ctx) as *const ScalarFunction);
+    let args = function_args(argc, argv);
+    // SQLite calls back synchronously, so wait here for the Dart closure.
+    // This parks the worker thread until the Dart isolate answers; a closure
+    // that calls back into this connection never completes
+    match catch_unwind(AssertUnwindSafe(|| block_on(function(args)))) {
+        Ok(value) => match value.try_into() {
+            Ok(value) => set_result(ctx, value),
+            Err(error) => set_error(ctx, &error.message),
+        },
+        Err(_) => set_error(ctx, "application function failed"),
+    }
+}
+
+unsafe extern "C" fn destroy_scalar(function: *mut c_void) {
+    drop(Box::from_raw(function as *mut ScalarFunction));
+}
+
+unsafe extern "C" fn destroy_aggregate(function: *mut c_void) {
+    drop(Box::from_raw(function as *mut AggregateFunction));
+}
+
+unsafe extern "C" fn call_step(
+    ctx: *mut ffi::sqlite3_context,
+    argc: c_int,
+    argv: *mut *mut ffi::sqlite3_value,
+) {
+    accumulate(ctx, argc, argv, |function| &function.step);
+}
+
+unsafe extern "C" fn call_inverse(
+    ctx: *mut ffi::sqlite3_context,
+    argc: c_int,
+    argv: *mut *mut ffi::sqlite3_value,
+) {
+    // only registered when `inverse` is set
+    accumulate(ctx, argc, argv, |function| {
+        function.inverse.as_ref().unwrap()
+    });
+}
+
+/// Runs `callback` on the accumulator of the current group, which lives in
+/// SQLite's aggregate context as a boxed `DartOpaque` until `call_final`.
+unsafe fn accumulate(
+    ctx: *mut ffi::sqlite3_context,
+    argc: c_int,
+    argv: *mut *mut ffi::sqlite3_value,
+    callback: impl Fn(&AggregateFunction) -> &AggregateStep,
+) {
+    let function = &*(ffi::sqlite3_user_data(ctx) as *const AggregateFunction);
+    let slot = ffi::sqlite3_aggregate_context(ctx, size_of::<*mut DartOpaque>() as c_int)
+        as *mut *mut DartOpaque;
+    if slot.is_null() {
+        ffi::sqlite3_result_error_nomem(ctx);
+        return;
+    }
+    let args = function_args(argc, argv);
+    let state = catch_unwind(AssertUnwindSafe(|| {
+        let state = match (*slot).as_ref() {
+            Some(state) => state.clone(),
+            None => block_on((function.init)()),
+        };
+        block_on(callback(function)(state, args))
+    }));
+    match state {
+        Ok(state) if (*slot).is_null() => *slot = Box::into_raw(Box::new(state)),
+        Ok(state) => **slot = state,
+        Err(_) => set_error(ctx, "application function failed"),
+    }
+}
+
+unsafe extern "C" fn call_value(ctx: *mut ffi::sqlite3_context) {
+    let function = &*(ffi::sqlite3_user_data(ctx) as *const AggregateFunction);
+    // a size of 0 only looks up the context, it is null for an empty window
+    let slot = ffi::sqlite3_aggregate_context(ctx, 0) as *mut *mut DartOpaque;
+    let state = slot.as_ref().and_then(|slot| slot.as_ref()).cloned();
+    // only registered when `value` is set
+    let value = function.value.as_ref().unwrap();
+    finish(ctx, function, state, value);
+}
+
+unsafe extern "C" fn call_final(ctx: *mut ffi::sqlite3_context) {
+    let function = &*(ffi::sqlite3_user_data(ctx) as *const AggregateFunction);
+    let slot = ffi::sqlite3_aggregate_context(ctx, 0) as *mut *mut DartOpaque;
+    let state = match slot.as_mut() {
+        Some(slot) if !slot.is_null() => {
+            Some(*Box::from_raw(std::mem::replace(slot, ptr::null_mut())))
+        }
+        _ => None,
+    };
+    finish(ctx, function, state, &function.finalize);
+}
+
+/// Sets the result from `state`, starting from a fresh accumulator when the
+/// group had no rows.
+unsafe fn finish(
+    ctx: *mut ffi::sqlite3_context,
+    function: &AggregateFunction,
+    state: Option<DartOpaque>,
+    callback: &AggregateResult,
+) {
+    let value = catch_unwind(AssertUnwindSafe(|| {
+        let state = match state {
+            Some(state) => state,
+            None => block_on((function.init)()),
+        };
+        block_on(callback(state))
+    }));
+    match value {
+        Ok(value) => match value.try_into() {
+            Ok(value) => set_result(ctx, value),
+            Err(error) => set_error(ctx, &error.message),
+        },
+        Err(_) => set_error(ctx, "application function failed"),
+    }
+}
+
+unsafe extern "C" fn call_collation(
+    collation: *mut c_void,
+    left_len: c_int,
+    left: *const c_void,
+    right_len: c_int,
+    right: *const c_void,
+) -> c_int {
+    let collation = &*(collation as *const Collation);
+    let left = String::from_utf8_lossy(raw_bytes(left as *const u8, left_len));
+    let right = String::from_utf8_lossy(raw_bytes(right as *const u8, right_len));
+    // collations can't fail, a panicking comparator treats values as equal
+    match catch_unwind(AssertUnwindSafe(|| collation(&left, &right))) {
+        Ok(Ordering::Less) => -1,
+        Ok(Ordering::Greater) => 1,
+        Ok(Ordering::Equal) | Err(_) => 0,
+    }
+}
+
+unsafe extern "C" fn destroy_collation(collation: *mut c_void) {
+    drop(Box::from_raw(collation as *mut Collation));
+}
+
+unsafe extern "C" fn on_update(
+    tracker: *mut c_void,
+    operation: c_int,
+    database: *const c_char,
+    table: *const c_char,
+    rowid: ffi::sqlite3_int64,
+) {
+    let tracker = &*(tracker as *const ChangeTracker);
+    let _ = catch_unwind(AssertUnwindSafe(|| {
+        let operation = if operation == ffi::SQLITE_INSERT as c_int {
+            LibsqlUpdateOperation::Insert
+        } else if operation == ffi::SQLITE_DELETE as c_int {
+            LibsqlUpdateOperation::Delete
+        } else {
+            LibsqlUpdateOperation::Update
+        };
+        tracker.update(LibsqlUpdate {
+            operation,
+            database: CStr::from_ptr(database).to_string_lossy().into_owned(),
+            table: CStr::from_ptr(table).to_string_lossy().into_owned(),
+            rowid,
+        });
+    }));
+}
+
+unsafe extern "C" fn on_commit(tracker: *mut c_void) -> c_int {
+    let tracker = &*(tracker as *const ChangeTracker);
+    let _ = catch_unwind(AssertUnwindSafe(|| tracker.commit()));
+    // anything but 0 would turn the commit into a rollback
+    0
+}
+
+unsafe extern "C" fn on_rollback(tracker: *mut c_void) {
+    let tracker = &*(tracker as *const ChangeTracker);
+    let _ = catch_unwind(AssertUnwindSafe(|| tracker.rollback()));
+}
+
+unsafe extern "C" fn on_trace(
+    event: c_uint,
+    tracker: *mut c_void,
+    statement: *mut c_void,
+    sql: *mut c_void,
+) -> c_int {
+    let hooked = tracker as *const ChangeTracker;
+    let tracker = &*hooked;
+    let _ = catch_unwind(AssertUnwindSafe(|| {
+        let db = ffi::sqlite3_db_handle(statement as *mut ffi::sqlite3_stmt);
+        let total_changes = ffi::sqlite3_total_changes64(db);
+        if event == ffi::SQLITE_TRACE_PROFILE as c_uint {
+            let autocommit = ffi::sqlite3_get_autocommit(db) != 0;
+            tracker.statement_finished(statement as usize, total_changes, autocommit);
+            if !tracker.has_listeners() {
+                // clearing the hooks drops their reference, this one keeps
+                // the tracker alive until the callback returns
+                Arc::increment_strong_count(hooked);
+                let _tracker = Arc::from_raw(hooked);
+                RawConnection(db).set_change_hooks(None);
+                tracker.unhooked();
+            }
+            return;
+        }
+        // SQLite traces the statement's own SQL as it starts, then a comment
+        // naming each trigger as it starts running within the statement
+        let sql = CStr::from_ptr(sql as *const c_char);
+        let own_sql = ffi::sqlite3_sql(statement as *mut ffi::sqlite3_stmt);
+        if own_sql.is_null() || sql != CStr::from_ptr(own_sql) {
+            tracker.trigger_started(statement as usize);
+            return;
+        }
+        tracker.statement_started(statement as usize, total_changes);
+        match savepoint_command(&sql.to_string_lossy()) {
+            Some(SavepointCommand::Savepoint(name)) => tracker.savepoint(name),
+            Some(SavepointCommand::Release(name)) => tracker.release(&name),
+            Some(SavepointCommand::RollbackTo(name)) => tracker.rollback_to(&name),
+            None => {}
+        }
+    }));
+    // the return value is unused and must be 0
+    0
+}
+
+unsafe fn function_args(argc: c_int, argv: *mut *mut ffi::sqlite3_value) -> Vec<LibsqlReturnValue> {
+    (0..argc as usize)
+        .map(|idx| value_from_raw(*argv.add(idx)))
+        .collect()
+}
+
+unsafe fn value_from_raw(value: *mut ffi::sqlite3_value) -> LibsqlReturnValue {
+    let kind = ffi::sqlite3_value_type(value);
+    if kind == ffi::SQLITE_INTEGER as c_int {
+        LibsqlReturnValue::Integer(ffi::sqlite3_value_int64(value))
+    } else if kind == ffi::SQLITE_FLOAT as c_int {
+        LibsqlReturnValue::Real(ffi::sqlite3_value_double(value))
+    } else if kind == ffi::SQLITE_TEXT as c_int {
+        let text = ffi::sqlite3_value_text(value);
+        let bytes = raw_bytes(text, ffi::sqlite3_value_bytes(value));
+        LibsqlReturnValue::Text(String::from_utf8_lossy(bytes).into_owned())
+    } else if kind == ffi::SQLITE_BLOB as c_int {
+        let blob = ffi::sqlite3_value_blob(value);
+        let bytes = raw_bytes(blob as *const u8, ffi::sqlite3_value_bytes(value));
+        LibsqlReturnValue::Blob(bytes.to_vec())
+    } else {
+        LibsqlReturnValue::Null
+    }
+}
+
+unsafe fn raw_bytes<'a>(data: *const u8, len: c_int) -> &'a [u8] {
+    // empty text and blobs may come back as a null pointer
+    if data.is_null() || len <= 0 {
+        return &[];
+    }
+    std::slice::from_raw_parts(data, len as usize)
+}
+
+unsafe fn set_result(ctx: *mut ffi::sqlite3_context, value: libsql::Value) {
+    match value {
+        libsql::Value::Null => ffi::sqlite3_result_null(ctx),
+        libsql::Value::Integer(i) => ffi::sqlite3_result_int64(ctx, i),
+        libsql::Value::Real(f) => ffi::sqlite3_result_double(ctx, f),
+        libsql::Value::Text(t) => ffi::sqlite3_result_text(
+            ctx,
+            t.as_ptr() as *const c_char,
+            t.len() as c_int,
+            ffi::SQLITE_TRANSIENT(),
+        ),
+        libsql::Value::Blob(b) => ffi::sqlite3_result_blob(
+            ctx,
+            b.as_ptr() as *const c_void,
+            b.len() as c_int,
+            ffi::SQLITE_TRANSIENT(),
+        ),
+    }
+}
+
+unsafe fn set_error(ctx: *mut ffi::sqlite3_context, message: &str) {
+    ffi::sqlite3_result_error(
+        ctx,
+        message.as_ptr() as *const c_char,
+        message.len() as c_int,
+    );
+}
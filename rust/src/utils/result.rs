@@ -8,24 +8,68 @@ pub struct ConnectResult {
     pub connection: LibsqlConnection,
 }
 
-pub struct SyncResult {}
+/// Outcome of syncing an embedded replica or synced database.
+#[derive(Clone, Default)]
+pub struct SyncResult {
+    /// Frames pulled from the remote during this sync
+    pub frames_synced: u64,
+    /// Frame number committed locally after this sync, if any
+    pub frame_no: Option<u64>,
+    /// Replication index of the local replica after this sync, if any
+    pub replication_index: Option<u64>,
+    pub duration_ms: u64,
+}
 
 pub struct QueryResult {
     pub rows: Vec<HashMap<String, LibsqlReturnValue>>,
     pub columns: Vec<String>,
+    /// Declared type of each column, `None` for expressions. Empty in the
+    /// results of a remote `batch_statements`
+    pub decl_types: Vec<Option<String>>,
+    pub rows_affected: u64,
+    pub last_insert_rowid: i64,
+    pub total_changes: u64,
+}
+
+/// Same as `QueryResult`, but column names are sent once and values are laid
+/// out row by row in a single flat vector, so row `r` spans
+/// `values[r * columns.len()..(r + 1) * columns.len()]`.
+///
+/// Unlike the map based rows, duplicate column names are kept.
+pub struct ColumnarQueryResult {
+    pub columns: Vec<String>,
+    pub decl_types: Vec<Option<String>>,
+    pub values: Vec<LibsqlReturnValue>,
+    pub row_count: u64,
     pub rows_affected: u64,
     pub last_insert_rowid: i64,
+    pub total_changes: u64,
 }
 
 pub struct ExecuteResult {
     pub rows_affected: u64,
+    pub last_insert_rowid: i64,
+    pub total_changes: u64,
+}
+
+pub struct LibsqlColumn {
+    pub name: String,
+    /// Column in the table the value is read from, `None` for expressions
+    pub origin_name: Option<String>,
+    pub table_name: Option<String>,
+    pub database_name: Option<String>,
+    /// Type the column was declared with in `CREATE TABLE`
+    pub decl_type: Option<String>,
 }
 
 pub struct PrepareResult {
     pub statement: LibsqlStatement,
 }
 
-pub struct BatchResult {}
+pub struct BatchResult {
+    /// One entry per statement, in the order they were given
+    pub results: Vec<QueryResult>,
+}
 
 pub struct TransactionResult {
     pub transaction: LibsqlTransaction,
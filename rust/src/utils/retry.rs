@@ -0,0 +1,110 @@
+use std::{future::Future, time::Duration};
+
+use super::error::{LibsqlError, LibsqlErrorKind};
+
+/// Retries operations failing with a `Busy` error, which covers both
+/// `SQLITE_BUSY` and `SQLITE_LOCKED`, waiting twice as long after each
+/// attempt.
+#[derive(Clone, Copy)]
+pub struct LibsqlBusyRetry {
+    /// Attempts including the first one
+    pub max_attempts: u32,
+    pub initial_delay_ms: u64,
+    pub max_delay_ms: u64,
+}
+
+/// Runs `operation`, running it again as `policy` allows while it is busy.
+pub async fn retry_busy<T, F, Fut>(
+    policy: Option<LibsqlBusyRetry>,
+    mut operation: F,
+) -> Result<T, LibsqlError>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<T, LibsqlError>>,
+{
+    let Some(policy) = policy else {
+        return operation().await;
+    };
+    let max_delay = Duration::from_millis(policy.max_delay_ms);
+    let mut delay = Duration::from_millis(policy.initial_delay_ms).min(max_delay);
+    let mut attempt = 1;
+    loop {
+        match operation().await {
+            Err(error)
+                if matches!(error.kind, LibsqlErrorKind::Busy) && attempt < policy.max_attempts =>
+            {
+                tokio::time::sleep(delay).await;
+                delay = (delay * 2).min(max_delay);
+                attempt += 1;
+            }
+            result => return result,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::Cell;
+
+    use super::{retry_busy, LibsqlBusyRetry};
+    use crate::utils::error::{LibsqlError, LibsqlErrorKind};
+
+    const POLICY: LibsqlBusyRetry = LibsqlBusyRetry {
+        max_attempts: 3,
+        initial_delay_ms: 1,
+        max_delay_ms: 2,
+    };
+
+    fn failing(attempts: &Cell<u32>, kind: LibsqlErrorKind) -> Result<(), LibsqlError> {
+        attempts.set(attempts.get() + 1);
+        Err(LibsqlError::new(kind, "failed"))
+    }
+
+    #[tokio::test]
+    async fn retries_until_it_succeeds() {
+        let attempts = Cell::new(0);
+        let result = retry_busy(Some(POLICY), || async {
+            // busy on the first attempt only
+            failing(&attempts, LibsqlErrorKind::Busy).or_else(|error| match attempts.get() {
+                1 => Err(error),
+                _ => Ok(()),
+            })
+        })
+        .await;
+        assert!(result.is_ok());
+        assert_eq!(attempts.get(), 2);
+    }
+
+    #[tokio::test]
+    async fn gives_up_after_max_attempts() {
+        let attempts = Cell::new(0);
+        let result = retry_busy(Some(POLICY), || async {
+            failing(&attempts, LibsqlErrorKind::Busy)
+        })
+        .await;
+        assert!(matches!(result.unwrap_err().kind, LibsqlErrorKind::Busy));
+        assert_eq!(attempts.get(), 3);
+    }
+
+    #[tokio::test]
+    async fn only_retries_busy_errors() {
+        let attempts = Cell::new(0);
+        let result = retry_busy(Some(POLICY), || async {
+            failing(&attempts, LibsqlErrorKind::Constraint)
+        })
+        .await;
+        assert!(matches!(
+            result.unwrap_err().kind,
+            LibsqlErrorKind::Constraint
+        ));
+        assert_eq!(attempts.get(), 1);
+    }
+
+    #[tokio::test]
+    async fn runs_once_without_a_policy() {
+        let attempts = Cell::new(0);
+        let result = retry_busy(None, || async { failing(&attempts, LibsqlErrorKind::Busy) }).await;
+        assert!(result.is_err());
+        assert_eq!(attempts.get(), 1);
+    }
+}
@@ -1,9 +1,18 @@
+use chrono::{DateTime, NaiveDate, NaiveDateTime, Utc};
+
+#[derive(Clone, PartialEq)]
 pub enum LibsqlReturnValue {
     Integer(i64),
     Real(f64),
     Text(String),
     Blob(Vec<u8>),
     Null,
+    /// Only produced with `LibsqlDecodeMode::DeclaredType`
+    Bool(bool),
+    /// Only produced with `LibsqlDecodeMode::DeclaredType`
+    DateTime(DateTime<Utc>),
+    /// Only produced with `LibsqlDecodeMode::DeclaredType`, holds the JSON text
+    Json(String),
 }
 
 impl From<libsql::Value> for LibsqlReturnValue {
@@ -17,3 +26,203 @@ impl From<libsql::Value> for LibsqlReturnValue {
         }
     }
 }
+
+#[derive(Clone, Copy, Default)]
+pub enum LibsqlDecodeMode {
+    /// Values come back as one of SQLite's five storage classes
+    #[default]
+    StorageClass,
+    /// Values are also converted according to the declared type of their
+    /// column: `BOOL`/`BOOLEAN` to `Bool`, `DATE`/`DATETIME`/`TIMESTAMP` to
+    /// `DateTime` (ISO-8601 text or unix time) and `JSON` to `Json`. Only
+    /// these exact type names are matched, ignoring case and any size such
+    /// as `TIMESTAMP(3)`. Values that don't fit the declared type are left
+    /// untouched.
+    DeclaredType,
+}
+
+/// Unit of unix timestamps stored as integers in date and time columns.
+#[derive(Clone, Copy, Default)]
+pub enum LibsqlDateTimeUnit {
+    /// Milliseconds since the unix epoch, as written by `LibsqlDateTimeFormat::UnixMillis`
+    #[default]
+    Milliseconds,
+    /// Seconds since the unix epoch, as returned by SQLite's `unixepoch()`
+    Seconds,
+}
+
+/// Decoding settings a connection hands to the statements, transactions and
+/// queries created from it.
+#[derive(Clone, Copy, Default)]
+pub struct DecodeOptions {
+    pub mode: LibsqlDecodeMode,
+    pub datetime_unit: LibsqlDateTimeUnit,
+}
+
+/// Turns raw column values into `LibsqlReturnValue`s for one result set.
+pub struct ValueDecoder {
+    decl_types: Vec<Option<String>>,
+    options: DecodeOptions,
+}
+
+impl ValueDecoder {
+    pub fn new(decl_types: Vec<Option<String>>, options: DecodeOptions) -> Self {
+        Self {
+            decl_types,
+            options,
+        }
+    }
+
+    pub fn from_statement(statement: &libsql::Statement, options: DecodeOptions) -> Self {
+        let decl_types = statement
+            .columns()
+            .iter()
+            .map(|column| column.decl_type().map(str::to_string))
+            .collect();
+        Self::new(decl_types, options)
+    }
+
+    pub fn decl_types(&self) -> Vec<Option<String>> {
+        self.decl_types.clone()
+    }
+
+    pub fn decode(&self, idx: usize, value: libsql::Value) -> LibsqlReturnValue {
+        let type_name = match (self.options.mode, self.decl_types.get(idx)) {
+            (LibsqlDecodeMode::DeclaredType, Some(Some(decl_type))) => type_name(decl_type),
+            _ => return value.into(),
+        };
+        match (type_name.as_str(), value) {
+            ("BOOL" | "BOOLEAN", libsql::Value::Integer(i)) => LibsqlReturnValue::Bool(i != 0),
+            ("DATE" | "DATETIME" | "TIMESTAMP", libsql::Value::Integer(i)) => {
+                let datetime = match self.options.datetime_unit {
+                    LibsqlDateTimeUnit::Milliseconds => DateTime::from_timestamp_millis(i),
+                    LibsqlDateTimeUnit::Seconds => DateTime::from_timestamp(i, 0),
+                };
+                match datetime {
+                    Some(datetime) => LibsqlReturnValue::DateTime(datetime),
+                    None => LibsqlReturnValue::Integer(i),
+                }
+            }
+            ("DATE" | "DATETIME" | "TIMESTAMP", libsql::Value::Text(t)) => {
+                match parse_datetime(&t) {
+                    Some(datetime) => LibsqlReturnValue::DateTime(datetime),
+                    None => LibsqlReturnValue::Text(t),
+                }
+            }
+            ("JSON", libsql::Value::Text(t)) => LibsqlReturnValue::Json(t),
+            (_, value) => value.into(),
+        }
+    }
+}
+
+/// The declared type without its size arguments, upper-cased, e.g. `TIMESTAMP`
+/// for `timestamp(3)`.
+fn type_name(decl_type: &str) -> String {
+    let name = decl_type.split('(').next().unwrap_or_default();
+    name.split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .to_uppercase()
+}
+
+/// Accepts RFC 3339 as well as the `YYYY-MM-DD HH:MM:SS[.SSS]` and
+/// `YYYY-MM-DD` forms produced by SQLite's date functions, read as UTC.
+fn parse_datetime(text: &str) -> Option<DateTime<Utc>> {
+    if let Ok(datetime) = DateTime::parse_from_rfc3339(text) {
+        return Some(datetime.with_timezone(&Utc));
+    }
+    for format in ["%Y-%m-%d %H:%M:%S%.f", "%Y-%m-%dT%H:%M:%S%.f"] {
+        if let Ok(datetime) = NaiveDateTime::parse_from_str(text, format) {
+            return Some(datetime.and_utc());
+        }
+    }
+    NaiveDate::parse_from_str(text, "%Y-%m-%d")
+        .ok()
+        .and_then(|date| date.and_hms_opt(0, 0, 0))
+        .map(|datetime| datetime.and_utc())
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{TimeZone, Utc};
+
+    use super::{
+        parse_datetime, DecodeOptions, LibsqlDateTimeUnit, LibsqlDecodeMode, LibsqlReturnValue,
+        ValueDecoder,
+    };
+
+    fn declared(decl_types: &[&str], datetime_unit: LibsqlDateTimeUnit) -> ValueDecoder {
+        let decl_types = decl_types.iter().map(|t| Some(t.to_string())).collect();
+        ValueDecoder::new(
+            decl_types,
+            DecodeOptions {
+                mode: LibsqlDecodeMode::DeclaredType,
+                datetime_unit,
+            },
+        )
+    }
+
+    #[test]
+    fn matches_whole_type_names() {
+        let decoder = declared(
+            &[
+                "boolean",
+                "BOOLEANISH",
+                "timestamp(3)",
+                "UPDATE_COUNT",
+                "json",
+                "JSONB",
+            ],
+            LibsqlDateTimeUnit::Milliseconds,
+        );
+        assert!(decoder.decode(0, libsql::Value::Integer(1)) == LibsqlReturnValue::Bool(true));
+        assert!(decoder.decode(1, libsql::Value::Integer(1)) == LibsqlReturnValue::Integer(1));
+        assert!(
+            decoder.decode(2, libsql::Value::Integer(1_000))
+                == LibsqlReturnValue::DateTime(Utc.timestamp_opt(1, 0).unwrap())
+        );
+        assert!(decoder.decode(3, libsql::Value::Integer(7)) == LibsqlReturnValue::Integer(7));
+        let json = libsql::Value::Text("{}".to_string());
+        assert!(decoder.decode(4, json.clone()) == LibsqlReturnValue::Json("{}".to_string()));
+        assert!(decoder.decode(5, json) == LibsqlReturnValue::Text("{}".to_string()));
+    }
+
+    #[test]
+    fn reads_integer_datetimes_in_the_configured_unit() {
+        let decoder = declared(&["DATETIME"], LibsqlDateTimeUnit::Seconds);
+        assert!(
+            decoder.decode(0, libsql::Value::Integer(1_700_000_000))
+                == LibsqlReturnValue::DateTime(Utc.timestamp_opt(1_700_000_000, 0).unwrap())
+        );
+    }
+
+    #[test]
+    fn parses_rfc3339_in_utc() {
+        assert_eq!(
+            parse_datetime("2024-05-06T07:08:09+02:00"),
+            Utc.with_ymd_and_hms(2024, 5, 6, 5, 8, 9).single()
+        );
+    }
+
+    #[test]
+    fn parses_sqlite_date_functions() {
+        let expected = Utc.with_ymd_and_hms(2024, 5, 6, 7, 8, 9).single();
+        assert_eq!(parse_datetime("2024-05-06 07:08:09"), expected);
+        assert_eq!(parse_datetime("2024-05-06T07:08:09"), expected);
+        assert_eq!(
+            parse_datetime("2024-05-06 07:08:09.250").map(|datetime| datetime.timestamp_millis()),
+            expected.map(|datetime| datetime.timestamp_millis() + 250)
+        );
+        assert_eq!(
+            parse_datetime("2024-05-06"),
+            Utc.with_ymd_and_hms(2024, 5, 6, 0, 0, 0).single()
+        );
+    }
+
+    #[test]
+    fn rejects_other_text() {
+        assert_eq!(parse_datetime("yesterday"), None);
+        assert_eq!(parse_datetime("2024-13-01"), None);
+        assert_eq!(parse_datetime("1715000000"), None);
+    }
+}
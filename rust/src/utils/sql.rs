@@ -0,0 +1,461 @@
+use std::{collections::HashSet, ops::Range};
+
+use fallible_iterator::FallibleIterator;
+use sqlite3_parser::{
+    ast::{
+        Cmd, Expr, FromClause, JoinConstraint, Name, OneSelect, ResultColumn, Select, SelectTable,
+        Stmt,
+    },
+    dialect::TokenType,
+    lexer::{
+        sql::{Parser, Tokenizer},
+        Scanner,
+    },
+};
+
+/// Whether every statement in `sql` only reads from the database.
+///
+/// SQL the parser does not understand is treated as a write.
+pub fn is_readonly(sql: &str) -> bool {
+    let mut parser = Parser::new(sql.as_bytes());
+    let mut readonly = false;
+    loop {
+        match parser.next() {
+            Ok(Some(cmd)) => {
+                readonly = match cmd {
+                    Cmd::Explain(_) | Cmd::ExplainQueryPlan(_) => true,
+                    Cmd::Stmt(Stmt::Select(_)) => true,
+                    // `PRAGMA name = value` and `PRAGMA name(value)` can write
+                    Cmd::Stmt(Stmt::Pragma(_, body)) => body.is_none(),
+                    Cmd::Stmt(_) => false,
+                };
+                if !readonly {
+                    return false;
+                }
+            }
+            Ok(None) => return readonly,
+            Err(_) => return false,
+        }
+    }
+}
+
+/// A statement moving between savepoints.
+pub enum SavepointCommand {
+    Savepoint(String),
+    Release(String),
+    RollbackTo(String),
+}
+
+/// What `sql` does to savepoints, with the name unquoted and lowercased as
+/// SQLite compares them, or `None` if it is not a savepoint statement.
+pub fn savepoint_command(sql: &str) -> Option<SavepointCommand> {
+    // skips parsing the statements that can't be one
+    let keyword = skip_comments(sql)
+        .split(|c: char| !c.is_ascii_alphabetic())
+        .next()?;
+    if !["savepoint", "release", "rollback"]
+        .iter()
+        .any(|command| keyword.eq_ignore_ascii_case(command))
+    {
+        return None;
+    }
+    match Parser::new(sql.as_bytes()).next() {
+        Ok(Some(Cmd::Stmt(Stmt::Savepoint(name)))) => {
+            Some(SavepointCommand::Savepoint(unquote(&name)))
+        }
+        Ok(Some(Cmd::Stmt(Stmt::Release(name)))) => Some(SavepointCommand::Release(unquote(&name))),
+        Ok(Some(Cmd::Stmt(Stmt::Rollback {
+            savepoint_name: Some(name),
+            ..
+        }))) => Some(SavepointCommand::RollbackTo(unquote(&name))),
+        _ => None,
+    }
+}
+
+/// `sql` without the whitespace and comments it starts with.
+fn skip_comments(mut sql: &str) -> &str {
+    loop {
+        sql = sql.trim_start();
+        if let Some(rest) = sql.strip_prefix("--") {
+            sql = rest.split_once('\n').map_or("", |(_, rest)| rest);
+        } else if let Some(rest) = sql.strip_prefix("/*") {
+            sql = rest.split_once("*/").map_or("", |(_, rest)| rest);
+        } else {
+            return sql;
+        }
+    }
+}
+
+/// A parameter as written in the SQL of a statement.
+pub struct Parameter {
+    /// Where the parameter is in the SQL, in bytes
+    pub span: Range<usize>,
+    /// `?NNN`, `:name`, `@name` or `$name`, `None` for a bare `?`
+    pub name: Option<String>,
+}
+
+/// The parameters of `sql` in the order they appear, or `None` if it cannot
+/// be tokenized or holds more than one statement.
+pub fn single_statement_parameters(sql: &str) -> Option<Vec<Parameter>> {
+    let mut scanner = Scanner::new(Tokenizer::new());
+    let mut parameters = Vec::new();
+    let mut ended = false;
+    loop {
+        let (start, token, end) = scanner.scan(sql.as_bytes()).ok()?;
+        match token {
+            None => return Some(parameters),
+            Some((_, TokenType::TK_SEMI)) => ended = true,
+            Some(_) if ended => return None,
+            // the tokenizer strips the `?` off numbered and bare parameters
+            Some((value, TokenType::TK_VARIABLE)) => {
+                let value = std::str::from_utf8(value).ok()?;
+                let name = match value {
+                    "" => None,
+                    _ if value.bytes().all(|b| b.is_ascii_digit()) => Some(format!("?{value}")),
+                    _ => Some(value.to_string()),
+                };
+                parameters.push(Parameter {
+                    span: start..end,
+                    name,
+                });
+            }
+            Some(_) => {}
+        }
+    }
+}
+
+/// `name` without its `:`, `@`, `$` or `?` prefix.
+pub fn bare_parameter_name(name: &str) -> &str {
+    name.strip_prefix([':', '@', '$', '?']).unwrap_or(name)
+}
+
+/// Tables the `SELECT` in `sql` reads from, including subqueries and common
+/// table expressions, lowercased and unquoted. `None` if `sql` is not a
+/// `SELECT` the parser understands.
+///
+/// Names of common table expressions and views are included as they are,
+/// not the tables behind them.
+pub fn referenced_tables(sql: &str) -> Option<HashSet<String>> {
+    let mut parser = Parser::new(sql.as_bytes());
+    let select = match parser.next() {
+        Ok(Some(Cmd::Stmt(Stmt::Select(select)))) => select,
+        _ => return None,
+    };
+    let mut tables = HashSet::new();
+    collect_select(&select, &mut tables);
+    Some(tables)
+}
+
+fn collect_select(select: &Select, tables: &mut HashSet<String>) {
+    if let Some(with) = &select.with {
+        for cte in &with.ctes {
+            collect_select(&cte.select, tables);
+        }
+    }
+    collect_one_select(&select.body.select, tables);
+    for compound in select.body.compounds.iter().flatten() {
+        collect_one_select(&compound.select, tables);
+    }
+    for column in select.order_by.iter().flatten() {
+        collect_expr(&column.expr, tables);
+    }
+    if let Some(limit) = &select.limit {
+        collect_expr(&limit.expr, tables);
+        if let Some(offset) = &limit.offset {
+            collect_expr(offset, tables);
+        }
+    }
+}
+
+fn collect_one_select(select: &OneSelect, tables: &mut HashSet<String>) {
+    match select {
+        OneSelect::Select {
+            columns,
+            from,
+            where_clause,
+            group_by,
+            ..
+        } => {
+            for column in columns {
+                if let ResultColumn::Expr(expr, _) = column {
+                    collect_expr(expr, tables);
+                }
+            }
+            if let Some(from) = from {
+                collect_from(from, tables);
+            }
+            if let Some(expr) = where_clause {
+                collect_expr(expr, tables);
+            }
+            if let Some(group_by) = group_by {
+                for expr in &group_by.exprs {
+                    collect_expr(expr, tables);
+                }
+                if let Some(having) = &group_by.having {
+                    collect_expr(having, tables);
+                }
+            }
+        }
+        OneSelect::Values(rows) => {
+            for expr in rows.iter().flatten() {
+                collect_expr(expr, tables);
+            }
+        }
+    }
+}
+
+fn collect_from(from: &FromClause, tables: &mut HashSet<String>) {
+    let joined = from.joins.iter().flatten().map(|join| &join.table);
+    for table in from.select.as_deref().into_iter().chain(joined) {
+        match table {
+            SelectTable::Table(name, _, _) => {
+                tables.insert(unquote(&name.name));
+            }
+            SelectTable::TableCall(_, args, _) => {
+                for expr in args.iter().flatten() {
+                    collect_expr(expr, tables);
+                }
+            }
+            SelectTable::Select(select, _) => collect_select(select, tables),
+            SelectTable::Sub(from, _) => collect_from(from, tables),
+        }
+    }
+    for join in from.joins.iter().flatten() {
+        if let Some(JoinConstraint::On(expr)) = &join.constraint {
+            collect_expr(expr, tables);
+        }
+    }
+}
+
+fn collect_expr(expr: &Expr, tables: &mut HashSet<String>) {
+    match expr {
+        Expr::Exists(select) | Expr::Subquery(select) => collect_select(select, tables),
+        Expr::InSelect { lhs, rhs, .. } => {
+            collect_expr(lhs, tables);
+            collect_select(rhs, tables);
+        }
+        Expr::InTable { lhs, rhs, args, .. } => {
+            collect_expr(lhs, tables);
+            tables.insert(unquote(&rhs.name));
+            for expr in args.iter().flatten() {
+                collect_expr(expr, tables);
+            }
+        }
+        Expr::Between {
+            lhs, start, end, ..
+        } => {
+            collect_expr(lhs, tables);
+            collect_expr(start, tables);
+            collect_expr(end, tables);
+        }
+        Expr::Binary(lhs, _, rhs) => {
+            collect_expr(lhs, tables);
+            collect_expr(rhs, tables);
+        }
+        Expr::Case {
+            base,
+            when_then_pairs,
+            else_expr,
+        } => {
+            for expr in base.iter().chain(else_expr) {
+                collect_expr(expr, tables);
+            }
+            for (when, then) in when_then_pairs {
+                collect_expr(when, tables);
+                collect_expr(then, tables);
+            }
+        }
+        Expr::Like {
+            lhs, rhs, escape, ..
+        } => {
+            collect_expr(lhs, tables);
+            collect_expr(rhs, tables);
+            if let Some(escape) = escape {
+                collect_expr(escape, tables);
+            }
+        }
+        Expr::FunctionCall { args, .. } => {
+            for expr in args.iter().flatten() {
+                collect_expr(expr, tables);
+            }
+        }
+        Expr::InList { lhs, rhs, .. } => {
+            collect_expr(lhs, tables);
+            for expr in rhs.iter().flatten() {
+                collect_expr(expr, tables);
+            }
+        }
+        Expr::Parenthesized(exprs) => {
+            for expr in exprs {
+                collect_expr(expr, tables);
+            }
+        }
+        Expr::Cast { expr, .. }
+        | Expr::Collate(expr, _)
+        | Expr::IsNull(expr)
+        | Expr::NotNull(expr)
+        | Expr::Unary(_, expr) => collect_expr(expr, tables),
+        _ => {}
+    }
+}
+
+/// `name` as SQLite compares it, without `"..."`, `[...]` or `` `...` ``
+/// quoting and in lowercase.
+pub fn unquote(name: &Name) -> String {
+    let name = name.0.as_str();
+    let unquoted = match name.as_bytes() {
+        [b'[', .., b']'] => name[1..name.len() - 1].to_string(),
+        [quote @ (b'"' | b'`' | b'\''), .., last] if quote == last => {
+            let quote = *quote as char;
+            name[1..name.len() - 1].replace(&format!("{quote}{quote}"), &quote.to_string())
+        }
+        _ => name.to_string(),
+    };
+    unquoted.to_lowercase()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use sqlite3_parser::ast::Name;
+
+    use super::*;
+
+    fn tables(sql: &str) -> HashSet<String> {
+        referenced_tables(sql).unwrap()
+    }
+
+    fn set(names: &[&str]) -> HashSet<String> {
+        names.iter().map(|name| name.to_string()).collect()
+    }
+
+    #[test]
+    fn readonly_statements() {
+        assert!(is_readonly("SELECT 1; EXPLAIN DELETE FROM t"));
+        assert!(is_readonly("PRAGMA user_version"));
+        assert!(!is_readonly("PRAGMA user_version = 2"));
+        assert!(!is_readonly("SELECT 1; DELETE FROM t"));
+        assert!(!is_readonly("SELEC 1"));
+    }
+
+    #[test]
+    fn tables_of_common_table_expressions() {
+        assert_eq!(
+            tables("WITH recent AS (SELECT * FROM Orders) SELECT * FROM recent JOIN users"),
+            set(&["orders", "recent", "users"])
+        );
+    }
+
+    #[test]
+    fn tables_of_compound_selects() {
+        assert_eq!(
+            tables(
+                "SELECT a FROM one UNION ALL SELECT b FROM two \
+                 EXCEPT VALUES ((SELECT c FROM three))"
+            ),
+            set(&["one", "two", "three"])
+        );
+    }
+
+    #[test]
+    fn tables_of_subqueries() {
+        assert_eq!(
+            tables(
+                "SELECT (SELECT max(x) FROM a) FROM b \
+                 WHERE y IN (SELECT y FROM c) AND z IN d AND EXISTS (SELECT 1 FROM e) \
+                 ORDER BY (SELECT 1 FROM f)"
+            ),
+            set(&["a", "b", "c", "d", "e", "f"])
+        );
+        assert_eq!(
+            tables("SELECT * FROM (SELECT * FROM nested) JOIN g ON g.id = (SELECT id FROM h)"),
+            set(&["nested", "g", "h"])
+        );
+    }
+
+    #[test]
+    fn tables_with_quoted_names() {
+        assert_eq!(
+            tables("SELECT * FROM \"My Table\", [Other], `third`, main.Fourth"),
+            set(&["my table", "other", "third", "fourth"])
+        );
+    }
+
+    #[test]
+    fn tables_of_statements_other_than_select() {
+        assert!(referenced_tables("DELETE FROM t").is_none());
+        assert!(referenced_tables("SELECT FROM").is_none());
+    }
+
+    #[test]
+    fn unquotes_names() {
+        let unquoted = |name: &str| unquote(&Name(name.to_string()));
+        assert_eq!(unquoted("Plain"), "plain");
+        assert_eq!(unquoted("\"Say \"\"Hi\"\"\""), "say \"hi\"");
+        assert_eq!(unquoted("[Brackets]"), "brackets");
+        assert_eq!(unquoted("`back``tick`"), "back`tick");
+        assert_eq!(unquoted("'single'"), "single");
+        assert_eq!(unquoted("\"unbalanced"), "\"unbalanced");
+    }
+
+    #[test]
+    fn parameters_in_order() {
+        let sql = "SELECT ?, ?3, :a, @b, $c, ? WHERE x = '?' -- ?\n";
+        let parameters = single_statement_parameters(sql).unwrap();
+        let names: Vec<_> = parameters.iter().map(|p| p.name.as_deref()).collect();
+        assert_eq!(
+            names,
+            [None, Some("?3"), Some(":a"), Some("@b"), Some("$c"), None]
+        );
+        let spans: Vec<_> = parameters.iter().map(|p| &sql[p.span.clone()]).collect();
+        assert_eq!(spans, ["?", "?3", ":a", "@b", "$c", "?"]);
+    }
+
+    #[test]
+    fn numbered_parameters_with_gaps() {
+        let parameters = single_statement_parameters("SELECT ?5, ?2, ?5").unwrap();
+        let names: Vec<_> = parameters.iter().map(|p| p.name.as_deref()).collect();
+        assert_eq!(names, [Some("?5"), Some("?2"), Some("?5")]);
+    }
+
+    #[test]
+    fn parameters_of_a_single_statement() {
+        assert_eq!(single_statement_parameters("SELECT ?;").unwrap().len(), 1);
+        assert_eq!(single_statement_parameters("").unwrap().len(), 0);
+        assert!(single_statement_parameters("SELECT ?; SELECT ?").is_none());
+    }
+
+    #[test]
+    fn strips_parameter_prefixes() {
+        assert_eq!(bare_parameter_name(":a"), "a");
+        assert_eq!(bare_parameter_name("?3"), "3");
+        assert_eq!(bare_parameter_name("name"), "name");
+    }
+
+    #[test]
+    fn savepoint_commands() {
+        assert!(matches!(
+            savepoint_command("SAVEPOINT \"Outer\""),
+            Some(SavepointCommand::Savepoint(name)) if name == "outer"
+        ));
+        assert!(matches!(
+            savepoint_command("release savepoint a"),
+            Some(SavepointCommand::Release(name)) if name == "a"
+        ));
+        assert!(matches!(
+            savepoint_command("  RELEASE b"),
+            Some(SavepointCommand::Release(name)) if name == "b"
+        ));
+        assert!(matches!(
+            savepoint_command("ROLLBACK TRANSACTION TO SAVEPOINT c"),
+            Some(SavepointCommand::RollbackTo(name)) if name == "c"
+        ));
+        assert!(matches!(
+            savepoint_command("-- note\n/* more */ SAVEPOINT d"),
+            Some(SavepointCommand::Savepoint(name)) if name == "d"
+        ));
+        assert!(savepoint_command("ROLLBACK").is_none());
+        assert!(savepoint_command("SELECT 'SAVEPOINT a'").is_none());
+    }
+}
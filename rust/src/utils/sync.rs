@@ -0,0 +1,363 @@
+use std::{
+    sync::{Mutex, Weak},
+    time::{Duration, Instant},
+};
+
+use chrono::{DateTime, Utc};
+use flutter_rust_bridge::RustAutoOpaqueNom;
+use futures::channel::mpsc::UnboundedSender;
+use libsql::{Builder, Database};
+use serde::Deserialize;
+
+use super::{
+    error::{LibsqlError, LibsqlErrorKind},
+    result::SyncResult,
+};
+use crate::frb_generated::StreamSink;
+
+#[derive(Clone, Copy)]
+pub enum LibsqlSyncTrigger {
+    /// A call to `LibsqlConnection::sync`
+    Manual,
+    /// The timer started by `sync_interval_seconds`
+    Periodic,
+}
+
+/// What `LibsqlConnection::push` does when the remote rejects local changes.
+#[derive(Clone, Copy, Default)]
+pub enum LibsqlSyncConflictPolicy {
+    /// Fail with a `SyncConflict` error and keep the local changes
+    #[default]
+    Fail,
+    /// Discard the local database and start over from the remote's state
+    RemoteWins,
+    /// Discard the local database, pull the remote's state, write the
+    /// changes again with the callback set by
+    /// `LibsqlConnection::set_conflict_replay` and push them, giving up
+    /// with the `SyncConflict` error after a few attempts
+    RetryAfterPull,
+}
+
+#[derive(Clone)]
+pub enum LibsqlSyncEvent {
+    Synced {
+        trigger: LibsqlSyncTrigger,
+        result: SyncResult,
+    },
+    Failed {
+        trigger: LibsqlSyncTrigger,
+        error: LibsqlError,
+    },
+}
+
+#[derive(Clone, Default)]
+pub struct LibsqlSyncStatus {
+    /// Whether a sync is running right now
+    pub syncing: bool,
+    pub last_synced_at: Option<DateTime<Utc>>,
+    /// Result of the last sync that succeeded
+    pub last_result: Option<SyncResult>,
+    /// Error of the last sync, cleared once one succeeds
+    pub last_error: Option<LibsqlError>,
+}
+
+/// Keeps the outcome of every sync of a connection's database and hands it
+/// to the subscribed streams.
+#[derive(Default)]
+pub struct SyncTracker {
+    status: Mutex<LibsqlSyncStatus>,
+    listeners: Mutex<Vec<StreamSink<LibsqlSyncEvent>>>,
+    // watched queries, woken by syncs that brought in frames
+    watchers: Mutex<Vec<UnboundedSender<()>>>,
+    // local WAL frames right after the last push or pull, unknown after
+    // any other sync
+    synced_frames: Mutex<Option<u32>>,
+}
+
+impl SyncTracker {
+    pub(crate) fn subscribe(&self, sink: StreamSink<LibsqlSyncEvent>) {
+        self.listeners.lock().unwrap().push(sink);
+    }
+
+    /// Wakes `watcher` after every sync that synced frames, as those don't
+    /// go through the connection's hooks.
+    pub(crate) fn watch(&self, watcher: UnboundedSender<()>) {
+        self.watchers.lock().unwrap().push(watcher);
+    }
+
+    pub(crate) fn status(&self) -> LibsqlSyncStatus {
+        self.status.lock().unwrap().clone()
+    }
+
+    pub(crate) fn synced_frames(&self) -> Option<u32> {
+        *self.synced_frames.lock().unwrap()
+    }
+
+    pub(crate) fn set_synced_frames(&self, frames: Option<u32>) {
+        *self.synced_frames.lock().unwrap() = frames;
+    }
+
+    fn start(&self) {
+        self.status.lock().unwrap().syncing = true;
+    }
+
+    fn finish(&self, trigger: LibsqlSyncTrigger, result: &Result<SyncResult, LibsqlError>) {
+        self.set_synced_frames(None);
+        let event = {
+            let mut status = self.status.lock().unwrap();
+            status.syncing = false;
+            match result {
+                Ok(result) => {
+                    status.last_synced_at = Some(Utc::now());
+                    status.last_result = Some(result.clone());
+                    status.last_error = None;
+                    LibsqlSyncEvent::Synced {
+                        trigger,
+                        result: result.clone(),
+                    }
+                }
+                Err(error) => {
+                    status.last_error = Some(error.clone());
+                    LibsqlSyncEvent::Failed {
+                        trigger,
+                        error: error.clone(),
+                    }
+                }
+            }
+        };
+        // streams cancelled on the Dart side fail to send and are dropped
+        self.listeners
+            .lock()
+            .unwrap()
+            .retain(|sink| sink.add(event.clone()).is_ok());
+        if matches!(result, Ok(result) if result.frames_synced > 0) {
+            self.watchers
+                .lock()
+                .unwrap()
+                .retain(|watcher| watcher.unbounded_send(()).is_ok());
+        }
+    }
+
+    /// Drops every listener, which ends their streams on the Dart side.
+    pub(crate) fn close(&self) {
+        self.listeners.lock().unwrap().clear();
+        self.watchers.lock().unwrap().clear();
+    }
+}
+
+/// Syncs `database` with its remote, until it reaches the replication
+/// index `until` if given, and records the outcome in `tracker`. Gives up
+/// with an `Interrupted` error once `timeout` runs out.
+///
+/// `offline` tells that `database` was opened with `offline`, for which
+/// libsql reports an unreachable remote as a sync of nothing. Pushes and
+/// pulls that reach it always count some frames, so that is turned back
+/// into a `Network` error.
+pub async fn sync(
+    database: &Database,
+    tracker: &SyncTracker,
+    trigger: LibsqlSyncTrigger,
+    until: Option<u64>,
+    timeout: Option<Duration>,
+    offline: bool,
+) -> Result<SyncResult, LibsqlError> {
+    tracker.start();
+    let started = Instant::now();
+    let sync = async {
+        let replicated = match until {
+            Some(replication_index) => database.sync_until(replication_index).await?,
+            None => database.sync().await?,
+        };
+        if offline && replicated.frame_no().is_none() && replicated.frames_synced() == 0 {
+            return Err(LibsqlError::new(
+                LibsqlErrorKind::Network,
+                "could not reach the remote, nothing was synced",
+            ));
+        }
+        // only embedded replicas keep a replication index
+        let replication_index = database.replication_index().await.ok().flatten();
+        Ok::<_, LibsqlError>(SyncResult {
+            frames_synced: replicated.frames_synced() as u64,
+            frame_no: replicated.frame_no(),
+            replication_index,
+            duration_ms: started.elapsed().as_millis() as u64,
+        })
+    };
+    let result = match timeout {
+        Some(timeout) => tokio::time::timeout(timeout, sync)
+            .await
+            .unwrap_or_else(|_| Err(LibsqlError::interrupted())),
+        None => sync.await,
+    };
+    tracker.finish(trigger, &result);
+    result
+}
+
+/// Syncs every `interval` until the database is taken by `close` or the
+/// connection owning `tracker` is dropped.
+pub async fn run_periodic(
+    database: RustAutoOpaqueNom<Option<Database>>,
+    tracker: Weak<SyncTracker>,
+    interval: Duration,
+) {
+    loop {
+        tokio::time::sleep(interval).await;
+        let Some(tracker) = tracker.upgrade() else {
+            return;
+        };
+        let database = database.read().await;
+        let Some(database) = database.as_ref() else {
+            return;
+        };
+        // failures are reported through the tracker, the next tick retries
+        let _ = sync(
+            database,
+            &tracker,
+            LibsqlSyncTrigger::Periodic,
+            None,
+            None,
+            false,
+        )
+        .await;
+    }
+}
+
+/// Generation and last frame of a synced database as libsql records them
+/// in the `-info` file next to it.
+#[derive(Clone, Copy, Deserialize)]
+pub struct SyncPosition {
+    pub generation: u32,
+    pub durable_frame_num: u32,
+}
+
+/// Where a synced database opened with `offline` lives, kept to open it
+/// again from scratch.
+#[derive(Clone)]
+pub struct SyncedReplica {
+    pub path: String,
+    pub sync_url: String,
+    pub auth_token: String,
+}
+
+impl SyncedReplica {
+    pub async fn open(&self) -> libsql::Result<Database> {
+        let connector = hyper_rustls::HttpsConnectorBuilder::new()
+            .with_webpki_roots()
+            .https_or_http()
+            .enable_http1()
+            .build();
+
+        Builder::new_synced_database(
+            self.path.clone(),
+            self.sync_url.clone(),
+            self.auth_token.clone(),
+        )
+        .connector(connector)
+        .build()
+        .await
+    }
+
+    /// Generation and last frame libsql recorded in sync with the remote,
+    /// `None` before the database was first opened.
+    pub fn position(&self) -> Result<Option<SyncPosition>, LibsqlError> {
+        let metadata = match std::fs::read(format!("{}-info", self.path)) {
+            Ok(metadata) => metadata,
+            Err(error) if error.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+            Err(error) => return Err(LibsqlError::new(LibsqlErrorKind::Io, error.to_string())),
+        };
+        serde_json::from_slice(&metadata)
+            .map(Some)
+            .map_err(|error| LibsqlError::new(LibsqlErrorKind::Sync, error.to_string()))
+    }
+
+    /// Where the remote is at right now, found by pulling a scratch copy of
+    /// it next to the database. This downloads the whole database, so it is
+    /// only meant for telling why a push failed.
+    pub async fn remote_position(&self) -> Result<SyncPosition, LibsqlError> {
+        let scratch = SyncedReplica {
+            path: format!("{}{PROBE}", self.path),
+            ..self.clone()
+        };
+        scratch.remove_files()?;
+        let position = async {
+            scratch.open().await?.sync().await?;
+            scratch
+                .position()?
+                .ok_or_else(|| LibsqlError::new(LibsqlErrorKind::Sync, "remote has no generation"))
+        }
+        .await;
+        let _ = scratch.remove_files();
+        position
+    }
+
+    /// Deletes the database and the sync metadata libsql keeps next to it,
+    /// so the next `open` bootstraps from the remote.
+    pub fn remove_files(&self) -> Result<(), LibsqlError> {
+        for suffix in FILES {
+            remove_file(&format!("{}{suffix}", self.path))?;
+        }
+        Ok(())
+    }
+
+    /// Moves the files `remove_files` deletes out of the way instead, for
+    /// `restore_files` to put back. Nothing is moved if it fails.
+    pub fn set_aside_files(&self) -> Result<(), LibsqlError> {
+        for suffix in FILES {
+            remove_file(&format!("{}{suffix}{SET_ASIDE}", self.path))?;
+        }
+        for (idx, suffix) in FILES.iter().enumerate() {
+            let file = format!("{}{suffix}", self.path);
+            if let Err(error) = rename_file(&file, &format!("{file}{SET_ASIDE}")) {
+                for suffix in &FILES[..idx] {
+                    let file = format!("{}{suffix}", self.path);
+                    let _ = rename_file(&format!("{file}{SET_ASIDE}"), &file);
+                }
+                return Err(error);
+            }
+        }
+        Ok(())
+    }
+
+    /// Replaces the files with the ones moved by `set_aside_files`.
+    pub fn restore_files(&self) -> Result<(), LibsqlError> {
+        self.remove_files()?;
+        for suffix in FILES {
+            let file = format!("{}{suffix}", self.path);
+            rename_file(&format!("{file}{SET_ASIDE}"), &file)?;
+        }
+        Ok(())
+    }
+
+    /// Deletes the files moved by `set_aside_files`.
+    pub fn remove_set_aside_files(&self) -> Result<(), LibsqlError> {
+        for suffix in FILES {
+            remove_file(&format!("{}{suffix}{SET_ASIDE}", self.path))?;
+        }
+        Ok(())
+    }
+}
+
+// the database and the files libsql and SQLite keep next to it
+const FILES: [&str; 4] = ["", "-wal", "-shm", "-info"];
+
+const SET_ASIDE: &str = ".conflict";
+
+const PROBE: &str = ".probe";
+
+// both skip files that don't exist
+fn remove_file(path: &str) -> Result<(), LibsqlError> {
+    ignore_missing(std::fs::remove_file(path))
+}
+
+fn rename_file(from: &str, to: &str) -> Result<(), LibsqlError> {
+    ignore_missing(std::fs::rename(from, to))
+}
+
+fn ignore_missing(result: std::io::Result<()>) -> Result<(), LibsqlError> {
+    match result {
+        Err(error) if error.kind() != std::io::ErrorKind::NotFound => {
+            Err(LibsqlError::new(LibsqlErrorKind::Io, error.to_string()))
+        }
+        _ => Ok(()),
+    }
+}
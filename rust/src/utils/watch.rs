@@ -0,0 +1,109 @@
+use std::{
+    collections::{HashMap, HashSet},
+    future::Future,
+    time::Duration,
+};
+
+use flutter_rust_bridge::RustAutoOpaqueNom;
+use futures::{
+    channel::mpsc::{UnboundedReceiver, UnboundedSender},
+    StreamExt,
+};
+use libsql::Connection;
+
+use super::{
+    changes::{LibsqlUpdate, Listener},
+    helpers::query_connection,
+    params::LibsqlParams,
+    result::QueryResult,
+    return_value::{DecodeOptions, LibsqlReturnValue},
+};
+use crate::frb_generated::StreamSink;
+
+type Rows = Vec<HashMap<String, LibsqlReturnValue>>;
+
+pub const DEFAULT_DEBOUNCE: Duration = Duration::from_millis(50);
+
+/// A query re-run by `LibsqlConnection::watch`.
+pub struct WatchedQuery {
+    pub sql: String,
+    pub parameters: Option<LibsqlParams>,
+    pub decoding: DecodeOptions,
+    pub debounce: Duration,
+}
+
+/// Listener that wakes the watch loop through `wake` on changes to
+/// `tables`, or on any change when the tables are unknown.
+pub fn change_listener(tables: Option<HashSet<String>>, wake: UnboundedSender<()>) -> Listener {
+    Box::new(move |updates: &[LibsqlUpdate]| {
+        let relevant = match &tables {
+            Some(tables) => updates
+                .iter()
+                .any(|update| tables.contains(&update.table.to_lowercase())),
+            None => true,
+        };
+        if relevant {
+            wake.unbounded_send(()).is_ok()
+        } else {
+            !wake.is_closed()
+        }
+    })
+}
+
+/// Runs `query` and again after every burst of changes, until the Dart
+/// stream is cancelled or the connection is closed. Each run takes the
+/// connection from `checkout`, keeping what comes with it until the run is
+/// done, and the watch ends once `checkout` has none left.
+pub async fn run<G, F>(
+    query: WatchedQuery,
+    checkout: impl Fn() -> F,
+    mut changes: UnboundedReceiver<()>,
+    sink: StreamSink<QueryResult>,
+) where
+    F: Future<Output = Option<(G, RustAutoOpaqueNom<Option<Connection>>)>>,
+{
+    let mut last: Option<(Vec<String>, Rows)> = None;
+    loop {
+        let Some((checked_out, connection)) = checkout().await else {
+            return;
+        };
+        // held while the query runs, so the connection is not swapped out
+        // or closed under it. Waits as long as it takes, as there is no
+        // caller to report a lock timeout to.
+        let guard = connection.read().await;
+        let Some(conn) = guard.as_ref() else {
+            return;
+        };
+        let sent = match query_connection(
+            conn,
+            &query.sql,
+            query.parameters.clone(),
+            query.decoding,
+        )
+        .await
+        {
+            Ok(result) if unchanged(&last, &result) => true,
+            Ok(result) => {
+                last = Some((result.columns.clone(), result.rows.clone()));
+                sink.add(result).is_ok()
+            }
+            Err(error) => sink.add_error(error).is_ok(),
+        };
+        drop(guard);
+        drop(checked_out);
+        if !sent {
+            return;
+        }
+        if changes.next().await.is_none() {
+            return;
+        }
+        // let a burst of changes settle before running the query again
+        tokio::time::sleep(query.debounce).await;
+        while changes.try_recv().is_ok() {}
+    }
+}
+
+fn unchanged(last: &Option<(Vec<String>, Rows)>, result: &QueryResult) -> bool {
+    last.as_ref()
+        .is_some_and(|(columns, rows)| *columns == result.columns && *rows == result.rows)
+}